@@ -125,6 +125,9 @@ pub fn bench_get_entity_by_id(
                             None,
                         ),
                     },
+                    sorting: Vec::new(),
+                    cursor: None,
+                    limit: None,
                 })
                 .await
                 .expect("failed to read entity from store");
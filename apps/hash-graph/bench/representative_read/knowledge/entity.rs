@@ -50,6 +50,9 @@ pub fn bench_get_entity_by_id(
                         pinned: PinnedTemporalAxisUnresolved::new(None),
                         variable: VariableTemporalAxisUnresolved::new(None, None),
                     },
+                    sorting: Vec::new(),
+                    cursor: None,
+                    limit: None,
                 })
                 .await
                 .expect("failed to read entity from store");
@@ -90,6 +93,9 @@ pub fn bench_get_entities_by_property(
                         None,
                     ),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await
             .expect("failed to read entity from store");
@@ -132,6 +138,9 @@ pub fn bench_get_link_by_target_by_property(
                         None,
                     ),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await
             .expect("failed to read entity from store");
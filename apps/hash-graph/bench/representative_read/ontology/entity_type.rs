@@ -43,6 +43,9 @@ pub fn bench_get_entity_type_by_id(
                             None,
                         ),
                     },
+                    sorting: Vec::new(),
+                    cursor: None,
+                    limit: None,
                 })
                 .await
                 .expect("failed to read entity type from store");
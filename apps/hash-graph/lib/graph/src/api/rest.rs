@@ -65,7 +65,11 @@ use crate::{
         OntologyTypeReference, Selector,
     },
     provenance::{OwnedById, ProvenanceMetadata, RecordCreatedById},
-    store::{error::VersionedUrlAlreadyExists, QueryError, Store, StorePool, TypeFetcher},
+    store::{
+        error::VersionedUrlAlreadyExists,
+        query::{Cursor, SortDirection},
+        QueryError, Store, StorePool, TypeFetcher,
+    },
     subgraph::{
         edges::{
             EdgeResolveDepths, GraphResolveDepths, KnowledgeGraphEdgeKind, OntologyEdgeKind,
@@ -277,6 +281,9 @@ async fn serve_static_schema(Path(path): Path<String>) -> Result<Response, Statu
             TransactionTime,
             QueryTemporalAxes,
             QueryTemporalAxesUnresolved,
+
+            Cursor,
+            SortDirection,
         )
     ),
 )]
@@ -490,6 +497,32 @@ impl Modify for FilterSchemaAddon {
             }
         }
 
+        let path = || {
+            ArrayBuilder::new().items(
+                OneOfBuilder::new()
+                    .item(Ref::from_schema_name("DataTypeQueryToken"))
+                    .item(Ref::from_schema_name("PropertyTypeQueryToken"))
+                    .item(Ref::from_schema_name("EntityTypeQueryToken"))
+                    .item(Ref::from_schema_name("EntityQueryToken"))
+                    .item(Ref::from_schema_name("Selector"))
+                    .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                    .item(ObjectBuilder::new().schema_type(SchemaType::Number)),
+            )
+        };
+
+        let binary_filter = |title: &str, operator: &str| {
+            ObjectBuilder::new()
+                .title(Some(title))
+                .property(
+                    operator,
+                    ArrayBuilder::new()
+                        .items(Ref::from_schema_name("FilterExpression"))
+                        .min_items(Some(2))
+                        .max_items(Some(2)),
+                )
+                .required(operator)
+        };
+
         if let Some(ref mut components) = openapi.components {
             components.schemas.insert(
                 "Filter".to_owned(),
@@ -519,65 +552,51 @@ impl Modify for FilterSchemaAddon {
                                 .property("not", Ref::from_schema_name("Filter"))
                                 .required("not"),
                         )
+                        .item(binary_filter("EqualFilter", "equal"))
+                        .item(binary_filter("NotEqualFilter", "notEqual"))
+                        .item(binary_filter("LessFilter", "less"))
+                        .item(binary_filter("LessOrEqualFilter", "lessOrEqual"))
+                        .item(binary_filter("GreaterFilter", "greater"))
+                        .item(binary_filter("GreaterOrEqualFilter", "greaterOrEqual"))
                         .item(
                             ObjectBuilder::new()
-                                .title(Some("EqualFilter"))
-                                .property(
-                                    "equal",
-                                    ArrayBuilder::new()
-                                        .items(Ref::from_schema_name("FilterExpression"))
-                                        .min_items(Some(2))
-                                        .max_items(Some(2)),
-                                )
-                                .required("equal"),
-                        )
-                        .item(
-                            ObjectBuilder::new()
-                                .title(Some("NotEqualFilter"))
-                                .property(
-                                    "notEqual",
-                                    ArrayBuilder::new()
-                                        .items(Ref::from_schema_name("FilterExpression"))
-                                        .min_items(Some(2))
-                                        .max_items(Some(2)),
-                                )
-                                .required("notEqual"),
+                                .title(Some("ExistsFilter"))
+                                .property("exists", Ref::from_schema_name("FilterExpression"))
+                                .required("exists"),
                         )
                         .item(
                             ObjectBuilder::new()
-                                .title(Some("StartsWithFilter"))
-                                .property(
-                                    "startsWith",
-                                    ArrayBuilder::new()
-                                        .items(Ref::from_schema_name("FilterExpression"))
-                                        .min_items(Some(2))
-                                        .max_items(Some(2)),
-                                )
-                                .required("startsWith"),
+                                .title(Some("IsNullFilter"))
+                                .property("isNull", Ref::from_schema_name("FilterExpression"))
+                                .required("isNull"),
                         )
                         .item(
                             ObjectBuilder::new()
-                                .title(Some("EndsWithFilter"))
+                                .title(Some("InFilter"))
                                 .property(
-                                    "endsWith",
+                                    "in",
                                     ArrayBuilder::new()
-                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .items(
+                                            OneOfBuilder::new()
+                                                .item(Ref::from_schema_name("FilterExpression"))
+                                                .item(ArrayBuilder::new().items(Any::schema().1)),
+                                        )
                                         .min_items(Some(2))
                                         .max_items(Some(2)),
                                 )
-                                .required("endsWith"),
+                                .required("in"),
                         )
+                        .item(binary_filter("StartsWithFilter", "startsWith"))
+                        .item(binary_filter("EndsWithFilter", "endsWith"))
+                        .item(binary_filter("ContainsSegmentFilter", "containsSegment"))
+                        .item(binary_filter("ContainsFilter", "contains"))
+                        .item(binary_filter("SearchFilter", "search"))
+                        .item(binary_filter("FuzzySearchFilter", "fuzzySearch"))
                         .item(
                             ObjectBuilder::new()
-                                .title(Some("ContainsSegmentFilter"))
-                                .property(
-                                    "containsSegment",
-                                    ArrayBuilder::new()
-                                        .items(Ref::from_schema_name("FilterExpression"))
-                                        .min_items(Some(2))
-                                        .max_items(Some(2)),
-                                )
-                                .required("containsSegment"),
+                                .title(Some("CaseInsensitiveFilter"))
+                                .property("caseInsensitive", Ref::from_schema_name("Filter"))
+                                .required("caseInsensitive"),
                         )
                         .build(),
                 )
@@ -590,25 +609,7 @@ impl Modify for FilterSchemaAddon {
                         .item(
                             ObjectBuilder::new()
                                 .title(Some("PathExpression"))
-                                .property(
-                                    "path",
-                                    ArrayBuilder::new().items(
-                                        OneOfBuilder::new()
-                                            .item(Ref::from_schema_name("DataTypeQueryToken"))
-                                            .item(Ref::from_schema_name("PropertyTypeQueryToken"))
-                                            .item(Ref::from_schema_name("EntityTypeQueryToken"))
-                                            .item(Ref::from_schema_name("EntityQueryToken"))
-                                            .item(Ref::from_schema_name("Selector"))
-                                            .item(
-                                                ObjectBuilder::new()
-                                                    .schema_type(SchemaType::String),
-                                            )
-                                            .item(
-                                                ObjectBuilder::new()
-                                                    .schema_type(SchemaType::Number),
-                                            ),
-                                    ),
-                                )
+                                .property("path", path())
                                 .required("path"),
                         )
                         .item(
@@ -621,6 +622,72 @@ impl Modify for FilterSchemaAddon {
                 )
                 .into(),
             );
+            components.schemas.insert(
+                "Grouping".to_owned(),
+                ObjectBuilder::new()
+                    .property("path", path())
+                    .required("path")
+                    .into(),
+            );
+            components.schemas.insert(
+                "Aggregation".to_owned(),
+                schema::Schema::OneOf(
+                    OneOfBuilder::new()
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("CountAggregation"))
+                                .property(
+                                    "function",
+                                    ObjectBuilder::new()
+                                        .schema_type(SchemaType::String)
+                                        .enum_values(Some(["count"])),
+                                )
+                                .required("function")
+                                .property("path", path()),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("NumericAggregation"))
+                                .property(
+                                    "function",
+                                    ObjectBuilder::new()
+                                        .schema_type(SchemaType::String)
+                                        .enum_values(Some(["min", "max", "sum", "average"])),
+                                )
+                                .required("function")
+                                .property("path", path())
+                                .required("path"),
+                        )
+                        .build(),
+                )
+                .into(),
+            );
+            components.schemas.insert(
+                "Sorting".to_owned(),
+                ObjectBuilder::new()
+                    .property("path", path())
+                    .required("path")
+                    .property("direction", Ref::from_schema_name("SortDirection"))
+                    .property(
+                        "relevance",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::String)
+                            .description(Some(
+                                "Orders by the relevance of the value at `path` for this \
+                                 full-text search query instead of the value itself.",
+                            )),
+                    )
+                    .property(
+                        "fuzzy",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::Boolean)
+                            .description(Some(
+                                "Uses the similarity to the `relevance` text as relevance, as \
+                                 used by a fuzzy search. Requires `relevance` to be set.",
+                            )),
+                    )
+                    .into(),
+            );
         }
     }
 }
@@ -1,26 +1,36 @@
 //! Web routes for CRU operations on entities.
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
-use axum::{http::StatusCode, routing::post, Extension, Router};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+    Extension, Router,
+};
+use error_stack::Report;
 use futures::TryFutureExt;
 use serde::{Deserialize, Serialize};
 use type_system::url::VersionedUrl;
 use utoipa::{OpenApi, ToSchema};
 
 use crate::{
-    api::rest::{
-        api_resource::RoutedResource, json::Json, report_to_status_code,
-        utoipa_typedef::subgraph::Subgraph,
+    api::{
+        error::{ErrorInfo, Status, StatusPayloads},
+        rest::{
+            api_resource::RoutedResource, json::Json, report_to_status_code,
+            status::status_to_response, utoipa_typedef::subgraph::Subgraph,
+        },
     },
     identifier::knowledge::{EntityEditionId, EntityId, EntityRecordId, EntityTemporalMetadata},
     knowledge::{
         Entity, EntityLinkOrder, EntityMetadata, EntityProperties, EntityQueryToken, EntityUuid,
-        LinkData, LinkOrder,
+        EntityValidationError, LinkData, LinkOrder, PropertyValidationError,
     },
     provenance::{OwnedById, RecordCreatedById},
     store::{
         error::{EntityDoesNotExist, RaceConditionOnUpdate},
+        query::{AggregationGroup, AggregationQuery, EntityAggregationQuery},
         EntityStore, StorePool,
     },
     subgraph::query::{EntityStructuralQuery, StructuralQuery},
@@ -31,6 +41,7 @@ use crate::{
     paths(
         create_entity,
         get_entities_by_query,
+        aggregate_entities,
         update_entity,
     ),
     components(
@@ -39,6 +50,8 @@ use crate::{
             UpdateEntityRequest,
             EntityQueryToken,
             EntityStructuralQuery,
+            EntityAggregationQuery,
+            AggregationGroup,
 
             Entity,
             EntityUuid,
@@ -68,7 +81,8 @@ impl RoutedResource for EntityResource {
             "/entities",
             Router::new()
                 .route("/", post(create_entity::<P>).put(update_entity::<P>))
-                .route("/query", post(get_entities_by_query::<P>)),
+                .route("/query", post(get_entities_by_query::<P>))
+                .route("/aggregate", post(aggregate_entities::<P>)),
         )
     }
 }
@@ -90,6 +104,33 @@ struct CreateEntityRequest {
     link_data: Option<LinkData>,
 }
 
+/// Lists every [`PropertyValidationError`] attached to the report in the response body.
+///
+/// The status is reported as `INVALID_ARGUMENT`, but the HTTP status code stays `422`, as the
+/// request itself was well-formed.
+fn validation_error_response<C>(report: &Report<C>) -> Response {
+    let mut errors = report
+        .frames()
+        .filter_map(|frame| frame.downcast_ref::<PropertyValidationError>())
+        .collect::<Vec<_>>();
+    // attachments are stored in reverse order
+    errors.reverse();
+
+    let mut response = status_to_response(Status::new(
+        hash_status::StatusCode::InvalidArgument,
+        Some("The entity properties are not valid with respect to the entity type.".to_owned()),
+        vec![StatusPayloads::ErrorInfo(ErrorInfo::new(
+            HashMap::from([(
+                "validationErrors".to_owned(),
+                serde_json::to_value(errors).expect("Could not serialize validation errors"),
+            )]),
+            "INVALID_ENTITY_PROPERTIES".to_owned(),
+        ))],
+    ));
+    *response.status_mut() = StatusCode::UNPROCESSABLE_ENTITY;
+    response
+}
+
 #[utoipa::path(
     post,
     path = "/entities",
@@ -97,7 +138,8 @@ struct CreateEntityRequest {
     tag = "Entity",
     responses(
         (status = 200, content_type = "application/json", description = "The metadata of the created entity", body = EntityMetadata),
-        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 400, content_type = "application/json", description = "Provided request body is invalid", body = VAR_STATUS),
+        (status = 422, content_type = "application/json", description = "The properties are not valid with respect to the entity type, every violation is listed in `validationErrors`", body = VAR_STATUS),
 
         (status = 404, description = "Entity Type URL was not found"),
         (status = 500, description = "Store error occurred"),
@@ -107,7 +149,7 @@ struct CreateEntityRequest {
 async fn create_entity<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
     body: Json<CreateEntityRequest>,
-) -> Result<Json<EntityMetadata>, StatusCode> {
+) -> Result<Json<EntityMetadata>, Response> {
     let Json(CreateEntityRequest {
         properties,
         entity_type_id,
@@ -119,7 +161,7 @@ async fn create_entity<P: StorePool + Send>(
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     store
@@ -137,8 +179,12 @@ async fn create_entity<P: StorePool + Send>(
         .map_err(|report| {
             tracing::error!(error=?report, "Could not create entity");
 
+            if report.contains::<EntityValidationError>() {
+                return validation_error_response(&report);
+            }
+
             // Insertion/update errors are considered internal server errors.
-            StatusCode::INTERNAL_SERVER_ERROR
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
         })
         .map(Json)
 }
@@ -182,6 +228,45 @@ async fn get_entities_by_query<P: StorePool + Send>(
         .map(|subgraph| Json(subgraph.into()))
 }
 
+#[utoipa::path(
+    post,
+    path = "/entities/aggregate",
+    request_body = EntityAggregationQuery,
+    tag = "Entity",
+    responses(
+        (status = 200, content_type = "application/json", body = [AggregationGroup], description = "The aggregations for every group of entities that satisfy the given query"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(pool))]
+async fn aggregate_entities<P: StorePool + Send>(
+    pool: Extension<Arc<P>>,
+    Json(query): Json<serde_json::Value>,
+) -> Result<Json<Vec<AggregationGroup>>, StatusCode> {
+    pool.acquire()
+        .map_err(|error| {
+            tracing::error!(?error, "Could not acquire access to the store");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+        .and_then(|store| async move {
+            let mut query = AggregationQuery::deserialize(&query).map_err(|error| {
+                tracing::error!(?error, "Could not deserialize query");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            query.filter.convert_parameters().map_err(|error| {
+                tracing::error!(?error, "Could not validate query");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            store.aggregate_entities(&query).await.map_err(|report| {
+                tracing::error!(error=?report, ?query, "Could not aggregate entities");
+                report_to_status_code(&report)
+            })
+        })
+        .await
+        .map(Json)
+}
+
 #[derive(Debug, ToSchema, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct UpdateEntityRequest {
@@ -201,7 +286,8 @@ struct UpdateEntityRequest {
     tag = "Entity",
     responses(
         (status = 200, content_type = "application/json", description = "The metadata of the updated entity", body = EntityMetadata),
-        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 400, content_type = "application/json", description = "Provided request body is invalid", body = VAR_STATUS),
+        (status = 422, content_type = "application/json", description = "The properties are not valid with respect to the entity type, every violation is listed in `validationErrors`", body = VAR_STATUS),
         (status = 423, content_type = "text/plain", description = "The entity that should be updated was unexpectedly updated at the same time"),
 
         (status = 404, description = "Entity ID or Entity Type URL was not found"),
@@ -213,7 +299,7 @@ struct UpdateEntityRequest {
 async fn update_entity<P: StorePool + Send>(
     pool: Extension<Arc<P>>,
     body: Json<UpdateEntityRequest>,
-) -> Result<Json<EntityMetadata>, StatusCode> {
+) -> Result<Json<EntityMetadata>, Response> {
     let Json(UpdateEntityRequest {
         properties,
         entity_id,
@@ -225,7 +311,7 @@ async fn update_entity<P: StorePool + Send>(
 
     let mut store = pool.acquire().await.map_err(|report| {
         tracing::error!(error=?report, "Could not acquire store");
-        StatusCode::INTERNAL_SERVER_ERROR
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
     })?;
 
     store
@@ -243,12 +329,14 @@ async fn update_entity<P: StorePool + Send>(
             tracing::error!(error=?report, "Could not update entity");
 
             if report.contains::<EntityDoesNotExist>() {
-                StatusCode::NOT_FOUND
+                StatusCode::NOT_FOUND.into_response()
             } else if report.contains::<RaceConditionOnUpdate>() {
-                StatusCode::LOCKED
+                StatusCode::LOCKED.into_response()
+            } else if report.contains::<EntityValidationError>() {
+                validation_error_response(&report)
             } else {
                 // Insertion/update errors are considered internal server errors.
-                StatusCode::INTERNAL_SERVER_ERROR
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
             }
         })
         .map(Json)
@@ -14,9 +14,12 @@ pub use self::{
         OntologyVertices, Vertex, Vertices,
     },
 };
-use crate::subgraph::{
-    edges::GraphResolveDepths, identifier::GraphElementVertexId,
-    temporal_axes::SubgraphTemporalAxes,
+use crate::{
+    store::query::Cursor,
+    subgraph::{
+        edges::GraphResolveDepths, identifier::GraphElementVertexId,
+        temporal_axes::SubgraphTemporalAxes,
+    },
 };
 
 #[derive(Serialize, ToSchema)]
@@ -27,16 +30,20 @@ pub struct Subgraph {
     edges: Edges,
     depths: GraphResolveDepths,
     temporal_axes: SubgraphTemporalAxes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    cursor: Option<Cursor>,
 }
 
 impl From<crate::subgraph::Subgraph> for Subgraph {
     fn from(subgraph: crate::subgraph::Subgraph) -> Self {
         Self {
-            roots: subgraph.roots.into_iter().collect(),
+            roots: subgraph.roots,
             vertices: subgraph.vertices.into(),
             edges: subgraph.edges.into(),
             depths: subgraph.depths,
             temporal_axes: subgraph.temporal_axes,
+            cursor: subgraph.cursor,
         }
     }
 }
@@ -5,5 +5,6 @@ mod entity;
 
 pub use self::entity::{
     Entity, EntityLinkOrder, EntityMetadata, EntityProperties, EntityQueryPath,
-    EntityQueryPathVisitor, EntityQueryToken, EntityUuid, LinkData, LinkOrder,
+    EntityQueryPathVisitor, EntityQueryToken, EntityUuid, EntityValidationError, EntityValidator,
+    LinkData, LinkOrder, PropertyPath, PropertyPathElement, PropertyValidationError,
 };
@@ -1,4 +1,5 @@
 mod query;
+mod validation;
 
 use std::{collections::HashMap, error::Error, fmt};
 
@@ -10,7 +11,13 @@ use type_system::url::{BaseUrl, VersionedUrl};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-pub use self::query::{EntityQueryPath, EntityQueryPathVisitor, EntityQueryToken};
+pub use self::{
+    query::{EntityQueryPath, EntityQueryPathVisitor, EntityQueryToken},
+    validation::{
+        EntityValidationError, EntityValidator, PropertyPath, PropertyPathElement,
+        PropertyValidationError,
+    },
+};
 use crate::{
     identifier::{
         knowledge::{EntityId, EntityRecordId, EntityTemporalMetadata},
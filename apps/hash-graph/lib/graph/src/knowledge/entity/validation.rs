@@ -0,0 +1,772 @@
+//! Validation of [`EntityProperties`] against the [`EntityType`] of an entity.
+//!
+//! The [`EntityType`] is resolved with the closure of every [`EntityType`] it inherits from and
+//! every [`PropertyType`] and [`DataType`] it references.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt,
+};
+
+use error_stack::{Context, Report, Result};
+use serde::Serialize;
+use serde_json::Value;
+use type_system::{
+    url::{BaseUrl, VersionedUrl},
+    Array, DataType, EntityType, Object, PropertyType, PropertyTypeReference, PropertyValues,
+    ValueOrArray,
+};
+
+use crate::knowledge::EntityProperties;
+
+#[derive(Debug)]
+#[must_use]
+pub struct EntityValidationError;
+
+impl fmt::Display for EntityValidationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The entity properties are not valid with respect to the entity type")
+    }
+}
+
+impl Context for EntityValidationError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum PropertyPathElement {
+    Property(String),
+    Index(usize),
+}
+
+/// The location of a value inside of [`EntityProperties`], starting at the root object.
+///
+/// Displayed as JSON path, e.g. `$["https://example.com/property-type/name/"][0]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct PropertyPath(Vec<PropertyPathElement>);
+
+impl PropertyPath {
+    #[must_use]
+    pub fn elements(&self) -> &[PropertyPathElement] {
+        &self.0
+    }
+
+    fn property(&self, key: &str) -> Self {
+        let mut path = self.clone();
+        path.0.push(PropertyPathElement::Property(key.to_owned()));
+        path
+    }
+
+    fn index(&self, index: usize) -> Self {
+        let mut path = self.clone();
+        path.0.push(PropertyPathElement::Index(index));
+        path
+    }
+}
+
+impl fmt::Display for PropertyPath {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("$")?;
+
+        for element in &self.0 {
+            match element {
+                PropertyPathElement::Property(key) => write!(fmt, "[{key:?}]")?,
+                PropertyPathElement::Index(index) => write!(fmt, "[{index}]")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A single violation of the [`EntityType`], every violation is attached to the
+/// [`EntityValidationError`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "reason", rename_all = "camelCase")]
+pub enum PropertyValidationError {
+    #[serde(rename_all = "camelCase")]
+    MissingRequiredProperty { path: PropertyPath },
+    #[serde(rename_all = "camelCase")]
+    UnexpectedProperty { path: PropertyPath },
+    #[serde(rename_all = "camelCase")]
+    InvalidType {
+        path: PropertyPath,
+        expected: String,
+        actual: &'static str,
+    },
+    #[serde(rename_all = "camelCase")]
+    InvalidConstant { path: PropertyPath, expected: Value },
+    #[serde(rename_all = "camelCase")]
+    TooFewItems {
+        path: PropertyPath,
+        min_items: usize,
+        actual: usize,
+    },
+    #[serde(rename_all = "camelCase")]
+    TooManyItems {
+        path: PropertyPath,
+        max_items: usize,
+        actual: usize,
+    },
+    /// The value does not match any of the possible values of the property type.
+    #[serde(rename_all = "camelCase")]
+    NoMatchingValue { path: PropertyPath },
+    /// A type in the closure of the entity type could not be resolved.
+    #[serde(rename_all = "camelCase")]
+    UnknownType { path: PropertyPath, url: String },
+}
+
+impl PropertyValidationError {
+    #[must_use]
+    pub const fn path(&self) -> &PropertyPath {
+        match self {
+            Self::MissingRequiredProperty { path }
+            | Self::UnexpectedProperty { path }
+            | Self::InvalidType { path, .. }
+            | Self::InvalidConstant { path, .. }
+            | Self::TooFewItems { path, .. }
+            | Self::TooManyItems { path, .. }
+            | Self::NoMatchingValue { path }
+            | Self::UnknownType { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for PropertyValidationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "`{}`: ", self.path())?;
+
+        match self {
+            Self::MissingRequiredProperty { .. } => fmt.write_str("required property is missing"),
+            Self::UnexpectedProperty { .. } => {
+                fmt.write_str("property is not specified by the type")
+            }
+            Self::InvalidType {
+                expected, actual, ..
+            } => write!(fmt, "expected a value of type `{expected}`, got `{actual}`"),
+            Self::InvalidConstant { expected, .. } => {
+                write!(fmt, "expected the value `{expected}`")
+            }
+            Self::TooFewItems {
+                min_items, actual, ..
+            } => write!(fmt, "expected at least {min_items} items, got {actual}"),
+            Self::TooManyItems {
+                max_items, actual, ..
+            } => write!(fmt, "expected at most {max_items} items, got {actual}"),
+            Self::NoMatchingValue { .. } => {
+                fmt.write_str("value does not match any of the values of the property type")
+            }
+            Self::UnknownType { url, .. } => write!(fmt, "type `{url}` could not be resolved"),
+        }
+    }
+}
+
+const fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Validates [`EntityProperties`] against an [`EntityType`].
+///
+/// Every type in the closure of the entity type has to be added before calling
+/// [`validate`](Self::validate), a type which is missing is reported as
+/// [`PropertyValidationError::UnknownType`].
+#[derive(Debug)]
+pub struct EntityValidator {
+    entity_type_id: VersionedUrl,
+    entity_types: HashMap<VersionedUrl, EntityType>,
+    property_types: HashMap<VersionedUrl, PropertyType>,
+    data_types: HashMap<VersionedUrl, DataType>,
+}
+
+impl EntityValidator {
+    #[must_use]
+    pub fn new(entity_type_id: &VersionedUrl) -> Self {
+        Self {
+            entity_type_id: entity_type_id.clone(),
+            entity_types: HashMap::new(),
+            property_types: HashMap::new(),
+            data_types: HashMap::new(),
+        }
+    }
+
+    /// Adds an [`EntityType`] to the closure of the validated entity type.
+    pub fn add_entity_type(&mut self, entity_type: EntityType) {
+        self.entity_types
+            .insert(entity_type.id().clone(), entity_type);
+    }
+
+    /// Adds a [`PropertyType`] to the closure of the validated entity type.
+    pub fn add_property_type(&mut self, property_type: PropertyType) {
+        self.property_types
+            .insert(property_type.id().clone(), property_type);
+    }
+
+    /// Adds a [`DataType`] to the closure of the validated entity type.
+    pub fn add_data_type(&mut self, data_type: DataType) {
+        self.data_types.insert(data_type.id().clone(), data_type);
+    }
+
+    /// Validates the properties of an entity.
+    ///
+    /// # Errors
+    ///
+    /// - if the properties are not valid with respect to the entity type, every violation is
+    ///   attached as [`PropertyValidationError`]
+    pub fn validate(&self, properties: &EntityProperties) -> Result<(), EntityValidationError> {
+        let root = PropertyPath::default();
+        let mut errors = Vec::new();
+
+        let mut schema = Properties::default();
+        let mut visited = HashSet::new();
+        self.collect_entity_type(&self.entity_type_id, &mut schema, &mut visited, &mut errors);
+
+        let values = properties
+            .properties()
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+            .collect();
+        self.validate_object(&schema, &values, &root, &mut errors);
+
+        let mut errors = errors.into_iter();
+        match errors.next() {
+            None => Ok(()),
+            Some(first) => Err(errors.fold(
+                Report::new(EntityValidationError).attach(first),
+                Report::attach,
+            )),
+        }
+    }
+
+    /// Merges the properties of the entity type with the properties of every entity type it
+    /// inherits from.
+    fn collect_entity_type<'s>(
+        &'s self,
+        url: &'s VersionedUrl,
+        schema: &mut Properties<'s>,
+        visited: &mut HashSet<&'s VersionedUrl>,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        if !visited.insert(url) {
+            return;
+        }
+
+        let Some(entity_type) = self.entity_types.get(url) else {
+            errors.push(PropertyValidationError::UnknownType {
+                path: PropertyPath::default(),
+                url: url.to_string(),
+            });
+            return;
+        };
+
+        schema.extend(entity_type.properties(), entity_type.required());
+
+        for parent in entity_type.inherits_from().all_of() {
+            self.collect_entity_type(parent.url(), schema, visited, errors);
+        }
+    }
+
+    fn validate_object(
+        &self,
+        schema: &Properties<'_>,
+        values: &BTreeMap<&str, &Value>,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        for required in &schema.required {
+            if !values.contains_key(required) {
+                errors.push(PropertyValidationError::MissingRequiredProperty {
+                    path: path.property(required),
+                });
+            }
+        }
+
+        for (key, value) in values {
+            let path = path.property(key);
+
+            match schema.properties.get(key) {
+                None => errors.push(PropertyValidationError::UnexpectedProperty { path }),
+                Some(ValueOrArray::Value(reference)) => {
+                    self.validate_property_type(reference.url(), value, &path, errors);
+                }
+                Some(ValueOrArray::Array(array)) => {
+                    if let Some(items) = validate_array(array, value, &path, errors) {
+                        for (index, item) in items.iter().enumerate() {
+                            self.validate_property_type(
+                                array.items().url(),
+                                item,
+                                &path.index(index),
+                                errors,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_property_type(
+        &self,
+        url: &VersionedUrl,
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        match self.property_types.get(url) {
+            Some(property_type) => {
+                self.validate_one_of(property_type.one_of(), value, path, errors);
+            }
+            None => errors.push(PropertyValidationError::UnknownType {
+                path: path.clone(),
+                url: url.to_string(),
+            }),
+        }
+    }
+
+    /// A value is valid if it matches any of the possible values.
+    ///
+    /// If there is only a single possible value, its errors are reported directly, as they are
+    /// more descriptive than [`PropertyValidationError::NoMatchingValue`].
+    fn validate_one_of(
+        &self,
+        one_of: &[PropertyValues],
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        if let [property_values] = one_of {
+            self.validate_property_values(property_values, value, path, errors);
+            return;
+        }
+
+        let is_valid = one_of.iter().any(|property_values| {
+            let mut variant_errors = Vec::new();
+            self.validate_property_values(property_values, value, path, &mut variant_errors);
+            variant_errors.is_empty()
+        });
+
+        if !is_valid {
+            errors.push(PropertyValidationError::NoMatchingValue { path: path.clone() });
+        }
+    }
+
+    fn validate_property_values(
+        &self,
+        property_values: &PropertyValues,
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        match property_values {
+            PropertyValues::DataTypeReference(reference) => {
+                self.validate_data_type(reference.url(), value, path, errors);
+            }
+            PropertyValues::ArrayOfPropertyValues(array) => {
+                if let Some(items) = validate_array(array, value, path, errors) {
+                    for (index, item) in items.iter().enumerate() {
+                        self.validate_one_of(
+                            array.items().one_of(),
+                            item,
+                            &path.index(index),
+                            errors,
+                        );
+                    }
+                }
+            }
+            PropertyValues::PropertyTypeObject(object) => {
+                let Value::Object(values) = value else {
+                    errors.push(PropertyValidationError::InvalidType {
+                        path: path.clone(),
+                        expected: "object".to_owned(),
+                        actual: json_type(value),
+                    });
+                    return;
+                };
+
+                let mut schema = Properties::default();
+                schema.extend_object(object);
+
+                let values = values
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value))
+                    .collect();
+                self.validate_object(&schema, &values, path, errors);
+            }
+        }
+    }
+
+    fn validate_data_type(
+        &self,
+        url: &VersionedUrl,
+        value: &Value,
+        path: &PropertyPath,
+        errors: &mut Vec<PropertyValidationError>,
+    ) {
+        let Some(data_type) = self.data_types.get(url) else {
+            errors.push(PropertyValidationError::UnknownType {
+                path: path.clone(),
+                url: url.to_string(),
+            });
+            return;
+        };
+
+        if data_type.json_type() != json_type(value) {
+            errors.push(PropertyValidationError::InvalidType {
+                path: path.clone(),
+                expected: data_type.json_type().to_owned(),
+                actual: json_type(value),
+            });
+        } else if let Some(expected) = data_type.additional_properties().get("const") {
+            if expected != value {
+                errors.push(PropertyValidationError::InvalidConstant {
+                    path: path.clone(),
+                    expected: expected.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// The properties of an object, which may be merged from multiple entity types.
+#[derive(Default)]
+struct Properties<'s> {
+    properties: BTreeMap<&'s str, &'s ValueOrArray<PropertyTypeReference>>,
+    required: BTreeSet<&'s str>,
+}
+
+impl<'s> Properties<'s> {
+    fn extend(
+        &mut self,
+        properties: &'s HashMap<BaseUrl, ValueOrArray<PropertyTypeReference>>,
+        required: &'s HashSet<BaseUrl>,
+    ) {
+        self.properties
+            .extend(properties.iter().map(|(key, value)| (key.as_str(), value)));
+        self.required.extend(required.iter().map(BaseUrl::as_str));
+    }
+
+    fn extend_object(&mut self, object: &'s Object<ValueOrArray<PropertyTypeReference>>) {
+        self.extend(object.properties(), object.required());
+    }
+}
+
+/// Checks the type and the number of items of an array, returns the items if it is an array.
+fn validate_array<'v, T>(
+    schema: &Array<T>,
+    value: &'v Value,
+    path: &PropertyPath,
+    errors: &mut Vec<PropertyValidationError>,
+) -> Option<&'v [Value]> {
+    let Value::Array(items) = value else {
+        errors.push(PropertyValidationError::InvalidType {
+            path: path.clone(),
+            expected: "array".to_owned(),
+            actual: json_type(value),
+        });
+        return None;
+    };
+
+    if let Some(min_items) = schema.min_items() {
+        if items.len() < min_items {
+            errors.push(PropertyValidationError::TooFewItems {
+                path: path.clone(),
+                min_items,
+                actual: items.len(),
+            });
+        }
+    }
+
+    if let Some(max_items) = schema.max_items() {
+        if items.len() > max_items {
+            errors.push(PropertyValidationError::TooManyItems {
+                path: path.clone(),
+                max_items,
+                actual: items.len(),
+            });
+        }
+    }
+
+    Some(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::Report;
+    use graph_test_data::{data_type, entity, entity_type, property_type};
+    use serde_json::json;
+    use type_system::repr;
+
+    use super::*;
+
+    const CONTACT_V1: &str = r#"
+        {
+          "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+          "kind": "entityType",
+          "$id": "https://blockprotocol.org/@alice/types/entity-type/contact/v/1",
+          "type": "object",
+          "title": "Contact",
+          "properties": {
+            "https://blockprotocol.org/@alice/types/property-type/contact-information/": {
+              "type": "array",
+              "items": {
+                "$ref": "https://blockprotocol.org/@alice/types/property-type/contact-information/v/1"
+              },
+              "minItems": 1
+            },
+            "https://blockprotocol.org/@alice/types/property-type/contrived-property/": {
+              "$ref": "https://blockprotocol.org/@alice/types/property-type/contrived-property/v/1"
+            }
+          },
+          "required": ["https://blockprotocol.org/@alice/types/property-type/contact-information/"]
+        }
+    "#;
+
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const CONTACT_INFORMATION: &str =
+        "https://blockprotocol.org/@alice/types/property-type/contact-information/";
+    const CONTRIVED_PROPERTY: &str =
+        "https://blockprotocol.org/@alice/types/property-type/contrived-property/";
+    const EMAIL: &str = "https://blockprotocol.org/@alice/types/property-type/email/";
+
+    fn validator(
+        entity_types: &[&str],
+        property_types: &[&str],
+        data_types: &[&str],
+    ) -> EntityValidator {
+        let entity_types = entity_types
+            .iter()
+            .map(|json| {
+                EntityType::try_from(
+                    serde_json::from_str::<repr::EntityType>(json).expect("invalid JSON"),
+                )
+                .expect("invalid entity type")
+            })
+            .collect::<Vec<_>>();
+
+        let mut validator = EntityValidator::new(entity_types[0].id());
+        for entity_type in entity_types {
+            validator.add_entity_type(entity_type);
+        }
+        for json in property_types {
+            validator.add_property_type(
+                PropertyType::try_from(
+                    serde_json::from_str::<repr::PropertyType>(json).expect("invalid JSON"),
+                )
+                .expect("invalid property type"),
+            );
+        }
+        for json in data_types {
+            validator.add_data_type(
+                DataType::try_from(
+                    serde_json::from_str::<repr::DataType>(json).expect("invalid JSON"),
+                )
+                .expect("invalid data type"),
+            );
+        }
+
+        validator
+    }
+
+    fn contact_validator() -> EntityValidator {
+        validator(
+            &[CONTACT_V1],
+            &[
+                property_type::CONTACT_INFORMATION_V1,
+                property_type::CONTRIVED_PROPERTY_V1,
+                property_type::EMAIL_V1,
+                property_type::PHONE_NUMBER_V1,
+            ],
+            &[data_type::TEXT_V1, data_type::NUMBER_V1],
+        )
+    }
+
+    fn properties(value: serde_json::Value) -> EntityProperties {
+        serde_json::from_value(value).expect("invalid entity properties")
+    }
+
+    fn errors(report: &Report<EntityValidationError>) -> Vec<&PropertyValidationError> {
+        let mut errors = report
+            .frames()
+            .filter_map(|frame| frame.downcast_ref::<PropertyValidationError>())
+            .collect::<Vec<_>>();
+        // attachments are stored in reverse order
+        errors.reverse();
+        errors
+    }
+
+    fn path(elements: impl IntoIterator<Item = PropertyPathElement>) -> PropertyPath {
+        PropertyPath(elements.into_iter().collect())
+    }
+
+    fn key(key: &str) -> PropertyPathElement {
+        PropertyPathElement::Property(key.to_owned())
+    }
+
+    #[test]
+    fn person() {
+        let validator = validator(&[entity_type::PERSON_V1], &[property_type::NAME_V1], &[
+            data_type::TEXT_V1,
+        ]);
+
+        validator
+            .validate(
+                &serde_json::from_str(entity::PERSON_ALICE_V1).expect("invalid entity properties"),
+            )
+            .expect("entity should be valid");
+    }
+
+    #[test]
+    fn inherited_entity_type() {
+        let validator = validator(
+            &[entity_type::link::FRIEND_OF_V1, entity_type::LINK_V1],
+            &[],
+            &[],
+        );
+
+        validator
+            .validate(&EntityProperties::empty())
+            .expect("entity should be valid");
+
+        let report = validator
+            .validate(&properties(json!({ NAME: "Alice" })))
+            .expect_err("entity should be invalid");
+        assert_eq!(errors(&report), [
+            &PropertyValidationError::UnexpectedProperty {
+                path: path([key(NAME)])
+            }
+        ]);
+    }
+
+    #[test]
+    fn array_expected() {
+        let validator = validator(
+            &[entity_type::BOOK_V1],
+            &[
+                property_type::NAME_V1,
+                property_type::BLURB_V1,
+                property_type::PUBLISHED_ON_V1,
+            ],
+            &[data_type::TEXT_V1],
+        );
+
+        validator
+            .validate(&properties(json!({ NAME: ["The Time Machine"] })))
+            .expect("entity should be valid");
+
+        let report = validator
+            .validate(&properties(json!({ NAME: "The Time Machine" })))
+            .expect_err("entity should be invalid");
+        assert_eq!(errors(&report), [&PropertyValidationError::InvalidType {
+            path: path([key(NAME)]),
+            expected: "array".to_owned(),
+            actual: "string",
+        }]);
+
+        let report = validator
+            .validate(&properties(json!({ NAME: ["The Time Machine", 1895] })))
+            .expect_err("entity should be invalid");
+        assert_eq!(errors(&report), [&PropertyValidationError::InvalidType {
+            path: path([key(NAME), PropertyPathElement::Index(1)]),
+            expected: "string".to_owned(),
+            actual: "number",
+        }]);
+    }
+
+    #[test]
+    fn nested_objects() {
+        let validator = contact_validator();
+
+        validator
+            .validate(&properties(json!({
+                CONTACT_INFORMATION: [{ EMAIL: "alice@example.com" }],
+                CONTRIVED_PROPERTY: [1, 2, 3, 4],
+            })))
+            .expect("entity should be valid");
+
+        let report = validator
+            .validate(&properties(json!({
+                CONTACT_INFORMATION: [{ EMAIL: "alice@example.com" }, {}],
+            })))
+            .expect_err("entity should be invalid");
+        assert_eq!(errors(&report), [
+            &PropertyValidationError::MissingRequiredProperty {
+                path: path([
+                    key(CONTACT_INFORMATION),
+                    PropertyPathElement::Index(1),
+                    key(EMAIL)
+                ]),
+            }
+        ]);
+    }
+
+    #[test]
+    fn every_error_is_reported() {
+        let validator = contact_validator();
+
+        let report = validator
+            .validate(&properties(json!({
+                CONTRIVED_PROPERTY: [1, 2, 3, 4, 5],
+                NAME: "Alice",
+            })))
+            .expect_err("entity should be invalid");
+
+        assert_eq!(errors(&report), [
+            &PropertyValidationError::MissingRequiredProperty {
+                path: path([key(CONTACT_INFORMATION)]),
+            },
+            &PropertyValidationError::NoMatchingValue {
+                path: path([key(CONTRIVED_PROPERTY)]),
+            },
+            &PropertyValidationError::UnexpectedProperty {
+                path: path([key(NAME)]),
+            },
+        ]);
+    }
+
+    #[test]
+    fn min_items() {
+        let report = contact_validator()
+            .validate(&properties(json!({ CONTACT_INFORMATION: [] })))
+            .expect_err("entity should be invalid");
+
+        assert_eq!(errors(&report), [&PropertyValidationError::TooFewItems {
+            path: path([key(CONTACT_INFORMATION)]),
+            min_items: 1,
+            actual: 0,
+        }]);
+    }
+
+    #[test]
+    fn unknown_type() {
+        let validator = validator(&[entity_type::PERSON_V1], &[property_type::NAME_V1], &[]);
+
+        let report = validator
+            .validate(&properties(json!({ NAME: "Alice" })))
+            .expect_err("entity should be invalid");
+
+        assert_eq!(errors(&report), [&PropertyValidationError::UnknownType {
+            path: path([key(NAME)]),
+            url: "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1".to_owned(),
+        }]);
+    }
+
+    #[test]
+    fn display_path() {
+        let path = path([key(NAME), PropertyPathElement::Index(0)]);
+
+        assert_eq!(
+            path.to_string(),
+            r#"$["https://blockprotocol.org/@alice/types/property-type/name/"][0]"#
+        );
+        assert_eq!(
+            serde_json::to_value(&path).expect("could not serialize path"),
+            json!([NAME, 0])
+        );
+    }
+}
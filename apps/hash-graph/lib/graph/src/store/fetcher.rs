@@ -32,7 +32,7 @@ use crate::{
     provenance::{OwnedById, ProvenanceMetadata, RecordCreatedById},
     store::{
         crud::Read,
-        query::{Filter, OntologyQueryPath},
+        query::{AggregationGroup, AggregationQuery, Filter, OntologyQueryPath},
         AccountStore, ConflictBehavior, DataTypeStore, EntityStore, EntityTypeStore,
         InsertionError, PropertyTypeStore, QueryError, Record, StoreError, StorePool, UpdateError,
     },
@@ -190,6 +190,9 @@ where
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             }
         }
 
@@ -794,6 +797,13 @@ where
         self.store.get_entity(query).await
     }
 
+    async fn aggregate_entities(
+        &self,
+        query: &AggregationQuery<Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        self.store.aggregate_entities(query).await
+    }
+
     async fn update_entity(
         &mut self,
         entity_id: EntityId,
@@ -9,7 +9,11 @@ use crate::{
     },
     knowledge::{Entity, EntityLinkOrder, EntityMetadata, EntityProperties, EntityUuid, LinkData},
     provenance::{OwnedById, RecordCreatedById},
-    store::{crud, InsertionError, QueryError, UpdateError},
+    store::{
+        crud,
+        query::{AggregationGroup, AggregationQuery},
+        InsertionError, QueryError, UpdateError,
+    },
     subgraph::{query::StructuralQuery, Subgraph},
 };
 
@@ -83,6 +87,17 @@ pub trait EntityStore: crud::Read<Entity> {
     /// - if the requested [`Entity`] doesn't exist
     async fn get_entity(&self, query: &StructuralQuery<Entity>) -> Result<Subgraph, QueryError>;
 
+    /// Computes the aggregations specified by the [`AggregationQuery`] without reading the
+    /// [`Entity`]s.
+    ///
+    /// # Errors
+    ///
+    /// - if a path aggregated by anything else than `count` does not point to a numeric value
+    async fn aggregate_entities(
+        &self,
+        query: &AggregationQuery<Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError>;
+
     /// Update an existing [`Entity`].
     ///
     /// # Errors
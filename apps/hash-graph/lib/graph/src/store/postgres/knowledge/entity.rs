@@ -1,9 +1,12 @@
+mod aggregation;
 mod read;
+mod validation;
 
 use std::collections::HashMap;
 
 use async_trait::async_trait;
 use error_stack::{IntoReport, Report, Result, ResultExt};
+use futures::TryStreamExt;
 use tokio_postgres::{error::SqlState, GenericClient};
 use type_system::url::VersionedUrl;
 use uuid::Uuid;
@@ -16,12 +19,12 @@ use crate::{
     knowledge::{Entity, EntityLinkOrder, EntityMetadata, EntityProperties, EntityUuid, LinkData},
     provenance::{OwnedById, ProvenanceMetadata, RecordCreatedById},
     store::{
-        crud::Read,
         error::{DeletionError, EntityDoesNotExist, RaceConditionOnUpdate},
         postgres::{
             knowledge::entity::read::EntityEdgeTraversalData, query::ReferenceTable,
             TraversalContext,
         },
+        query::{AggregationGroup, AggregationQuery},
         AsClient, EntityStore, InsertionError, PostgresStore, QueryError, UpdateError,
     },
     subgraph::{
@@ -227,6 +230,12 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             .await
             .change_context(InsertionError)?;
 
+        self.entity_validator(&entity_type_id)
+            .await
+            .change_context(InsertionError)?
+            .validate(&properties)
+            .change_context(InsertionError)?;
+
         let row = self
             .as_client()
             .query_one(
@@ -426,26 +435,42 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             ref filter,
             graph_resolve_depths,
             temporal_axes: ref unresolved_temporal_axes,
+            ref sorting,
+            ref cursor,
+            limit,
         } = *query;
 
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
         let time_axis = temporal_axes.variable_time_axis();
 
-        let entities = Read::<Entity>::read_vec(self, filter, Some(&temporal_axes))
+        let entities = self
+            .read_sorted_entities(
+                filter,
+                Some(&temporal_axes),
+                sorting,
+                cursor.as_ref(),
+                limit,
+            )
             .await?
-            .into_iter()
-            .map(|entity| (entity.vertex_id(time_axis), entity))
-            .collect();
+            .try_collect::<Vec<_>>()
+            .await?;
 
         let mut subgraph = Subgraph::new(
             graph_resolve_depths,
             unresolved_temporal_axes.clone(),
             temporal_axes.clone(),
         );
-        subgraph.vertices.entities = entities;
 
-        for vertex_id in subgraph.vertices.entities.keys() {
-            subgraph.roots.insert((*vertex_id).into());
+        if limit == Some(entities.len()) {
+            if let Some((entity, sorting_values)) = entities.last() {
+                subgraph.cursor = Some(Self::entity_cursor(entity, sorting_values, time_axis)?);
+            }
+        }
+
+        for (entity, _) in entities {
+            let vertex_id = entity.vertex_id(time_axis);
+            subgraph.roots.push(vertex_id.into());
+            subgraph.vertices.entities.insert(vertex_id, entity);
         }
 
         let mut traversal_context = TraversalContext::default();
@@ -477,6 +502,24 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         Ok(subgraph)
     }
 
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn aggregate_entities(
+        &self,
+        query: &AggregationQuery<Entity>,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        let AggregationQuery {
+            ref filter,
+            temporal_axes: ref unresolved_temporal_axes,
+            ref group_by,
+            ref aggregations,
+        } = *query;
+
+        let temporal_axes = unresolved_temporal_axes.clone().resolve();
+
+        self.read_entity_aggregation(filter, &temporal_axes, group_by, aggregations)
+            .await
+    }
+
     #[tracing::instrument(level = "info", skip(self, properties))]
     async fn update_entity(
         &mut self,
@@ -493,6 +536,12 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             .await
             .change_context(UpdateError)?;
 
+        self.entity_validator(&entity_type_id)
+            .await
+            .change_context(UpdateError)?
+            .validate(&properties)
+            .change_context(UpdateError)?;
+
         // The transaction is required to check if the update happened. If there is no returned
         // row, it either means, that there was no entity with that parameters or a race condition
         // happened.
@@ -0,0 +1,87 @@
+use error_stack::{IntoReport, Report, Result, ResultExt};
+use serde_json::Value;
+use tokio_postgres::GenericClient;
+
+use crate::{
+    knowledge::{Entity, EntityQueryPath},
+    store::{
+        postgres::query::{Distinctness, SelectCompiler},
+        query::{Aggregation, AggregationGroup, Filter, Grouping, ParameterType, QueryPath},
+        AsClient, PostgresStore, QueryError,
+    },
+    subgraph::temporal_axes::QueryTemporalAxes,
+};
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Computes the `aggregations` over the entities matching `filter` for every group of
+    /// `group_by`.
+    pub(crate) async fn read_entity_aggregation(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_axes: &QueryTemporalAxes,
+        group_by: &[Grouping<'_, Entity>],
+        aggregations: &[Aggregation<'_, Entity>],
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        for aggregation in aggregations {
+            if let Aggregation::Min { path }
+            | Aggregation::Max { path }
+            | Aggregation::Sum { path }
+            | Aggregation::Average { path } = aggregation
+            {
+                let expected_type = path.expected_type();
+                if !matches!(
+                    expected_type,
+                    ParameterType::Number | ParameterType::OntologyTypeVersion | ParameterType::Any
+                ) {
+                    return Err(Report::new(QueryError).attach_printable(format!(
+                        "cannot aggregate `{path}` as its type `{expected_type}` is not numeric"
+                    )));
+                }
+            }
+        }
+
+        let mut compiler = SelectCompiler::new(Some(temporal_axes));
+
+        // Filters across links or the inheritance of types join multiple rows for the same
+        // edition, so the editions are deduplicated before they are aggregated.
+        for path in [
+            &EntityQueryPath::OwnedById,
+            &EntityQueryPath::Uuid,
+            &EntityQueryPath::DecisionTime,
+            &EntityQueryPath::TransactionTime,
+        ] {
+            compiler.add_distinct_selection_with_ordering(path, Distinctness::Distinct, None);
+        }
+
+        let key_indices = group_by
+            .iter()
+            .map(|grouping| compiler.add_grouping(grouping))
+            .collect::<Vec<_>>();
+        let value_indices = aggregations
+            .iter()
+            .map(|aggregation| compiler.add_aggregation(aggregation))
+            .collect::<Vec<_>>();
+
+        compiler.add_filter(filter);
+        let (statement, parameters) = compiler.compile();
+
+        Ok(self
+            .as_client()
+            .query(&statement, parameters)
+            .await
+            .into_report()
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| AggregationGroup {
+                key: key_indices
+                    .iter()
+                    .map(|index| row.get::<_, Option<Value>>(*index).unwrap_or(Value::Null))
+                    .collect(),
+                values: value_indices
+                    .iter()
+                    .map(|index| row.get::<_, Option<Value>>(*index).unwrap_or(Value::Null))
+                    .collect(),
+            })
+            .collect())
+    }
+}
@@ -1,8 +1,11 @@
 use std::{mem::swap, str::FromStr};
 
 use async_trait::async_trait;
-use error_stack::{IntoReport, Result, ResultExt};
-use futures::{StreamExt, TryStreamExt};
+use error_stack::{IntoReport, Report, Result, ResultExt};
+use futures::{Stream, StreamExt, TryStreamExt};
+use postgres_types::ToSql;
+use serde::Deserialize;
+use serde_json::Value;
 use tokio_postgres::GenericClient;
 use type_system::url::{BaseUrl, VersionedUrl};
 use uuid::Uuid;
@@ -24,10 +27,11 @@ use crate::{
         postgres::{
             ontology::OntologyId,
             query::{
-                Distinctness, ForeignKeyReference, ReferenceTable, SelectCompiler, Table, Transpile,
+                Distinctness, Expression, ForeignKeyReference, Function, Ordering, ReferenceTable,
+                SelectCompiler, Table, Transpile,
             },
         },
-        query::Filter,
+        query::{Cursor, Filter, Sorting},
         AsClient, PostgresStore, QueryError,
     },
     subgraph::{
@@ -49,6 +53,40 @@ impl<C: AsClient> crud::Read<Entity> for PostgresStore<C> {
         filter: &Filter<Entity>,
         temporal_axes: Option<&QueryTemporalAxes>,
     ) -> Result<Self::ReadStream, QueryError> {
+        Ok(self
+            .read_sorted_entities(filter, temporal_axes, &[], None, None)
+            .await?
+            .map_ok(|(entity, _)| entity))
+    }
+}
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Reads the entities matching `filter` ordered by `sorting`.
+    ///
+    /// Every entity is returned alongside the values it was sorted by, which are used to create a
+    /// [`Cursor`] with [`Self::entity_cursor`]. If neither a sorting, a cursor, nor a limit is
+    /// specified, the entities are returned in an unspecified order.
+    pub(crate) async fn read_sorted_entities(
+        &self,
+        filter: &Filter<'_, Entity>,
+        temporal_axes: Option<&QueryTemporalAxes>,
+        sorting: &[Sorting<'_, Entity>],
+        cursor: Option<&Cursor>,
+        limit: Option<usize>,
+    ) -> Result<
+        impl Stream<Item = Result<(Entity, Vec<Option<Value>>), QueryError>> + Send + Sync,
+        QueryError,
+    > {
+        let ordering = (!sorting.is_empty() || cursor.is_some() || limit.is_some())
+            .then_some(Ordering::Ascending);
+        let variable_time_axis = temporal_axes.map_or(
+            TimeAxis::DecisionTime,
+            QueryTemporalAxes::variable_time_axis,
+        );
+        let cursor = cursor
+            .map(|cursor| EntityCursorKeys::split(cursor, sorting.len()))
+            .transpose()?;
+
         // We can't define these inline otherwise we'll drop while borrowed
         let left_entity_uuid_path = EntityQueryPath::EntityEdge {
             edge_kind: KnowledgeGraphEdgeKind::HasLeftEntity,
@@ -76,23 +114,43 @@ impl<C: AsClient> crud::Read<Entity> for PostgresStore<C> {
         let owned_by_id_index = compiler.add_distinct_selection_with_ordering(
             &EntityQueryPath::OwnedById,
             Distinctness::Distinct,
-            None,
+            ordering,
         );
         let entity_uuid_index = compiler.add_distinct_selection_with_ordering(
             &EntityQueryPath::Uuid,
             Distinctness::Distinct,
-            None,
-        );
-        let decision_time_index = compiler.add_distinct_selection_with_ordering(
-            &EntityQueryPath::DecisionTime,
-            Distinctness::Distinct,
-            None,
-        );
-        let transaction_time_index = compiler.add_distinct_selection_with_ordering(
-            &EntityQueryPath::TransactionTime,
-            Distinctness::Distinct,
-            None,
+            ordering,
         );
+        // The variable time axis is ordered first, so entities with multiple editions are ordered
+        // by the start of the variable time axis, which is used as key for the cursor.
+        let (decision_time_index, transaction_time_index) = match variable_time_axis {
+            TimeAxis::DecisionTime => {
+                let decision_time_index = compiler.add_distinct_selection_with_ordering(
+                    &EntityQueryPath::DecisionTime,
+                    Distinctness::Distinct,
+                    ordering,
+                );
+                let transaction_time_index = compiler.add_distinct_selection_with_ordering(
+                    &EntityQueryPath::TransactionTime,
+                    Distinctness::Distinct,
+                    ordering,
+                );
+                (decision_time_index, transaction_time_index)
+            }
+            TimeAxis::TransactionTime => {
+                let transaction_time_index = compiler.add_distinct_selection_with_ordering(
+                    &EntityQueryPath::TransactionTime,
+                    Distinctness::Distinct,
+                    ordering,
+                );
+                let decision_time_index = compiler.add_distinct_selection_with_ordering(
+                    &EntityQueryPath::DecisionTime,
+                    Distinctness::Distinct,
+                    ordering,
+                );
+                (decision_time_index, transaction_time_index)
+            }
+        };
 
         let edition_id_index = compiler.add_selection_path(&EntityQueryPath::EditionId);
         let type_id_index = compiler.add_selection_path(&EntityQueryPath::EntityTypeEdge {
@@ -118,6 +176,37 @@ impl<C: AsClient> crud::Read<Entity> for PostgresStore<C> {
 
         let archived_index = compiler.add_selection_path(&EntityQueryPath::Archived);
 
+        let sorting_indices = sorting
+            .iter()
+            .map(|sorting| compiler.add_sorting(sorting))
+            .collect::<Vec<_>>();
+
+        if let Some((values, cursor_keys)) = &cursor {
+            let owned_by_id = compiler.compile_path_column(&EntityQueryPath::OwnedById);
+            let entity_uuid = compiler.compile_path_column(&EntityQueryPath::Uuid);
+            let variable_time = compiler.compile_path_column(match variable_time_axis {
+                TimeAxis::DecisionTime => &EntityQueryPath::DecisionTime,
+                TimeAxis::TransactionTime => &EntityQueryPath::TransactionTime,
+            });
+            compiler.add_cursor(values, [
+                (
+                    Expression::Column(owned_by_id),
+                    &cursor_keys.owned_by_id as &(dyn ToSql + Sync),
+                ),
+                (Expression::Column(entity_uuid), &cursor_keys.entity_uuid),
+                (
+                    Expression::Function(Function::Lower(Box::new(Expression::Column(
+                        variable_time,
+                    )))),
+                    &cursor_keys.revision_id,
+                ),
+            ]);
+        }
+
+        if let Some(limit) = limit {
+            compiler.set_limit(limit);
+        }
+
         compiler.add_filter(filter);
         let (statement, parameters) = compiler.compile();
 
@@ -128,77 +217,148 @@ impl<C: AsClient> crud::Read<Entity> for PostgresStore<C> {
             .into_report()
             .change_context(QueryError)?
             .map(|row| row.into_report().change_context(QueryError))
-            .and_then(move |row| async move {
-                let entity_type_id = VersionedUrl::from_str(row.get(type_id_index))
-                    .into_report()
-                    .change_context(QueryError)?;
-
-                let link_data = {
-                    let left_owned_by_id: Option<AccountId> =
-                        row.get(left_entity_owned_by_id_index);
-                    let left_entity_uuid: Option<Uuid> = row.get(left_entity_uuid_index);
-                    let right_owned_by_id: Option<AccountId> =
-                        row.get(right_entity_owned_by_id_index);
-                    let right_entity_uuid: Option<Uuid> = row.get(right_entity_uuid_index);
-                    match (
-                        left_owned_by_id,
-                        left_entity_uuid,
-                        right_owned_by_id,
-                        right_entity_uuid,
-                    ) {
-                        (
-                            Some(left_owned_by_id),
-                            Some(left_entity_uuid),
-                            Some(right_owned_by_id),
-                            Some(right_entity_uuid),
-                        ) => Some(LinkData {
-                            left_entity_id: EntityId {
-                                owned_by_id: OwnedById::new(left_owned_by_id),
-                                entity_uuid: EntityUuid::new(left_entity_uuid),
-                            },
-                            right_entity_id: EntityId {
-                                owned_by_id: OwnedById::new(right_owned_by_id),
-                                entity_uuid: EntityUuid::new(right_entity_uuid),
-                            },
-                            order: EntityLinkOrder {
-                                left_to_right: row.get(left_to_right_order_index),
-                                right_to_left: row.get(right_to_left_order_index),
-                            },
-                        }),
-                        (None, None, None, None) => None,
-                        _ => unreachable!(
-                            "It's not possible to have a link entity with the left entityId or \
-                             right entityId unspecified"
-                        ),
-                    }
-                };
-
-                let record_created_by_id =
-                    RecordCreatedById::new(row.get(record_created_by_id_index));
-
-                Ok(Entity {
-                    properties: row.get(properties_index),
-                    link_data,
-                    metadata: EntityMetadata::new(
-                        EntityRecordId {
-                            entity_id: EntityId {
-                                owned_by_id: row.get(owned_by_id_index),
-                                entity_uuid: row.get(entity_uuid_index),
-                            },
-                            edition_id: row.get(edition_id_index),
-                        },
-                        EntityTemporalMetadata {
-                            decision_time: row.get(decision_time_index),
-                            transaction_time: row.get(transaction_time_index),
+            .and_then(move |row| {
+                let sorting_values = sorting_indices
+                    .iter()
+                    .map(|index| row.get::<_, Option<Value>>(*index))
+                    .collect::<Vec<_>>();
+                async move {
+                    let entity_type_id = VersionedUrl::from_str(row.get(type_id_index))
+                        .into_report()
+                        .change_context(QueryError)?;
+
+                    let link_data = {
+                        let left_owned_by_id: Option<AccountId> =
+                            row.get(left_entity_owned_by_id_index);
+                        let left_entity_uuid: Option<Uuid> = row.get(left_entity_uuid_index);
+                        let right_owned_by_id: Option<AccountId> =
+                            row.get(right_entity_owned_by_id_index);
+                        let right_entity_uuid: Option<Uuid> = row.get(right_entity_uuid_index);
+                        match (
+                            left_owned_by_id,
+                            left_entity_uuid,
+                            right_owned_by_id,
+                            right_entity_uuid,
+                        ) {
+                            (
+                                Some(left_owned_by_id),
+                                Some(left_entity_uuid),
+                                Some(right_owned_by_id),
+                                Some(right_entity_uuid),
+                            ) => Some(LinkData {
+                                left_entity_id: EntityId {
+                                    owned_by_id: OwnedById::new(left_owned_by_id),
+                                    entity_uuid: EntityUuid::new(left_entity_uuid),
+                                },
+                                right_entity_id: EntityId {
+                                    owned_by_id: OwnedById::new(right_owned_by_id),
+                                    entity_uuid: EntityUuid::new(right_entity_uuid),
+                                },
+                                order: EntityLinkOrder {
+                                    left_to_right: row.get(left_to_right_order_index),
+                                    right_to_left: row.get(right_to_left_order_index),
+                                },
+                            }),
+                            (None, None, None, None) => None,
+                            _ => unreachable!(
+                                "It's not possible to have a link entity with the left entityId \
+                                 or right entityId unspecified"
+                            ),
+                        }
+                    };
+
+                    let record_created_by_id =
+                        RecordCreatedById::new(row.get(record_created_by_id_index));
+
+                    Ok((
+                        Entity {
+                            properties: row.get(properties_index),
+                            link_data,
+                            metadata: EntityMetadata::new(
+                                EntityRecordId {
+                                    entity_id: EntityId {
+                                        owned_by_id: row.get(owned_by_id_index),
+                                        entity_uuid: row.get(entity_uuid_index),
+                                    },
+                                    edition_id: row.get(edition_id_index),
+                                },
+                                EntityTemporalMetadata {
+                                    decision_time: row.get(decision_time_index),
+                                    transaction_time: row.get(transaction_time_index),
+                                },
+                                entity_type_id,
+                                ProvenanceMetadata::new(record_created_by_id),
+                                row.get(archived_index),
+                            ),
                         },
-                        entity_type_id,
-                        ProvenanceMetadata::new(record_created_by_id),
-                        row.get(archived_index),
-                    ),
-                })
+                        sorting_values,
+                    ))
+                }
             });
         Ok(stream)
     }
+
+    /// Creates a [`Cursor`] pointing to `entity`, which was read with the `sorting_values` by
+    /// [`Self::read_sorted_entities`].
+    pub(crate) fn entity_cursor(
+        entity: &Entity,
+        sorting_values: &[Option<Value>],
+        variable_time_axis: TimeAxis,
+    ) -> Result<Cursor, QueryError> {
+        let vertex_id = entity.vertex_id(variable_time_axis);
+        let keys = serde_json::to_value((
+            vertex_id.base_id.owned_by_id,
+            vertex_id.base_id.entity_uuid,
+            vertex_id.revision_id,
+        ))
+        .into_report()
+        .change_context(QueryError)?;
+        let Value::Array(keys) = keys else {
+            unreachable!("a tuple is serialized as array");
+        };
+
+        Ok(Cursor::new(
+            sorting_values
+                .iter()
+                .cloned()
+                .chain(keys.into_iter().map(Some))
+                .collect(),
+        ))
+    }
+}
+
+/// The keys of an entity [`Cursor`] following the sorting values, which identify the last entity
+/// read.
+struct EntityCursorKeys {
+    owned_by_id: Uuid,
+    entity_uuid: Uuid,
+    revision_id: Timestamp<VariableAxis>,
+}
+
+impl EntityCursorKeys {
+    /// Splits the `cursor` into the values of the `sortings` and the keys of the entity.
+    fn split(cursor: &Cursor, sortings: usize) -> Result<(&[Option<Value>], Self), QueryError> {
+        let values = cursor.values();
+        let (values, [Some(owned_by_id), Some(entity_uuid), Some(revision_id)]) =
+            values.split_at(sortings.min(values.len()))
+        else {
+            return Err(
+                Report::new(QueryError).attach_printable("The cursor does not match the query")
+            );
+        };
+
+        Ok((values, Self {
+            owned_by_id: Uuid::deserialize(owned_by_id)
+                .into_report()
+                .change_context(QueryError)?,
+            entity_uuid: Uuid::deserialize(entity_uuid)
+                .into_report()
+                .change_context(QueryError)?,
+            revision_id: Timestamp::deserialize(revision_id)
+                .into_report()
+                .change_context(QueryError)?,
+        }))
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,117 @@
+use std::{collections::HashSet, mem};
+
+use error_stack::Result;
+use type_system::url::VersionedUrl;
+
+use crate::{
+    knowledge::EntityValidator,
+    ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
+    store::{crud::Read, query::Filter, AsClient, PostgresStore, QueryError},
+};
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Reads the [`EntityType`] with every type in its closure, which is required to validate the
+    /// properties of an entity.
+    ///
+    /// The closure is read breadth-first, so every level of the closure is a single query. Types
+    /// which do not exist are not read, the validation reports them as unknown.
+    ///
+    /// [`EntityType`]: type_system::EntityType
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) async fn entity_validator(
+        &self,
+        entity_type_id: &VersionedUrl,
+    ) -> Result<EntityValidator, QueryError> {
+        let mut validator = EntityValidator::new(entity_type_id);
+
+        let mut visited_entity_types = HashSet::new();
+        let mut entity_type_queue = HashSet::from([entity_type_id.clone()]);
+        let mut property_type_queue = HashSet::new();
+        while !entity_type_queue.is_empty() {
+            let urls = mem::take(&mut entity_type_queue);
+            let entity_types = Read::<EntityTypeWithMetadata>::read_vec(
+                self,
+                &Filter::Any(urls.iter().map(Filter::for_versioned_url).collect()),
+                None,
+            )
+            .await?;
+            visited_entity_types.extend(urls);
+
+            for entity_type in entity_types {
+                let entity_type = entity_type.schema;
+
+                entity_type_queue.extend(
+                    entity_type
+                        .inherits_from()
+                        .all_of()
+                        .iter()
+                        .map(|reference| reference.url())
+                        .filter(|url| !visited_entity_types.contains(*url))
+                        .cloned(),
+                );
+                property_type_queue.extend(
+                    entity_type
+                        .property_type_references()
+                        .into_iter()
+                        .map(|reference| reference.url().clone()),
+                );
+
+                validator.add_entity_type(entity_type);
+            }
+        }
+
+        let mut visited_property_types = HashSet::new();
+        let mut data_type_queue = HashSet::new();
+        while !property_type_queue.is_empty() {
+            let urls = mem::take(&mut property_type_queue);
+            let property_types = Read::<PropertyTypeWithMetadata>::read_vec(
+                self,
+                &Filter::Any(urls.iter().map(Filter::for_versioned_url).collect()),
+                None,
+            )
+            .await?;
+            visited_property_types.extend(urls);
+
+            for property_type in property_types {
+                let property_type = property_type.schema;
+
+                property_type_queue.extend(
+                    property_type
+                        .property_type_references()
+                        .into_iter()
+                        .map(|reference| reference.url())
+                        .filter(|url| !visited_property_types.contains(*url))
+                        .cloned(),
+                );
+                data_type_queue.extend(
+                    property_type
+                        .data_type_references()
+                        .into_iter()
+                        .map(|reference| reference.url().clone()),
+                );
+
+                validator.add_property_type(property_type);
+            }
+        }
+
+        if !data_type_queue.is_empty() {
+            let data_types = Read::<DataTypeWithMetadata>::read_vec(
+                self,
+                &Filter::Any(
+                    data_type_queue
+                        .iter()
+                        .map(Filter::for_versioned_url)
+                        .collect(),
+                ),
+                None,
+            )
+            .await?;
+
+            for data_type in data_types {
+                validator.add_data_type(data_type.schema);
+            }
+        }
+
+        Ok(validator)
+    }
+}
@@ -17,7 +17,8 @@ use crate::{
         UpdateError,
     },
     subgraph::{
-        edges::GraphResolveDepths, query::StructuralQuery, temporal_axes::VariableAxis, Subgraph,
+        edges::GraphResolveDepths, identifier::DataTypeVertexId, query::StructuralQuery,
+        temporal_axes::VariableAxis, Subgraph,
     },
 };
 
@@ -110,6 +111,9 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
             ref filter,
             graph_resolve_depths,
             temporal_axes: ref unresolved_temporal_axes,
+            ref sorting,
+            ref cursor,
+            limit,
         } = *query;
 
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
@@ -121,7 +125,7 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
             temporal_axes.clone(),
         );
 
-        if graph_resolve_depths.is_empty() {
+        if graph_resolve_depths.is_empty() && !query.is_paginated() {
             subgraph.vertices.data_types =
                 Read::<DataTypeWithMetadata>::read_vec(self, filter, Some(&temporal_axes))
                     .await?
@@ -129,15 +133,25 @@ impl<C: AsClient> DataTypeStore for PostgresStore<C> {
                     .map(|data_type| (data_type.vertex_id(time_axis), data_type))
                     .collect();
             for vertex_id in subgraph.vertices.data_types.keys() {
-                subgraph.roots.insert(vertex_id.clone().into());
+                subgraph.roots.push(vertex_id.clone().into());
             }
         } else {
             let mut traversal_context = TraversalContext::default();
             let traversal_data = self
-                .read_ontology_ids::<DataTypeWithMetadata>(filter, Some(&temporal_axes))
+                .read_ontology_ids::<DataTypeWithMetadata>(
+                    filter,
+                    Some(&temporal_axes),
+                    sorting,
+                    cursor.as_ref(),
+                    limit,
+                )
                 .await?
-                .map_ok(|(vertex_id, ontology_id)| {
-                    subgraph.roots.insert(vertex_id.into());
+                .map_ok(|(url, ontology_id, sorting_values)| {
+                    // The query is limited, so no root follows the root reaching the limit.
+                    if limit == Some(subgraph.roots.len() + 1) {
+                        subgraph.cursor = Some(Self::ontology_cursor(&url, &sorting_values));
+                    }
+                    subgraph.roots.push(DataTypeVertexId::from(url).into());
                     stream::iter(
                         traversal_context
                             .add_data_type_id(
@@ -255,6 +255,9 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
             ref filter,
             graph_resolve_depths,
             temporal_axes: ref unresolved_temporal_axes,
+            ref sorting,
+            ref cursor,
+            limit,
         } = *query;
 
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
@@ -266,7 +269,7 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
             temporal_axes.clone(),
         );
 
-        if graph_resolve_depths.is_empty() {
+        if graph_resolve_depths.is_empty() && !query.is_paginated() {
             subgraph.vertices.entity_types =
                 Read::<EntityTypeWithMetadata>::read_vec(self, filter, Some(&temporal_axes))
                     .await?
@@ -274,15 +277,25 @@ impl<C: AsClient> EntityTypeStore for PostgresStore<C> {
                     .map(|entity_type| (entity_type.vertex_id(time_axis), entity_type))
                     .collect();
             for vertex_id in subgraph.vertices.entity_types.keys() {
-                subgraph.roots.insert(vertex_id.clone().into());
+                subgraph.roots.push(vertex_id.clone().into());
             }
         } else {
             let mut traversal_context = TraversalContext::default();
             let traversal_data = self
-                .read_ontology_ids::<EntityTypeWithMetadata>(filter, Some(&temporal_axes))
+                .read_ontology_ids::<EntityTypeWithMetadata>(
+                    filter,
+                    Some(&temporal_axes),
+                    sorting,
+                    cursor.as_ref(),
+                    limit,
+                )
                 .await?
-                .map_ok(|(vertex_id, ontology_id)| {
-                    subgraph.roots.insert(vertex_id.into());
+                .map_ok(|(url, ontology_id, sorting_values)| {
+                    // The query is limited, so no root follows the root reaching the limit.
+                    if limit == Some(subgraph.roots.len() + 1) {
+                        subgraph.cursor = Some(Self::ontology_cursor(&url, &sorting_values));
+                    }
+                    subgraph.roots.push(EntityTypeVertexId::from(url).into());
                     stream::iter(
                         traversal_context
                             .add_entity_type_id(
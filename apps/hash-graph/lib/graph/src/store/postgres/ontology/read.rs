@@ -1,10 +1,11 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use error_stack::{IntoReport, Result, ResultExt};
+use error_stack::{IntoReport, Report, Result, ResultExt};
 use futures::{Stream, StreamExt, TryStreamExt};
-use postgres_types::{FromSql, Type};
+use postgres_types::{FromSql, ToSql, Type};
 use serde::Deserialize;
+use serde_json::Value;
 use time::OffsetDateTime;
 use tokio_postgres::GenericClient;
 use type_system::{
@@ -29,11 +30,11 @@ use crate::{
         postgres::{
             ontology::OntologyId,
             query::{
-                Distinctness, ForeignKeyReference, PostgresQueryPath, PostgresRecord,
-                ReferenceTable, SelectCompiler, Table, Transpile,
+                Distinctness, Expression, ForeignKeyReference, Ordering, PostgresQueryPath,
+                PostgresRecord, ReferenceTable, SelectCompiler, Table, Transpile,
             },
         },
-        query::{Filter, OntologyQueryPath},
+        query::{Cursor, Filter, OntologyQueryPath, Sorting},
         AsClient, PostgresStore, QueryError, Record,
     },
     subgraph::{
@@ -398,28 +399,82 @@ pub struct OntologyEdgeTraversal<L, R> {
 }
 
 impl<C: AsClient> PostgresStore<C> {
+    /// Reads the ids of the ontology types matching `filter` ordered by `sorting`.
+    ///
+    /// Every id is returned alongside the values it was sorted by, which are used to create a
+    /// [`Cursor`] with [`Self::ontology_cursor`]. If neither a sorting, a cursor, nor a limit is
+    /// specified, the ids are returned in an unspecified order.
     pub(crate) async fn read_ontology_ids<R>(
         &self,
         filter: &Filter<'_, R>,
         temporal_axes: Option<&QueryTemporalAxes>,
-    ) -> Result<impl Stream<Item = Result<(R::VertexId, OntologyId), QueryError>>, QueryError>
+        sorting: &[Sorting<'_, R>],
+        cursor: Option<&Cursor>,
+        limit: Option<usize>,
+    ) -> Result<
+        impl Stream<Item = Result<(VersionedUrl, OntologyId, Vec<Option<Value>>), QueryError>>,
+        QueryError,
+    >
     where
         R: for<'p> Record<QueryPath<'p>: PostgresQueryPath + OntologyQueryPath> + PostgresRecord,
-        R::VertexId: From<VersionedUrl>,
     {
-        let mut compiler = SelectCompiler::new(temporal_axes);
+        let cursor = cursor
+            .map(|cursor| OntologyCursorKeys::split(cursor, sorting.len()))
+            .transpose()?;
 
         let ontology_id_path = <R::QueryPath<'static> as OntologyQueryPath>::ontology_id();
         let base_url_path = <R::QueryPath<'static> as OntologyQueryPath>::base_url();
         let version_path = <R::QueryPath<'static> as OntologyQueryPath>::version();
 
-        let ontology_id_index = compiler.add_distinct_selection_with_ordering(
-            &ontology_id_path,
-            Distinctness::Distinct,
-            None,
-        );
-        let base_url_index = compiler.add_selection_path(&base_url_path);
-        let version_index = compiler.add_selection_path(&version_path);
+        let mut compiler = SelectCompiler::new(temporal_axes);
+
+        let (ontology_id_index, base_url_index, version_index) =
+            if sorting.is_empty() && cursor.is_none() && limit.is_none() {
+                let ontology_id_index = compiler.add_distinct_selection_with_ordering(
+                    &ontology_id_path,
+                    Distinctness::Distinct,
+                    None,
+                );
+                let base_url_index = compiler.add_selection_path(&base_url_path);
+                let version_index = compiler.add_selection_path(&version_path);
+                (ontology_id_index, base_url_index, version_index)
+            } else {
+                // The base URL and the version are unique for an ontology type, so they are used
+                // to order ontology types, which compare equal for every sorting.
+                let base_url_index = compiler.add_distinct_selection_with_ordering(
+                    &base_url_path,
+                    Distinctness::Distinct,
+                    Some(Ordering::Ascending),
+                );
+                let version_index = compiler.add_distinct_selection_with_ordering(
+                    &version_path,
+                    Distinctness::Distinct,
+                    Some(Ordering::Ascending),
+                );
+                let ontology_id_index = compiler.add_selection_path(&ontology_id_path);
+                (ontology_id_index, base_url_index, version_index)
+            };
+
+        let sorting_indices = sorting
+            .iter()
+            .map(|sorting| compiler.add_sorting(sorting))
+            .collect::<Vec<_>>();
+
+        if let Some((values, cursor_keys)) = &cursor {
+            let base_url = compiler.compile_path_column(&base_url_path);
+            let version = compiler.compile_path_column(&version_path);
+            compiler.add_cursor(values, [
+                (
+                    Expression::Column(base_url),
+                    &cursor_keys.base_url as &(dyn ToSql + Sync),
+                ),
+                (Expression::Column(version), &cursor_keys.version),
+            ]);
+        }
+
+        if let Some(limit) = limit {
+            compiler.set_limit(limit);
+        }
 
         compiler.add_filter(filter);
         let (statement, parameters) = compiler.compile();
@@ -437,13 +492,31 @@ impl<C: AsClient> PostgresStore<C> {
                         base_url: BaseUrl::new(row.get(base_url_index))
                             .expect("Ontology type record base URL should always be a valid URL"),
                         version: row.get::<_, OntologyTypeVersion>(version_index).inner(),
-                    }
-                    .into(),
+                    },
                     row.get(ontology_id_index),
+                    sorting_indices
+                        .iter()
+                        .map(|index| row.get::<_, Option<Value>>(*index))
+                        .collect(),
                 )
             }))
     }
 
+    /// Creates a [`Cursor`] pointing to the ontology type identified by `url`, which was read with
+    /// the `sorting_values` by [`Self::read_ontology_ids`].
+    pub(crate) fn ontology_cursor(url: &VersionedUrl, sorting_values: &[Option<Value>]) -> Cursor {
+        Cursor::new(
+            sorting_values
+                .iter()
+                .cloned()
+                .chain([
+                    Some(Value::from(url.base_url.as_str())),
+                    Some(Value::from(url.version)),
+                ])
+                .collect(),
+        )
+    }
+
     pub(crate) async fn read_ontology_edges<'r, L, R>(
         &self,
         record_ids: &'r OntologyTypeTraversalData,
@@ -528,3 +601,32 @@ impl<C: AsClient> PostgresStore<C> {
             }))
     }
 }
+
+/// The keys of an ontology type [`Cursor`] following the sorting values, which identify the last
+/// ontology type read.
+struct OntologyCursorKeys {
+    base_url: String,
+    version: OntologyTypeVersion,
+}
+
+impl OntologyCursorKeys {
+    /// Splits the `cursor` into the values of the `sortings` and the keys of the ontology type.
+    fn split(cursor: &Cursor, sortings: usize) -> Result<(&[Option<Value>], Self), QueryError> {
+        let values = cursor.values();
+        let (values, [Some(base_url), Some(version)]) = values.split_at(sortings.min(values.len()))
+        else {
+            return Err(
+                Report::new(QueryError).attach_printable("The cursor does not match the query")
+            );
+        };
+
+        Ok((values, Self {
+            base_url: String::deserialize(base_url)
+                .into_report()
+                .change_context(QueryError)?,
+            version: OntologyTypeVersion::deserialize(version)
+                .into_report()
+                .change_context(QueryError)?,
+        }))
+    }
+}
@@ -18,8 +18,9 @@ pub use self::{
     compile::SelectCompiler,
     condition::{Condition, EqualityOperator},
     expression::{
-        CommonTableExpression, Expression, Function, JoinExpression, OrderByExpression, Ordering,
-        SelectExpression, WhereExpression, WithExpression,
+        AggregationColumn, AggregationExpression, CommonTableExpression, Constant, Expression,
+        Function, JoinExpression, OrderByExpression, Ordering, SelectExpression, WhereExpression,
+        WithExpression,
     },
     statement::{Distinctness, SelectStatement, Statement, WindowStatement},
     table::{Alias, AliasedColumn, AliasedTable, ForeignKeyReference, ReferenceTable, Table},
@@ -1,18 +1,23 @@
-use std::{collections::HashSet, fmt::Display, marker::PhantomData};
+use std::{collections::HashSet, fmt::Display, iter::once, marker::PhantomData};
 
 use postgres_types::ToSql;
+use serde_json::Value;
 use tokio_postgres::row::RowIndex;
 
 use crate::{
     store::{
         postgres::query::{
             table::{EntityTemporalMetadata, OntologyIds},
-            Alias, AliasedColumn, AliasedTable, Column, Condition, Distinctness, EqualityOperator,
-            Expression, Function, JoinExpression, OrderByExpression, Ordering, PostgresQueryPath,
-            PostgresRecord, SelectExpression, SelectStatement, Table, Transpile, WhereExpression,
-            WindowStatement, WithExpression,
+            AggregationColumn, AggregationExpression, Alias, AliasedColumn, AliasedTable, Column,
+            Condition, Constant, Distinctness, EqualityOperator, Expression, Function,
+            JoinExpression, OrderByExpression, Ordering, PostgresQueryPath, PostgresRecord,
+            SelectExpression, SelectStatement, Table, Transpile, WhereExpression, WindowStatement,
+            WithExpression,
+        },
+        query::{
+            Aggregation, Filter, FilterExpression, Grouping, Parameter, ParameterList,
+            ParameterType, SortDirection, Sorting,
         },
-        query::{Filter, FilterExpression, Parameter, ParameterList, ParameterType},
     },
     subgraph::temporal_axes::QueryTemporalAxes,
 };
@@ -33,6 +38,17 @@ pub struct CompilerArtifacts<'p> {
     condition_index: usize,
     required_tables: HashSet<AliasedTable>,
     temporal_tables: Option<TemporalTableInfo>,
+    sortings: Vec<(Expression, Ordering)>,
+    /// Set while compiling the filter nested in a [`Filter::CaseInsensitive`].
+    case_insensitive: bool,
+}
+
+/// A key of a keyset pagination, which is compared to the value of the last row of a page.
+struct KeysetKey {
+    expression: Expression,
+    ordering: Ordering,
+    value: Option<Expression>,
+    nullable: bool,
 }
 
 pub struct SelectCompiler<'p, T> {
@@ -57,13 +73,17 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
                 }),
                 joins: Vec::new(),
                 where_expression: WhereExpression::default(),
+                aggregation: AggregationExpression::default(),
                 order_by_expression: OrderByExpression::default(),
+                limit: None,
             },
             artifacts: CompilerArtifacts {
                 parameters: Vec::new(),
                 condition_index: 0,
                 required_tables: HashSet::new(),
                 temporal_tables: None,
+                sortings: Vec::new(),
+                case_insensitive: false,
             },
             temporal_axes,
             _marker: PhantomData,
@@ -165,10 +185,12 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
     {
         let column = self.compile_path_column(path);
         if distinctness == Distinctness::Distinct {
-            self.statement.distinct.push(column);
+            self.statement.distinct.push(Expression::Column(column));
         }
         if let Some(ordering) = ordering {
-            self.statement.order_by_expression.push(column, ordering);
+            self.statement
+                .order_by_expression
+                .push(Expression::Column(column), ordering);
         }
         self.statement
             .selects
@@ -176,6 +198,246 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
         self.statement.selects.len() - 1
     }
 
+    /// Sorts the selection by the value at the path of `sorting`.
+    ///
+    /// The value is compared as JSON value and takes precedence over the orderings added by
+    /// [`Self::add_distinct_selection_with_ordering`] and previously added sortings. Distinct
+    /// selections have to be added before adding a sorting. The compared value is selected, so it
+    /// can be used to create a [`Cursor`]. If the sorting has a `relevance` query, the relevance of
+    /// the value for the full-text search, or the similarity for a `fuzzy` sorting, is compared
+    /// instead.
+    ///
+    /// [`Cursor`]: crate::store::query::Cursor
+    pub fn add_sorting<'s: 'p>(
+        &mut self,
+        sorting: &'p Sorting<'s, R>,
+    ) -> impl RowIndex + Display + Copy
+    where
+        R::QueryPath<'s>: PostgresQueryPath,
+    {
+        let column = self.compile_path_column(&sorting.path);
+        let parameter_type = column.column.parameter_type();
+        let value = match &sorting.relevance {
+            Some(query) if sorting.fuzzy => {
+                self.artifacts.parameters.push(query);
+                Expression::Function(Function::WordSimilarity(
+                    Box::new(Expression::Parameter(self.artifacts.parameters.len())),
+                    Box::new(Self::compile_fuzzy_text(
+                        Expression::Column(column),
+                        parameter_type,
+                    )),
+                ))
+            }
+            Some(query) => {
+                self.artifacts.parameters.push(query);
+                Expression::Function(Function::TsRank(
+                    Box::new(Self::compile_search_vector(
+                        Expression::Column(column),
+                        parameter_type,
+                    )),
+                    Box::new(Expression::Function(Function::WebSearchToTsQuery(
+                        Box::new(Expression::Parameter(self.artifacts.parameters.len())),
+                    ))),
+                ))
+            }
+            None => Expression::Column(column),
+        };
+        let expression = Expression::Function(Function::ToJson(Box::new(value)));
+        let ordering = match sorting.direction {
+            SortDirection::Ascending => Ordering::Ascending,
+            SortDirection::Descending => Ordering::Descending,
+        };
+
+        // `DISTINCT ON` requires its expressions to be the leftmost expressions in `ORDER BY`
+        let position = self.artifacts.sortings.len();
+        if !self.statement.distinct.is_empty() {
+            self.statement.distinct.insert(position, expression.clone());
+        }
+        self.statement
+            .order_by_expression
+            .insert(position, expression.clone(), ordering);
+        self.artifacts.sortings.push((expression.clone(), ordering));
+
+        self.statement
+            .selects
+            .push(SelectExpression::new(expression, None));
+        self.statement.selects.len() - 1
+    }
+
+    /// Restricts the selection to the rows after the row described by `values` and `keys`.
+    ///
+    /// `values` are compared to the sortings added by [`Self::add_sorting`], where `None` denotes
+    /// the absence of a value. Afterwards, the `keys` are compared in ascending order to their
+    /// parameter. The `keys` have to identify a row uniquely and have to match the ordering of the
+    /// selection.
+    pub fn add_cursor(
+        &mut self,
+        values: &'p [Option<Value>],
+        keys: impl IntoIterator<Item = (Expression, &'p (dyn ToSql + Sync))>,
+    ) {
+        let mut keyset = Vec::new();
+        for ((expression, ordering), value) in self.artifacts.sortings.iter().zip(values) {
+            let value = value.as_ref().map(|value| {
+                self.artifacts.parameters.push(value);
+                Expression::Parameter(self.artifacts.parameters.len())
+            });
+            keyset.push(KeysetKey {
+                expression: expression.clone(),
+                ordering: *ordering,
+                value,
+                nullable: true,
+            });
+        }
+        for (expression, parameter) in keys {
+            self.artifacts.parameters.push(parameter);
+            keyset.push(KeysetKey {
+                expression,
+                ordering: Ordering::Ascending,
+                value: Some(Expression::Parameter(self.artifacts.parameters.len())),
+                nullable: false,
+            });
+        }
+
+        let condition = Condition::Any(
+            keyset
+                .iter()
+                .enumerate()
+                .filter_map(|(index, key)| {
+                    let after = match (key.ordering, &key.value) {
+                        // `NULL` is sorted after any other value in ascending order ...
+                        (Ordering::Ascending, Some(value)) => {
+                            let greater = Condition::Greater(key.expression.clone(), value.clone());
+                            if key.nullable {
+                                Condition::Any(vec![
+                                    greater,
+                                    Condition::Equal(Some(key.expression.clone()), None),
+                                ])
+                            } else {
+                                greater
+                            }
+                        }
+                        (Ordering::Ascending, None) => return None,
+                        // ... and before any other value in descending order
+                        (Ordering::Descending, Some(value)) => {
+                            Condition::Less(key.expression.clone(), value.clone())
+                        }
+                        (Ordering::Descending, None) => {
+                            Condition::NotEqual(Some(key.expression.clone()), None)
+                        }
+                    };
+
+                    Some(Condition::All(
+                        keyset[..index]
+                            .iter()
+                            .map(|previous| {
+                                Condition::Equal(
+                                    Some(previous.expression.clone()),
+                                    previous.value.clone(),
+                                )
+                            })
+                            .chain(once(after))
+                            .collect(),
+                    ))
+                })
+                .collect(),
+        );
+
+        self.statement.where_expression.add_condition(condition);
+    }
+
+    /// Groups the aggregated rows by the value at the path of `grouping`.
+    ///
+    /// The value is selected as JSON value and the groups are ordered by it. If the selection is
+    /// distinct, a row is only aggregated once for every value.
+    pub fn add_grouping<'g: 'p>(
+        &mut self,
+        grouping: &'p Grouping<'g, R>,
+    ) -> impl RowIndex + Display + Copy
+    where
+        R::QueryPath<'g>: PostgresQueryPath,
+    {
+        let expression = Expression::Function(Function::ToJson(Box::new(Expression::Column(
+            self.compile_path_column(&grouping.path),
+        ))));
+
+        if !self.statement.distinct.is_empty() {
+            self.statement.distinct.push(expression.clone());
+        }
+        let index = self.add_aggregated_expression(expression);
+        self.statement
+            .aggregation
+            .push(AggregationColumn::Group(index))
+    }
+
+    /// Selects the result of the `aggregation` over the rows of a group as JSON value.
+    ///
+    /// The aggregation is computed over the rows of the selection, so a distinct selection is
+    /// required to aggregate every record only once when filtering across joined tables. JSON
+    /// values are only aggregated if they are numbers, other JSON values are ignored.
+    pub fn add_aggregation<'a: 'p>(
+        &mut self,
+        aggregation: &'p Aggregation<'a, R>,
+    ) -> impl RowIndex + Display + Copy
+    where
+        R::QueryPath<'a>: PostgresQueryPath,
+    {
+        let column = match aggregation {
+            Aggregation::Count { path: None } => AggregationColumn::Count(None),
+            Aggregation::Count { path: Some(path) } => {
+                let column = self.compile_path_column(path);
+                AggregationColumn::Count(Some(
+                    self.add_aggregated_expression(Expression::Column(column)),
+                ))
+            }
+            Aggregation::Min { path } => {
+                let expression = self.compile_numeric_path(path);
+                AggregationColumn::Min(self.add_aggregated_expression(expression))
+            }
+            Aggregation::Max { path } => {
+                let expression = self.compile_numeric_path(path);
+                AggregationColumn::Max(self.add_aggregated_expression(expression))
+            }
+            Aggregation::Sum { path } => {
+                let expression = self.compile_numeric_path(path);
+                AggregationColumn::Sum(self.add_aggregated_expression(expression))
+            }
+            Aggregation::Average { path } => {
+                let expression = self.compile_numeric_path(path);
+                AggregationColumn::Average(self.add_aggregated_expression(expression))
+            }
+        };
+
+        self.statement.aggregation.push(column)
+    }
+
+    /// Selects the `expression` to be aggregated and returns its index in the selection.
+    fn add_aggregated_expression(&mut self, expression: Expression) -> usize {
+        self.statement
+            .selects
+            .push(SelectExpression::new(expression, None));
+        self.statement.selects.len() - 1
+    }
+
+    /// Compiles the `path` to a numeric value, JSON values which are not numbers are `NULL`.
+    fn compile_numeric_path<'q>(&mut self, path: &'p R::QueryPath<'q>) -> Expression
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let column = self.compile_path_column(path);
+        if column.column.parameter_type() == ParameterType::Any {
+            Expression::Function(Function::JsonExtractNumber(Box::new(Expression::Column(
+                column,
+            ))))
+        } else {
+            Expression::Column(column)
+        }
+    }
+
+    /// Restricts the number of rows returned by the selection.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.statement.limit = Some(limit);
+    }
+
     /// Adds a new filter to the selection.
     pub fn add_filter<'f: 'p>(&mut self, filter: &'p Filter<'f, R>)
     where
@@ -217,73 +479,286 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
                     .collect(),
             ),
             Filter::Not(filter) => Condition::Not(Box::new(self.compile_filter(filter))),
-            Filter::Equal(lhs, rhs) => Condition::Equal(
-                lhs.as_ref()
-                    .map(|expression| self.compile_filter_expression(expression).0),
-                rhs.as_ref()
-                    .map(|expression| self.compile_filter_expression(expression).0),
-            ),
-            Filter::NotEqual(lhs, rhs) => Condition::NotEqual(
-                lhs.as_ref()
-                    .map(|expression| self.compile_filter_expression(expression).0),
-                rhs.as_ref()
-                    .map(|expression| self.compile_filter_expression(expression).0),
-            ),
+            Filter::Equal(lhs, rhs) => {
+                let case_insensitive =
+                    self.artifacts.case_insensitive && lhs.iter().chain(rhs).all(Self::is_textual);
+                Condition::Equal(
+                    lhs.as_ref().map(|expression| {
+                        self.compile_equality_operand(expression, case_insensitive)
+                    }),
+                    rhs.as_ref().map(|expression| {
+                        self.compile_equality_operand(expression, case_insensitive)
+                    }),
+                )
+            }
+            Filter::NotEqual(lhs, rhs) => {
+                let case_insensitive =
+                    self.artifacts.case_insensitive && lhs.iter().chain(rhs).all(Self::is_textual);
+                Condition::NotEqual(
+                    lhs.as_ref().map(|expression| {
+                        self.compile_equality_operand(expression, case_insensitive)
+                    }),
+                    rhs.as_ref().map(|expression| {
+                        self.compile_equality_operand(expression, case_insensitive)
+                    }),
+                )
+            }
+            Filter::Less(lhs, rhs) => self.compile_ordering(lhs, rhs, Condition::Less),
+            Filter::LessOrEqual(lhs, rhs) => {
+                self.compile_ordering(lhs, rhs, Condition::LessOrEqual)
+            }
+            Filter::Greater(lhs, rhs) => self.compile_ordering(lhs, rhs, Condition::Greater),
+            Filter::GreaterOrEqual(lhs, rhs) => {
+                self.compile_ordering(lhs, rhs, Condition::GreaterOrEqual)
+            }
+            Filter::Exists(expression) => {
+                Condition::NotEqual(Some(self.compile_filter_expression(expression).0), None)
+            }
+            Filter::IsNull(expression) => {
+                let (expression, parameter_type) = self.compile_filter_expression(expression);
+                if parameter_type == ParameterType::Any {
+                    Condition::Any(vec![
+                        Condition::Equal(Some(expression.clone()), None),
+                        Condition::Equal(
+                            Some(expression),
+                            Some(Expression::Constant(Constant::String("null"))),
+                        ),
+                    ])
+                } else {
+                    Condition::Equal(Some(expression), None)
+                }
+            }
+            // Postgres infers the type of each parameter in `IN (...)` from the left-hand side,
+            // which is not possible for an array of parameters, so each value is a parameter.
+            Filter::In(lhs, ParameterList::Parameters(parameters)) => {
+                let case_insensitive = self.artifacts.case_insensitive
+                    && Self::is_textual(lhs)
+                    && parameters.iter().all(Self::is_textual_parameter);
+                let lhs = self.compile_equality_operand(lhs, case_insensitive);
+                Condition::Any(
+                    parameters
+                        .iter()
+                        .map(|parameter| {
+                            Condition::Equal(
+                                Some(lhs.clone()),
+                                Some(self.compile_equality_parameter(parameter, case_insensitive)),
+                            )
+                        })
+                        .collect(),
+                )
+            }
             Filter::In(lhs, rhs) => Condition::In(
                 self.compile_filter_expression(lhs).0,
                 self.compile_parameter_list(rhs).0,
             ),
-            Filter::StartsWith(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
+            Filter::StartsWith(lhs, rhs) => Condition::StartsWith(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::EndsWith(lhs, rhs) => Condition::EndsWith(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::ContainsSegment(lhs, rhs) => Condition::ContainsSegment(
+                self.compile_text_expression(lhs),
+                self.compile_text_expression(rhs),
+            ),
+            Filter::Contains(lhs, rhs) => Condition::Contains(
+                self.compile_json_expression(lhs),
+                self.compile_json_expression(rhs),
+            ),
+            Filter::Search(lhs, rhs) => {
+                let (vector, parameter_type) = self.compile_filter_expression(lhs);
+                Condition::Matches(
+                    Self::compile_search_vector(vector, parameter_type),
+                    Expression::Function(Function::WebSearchToTsQuery(Box::new(
+                        self.compile_text_expression(rhs),
+                    ))),
+                )
+            }
+            Filter::FuzzySearch(lhs, rhs) => {
+                let (text, parameter_type) = self.compile_filter_expression(lhs);
+                Condition::Similar(
+                    Self::compile_fuzzy_text(text, parameter_type),
+                    self.compile_text_expression(rhs),
+                )
+            }
+            Filter::CaseInsensitive(filter) => {
+                let case_insensitive = self.artifacts.case_insensitive;
+                self.artifacts.case_insensitive = true;
+                let condition = self.compile_filter(filter);
+                self.artifacts.case_insensitive = case_insensitive;
+                condition
+            }
+        }
+    }
 
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
+    /// Compiles an ordering comparison of `lhs` and `rhs` using `condition`.
+    ///
+    /// Postgres orders JSON values of different types by their type, e.g. every string is greater
+    /// than every number. If both sides are JSON values, the comparison is only satisfied if they
+    /// are of the same type.
+    fn compile_ordering<'f: 'p>(
+        &mut self,
+        lhs: &'p FilterExpression<'f, R>,
+        rhs: &'p FilterExpression<'f, R>,
+        condition: fn(Expression, Expression) -> Condition,
+    ) -> Condition
+    where
+        R::QueryPath<'f>: PostgresQueryPath,
+    {
+        let (lhs, lhs_type) = self.compile_filter_expression(lhs);
+        let (rhs, rhs_type) = self.compile_filter_expression(rhs);
 
-                Condition::StartsWith(left_filter, right_filter)
-            }
-            Filter::EndsWith(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
+        if lhs_type == ParameterType::Any && rhs_type == ParameterType::Any {
+            Condition::All(vec![
+                Condition::Equal(
+                    Some(Expression::Function(Function::JsonTypeof(Box::new(
+                        lhs.clone(),
+                    )))),
+                    Some(Expression::Function(Function::JsonTypeof(Box::new(
+                        rhs.clone(),
+                    )))),
+                ),
+                condition(lhs, rhs),
+            ])
+        } else {
+            condition(lhs, rhs)
+        }
+    }
 
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
+    /// Returns if `expression` is compared as text inside of a [`Filter::CaseInsensitive`].
+    fn is_textual<'f>(expression: &FilterExpression<'f, R>) -> bool
+    where
+        R::QueryPath<'f>: PostgresQueryPath,
+    {
+        match expression {
+            FilterExpression::Path(path) => matches!(
+                path.terminating_column().parameter_type(),
+                ParameterType::Text | ParameterType::Any
+            ),
+            FilterExpression::Parameter(parameter) => Self::is_textual_parameter(parameter),
+        }
+    }
 
-                Condition::EndsWith(left_filter, right_filter)
+    const fn is_textual_parameter(parameter: &Parameter<'_>) -> bool {
+        matches!(
+            parameter,
+            Parameter::Text(_) | Parameter::Any(Value::String(_))
+        )
+    }
+
+    /// Compiles an operand of an equality comparison, which is lowercase text if the comparison is
+    /// `case_insensitive`.
+    fn compile_equality_operand<'f: 'p>(
+        &mut self,
+        expression: &'p FilterExpression<'f, R>,
+        case_insensitive: bool,
+    ) -> Expression
+    where
+        R::QueryPath<'f>: PostgresQueryPath,
+    {
+        match expression {
+            FilterExpression::Parameter(parameter) => {
+                self.compile_equality_parameter(parameter, case_insensitive)
             }
-            Filter::ContainsSegment(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
+            FilterExpression::Path(_) => {
+                let (expression, parameter_type) = self.compile_filter_expression(expression);
+                if !case_insensitive {
+                    expression
+                } else if parameter_type == ParameterType::Any {
+                    Expression::Function(Function::Lower(Box::new(Expression::Function(
+                        Function::JsonExtractText(Box::new(expression)),
+                    ))))
                 } else {
-                    left_filter
-                };
+                    Expression::Function(Function::Lower(Box::new(expression)))
+                }
+            }
+        }
+    }
 
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
+    fn compile_equality_parameter<'f: 'p>(
+        &mut self,
+        parameter: &'p Parameter<'f>,
+        case_insensitive: bool,
+    ) -> Expression {
+        if !case_insensitive {
+            return self.compile_parameter(parameter).0;
+        }
 
-                Condition::ContainsSegment(left_filter, right_filter)
-            }
+        // A JSON string is passed as text, so it can be converted to lowercase
+        let parameter = if let Parameter::Any(Value::String(text)) = parameter {
+            self.artifacts.parameters.push(text);
+            Expression::Parameter(self.artifacts.parameters.len())
+        } else {
+            self.compile_parameter(parameter).0
+        };
+        Expression::Function(Function::Lower(Box::new(parameter)))
+    }
+
+    /// Compiles the `expression` as text, JSON values are extracted as text.
+    ///
+    /// Inside of a [`Filter::CaseInsensitive`] the text is lowercase.
+    fn compile_text_expression<'f: 'p>(
+        &mut self,
+        expression: &'p FilterExpression<'f, R>,
+    ) -> Expression
+    where
+        R::QueryPath<'f>: PostgresQueryPath,
+    {
+        let (expression, parameter_type) = self.compile_filter_expression(expression);
+        let expression = if parameter_type == ParameterType::Any {
+            Expression::Function(Function::JsonExtractText(Box::new(expression)))
+        } else {
+            expression
+        };
+
+        if self.artifacts.case_insensitive {
+            Expression::Function(Function::Lower(Box::new(expression)))
+        } else {
+            expression
+        }
+    }
+
+    /// Converts the text or JSON value in `expression` to a `tsvector` for a full-text search.
+    ///
+    /// For the paths with an index, the created expression matches the expression of the index.
+    fn compile_search_vector(expression: Expression, parameter_type: ParameterType) -> Expression {
+        if parameter_type == ParameterType::Any {
+            Expression::Function(Function::JsonToTsVector(Box::new(expression)))
+        } else {
+            Expression::Function(Function::ToTsVector(Box::new(expression)))
+        }
+    }
+
+    /// Converts the text or JSON value in `expression` to the text compared by a fuzzy search.
+    ///
+    /// Only the strings inside of a JSON value are compared, so the keys of an object do not affect
+    /// the similarity. For the paths with an index, the created expression matches the expression
+    /// of the index.
+    fn compile_fuzzy_text(expression: Expression, parameter_type: ParameterType) -> Expression {
+        if parameter_type == ParameterType::Any {
+            Expression::Function(Function::JsonExtractText(Box::new(Expression::Function(
+                Function::JsonStringValues(Box::new(expression)),
+            ))))
+        } else {
+            expression
+        }
+    }
+
+    /// Compiles the `expression` as JSON value.
+    fn compile_json_expression<'f: 'p>(
+        &mut self,
+        expression: &'p FilterExpression<'f, R>,
+    ) -> Expression
+    where
+        R::QueryPath<'f>: PostgresQueryPath,
+    {
+        let (expression, parameter_type) = self.compile_filter_expression(expression);
+        if parameter_type == ParameterType::Any {
+            expression
+        } else {
+            Expression::Function(Function::ToJson(Box::new(expression)))
         }
     }
 
@@ -329,7 +804,9 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
                 from: version_column.table(),
                 joins: vec![],
                 where_expression: WhereExpression::default(),
+                aggregation: AggregationExpression::default(),
                 order_by_expression: OrderByExpression::default(),
+                limit: None,
             });
 
         let alias = self.add_join_statements(path);
@@ -423,41 +900,46 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
                 let parameter_type = column.column.parameter_type();
                 (Expression::Column(column), parameter_type)
             }
-            FilterExpression::Parameter(parameter) => {
-                let parameter_type = match parameter {
-                    Parameter::Number(number) => {
-                        self.artifacts.parameters.push(number);
-                        ParameterType::Number
-                    }
-                    Parameter::Text(text) => {
-                        self.artifacts.parameters.push(text);
-                        ParameterType::Text
-                    }
-                    Parameter::Boolean(bool) => {
-                        self.artifacts.parameters.push(bool);
-                        ParameterType::Boolean
-                    }
-                    Parameter::Any(json) => {
-                        self.artifacts.parameters.push(json);
-                        ParameterType::Any
-                    }
-                    Parameter::Uuid(uuid) => {
-                        self.artifacts.parameters.push(uuid);
-                        ParameterType::Uuid
-                    }
-                    Parameter::OntologyTypeVersion(version) => {
-                        self.artifacts.parameters.push(version);
-                        ParameterType::OntologyTypeVersion
-                    }
-                };
-                (
-                    Expression::Parameter(self.artifacts.parameters.len()),
-                    parameter_type,
-                )
-            }
+            FilterExpression::Parameter(parameter) => self.compile_parameter(parameter),
         }
     }
 
+    pub fn compile_parameter<'f: 'p>(
+        &mut self,
+        parameter: &'p Parameter<'f>,
+    ) -> (Expression, ParameterType) {
+        let parameter_type = match parameter {
+            Parameter::Number(number) => {
+                self.artifacts.parameters.push(number);
+                ParameterType::Number
+            }
+            Parameter::Text(text) => {
+                self.artifacts.parameters.push(text);
+                ParameterType::Text
+            }
+            Parameter::Boolean(bool) => {
+                self.artifacts.parameters.push(bool);
+                ParameterType::Boolean
+            }
+            Parameter::Any(json) => {
+                self.artifacts.parameters.push(json);
+                ParameterType::Any
+            }
+            Parameter::Uuid(uuid) => {
+                self.artifacts.parameters.push(uuid);
+                ParameterType::Uuid
+            }
+            Parameter::OntologyTypeVersion(version) => {
+                self.artifacts.parameters.push(version);
+                ParameterType::OntologyTypeVersion
+            }
+        };
+        (
+            Expression::Parameter(self.artifacts.parameters.len()),
+            parameter_type,
+        )
+    }
+
     pub fn compile_parameter_list<'f: 'p>(
         &mut self,
         parameters: &'p ParameterList<'f>,
@@ -467,6 +949,9 @@ impl<'p, R: PostgresRecord> SelectCompiler<'p, R> {
                 self.artifacts.parameters.push(uuids);
                 ParameterType::Uuid
             }
+            ParameterList::Parameters(_) => {
+                unreachable!("a list of parameters is compiled as list of conditions")
+            }
         };
         (
             Expression::Parameter(self.artifacts.parameters.len()),
@@ -12,12 +12,19 @@ pub enum Condition {
     Not(Box<Self>),
     Equal(Option<Expression>, Option<Expression>),
     NotEqual(Option<Expression>, Option<Expression>),
+    Less(Expression, Expression),
+    LessOrEqual(Expression, Expression),
+    Greater(Expression, Expression),
+    GreaterOrEqual(Expression, Expression),
     In(Expression, Expression),
     TimeIntervalContainsTimestamp(Expression, Expression),
     Overlap(Expression, Expression),
     StartsWith(Expression, Expression),
     EndsWith(Expression, Expression),
     ContainsSegment(Expression, Expression),
+    Contains(Expression, Expression),
+    Matches(Expression, Expression),
+    Similar(Expression, Expression),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -82,6 +89,26 @@ impl Transpile for Condition {
                 fmt.write_str(" != ")?;
                 rhs.transpile(fmt)
             }
+            Self::Less(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" < ")?;
+                rhs.transpile(fmt)
+            }
+            Self::LessOrEqual(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" <= ")?;
+                rhs.transpile(fmt)
+            }
+            Self::Greater(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" > ")?;
+                rhs.transpile(fmt)
+            }
+            Self::GreaterOrEqual(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" >= ")?;
+                rhs.transpile(fmt)
+            }
             Self::In(lhs, rhs) => {
                 lhs.transpile(fmt)?;
                 fmt.write_str(" = ANY(")?;
@@ -116,6 +143,23 @@ impl Transpile for Condition {
                 rhs.transpile(fmt)?;
                 fmt.write_str(" || '%'")
             }
+            Self::Contains(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" @> ")?;
+                rhs.transpile(fmt)
+            }
+            Self::Matches(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" @@ ")?;
+                rhs.transpile(fmt)
+            }
+            Self::Similar(lhs, rhs) => {
+                // `<%` compares the text on the right to the most similar part of the text on the
+                // left, so the text on the right is the searched text
+                rhs.transpile(fmt)?;
+                fmt.write_str(" <% ")?;
+                lhs.transpile(fmt)
+            }
         }
     }
 }
@@ -125,12 +169,13 @@ mod tests {
     use std::borrow::Cow;
 
     use postgres_types::ToSql;
+    use serde_json::json;
 
     use crate::{
         ontology::{DataTypeQueryPath, DataTypeWithMetadata},
         store::{
             postgres::query::{SelectCompiler, Transpile},
-            query::{Filter, FilterExpression, Parameter},
+            query::{Filter, FilterExpression, Parameter, ParameterList},
         },
         subgraph::temporal_axes::QueryTemporalAxesUnresolved,
     };
@@ -302,4 +347,158 @@ mod tests {
             &[],
         );
     }
+
+    #[test]
+    fn transpile_comparison_condition() {
+        test_condition(
+            &Filter::Less(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("b"))),
+            ),
+            r#""data_types_0_0_0"."schema"->>'title' < $1"#,
+            &[&"b"],
+        );
+
+        test_condition(
+            &Filter::GreaterOrEqual(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("b"))),
+            ),
+            r#""data_types_0_0_0"."schema"->>'title' >= $1"#,
+            &[&"b"],
+        );
+
+        let value = json!(1);
+        test_condition(
+            &Filter::Greater(
+                FilterExpression::Path(DataTypeQueryPath::Schema(None)),
+                FilterExpression::Parameter(Parameter::Any(value.clone())),
+            ),
+            r#"(jsonb_typeof("data_types_0_0_0"."schema") = jsonb_typeof($1)) AND ("data_types_0_0_0"."schema" > $1)"#,
+            &[&value],
+        );
+    }
+
+    #[test]
+    fn transpile_exists_condition() {
+        test_condition(
+            &Filter::Exists(FilterExpression::Path(DataTypeQueryPath::Description)),
+            r#""data_types_0_0_0"."schema"->>'description' IS NOT NULL"#,
+            &[],
+        );
+
+        test_condition(
+            &Filter::IsNull(FilterExpression::Path(DataTypeQueryPath::Description)),
+            r#""data_types_0_0_0"."schema"->>'description' IS NULL"#,
+            &[],
+        );
+
+        test_condition(
+            &Filter::IsNull(FilterExpression::Path(DataTypeQueryPath::Schema(None))),
+            r#"(("data_types_0_0_0"."schema" IS NULL) OR ("data_types_0_0_0"."schema" = 'null'))"#,
+            &[],
+        );
+    }
+
+    #[test]
+    fn transpile_in_condition() {
+        test_condition(
+            &Filter::In(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                ParameterList::Parameters(vec![
+                    Parameter::Text(Cow::Borrowed("a")),
+                    Parameter::Text(Cow::Borrowed("b")),
+                ]),
+            ),
+            r#"(("data_types_0_0_0"."schema"->>'title' = $1) OR ("data_types_0_0_0"."schema"->>'title' = $2))"#,
+            &[&"a", &"b"],
+        );
+    }
+
+    #[test]
+    fn transpile_contains_condition() {
+        let value = json!({ "type": "string" });
+        test_condition(
+            &Filter::Contains(
+                FilterExpression::Path(DataTypeQueryPath::Schema(None)),
+                FilterExpression::Parameter(Parameter::Any(value.clone())),
+            ),
+            r#""data_types_0_0_0"."schema" @> $1"#,
+            &[&value],
+        );
+    }
+
+    #[test]
+    fn transpile_search_condition() {
+        test_condition(
+            &Filter::Search(
+                FilterExpression::Path(DataTypeQueryPath::Description),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("text"))),
+            ),
+            r#"to_tsvector('english'::regconfig, "data_types_0_0_0"."schema"->>'description') @@ websearch_to_tsquery('english'::regconfig, $1)"#,
+            &[&"text"],
+        );
+
+        test_condition(
+            &Filter::Search(
+                FilterExpression::Path(DataTypeQueryPath::Schema(None)),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("text"))),
+            ),
+            r#"jsonb_to_tsvector('english'::regconfig, "data_types_0_0_0"."schema", '["string"]'::jsonb) @@ websearch_to_tsquery('english'::regconfig, $1)"#,
+            &[&"text"],
+        );
+    }
+
+    #[test]
+    fn transpile_fuzzy_search_condition() {
+        test_condition(
+            &Filter::FuzzySearch(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("txet"))),
+            ),
+            r#"$1 <% "data_types_0_0_0"."schema"->>'title'"#,
+            &[&"txet"],
+        );
+
+        test_condition(
+            &Filter::FuzzySearch(
+                FilterExpression::Path(DataTypeQueryPath::Schema(None)),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("txet"))),
+            ),
+            r#"$1 <% ((jsonb_path_query_array("data_types_0_0_0"."schema", 'strict $.** ? (@.type() == "string")'::jsonpath)) #>> '{}'::text[])"#,
+            &[&"txet"],
+        );
+    }
+
+    #[test]
+    fn transpile_case_insensitive_condition() {
+        test_condition(
+            &Filter::CaseInsensitive(Box::new(Filter::Equal(
+                Some(FilterExpression::Path(DataTypeQueryPath::Title)),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    "Text",
+                )))),
+            ))),
+            r#"lower("data_types_0_0_0"."schema"->>'title') = lower($1)"#,
+            &[&"Text"],
+        );
+
+        test_condition(
+            &Filter::CaseInsensitive(Box::new(Filter::StartsWith(
+                FilterExpression::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("Te"))),
+            ))),
+            r#"lower("data_types_0_0_0"."schema"->>'title') LIKE lower($1) || '%'"#,
+            &[&"Te"],
+        );
+
+        test_condition(
+            &Filter::CaseInsensitive(Box::new(Filter::Equal(
+                Some(FilterExpression::Path(DataTypeQueryPath::Schema(None))),
+                Some(FilterExpression::Parameter(Parameter::Any(json!("Text")))),
+            ))),
+            r#"lower((("data_types_0_0_0"."schema") #>> '{}'::text[])) = lower($1)"#,
+            &[&"Text"],
+        );
+    }
 }
@@ -1,3 +1,4 @@
+mod aggregation_clause;
 mod conditional;
 mod join_clause;
 mod order_clause;
@@ -6,6 +7,7 @@ mod where_clause;
 mod with_clause;
 
 pub use self::{
+    aggregation_clause::{AggregationColumn, AggregationExpression},
     conditional::{Constant, Expression, Function},
     join_clause::{JoinCondition, JoinExpression},
     order_clause::{OrderByExpression, Ordering},
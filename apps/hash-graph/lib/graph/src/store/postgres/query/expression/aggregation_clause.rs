@@ -0,0 +1,164 @@
+use std::fmt::{self, Write};
+
+/// A column selected by an [`AggregationExpression`].
+///
+/// The index refers to a column of the aggregated selection.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AggregationColumn {
+    /// Groups the rows by the value of the column, which is selected as is.
+    Group(usize),
+    /// Counts the rows, or the rows with a value in the column.
+    Count(Option<usize>),
+    Min(usize),
+    Max(usize),
+    Sum(usize),
+    Average(usize),
+}
+
+/// Computes aggregates over the rows of a selection.
+///
+/// The selection is used as subquery, so its rows can be deduplicated by `DISTINCT ON` before they
+/// are aggregated. The aggregates are selected as JSON values and the groups are ordered by their
+/// values.
+#[derive(Debug, Default, PartialEq, Eq, Hash)]
+pub struct AggregationExpression {
+    columns: Vec<AggregationColumn>,
+}
+
+impl AggregationExpression {
+    /// Adds the `column` and returns its index in the aggregation.
+    pub fn push(&mut self, column: AggregationColumn) -> usize {
+        self.columns.push(column);
+        self.columns.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Transpiles the selected aggregates up to the start of the subquery.
+    pub fn transpile_select(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("SELECT ")?;
+        for (idx, column) in self.columns.iter().enumerate() {
+            if idx > 0 {
+                fmt.write_str(", ")?;
+            }
+            let (function, index) = match column {
+                AggregationColumn::Group(index) => {
+                    write!(fmt, r#""column_{index}""#)?;
+                    continue;
+                }
+                AggregationColumn::Count(None) => {
+                    fmt.write_str("to_jsonb(COUNT(*))")?;
+                    continue;
+                }
+                AggregationColumn::Count(Some(index)) => ("COUNT", index),
+                AggregationColumn::Min(index) => ("MIN", index),
+                AggregationColumn::Max(index) => ("MAX", index),
+                AggregationColumn::Sum(index) => ("SUM", index),
+                AggregationColumn::Average(index) => ("AVG", index),
+            };
+            write!(fmt, r#"to_jsonb({function}("column_{index}"))"#)?;
+        }
+        fmt.write_str("\nFROM (\n")
+    }
+
+    /// Transpiles the end of the subquery, which selects `num_columns` columns, and the grouping
+    /// of its rows.
+    pub fn transpile_group_by(&self, num_columns: usize, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("\n) AS \"aggregated\"(")?;
+        for index in 0..num_columns {
+            if index > 0 {
+                fmt.write_str(", ")?;
+            }
+            write!(fmt, r#""column_{index}""#)?;
+        }
+        fmt.write_char(')')?;
+
+        let groups = self
+            .columns
+            .iter()
+            .filter_map(|column| match column {
+                AggregationColumn::Group(index) => Some(*index),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if groups.is_empty() {
+            return Ok(());
+        }
+
+        fmt.write_str("\nGROUP BY ")?;
+        for (idx, index) in groups.iter().enumerate() {
+            if idx > 0 {
+                fmt.write_str(", ")?;
+            }
+            write!(fmt, r#""column_{index}""#)?;
+        }
+        fmt.write_str("\nORDER BY ")?;
+        for (idx, index) in groups.iter().enumerate() {
+            if idx > 0 {
+                fmt.write_str(", ")?;
+            }
+            write!(fmt, r#""column_{index}" ASC"#)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::postgres::query::test_helper::trim_whitespace;
+
+    struct Aggregation<'a>(&'a AggregationExpression, &'static str);
+
+    impl fmt::Display for Aggregation<'_> {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.transpile_select(fmt)?;
+            fmt.write_str(self.1)?;
+            self.0.transpile_group_by(3, fmt)
+        }
+    }
+
+    #[test]
+    fn aggregate_groups() {
+        let mut aggregation = AggregationExpression::default();
+        assert_eq!(aggregation.push(AggregationColumn::Group(1)), 0);
+        assert_eq!(aggregation.push(AggregationColumn::Count(None)), 1);
+        assert_eq!(aggregation.push(AggregationColumn::Sum(2)), 2);
+
+        assert_eq!(
+            trim_whitespace(Aggregation(&aggregation, "SELECT 1, 2, 3").to_string()),
+            trim_whitespace(
+                r#"
+                SELECT "column_1", to_jsonb(COUNT(*)), to_jsonb(SUM("column_2"))
+                FROM (
+                  SELECT 1, 2, 3
+                ) AS "aggregated"("column_0", "column_1", "column_2")
+                GROUP BY "column_1"
+                ORDER BY "column_1" ASC
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn aggregate_without_groups() {
+        let mut aggregation = AggregationExpression::default();
+        aggregation.push(AggregationColumn::Count(Some(0)));
+        aggregation.push(AggregationColumn::Average(1));
+
+        assert_eq!(
+            trim_whitespace(Aggregation(&aggregation, "SELECT 1, 2, 3").to_string()),
+            trim_whitespace(
+                r#"
+                SELECT to_jsonb(COUNT("column_0")), to_jsonb(AVG("column_1"))
+                FROM (
+                  SELECT 1, 2, 3
+                ) AS "aggregated"("column_0", "column_1", "column_2")
+                "#
+            )
+        );
+    }
+}
@@ -2,17 +2,31 @@ use std::fmt::{self, Write};
 
 use crate::store::postgres::query::{AliasedColumn, Transpile, WindowStatement};
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+/// The text search configuration used for full-text search.
+///
+/// The indexes created by the migrations have to use the same configuration to be used by a query.
+const TEXT_SEARCH_CONFIGURATION: &str = "'english'::regconfig";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Function {
     Min(Box<Expression>),
     Max(Box<Expression>),
     JsonExtractText(Box<Expression>),
+    JsonExtractNumber(Box<Expression>),
     JsonExtractPath(Vec<Expression>),
+    JsonTypeof(Box<Expression>),
     JsonContains(Box<Expression>, Box<Expression>),
     JsonBuildArray(Vec<Expression>),
     JsonBuildObject(Vec<(Expression, Expression)>),
+    ToJson(Box<Expression>),
     Lower(Box<Expression>),
     Upper(Box<Expression>),
+    ToTsVector(Box<Expression>),
+    JsonToTsVector(Box<Expression>),
+    JsonStringValues(Box<Expression>),
+    WebSearchToTsQuery(Box<Expression>),
+    TsRank(Box<Expression>, Box<Expression>),
+    WordSimilarity(Box<Expression>, Box<Expression>),
     Now,
 }
 
@@ -44,6 +58,19 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_str(") #>> '{}'::text[])")
             }
+            // Any other JSON value than a number is treated as absent value
+            Self::JsonExtractNumber(expression) => {
+                fmt.write_str("CASE WHEN jsonb_typeof(")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(") = 'number' THEN (")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(")::numeric END")
+            }
+            Self::JsonTypeof(expression) => {
+                fmt.write_str("jsonb_typeof(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::JsonContains(json, value) => {
                 fmt.write_str("jsonb_contains(")?;
                 json.transpile(fmt)?;
@@ -73,6 +100,11 @@ impl Transpile for Function {
                 }
                 fmt.write_char(')')
             }
+            Self::ToJson(expression) => {
+                fmt.write_str("to_jsonb(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::Now => fmt.write_str("now()"),
             Self::Lower(expression) => {
                 fmt.write_str("lower(")?;
@@ -84,11 +116,45 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::ToTsVector(expression) => {
+                write!(fmt, "to_tsvector({TEXT_SEARCH_CONFIGURATION}, ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::JsonToTsVector(expression) => {
+                write!(fmt, "jsonb_to_tsvector({TEXT_SEARCH_CONFIGURATION}, ")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(", '[\"string\"]'::jsonb)")
+            }
+            Self::JsonStringValues(expression) => {
+                fmt.write_str("jsonb_path_query_array(")?;
+                expression.transpile(fmt)?;
+                fmt.write_str(r#", 'strict $.** ? (@.type() == "string")'::jsonpath)"#)
+            }
+            Self::WebSearchToTsQuery(expression) => {
+                write!(fmt, "websearch_to_tsquery({TEXT_SEARCH_CONFIGURATION}, ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::TsRank(vector, query) => {
+                fmt.write_str("ts_rank(")?;
+                vector.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                query.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::WordSimilarity(lhs, rhs) => {
+                fmt.write_str("word_similarity(")?;
+                lhs.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                rhs.transpile(fmt)?;
+                fmt.write_char(')')
+            }
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Constant {
     Boolean(bool),
     String(&'static str),
@@ -104,7 +170,7 @@ impl Transpile for Constant {
 }
 
 /// A compiled expression in Postgres.
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Expression {
     Asterisk,
     Column(AliasedColumn),
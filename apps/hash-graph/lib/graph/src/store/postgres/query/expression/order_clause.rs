@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::store::postgres::query::{AliasedColumn, Transpile};
+use crate::store::postgres::query::{Expression, Transpile};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Ordering {
@@ -10,12 +10,16 @@ pub enum Ordering {
 
 #[derive(Debug, Default, PartialEq, Eq, Hash)]
 pub struct OrderByExpression {
-    columns: Vec<(AliasedColumn, Ordering)>,
+    columns: Vec<(Expression, Ordering)>,
 }
 
 impl OrderByExpression {
-    pub fn push(&mut self, column: AliasedColumn, ordering: Ordering) {
-        self.columns.push((column, ordering));
+    pub fn push(&mut self, expression: Expression, ordering: Ordering) {
+        self.columns.push((expression, ordering));
+    }
+
+    pub fn insert(&mut self, index: usize, expression: Expression, ordering: Ordering) {
+        self.columns.insert(index, (expression, ordering));
     }
 
     pub fn is_empty(&self) -> bool {
@@ -30,11 +34,11 @@ impl Transpile for OrderByExpression {
         }
 
         fmt.write_str("ORDER BY ")?;
-        for (idx, (column, ordering)) in self.columns.iter().enumerate() {
+        for (idx, (expression, ordering)) in self.columns.iter().enumerate() {
             if idx > 0 {
                 fmt.write_str(", ")?;
             }
-            column.transpile(fmt)?;
+            expression.transpile(fmt)?;
             match ordering {
                 Ordering::Ascending => write!(fmt, " ASC")?,
                 Ordering::Descending => write!(fmt, " DESC")?,
@@ -57,13 +61,15 @@ mod tests {
     fn order_one() {
         let mut order_by_expression = OrderByExpression::default();
         order_by_expression.push(
-            DataTypeQueryPath::Version
-                .terminating_column()
-                .aliased(Alias {
-                    condition_index: 1,
-                    chain_depth: 2,
-                    number: 3,
-                }),
+            Expression::Column(
+                DataTypeQueryPath::Version
+                    .terminating_column()
+                    .aliased(Alias {
+                        condition_index: 1,
+                        chain_depth: 2,
+                        number: 3,
+                    }),
+            ),
             Ordering::Ascending,
         );
         assert_eq!(
@@ -76,21 +82,23 @@ mod tests {
     fn order_multiple() {
         let mut order_by_expression = OrderByExpression::default();
         order_by_expression.push(
-            DataTypeQueryPath::BaseUrl
-                .terminating_column()
-                .aliased(Alias {
-                    condition_index: 1,
-                    chain_depth: 2,
-                    number: 3,
-                }),
+            Expression::Column(
+                DataTypeQueryPath::BaseUrl
+                    .terminating_column()
+                    .aliased(Alias {
+                        condition_index: 1,
+                        chain_depth: 2,
+                        number: 3,
+                    }),
+            ),
             Ordering::Ascending,
         );
         order_by_expression.push(
-            DataTypeQueryPath::Type.terminating_column().aliased(Alias {
+            Expression::Column(DataTypeQueryPath::Type.terminating_column().aliased(Alias {
                 condition_index: 4,
                 chain_depth: 5,
                 number: 6,
-            }),
+            })),
             Ordering::Descending,
         );
 
@@ -55,7 +55,7 @@ impl Transpile for WithExpression {
 mod tests {
     use super::*;
     use crate::store::postgres::query::{
-        expression::OrderByExpression,
+        expression::{AggregationExpression, OrderByExpression},
         test_helper::{max_version_expression, trim_whitespace},
         Alias, Expression, SelectExpression, SelectStatement, Table, WhereExpression,
     };
@@ -79,7 +79,9 @@ mod tests {
             }),
             joins: vec![],
             where_expression: WhereExpression::default(),
+            aggregation: AggregationExpression::default(),
             order_by_expression: OrderByExpression::default(),
+            limit: None,
         });
 
         assert_eq!(
@@ -101,7 +103,9 @@ mod tests {
             }),
             joins: vec![],
             where_expression: WhereExpression::default(),
+            aggregation: AggregationExpression::default(),
             order_by_expression: OrderByExpression::default(),
+            limit: None,
         });
 
         assert_eq!(
@@ -1,19 +1,22 @@
 use std::fmt::{self, Write};
 
 use crate::store::postgres::query::{
-    expression::OrderByExpression, AliasedColumn, AliasedTable, JoinExpression, SelectExpression,
-    Transpile, WhereExpression, WithExpression,
+    expression::{AggregationExpression, OrderByExpression},
+    AliasedTable, Expression, JoinExpression, SelectExpression, Transpile, WhereExpression,
+    WithExpression,
 };
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SelectStatement {
     pub with: WithExpression,
-    pub distinct: Vec<AliasedColumn>,
+    pub distinct: Vec<Expression>,
     pub selects: Vec<SelectExpression>,
     pub from: AliasedTable,
     pub joins: Vec<JoinExpression>,
     pub where_expression: WhereExpression,
+    pub aggregation: AggregationExpression,
     pub order_by_expression: OrderByExpression,
+    pub limit: Option<usize>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -29,16 +32,21 @@ impl Transpile for SelectStatement {
             fmt.write_char('\n')?;
         }
 
+        // The aggregates are computed over the rows of this statement
+        if !self.aggregation.is_empty() {
+            self.aggregation.transpile_select(fmt)?;
+        }
+
         fmt.write_str("SELECT ")?;
 
         if !self.distinct.is_empty() {
             fmt.write_str("DISTINCT ON(")?;
 
-            for (idx, column) in self.distinct.iter().enumerate() {
+            for (idx, expression) in self.distinct.iter().enumerate() {
                 if idx > 0 {
                     fmt.write_str(", ")?;
                 }
-                column.transpile(fmt)?;
+                expression.transpile(fmt)?;
             }
             fmt.write_str(") ")?;
         }
@@ -69,6 +77,15 @@ impl Transpile for SelectStatement {
             self.order_by_expression.transpile(fmt)?;
         }
 
+        if let Some(limit) = self.limit {
+            write!(fmt, "\nLIMIT {limit}")?;
+        }
+
+        if !self.aggregation.is_empty() {
+            self.aggregation
+                .transpile_group_by(self.selects.len(), fmt)?;
+        }
+
         Ok(())
     }
 }
@@ -78,9 +95,11 @@ mod tests {
     use std::borrow::Cow;
 
     use postgres_types::ToSql;
+    use serde_json::json;
     use uuid::Uuid;
 
     use crate::{
+        identifier::ontology::OntologyTypeVersion,
         knowledge::{Entity, EntityQueryPath},
         ontology::{
             DataTypeQueryPath, DataTypeWithMetadata, EntityTypeQueryPath, EntityTypeWithMetadata,
@@ -88,10 +107,13 @@ mod tests {
         },
         store::{
             postgres::query::{
-                test_helper::trim_whitespace, Distinctness, Ordering, PostgresRecord,
-                SelectCompiler,
+                test_helper::trim_whitespace, Alias, Distinctness, Expression, Ordering,
+                PostgresQueryPath, PostgresRecord, SelectCompiler,
+            },
+            query::{
+                Aggregation, Filter, FilterExpression, Grouping, JsonPath, Parameter, PathToken,
+                SortDirection, Sorting,
             },
-            query::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
         },
         subgraph::{
             edges::{EdgeDirection, KnowledgeGraphEdgeKind, OntologyEdgeKind, SharedEdgeKind},
@@ -927,6 +949,264 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_sorting_with_cursor() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/name/""#,
+        ))]);
+        let sorting = Sorting {
+            path: EntityQueryPath::Properties(Some(json_path.clone())),
+            direction: SortDirection::Descending,
+            relevance: None,
+            fuzzy: false,
+        };
+        let cursor_values = [Some(json!("Bob"))];
+        let entity_uuid = Uuid::nil();
+
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes));
+        compiler.add_distinct_selection_with_ordering(
+            &EntityQueryPath::Uuid,
+            Distinctness::Distinct,
+            Some(Ordering::Ascending),
+        );
+        compiler.add_sorting(&sorting);
+        compiler.add_cursor(&cursor_values, [(
+            Expression::Column(EntityQueryPath::Uuid.terminating_column().aliased(Alias {
+                condition_index: 0,
+                chain_depth: 0,
+                number: 0,
+            })),
+            &entity_uuid as &(dyn ToSql + Sync),
+        )]);
+        compiler.set_limit(10);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                DISTINCT ON(to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", $3::text::jsonpath)), "entity_temporal_metadata_0_0_0"."entity_uuid")
+                "entity_temporal_metadata_0_0_0"."entity_uuid",
+                to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", $3::text::jsonpath))
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+              AND (((to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", $3::text::jsonpath)) < $4))
+                OR ((to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", $3::text::jsonpath)) = $4)
+                  AND ("entity_temporal_metadata_0_0_0"."entity_uuid" > $5)))
+            ORDER BY to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", $3::text::jsonpath)) DESC,
+                     "entity_temporal_metadata_0_0_0"."entity_uuid" ASC
+            LIMIT 10
+            "#,
+            &[
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &json_path,
+                &json!("Bob"),
+                &entity_uuid,
+            ],
+        );
+    }
+
+    #[test]
+    fn data_type_sorting_with_absent_cursor_value() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let cursor_values = [None];
+        let base_url = "https://blockprotocol.org/@blockprotocol/types/data-type/text/";
+        let version = OntologyTypeVersion::new(1);
+        let sorting = Sorting {
+            path: DataTypeQueryPath::Title,
+            direction: SortDirection::Ascending,
+            relevance: None,
+            fuzzy: false,
+        };
+
+        let mut compiler = SelectCompiler::<DataTypeWithMetadata>::new(Some(&temporal_axes));
+        compiler.add_distinct_selection_with_ordering(
+            &DataTypeQueryPath::BaseUrl,
+            Distinctness::Distinct,
+            Some(Ordering::Ascending),
+        );
+        compiler.add_distinct_selection_with_ordering(
+            &DataTypeQueryPath::Version,
+            Distinctness::Distinct,
+            Some(Ordering::Ascending),
+        );
+        compiler.add_sorting(&sorting);
+        let alias = Alias {
+            condition_index: 0,
+            chain_depth: 1,
+            number: 0,
+        };
+        compiler.add_cursor(&cursor_values, [
+            (
+                Expression::Column(
+                    DataTypeQueryPath::BaseUrl
+                        .terminating_column()
+                        .aliased(alias),
+                ),
+                &base_url as &(dyn ToSql + Sync),
+            ),
+            (
+                Expression::Column(
+                    DataTypeQueryPath::Version
+                        .terminating_column()
+                        .aliased(alias),
+                ),
+                &version as &(dyn ToSql + Sync),
+            ),
+        ]);
+        compiler.set_limit(5);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                DISTINCT ON(to_jsonb("data_types_0_0_0"."schema"->>'title'), "ontology_id_with_metadata_0_1_0"."base_url", "ontology_id_with_metadata_0_1_0"."version")
+                "ontology_id_with_metadata_0_1_0"."base_url",
+                "ontology_id_with_metadata_0_1_0"."version",
+                to_jsonb("data_types_0_0_0"."schema"->>'title')
+            FROM "data_types" AS "data_types_0_0_0"
+            INNER JOIN "ontology_id_with_metadata" AS "ontology_id_with_metadata_0_1_0"
+              ON "ontology_id_with_metadata_0_1_0"."ontology_id" = "data_types_0_0_0"."ontology_id"
+            WHERE (((to_jsonb("data_types_0_0_0"."schema"->>'title') IS NULL)
+                AND ("ontology_id_with_metadata_0_1_0"."base_url" > $1))
+              OR ((to_jsonb("data_types_0_0_0"."schema"->>'title') IS NULL)
+                AND ("ontology_id_with_metadata_0_1_0"."base_url" = $1)
+                AND ("ontology_id_with_metadata_0_1_0"."version" > $2)))
+            ORDER BY to_jsonb("data_types_0_0_0"."schema"->>'title') ASC,
+                     "ontology_id_with_metadata_0_1_0"."base_url" ASC,
+                     "ontology_id_with_metadata_0_1_0"."version" ASC
+            LIMIT 5
+            "#,
+            &[&base_url, &version],
+        );
+    }
+
+    #[test]
+    fn data_type_search_by_relevance() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let filter = Filter::Search(
+            FilterExpression::Path(DataTypeQueryPath::Title),
+            FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("text"))),
+        );
+        let sorting = Sorting {
+            path: DataTypeQueryPath::Title,
+            direction: SortDirection::Descending,
+            relevance: Some(Cow::Borrowed("text")),
+            fuzzy: false,
+        };
+
+        let mut compiler =
+            SelectCompiler::<DataTypeWithMetadata>::with_asterisk(Some(&temporal_axes));
+        compiler.add_filter(&filter);
+        compiler.add_sorting(&sorting);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *, to_jsonb(ts_rank(to_tsvector('english'::regconfig, "data_types_0_0_0"."schema"->>'title'), websearch_to_tsquery('english'::regconfig, $2)))
+            FROM "data_types" AS "data_types_0_0_0"
+            WHERE to_tsvector('english'::regconfig, "data_types_0_0_0"."schema"->>'title') @@ websearch_to_tsquery('english'::regconfig, $1)
+            ORDER BY to_jsonb(ts_rank(to_tsvector('english'::regconfig, "data_types_0_0_0"."schema"->>'title'), websearch_to_tsquery('english'::regconfig, $2))) DESC
+            "#,
+            &[&"text", &"text"],
+        );
+    }
+
+    #[test]
+    fn entity_aggregation() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/age/""#,
+        ))]);
+        let grouping = Grouping {
+            path: EntityQueryPath::OwnedById,
+        };
+        let count = Aggregation::Count { path: None };
+        let sum = Aggregation::Sum {
+            path: EntityQueryPath::Properties(Some(json_path.clone())),
+        };
+
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes));
+        for path in [
+            &EntityQueryPath::OwnedById,
+            &EntityQueryPath::Uuid,
+            &EntityQueryPath::DecisionTime,
+            &EntityQueryPath::TransactionTime,
+        ] {
+            compiler.add_distinct_selection_with_ordering(path, Distinctness::Distinct, None);
+        }
+        compiler.add_grouping(&grouping);
+        compiler.add_aggregation(&count);
+        compiler.add_aggregation(&sum);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT "column_4", to_jsonb(COUNT(*)), to_jsonb(SUM("column_5"))
+            FROM (
+              SELECT
+                  DISTINCT ON("entity_temporal_metadata_0_0_0"."owned_by_id", "entity_temporal_metadata_0_0_0"."entity_uuid", "entity_temporal_metadata_0_0_0"."decision_time", "entity_temporal_metadata_0_0_0"."transaction_time", to_jsonb("entity_temporal_metadata_0_0_0"."owned_by_id"))
+                  "entity_temporal_metadata_0_0_0"."owned_by_id",
+                  "entity_temporal_metadata_0_0_0"."entity_uuid",
+                  "entity_temporal_metadata_0_0_0"."decision_time",
+                  "entity_temporal_metadata_0_0_0"."transaction_time",
+                  to_jsonb("entity_temporal_metadata_0_0_0"."owned_by_id"),
+                  CASE WHEN jsonb_typeof(jsonb_path_query_first("entity_editions_0_1_0"."properties", $3::text::jsonpath)) = 'number'
+                    THEN (jsonb_path_query_first("entity_editions_0_1_0"."properties", $3::text::jsonpath))::numeric END
+              FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+              INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+                ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+              WHERE "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+                AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+            ) AS "aggregated"("column_0", "column_1", "column_2", "column_3", "column_4", "column_5")
+            GROUP BY "column_4"
+            ORDER BY "column_4" ASC
+            "#,
+            &[
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &json_path,
+            ],
+        );
+    }
+
+    #[test]
+    fn data_type_fuzzy_search_by_similarity() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let filter = Filter::FuzzySearch(
+            FilterExpression::Path(DataTypeQueryPath::Title),
+            FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("txet"))),
+        );
+        let sorting = Sorting {
+            path: DataTypeQueryPath::Title,
+            direction: SortDirection::Descending,
+            relevance: Some(Cow::Borrowed("txet")),
+            fuzzy: true,
+        };
+
+        let mut compiler =
+            SelectCompiler::<DataTypeWithMetadata>::with_asterisk(Some(&temporal_axes));
+        compiler.add_filter(&filter);
+        compiler.add_sorting(&sorting);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT *, to_jsonb(word_similarity($2, "data_types_0_0_0"."schema"->>'title'))
+            FROM "data_types" AS "data_types_0_0_0"
+            WHERE $1 <% "data_types_0_0_0"."schema"->>'title'
+            ORDER BY to_jsonb(word_similarity($2, "data_types_0_0_0"."schema"->>'title')) DESC
+            "#,
+            &[&"txet", &"txet"],
+        );
+    }
+
     mod predefined {
         use type_system::url::{BaseUrl, VersionedUrl};
 
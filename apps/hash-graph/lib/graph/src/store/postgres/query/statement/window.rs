@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::store::postgres::query::{AliasedColumn, Expression, Transpile};
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WindowStatement {
     partition: Vec<Expression>,
 }
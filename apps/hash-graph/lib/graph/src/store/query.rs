@@ -1,11 +1,17 @@
+mod aggregation;
 mod filter;
 mod path;
+mod sorting;
 
 use std::fmt;
 
 pub use self::{
+    aggregation::{
+        Aggregation, AggregationGroup, AggregationQuery, EntityAggregationQuery, Grouping,
+    },
     filter::{Filter, FilterExpression, Parameter, ParameterConversionError, ParameterList},
     path::{JsonPath, PathToken},
+    sorting::{Cursor, SortDirection, Sorting},
 };
 
 pub trait QueryPath {
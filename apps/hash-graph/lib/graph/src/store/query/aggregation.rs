@@ -0,0 +1,220 @@
+use std::fmt;
+
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{
+    openapi::{ArrayBuilder, ObjectBuilder, Ref, RefOr, Schema, SchemaType},
+    ToSchema,
+};
+
+use crate::{
+    knowledge::Entity,
+    store::{query::Filter, Record},
+    subgraph::temporal_axes::QueryTemporalAxesUnresolved,
+};
+
+/// A value computed over the records in a group of an [`AggregationQuery`].
+///
+/// Every function except `count` requires a `path` pointing to a numeric value. Inside of a JSON
+/// value, e.g. the properties of an entity, only numbers are taken into account, any other value
+/// is treated like an absent value.
+#[derive(Derivative, Deserialize)]
+#[derivative(
+    Debug(bound = "R::QueryPath<'p>: fmt::Debug"),
+    PartialEq(bound = "R::QueryPath<'p>: PartialEq")
+)]
+#[serde(
+    tag = "function",
+    rename_all = "camelCase",
+    deny_unknown_fields,
+    bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>"
+)]
+pub enum Aggregation<'p, R: Record + ?Sized> {
+    /// The number of records, or the number of records with a value at `path`.
+    Count {
+        #[serde(default)]
+        path: Option<R::QueryPath<'p>>,
+    },
+    Min {
+        path: R::QueryPath<'p>,
+    },
+    Max {
+        path: R::QueryPath<'p>,
+    },
+    Sum {
+        path: R::QueryPath<'p>,
+    },
+    Average {
+        path: R::QueryPath<'p>,
+    },
+}
+
+/// Groups the records of an [`AggregationQuery`] by the value at `path`.
+///
+/// Records without a value at `path` form a group on their own.
+#[derive(Derivative, Deserialize)]
+#[derivative(
+    Debug(bound = "R::QueryPath<'p>: fmt::Debug"),
+    PartialEq(bound = "R::QueryPath<'p>: PartialEq")
+)]
+#[serde(
+    rename_all = "camelCase",
+    deny_unknown_fields,
+    bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>"
+)]
+pub struct Grouping<'p, R: Record + ?Sized> {
+    pub path: R::QueryPath<'p>,
+}
+
+/// Computes [`Aggregation`]s over the records matching a [`Filter`] without reading the records.
+///
+/// The records are counted the same way as the root vertices of a [`StructuralQuery`] with the
+/// same filter and temporal axes, so every edition of a record in the variable temporal axis is
+/// taken into account. An edition is only aggregated once, even if the filter matches it through
+/// multiple links or types.
+///
+/// If `groupBy` is empty, the aggregations are computed over all matching records and a single
+/// [`AggregationGroup`] is returned. Otherwise, one [`AggregationGroup`] is returned for every
+/// distinct combination of values of the [`Grouping`]s.
+///
+/// # Examples
+///
+/// This counts the entities of every entity type and sums up the value of the `age` property:
+///
+/// ```json
+/// {
+///   "filter": { "all": [] },
+///   "temporalAxes": {
+///     "pinned": { "axis": "transactionTime", "timestamp": null },
+///     "variable": {
+///       "axis": "decisionTime",
+///       "interval": { "start": null, "end": null }
+///     }
+///   },
+///   "groupBy": [
+///     { "path": ["type", "versionedUrl"] }
+///   ],
+///   "aggregations": [
+///     { "function": "count" },
+///     { "function": "sum", "path": ["properties", "https://example.com/property-type/age/"] }
+///   ]
+/// }
+/// ```
+///
+/// [`StructuralQuery`]: crate::subgraph::query::StructuralQuery
+#[derive(Deserialize, Derivative)]
+#[derivative(Debug(bound = "R::QueryPath<'p>: fmt::Debug"))]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "camelCase",
+    bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>"
+)]
+pub struct AggregationQuery<'p, R: Record> {
+    pub filter: Filter<'p, R>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    #[serde(default)]
+    pub group_by: Vec<Grouping<'p, R>>,
+    pub aggregations: Vec<Aggregation<'p, R>>,
+}
+
+pub type EntityAggregationQuery = AggregationQuery<'static, Entity>;
+
+impl<'p> ToSchema<'_> for AggregationQuery<'p, Entity> {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        (
+            "EntityAggregationQuery",
+            ObjectBuilder::new()
+                .property("filter", Ref::from_schema_name("Filter"))
+                .required("filter")
+                .property(
+                    "temporalAxes",
+                    Ref::from_schema_name(QueryTemporalAxesUnresolved::schema().0),
+                )
+                .required("temporalAxes")
+                .property(
+                    "groupBy",
+                    ArrayBuilder::new().items(Ref::from_schema_name("Grouping")),
+                )
+                .property(
+                    "aggregations",
+                    ArrayBuilder::new().items(Ref::from_schema_name("Aggregation")),
+                )
+                .required("aggregations")
+                .into(),
+        )
+    }
+}
+
+/// The result of an [`AggregationQuery`] for one group of records.
+///
+/// `key` contains the value of every [`Grouping`] and `values` contains the result of every
+/// [`Aggregation`], both in the order they were requested. An absent value is `null`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregationGroup {
+    pub key: Vec<Value>,
+    pub values: Vec<Value>,
+}
+
+impl ToSchema<'_> for AggregationGroup {
+    fn schema() -> (&'static str, RefOr<Schema>) {
+        // `SchemaType::Value` accepts any JSON value
+        let values =
+            || ArrayBuilder::new().items(ObjectBuilder::new().schema_type(SchemaType::Value));
+
+        (
+            "AggregationGroup",
+            ObjectBuilder::new()
+                .property("key", values())
+                .required("key")
+                .property("values", values())
+                .required("values")
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::knowledge::EntityQueryPath;
+
+    #[test]
+    fn aggregation_query_representation() {
+        let query = EntityAggregationQuery::deserialize(json!({
+            "filter": { "all": [] },
+            "temporalAxes": {
+                "pinned": { "axis": "transactionTime", "timestamp": null },
+                "variable": {
+                    "axis": "decisionTime",
+                    "interval": { "start": null, "end": null }
+                }
+            },
+            "groupBy": [{ "path": ["ownedById"] }],
+            "aggregations": [
+                { "function": "count" },
+                { "function": "max", "path": ["properties"] }
+            ]
+        }))
+        .expect("could not deserialize aggregation query");
+
+        assert_eq!(query.group_by, [Grouping {
+            path: EntityQueryPath::OwnedById
+        }]);
+        assert_eq!(query.aggregations, [
+            Aggregation::Count { path: None },
+            Aggregation::Max {
+                path: EntityQueryPath::Properties(None)
+            }
+        ]);
+    }
+
+    #[test]
+    fn aggregation_requires_path() {
+        Aggregation::<Entity>::deserialize(json!({ "function": "sum" }))
+            .expect_err("deserialized an aggregation without path");
+    }
+}
@@ -39,11 +39,41 @@ pub enum Filter<'p, R: Record + ?Sized> {
         Option<FilterExpression<'p, R>>,
         Option<FilterExpression<'p, R>>,
     ),
-    #[serde(skip)]
+    Less(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    LessOrEqual(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    Greater(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    GreaterOrEqual(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the expression has a value. A JSON `null` inside of a JSON value counts as
+    /// value.
+    Exists(FilterExpression<'p, R>),
+    /// Matches if the expression has no value or is the JSON `null`.
+    IsNull(FilterExpression<'p, R>),
     In(FilterExpression<'p, R>, ParameterList<'p>),
     StartsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     EndsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     ContainsSegment(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the JSON value on the left contains the JSON value on the right.
+    ///
+    /// A JSON array contains another array if every element of the other array is contained in
+    /// it, a single value is contained if it is an element of the array.
+    Contains(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the text on the left matches the full-text search query on the right.
+    ///
+    /// Words are compared by their stem, so `"running"` matches `"run"`. The query supports the
+    /// syntax of web search engines: `"quoted text"` matches a phrase, `or` matches either side,
+    /// and `-` excludes a word. Searching in a JSON value, e.g. the properties of an entity,
+    /// searches every string inside of it.
+    Search(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if a part of the text on the left is similar to the text on the right.
+    ///
+    /// Texts are similar if they share enough trigrams, i.e. groups of three consecutive
+    /// characters, which tolerates typos and is not sensitive to case. Searching in a JSON value,
+    /// e.g. the properties of an entity, compares the strings inside of it, but not the keys.
+    FuzzySearch(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Compares text case-insensitively in the nested filter.
+    ///
+    /// This applies to `equal`, `notEqual`, `in`, `startsWith`, `endsWith` and `containsSegment`.
+    CaseInsensitive(Box<Self>),
 }
 
 impl<'p, R> Filter<'p, R>
@@ -107,7 +137,7 @@ where
             Self::All(filters) | Self::Any(filters) => {
                 filters.iter_mut().try_for_each(Self::convert_parameters)?;
             }
-            Self::Not(filter) => filter.convert_parameters()?,
+            Self::Not(filter) | Self::CaseInsensitive(filter) => filter.convert_parameters()?,
             Self::Equal(lhs, rhs) | Self::NotEqual(lhs, rhs) => match (lhs, rhs) {
                 (
                     Some(FilterExpression::Parameter(parameter)),
@@ -119,18 +149,42 @@ where
                 ) => parameter.convert_to_parameter_type(path.expected_type())?,
                 (..) => {}
             },
-            Self::In(lhs, rhs) => {
+            Self::Less(lhs, rhs)
+            | Self::LessOrEqual(lhs, rhs)
+            | Self::Greater(lhs, rhs)
+            | Self::GreaterOrEqual(lhs, rhs) => match (lhs, rhs) {
+                (FilterExpression::Parameter(parameter), FilterExpression::Path(path))
+                | (FilterExpression::Path(path), FilterExpression::Parameter(parameter)) => {
+                    parameter.convert_to_parameter_type(path.expected_type())?;
+                }
+                (..) => {}
+            },
+            Self::Exists(_) | Self::IsNull(_) => {}
+            Self::In(lhs, rhs) => match (lhs, rhs) {
+                (FilterExpression::Parameter(parameter), ParameterList::Uuid(_)) => {
+                    parameter.convert_to_parameter_type(ParameterType::Uuid)?;
+                }
+                (FilterExpression::Path(path), ParameterList::Parameters(parameters)) => {
+                    let expected = path.expected_type();
+                    parameters
+                        .iter_mut()
+                        .try_for_each(|parameter| parameter.convert_to_parameter_type(expected))?;
+                }
+                (..) => {}
+            },
+            Self::Contains(lhs, rhs) => {
                 if let FilterExpression::Parameter(parameter) = lhs {
-                    match rhs {
-                        ParameterList::Uuid(_) => {
-                            parameter.convert_to_parameter_type(ParameterType::Uuid)?;
-                        }
-                    }
+                    parameter.convert_to_parameter_type(ParameterType::Any)?;
+                }
+                if let FilterExpression::Parameter(parameter) = rhs {
+                    parameter.convert_to_parameter_type(ParameterType::Any)?;
                 }
             }
             Self::StartsWith(lhs, rhs)
             | Self::EndsWith(lhs, rhs)
-            | Self::ContainsSegment(lhs, rhs) => {
+            | Self::ContainsSegment(lhs, rhs)
+            | Self::Search(lhs, rhs)
+            | Self::FuzzySearch(lhs, rhs) => {
                 // TODO: We need to find a way to support lists in addition to strings as well
                 if let FilterExpression::Parameter(parameter) = lhs {
                     parameter.convert_to_parameter_type(ParameterType::Text)?;
@@ -173,8 +227,12 @@ pub enum Parameter<'p> {
     OntologyTypeVersion(OntologyTypeVersion),
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A list of values in a [`Filter::In`].
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
 pub enum ParameterList<'p> {
+    Parameters(Vec<Parameter<'p>>),
+    #[serde(skip)]
     Uuid(&'p [Uuid]),
 }
 
@@ -405,4 +463,45 @@ mod tests {
             &expected,
         );
     }
+
+    #[test]
+    fn in_list() {
+        let expected = json!({
+          "in": [
+            { "path": ["title"] },
+            ["Text", "Number"]
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::In(
+                FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::Title),
+                ParameterList::Parameters(vec![
+                    Parameter::Text(Cow::Borrowed("Text")),
+                    Parameter::Text(Cow::Borrowed("Number")),
+                ]),
+            ),
+            &expected,
+        );
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let expected = json!({
+          "caseInsensitive": {
+            "startsWith": [
+              { "path": ["title"] },
+              { "parameter": "text" }
+            ]
+          }
+        });
+
+        test_filter_representation(
+            &Filter::CaseInsensitive(Box::new(Filter::StartsWith(
+                FilterExpression::<DataTypeWithMetadata>::Path(DataTypeQueryPath::Title),
+                FilterExpression::Parameter(Parameter::Text(Cow::Borrowed("text"))),
+            ))),
+            &expected,
+        );
+    }
 }
@@ -0,0 +1,249 @@
+use std::{borrow::Cow, fmt};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use derivative::Derivative;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use utoipa::{openapi, ToSchema};
+
+use crate::store::Record;
+
+/// The direction in which records are ordered by a [`Sorting`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Orders the records of a query by the value at `path`.
+///
+/// Values are compared as JSON values, so a `path` pointing into the properties of an entity can be
+/// used the same way as any other path. Records without a value are sorted after all other records
+/// when sorting in ascending order and before all other records when sorting in descending order.
+///
+/// If `relevance` is specified, records are ordered by how well the value at `path` matches the
+/// `relevance` query instead, which uses the same syntax as a [`Filter::Search`]. The most relevant
+/// records come first when sorting in descending order. If `fuzzy` is set, the relevance is the
+/// similarity of the value to the `relevance` text as used by a [`Filter::FuzzySearch`], so `fuzzy`
+/// cannot be set without `relevance`.
+///
+/// Records, which compare equal for every sorting, are ordered by their identifier, so the order
+/// is stable across queries.
+///
+/// [`Filter::Search`]: crate::store::query::Filter::Search
+/// [`Filter::FuzzySearch`]: crate::store::query::Filter::FuzzySearch
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "R::QueryPath<'p>: fmt::Debug"),
+    PartialEq(bound = "R::QueryPath<'p>: PartialEq")
+)]
+pub struct Sorting<'p, R: Record + ?Sized> {
+    pub path: R::QueryPath<'p>,
+    pub direction: SortDirection,
+    pub relevance: Option<Cow<'p, str>>,
+    pub fuzzy: bool,
+}
+
+/// The serialized form of a [`Sorting`], which is validated before it's converted.
+#[derive(Deserialize)]
+#[serde(
+    rename_all = "camelCase",
+    deny_unknown_fields,
+    bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>"
+)]
+struct SortingRepr<'p, R: Record + ?Sized> {
+    path: R::QueryPath<'p>,
+    #[serde(default)]
+    direction: SortDirection,
+    #[serde(default)]
+    relevance: Option<Cow<'p, str>>,
+    #[serde(default)]
+    fuzzy: bool,
+}
+
+impl<'de: 'p, 'p, R> Deserialize<'de> for Sorting<'p, R>
+where
+    R: Record + ?Sized,
+    R::QueryPath<'p>: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let sorting = SortingRepr::<R>::deserialize(deserializer)?;
+        if sorting.fuzzy && sorting.relevance.is_none() {
+            return Err(de::Error::custom(
+                "a `fuzzy` sorting requires a `relevance` text to compare to",
+            ));
+        }
+
+        Ok(Self {
+            path: sorting.path,
+            direction: sorting.direction,
+            relevance: sorting.relevance,
+            fuzzy: sorting.fuzzy,
+        })
+    }
+}
+
+/// Position of a record in a sorted query.
+///
+/// A cursor is returned alongside a page of records and is passed to the next query to continue
+/// after the last record of the previous page. The cursor is only valid for a query with the same
+/// sorting and is exposed as an opaque string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    values: Vec<Option<Value>>,
+}
+
+impl Cursor {
+    /// Creates a cursor from the values of the last record, where `None` denotes the absence of a
+    /// value.
+    #[must_use]
+    pub const fn new(values: Vec<Option<Value>>) -> Self {
+        Self { values }
+    }
+
+    #[must_use]
+    pub fn values(&self) -> &[Option<Value>] {
+        &self.values
+    }
+}
+
+/// Distinguishes the absence of a value from the JSON `null` value.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum CursorValue<V> {
+    None,
+    Some(V),
+}
+
+impl Serialize for Cursor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let values = self
+            .values
+            .iter()
+            .map(|value| value.as_ref().map_or(CursorValue::None, CursorValue::Some))
+            .collect::<Vec<_>>();
+        let json = serde_json::to_vec(&values).map_err(ser::Error::custom)?;
+
+        serializer.serialize_str(&URL_SAFE_NO_PAD.encode(json))
+    }
+}
+
+impl<'de> Deserialize<'de> for Cursor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+        let json = URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|_| de::Error::custom("invalid cursor"))?;
+        let values = serde_json::from_slice::<Vec<CursorValue<Value>>>(&json)
+            .map_err(|_| de::Error::custom("invalid cursor"))?;
+
+        Ok(Self::new(
+            values
+                .into_iter()
+                .map(|value| match value {
+                    CursorValue::None => None,
+                    CursorValue::Some(value) => Some(value),
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl ToSchema<'_> for Cursor {
+    fn schema() -> (&'static str, openapi::RefOr<openapi::Schema>) {
+        (
+            "Cursor",
+            openapi::ObjectBuilder::new()
+                .schema_type(openapi::SchemaType::String)
+                .description(Some(
+                    "An opaque position in a sorted query, which is returned alongside a page of \
+                     results.",
+                ))
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{knowledge::Entity, ontology::DataTypeWithMetadata};
+
+    #[test]
+    fn cursor_round_trip() {
+        let cursor = Cursor::new(vec![
+            Some(json!("foo")),
+            Some(Value::Null),
+            None,
+            Some(json!({ "bar": [1, 2] })),
+        ]);
+
+        let serialized = serde_json::to_value(&cursor).expect("could not serialize cursor");
+        assert!(serialized.is_string());
+        assert_eq!(
+            serde_json::from_value::<Cursor>(serialized).expect("could not deserialize cursor"),
+            cursor
+        );
+    }
+
+    #[test]
+    fn invalid_cursor() {
+        serde_json::from_value::<Cursor>(json!("not a cursor"))
+            .expect_err("deserialized an invalid cursor");
+        serde_json::from_value::<Cursor>(json!(URL_SAFE_NO_PAD.encode("{}")))
+            .expect_err("deserialized an invalid cursor");
+    }
+
+    #[test]
+    fn sorting_representation() {
+        let sorting = Sorting::<DataTypeWithMetadata>::deserialize(&json!({
+            "path": ["title"]
+        }))
+        .expect("could not deserialize sorting");
+        assert_eq!(sorting.direction, SortDirection::Ascending);
+
+        let sorting = Sorting::<Entity>::deserialize(&json!({
+            "path": ["properties", "https://blockprotocol.org/@alice/types/property-type/name/"],
+            "direction": "descending"
+        }))
+        .expect("could not deserialize sorting");
+        assert_eq!(sorting.direction, SortDirection::Descending);
+        assert_eq!(sorting.relevance, None);
+
+        let sorting = Sorting::<Entity>::deserialize(&json!({
+            "path": ["properties"],
+            "direction": "descending",
+            "relevance": "alice"
+        }))
+        .expect("could not deserialize sorting");
+        assert_eq!(sorting.relevance.as_deref(), Some("alice"));
+        assert!(!sorting.fuzzy);
+
+        let sorting = Sorting::<Entity>::deserialize(&json!({
+            "path": ["properties"],
+            "relevance": "alcie",
+            "fuzzy": true
+        }))
+        .expect("could not deserialize sorting");
+        assert!(sorting.fuzzy);
+
+        Sorting::<Entity>::deserialize(&json!({
+            "path": ["properties"],
+            "fuzzy": true
+        }))
+        .expect_err("deserialized a fuzzy sorting without relevance");
+    }
+}
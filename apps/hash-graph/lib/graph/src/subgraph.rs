@@ -5,10 +5,7 @@ pub mod temporal_axes;
 pub mod vertices;
 
 use std::{
-    collections::{
-        hash_map::{RandomState, RawEntryMut},
-        HashSet,
-    },
+    collections::hash_map::{RandomState, RawEntryMut},
     hash::Hash,
 };
 
@@ -19,7 +16,7 @@ use self::{
     vertices::Vertices,
 };
 use crate::{
-    store::Record,
+    store::{query::Cursor, Record},
     subgraph::{
         edges::{EdgeDirection, EdgeKind},
         identifier::{EdgeEndpoint, VertexId},
@@ -28,11 +25,14 @@ use crate::{
 
 #[derive(Debug)]
 pub struct Subgraph {
-    pub roots: HashSet<GraphElementVertexId>,
+    /// The vertices matching the query in the order specified by its sorting.
+    pub roots: Vec<GraphElementVertexId>,
     pub vertices: Vertices,
     pub edges: Edges,
     pub depths: GraphResolveDepths,
     pub temporal_axes: SubgraphTemporalAxes,
+    /// Points after the last root if the query was limited and more roots may follow.
+    pub cursor: Option<Cursor>,
 }
 
 impl Subgraph {
@@ -43,7 +43,7 @@ impl Subgraph {
         resolved_temporal_axes: QueryTemporalAxes,
     ) -> Self {
         Self {
-            roots: HashSet::new(),
+            roots: Vec::new(),
             vertices: Vertices::default(),
             edges: Edges::default(),
             depths,
@@ -51,6 +51,7 @@ impl Subgraph {
                 initial: initial_temporal_axes,
                 resolved: resolved_temporal_axes,
             },
+            cursor: None,
         }
     }
 
@@ -3,14 +3,17 @@ use std::fmt::Debug;
 use derivative::Derivative;
 use serde::Deserialize;
 use utoipa::{
-    openapi::{ObjectBuilder, Ref, RefOr, Schema},
+    openapi::{ArrayBuilder, ObjectBuilder, Ref, RefOr, Schema, SchemaType},
     ToSchema,
 };
 
 use crate::{
     knowledge::Entity,
     ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
-    store::{query::Filter, Record},
+    store::{
+        query::{Cursor, Filter, Sorting},
+        Record,
+    },
     subgraph::{edges::GraphResolveDepths, temporal_axes::QueryTemporalAxesUnresolved},
 };
 
@@ -45,6 +48,22 @@ use crate::{
 /// an in-depth explanation of the depth of a query, please see the documentation on
 /// [`GraphResolveDepths`].
 ///
+/// # Pagination
+///
+/// By default, every root vertex matching the filter is returned. The root vertices can be ordered
+/// by a list of [`Sorting`]s, where each [`Sorting`] consists of a [`RecordPath`] and a direction.
+/// Root vertices, which are equal for all [`Sorting`]s, are ordered by their identifier, so the
+/// order is stable.
+///
+/// Results of a full-text search with [`Filter::Search`] can be ordered by their relevance by
+/// specifying the search query as `relevance` of a [`Sorting`]. Results of a
+/// [`Filter::FuzzySearch`] are ordered by their similarity if the [`Sorting`] is `fuzzy` as well.
+///
+/// The number of root vertices can be restricted by `limit`. If the limit is reached, the returned
+/// [`Subgraph`] contains a [`Cursor`] pointing to the last root vertex. Passing this cursor as
+/// `cursor` with an otherwise unchanged query returns the root vertices following the last root
+/// vertex.
+///
 /// # Examples
 ///
 /// Typically, a structural will be deserialized from a JSON request. The following examples assume,
@@ -154,6 +173,7 @@ use crate::{
 ///
 /// [`RecordPath`]: crate::store::query::QueryPath
 /// [`Parameter`]: crate::store::query::Parameter
+/// [`Subgraph`]: crate::subgraph::Subgraph
 #[derive(Deserialize, Derivative)]
 #[derivative(Debug(bound = "R::QueryPath<'p>: Debug"))]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
@@ -162,9 +182,21 @@ pub struct StructuralQuery<'p, R: Record> {
     pub filter: Filter<'p, R>,
     pub graph_resolve_depths: GraphResolveDepths,
     pub temporal_axes: QueryTemporalAxesUnresolved,
+    #[serde(default, bound = "'de: 'p, R::QueryPath<'p>: Deserialize<'de>")]
+    pub sorting: Vec<Sorting<'p, R>>,
+    #[serde(default)]
+    pub cursor: Option<Cursor>,
+    #[serde(default)]
+    pub limit: Option<usize>,
 }
 
 impl<'p, R: Record> StructuralQuery<'p, R> {
+    /// Returns if the root vertices have to be read in a stable order.
+    #[must_use]
+    pub fn is_paginated(&self) -> bool {
+        !self.sorting.is_empty() || self.cursor.is_some() || self.limit.is_some()
+    }
+
     fn generate_schema() -> RefOr<Schema> {
         ObjectBuilder::new()
             .property("filter", Ref::from_schema_name("Filter"))
@@ -179,6 +211,17 @@ impl<'p, R: Record> StructuralQuery<'p, R> {
                 Ref::from_schema_name(QueryTemporalAxesUnresolved::schema().0),
             )
             .required("temporalAxes")
+            .property(
+                "sorting",
+                ArrayBuilder::new().items(Ref::from_schema_name("Sorting")),
+            )
+            .property("cursor", Ref::from_schema_name(Cursor::schema().0))
+            .property(
+                "limit",
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::Integer)
+                    .minimum(Some(0.0)),
+            )
             .into()
     }
 }
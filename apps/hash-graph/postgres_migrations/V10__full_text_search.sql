@@ -0,0 +1,36 @@
+-- The expressions of these indexes have to match the expressions created by the query compiler for
+-- a `search` filter exactly, otherwise the index is not used.
+CREATE INDEX IF NOT EXISTS
+  "entity_editions_properties_search_idx" ON "entity_editions" USING gin (
+    jsonb_to_tsvector('english'::regconfig, "properties", '["string"]'::jsonb)
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "data_types_title_search_idx" ON "data_types" USING gin (
+    to_tsvector('english'::regconfig, "schema" ->> 'title')
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "data_types_description_search_idx" ON "data_types" USING gin (
+    to_tsvector('english'::regconfig, "schema" ->> 'description')
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "property_types_title_search_idx" ON "property_types" USING gin (
+    to_tsvector('english'::regconfig, "schema" ->> 'title')
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "property_types_description_search_idx" ON "property_types" USING gin (
+    to_tsvector('english'::regconfig, "schema" ->> 'description')
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "entity_types_title_search_idx" ON "entity_types" USING gin (
+    to_tsvector('english'::regconfig, "schema" ->> 'title')
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "entity_types_description_search_idx" ON "entity_types" USING gin (
+    to_tsvector('english'::regconfig, "schema" ->> 'description')
+  );
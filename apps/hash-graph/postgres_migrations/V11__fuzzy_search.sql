@@ -0,0 +1,45 @@
+CREATE EXTENSION IF NOT EXISTS "pg_trgm";
+
+-- The expressions of these indexes have to match the expressions created by the query compiler for
+-- a `fuzzySearch` filter exactly, otherwise the index is not used.
+
+-- Only the string values of the properties are indexed, the property type URLs used as keys would
+-- otherwise be part of every compared text.
+CREATE INDEX IF NOT EXISTS
+  "entity_editions_properties_trgm_idx" ON "entity_editions" USING gin (
+    (
+      jsonb_path_query_array(
+        "properties", 'strict $.** ? (@.type() == "string")'::jsonpath
+      ) #>> '{}'::text[]
+    ) gin_trgm_ops
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "data_types_title_trgm_idx" ON "data_types" USING gin (
+    ("schema" ->> 'title') gin_trgm_ops
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "data_types_description_trgm_idx" ON "data_types" USING gin (
+    ("schema" ->> 'description') gin_trgm_ops
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "property_types_title_trgm_idx" ON "property_types" USING gin (
+    ("schema" ->> 'title') gin_trgm_ops
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "property_types_description_trgm_idx" ON "property_types" USING gin (
+    ("schema" ->> 'description') gin_trgm_ops
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "entity_types_title_trgm_idx" ON "entity_types" USING gin (
+    ("schema" ->> 'title') gin_trgm_ops
+  );
+
+CREATE INDEX IF NOT EXISTS
+  "entity_types_description_trgm_idx" ON "entity_types" USING gin (
+    ("schema" ->> 'description') gin_trgm_ops
+  );
@@ -1,11 +1,20 @@
+use std::borrow::Cow;
+
 use graph::{
     identifier::time::ClosedTemporalBound,
-    knowledge::{EntityLinkOrder, EntityProperties},
+    knowledge::{Entity, EntityLinkOrder, EntityProperties, EntityQueryPath},
+    ontology::EntityTypeQueryPath,
+    store::query::{
+        Aggregation, AggregationGroup, Filter, FilterExpression, Grouping, JsonPath, Parameter,
+        ParameterList, PathToken, SortDirection, Sorting,
+    },
+    subgraph::edges::{EdgeDirection, KnowledgeGraphEdgeKind, SharedEdgeKind},
 };
 use graph_test_data::{data_type, entity, entity_type, property_type};
+use serde_json::{json, Value};
 use type_system::url::{BaseUrl, VersionedUrl};
 
-use crate::DatabaseTestWrapper;
+use crate::{DatabaseApi, DatabaseTestWrapper};
 
 #[tokio::test]
 async fn insert() {
@@ -163,3 +172,490 @@ async fn update() {
 
     assert_eq!(entity_v2.properties, page_v2);
 }
+
+#[tokio::test]
+async fn sorting_with_cursor() {
+    let alice: EntityProperties =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob: EntityProperties =
+        serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+    let charles: EntityProperties =
+        serde_json::from_str(entity::PERSON_CHARLES_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    for person in [&alice, &bob, &charles] {
+        api.create_entity(
+            person.clone(),
+            VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: 1,
+            },
+            None,
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    let sorting = || {
+        vec![Sorting {
+            path: EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![
+                PathToken::Field(Cow::Borrowed(
+                    "https://blockprotocol.org/@alice/types/property-type/name/",
+                )),
+            ]))),
+            direction: SortDirection::Descending,
+            relevance: None,
+            fuzzy: false,
+        }]
+    };
+
+    let (entities, cursor) = api
+        .get_sorted_entities(sorting(), None, 2)
+        .await
+        .expect("could not get entities");
+    assert_eq!(
+        entities
+            .iter()
+            .map(|entity| &entity.properties)
+            .collect::<Vec<_>>(),
+        [&charles, &bob]
+    );
+
+    let (entities, cursor) = api
+        .get_sorted_entities(sorting(), cursor, 2)
+        .await
+        .expect("could not get entities");
+    assert_eq!(
+        entities
+            .iter()
+            .map(|entity| &entity.properties)
+            .collect::<Vec<_>>(),
+        [&alice]
+    );
+    assert!(cursor.is_none());
+}
+
+#[tokio::test]
+async fn search() {
+    let alice: EntityProperties =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob: EntityProperties =
+        serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+    let charles: EntityProperties =
+        serde_json::from_str(entity::PERSON_CHARLES_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    for person in [&alice, &bob, &charles] {
+        api.create_entity(
+            person.clone(),
+            VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: 1,
+            },
+            None,
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    let entities = api
+        .search_entities("alice")
+        .await
+        .expect("could not search entities");
+    assert_eq!(
+        entities
+            .iter()
+            .map(|entity| &entity.properties)
+            .collect::<Vec<_>>(),
+        [&alice]
+    );
+
+    let entities = api
+        .search_entities("bob or charles")
+        .await
+        .expect("could not search entities");
+    assert_eq!(entities.len(), 2);
+    assert!(!entities.iter().any(|entity| entity.properties == alice));
+}
+
+#[tokio::test]
+async fn fuzzy_search() {
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    for name in ["Alice", "Alicia", "Bob"] {
+        api.create_entity(
+            serde_json::from_value(json!({
+                "https://blockprotocol.org/@alice/types/property-type/name/": name
+            }))
+            .expect("could not parse entity"),
+            VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: 1,
+            },
+            None,
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    // The most similar name comes first, names without enough common trigrams are not returned
+    let entities = api
+        .fuzzy_search_entities(NAME, "alice")
+        .await
+        .expect("could not search entities");
+    assert_eq!(
+        entities
+            .iter()
+            .map(|entity| serde_json::to_value(&entity.properties).expect("invalid properties"))
+            .collect::<Vec<_>>(),
+        [
+            json!({ "https://blockprotocol.org/@alice/types/property-type/name/": "Alice" }),
+            json!({ "https://blockprotocol.org/@alice/types/property-type/name/": "Alicia" }),
+        ]
+    );
+
+    let entities = api
+        .fuzzy_search_entities(NAME, "allice")
+        .await
+        .expect("could not search entities");
+    assert_eq!(
+        entities
+            .iter()
+            .map(|entity| serde_json::to_value(&entity.properties).expect("invalid properties"))
+            .collect::<Vec<_>>(),
+        [json!({ "https://blockprotocol.org/@alice/types/property-type/name/": "Alice" })]
+    );
+}
+
+fn property_path(property: &'static str) -> FilterExpression<'static, Entity> {
+    FilterExpression::Path(EntityQueryPath::Properties(Some(
+        JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(property))]),
+    )))
+}
+
+/// Queries the entities matching `filter` and returns their sorted names.
+async fn query_names(api: &DatabaseApi<'_>, filter: Filter<'_, Entity>) -> Vec<String> {
+    let mut names = api
+        .query_entities(filter)
+        .await
+        .expect("could not query entities")
+        .into_iter()
+        .map(|entity| {
+            serde_json::to_value(entity.properties).expect("could not serialize properties")
+                ["https://blockprotocol.org/@alice/types/property-type/name/"]
+                .as_str()
+                .expect("name should be a string")
+                .to_owned()
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn filters() {
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const AGE: &str = "https://blockprotocol.org/@alice/types/property-type/age/";
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([data_type::TEXT_V1], [property_type::NAME_V1], [
+            entity_type::LINK_V1,
+            entity_type::link::FRIEND_OF_V1,
+            entity_type::PERSON_V1,
+        ])
+        .await
+        .expect("could not seed database");
+
+    for person in [
+        entity::PERSON_ALICE_V1,
+        entity::PERSON_BOB_V1,
+        entity::PERSON_CHARLES_V1,
+    ] {
+        api.create_entity(
+            serde_json::from_str(person).expect("could not parse entity"),
+            VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: 1,
+            },
+            None,
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    assert_eq!(
+        query_names(
+            &api,
+            Filter::Greater(
+                property_path(NAME),
+                FilterExpression::Parameter(Parameter::Any(json!("Bob"))),
+            )
+        )
+        .await,
+        ["Charles"]
+    );
+    assert_eq!(
+        query_names(
+            &api,
+            Filter::LessOrEqual(
+                property_path(NAME),
+                FilterExpression::Parameter(Parameter::Any(json!("Bob"))),
+            )
+        )
+        .await,
+        ["Alice", "Bob"]
+    );
+    // Every JSON string is greater than every JSON number, values of different types are not
+    // compared
+    assert!(
+        query_names(
+            &api,
+            Filter::Greater(
+                property_path(NAME),
+                FilterExpression::Parameter(Parameter::Any(json!(1))),
+            )
+        )
+        .await
+        .is_empty()
+    );
+
+    assert_eq!(
+        query_names(&api, Filter::Exists(property_path(NAME))).await,
+        ["Alice", "Bob", "Charles"]
+    );
+    assert!(
+        query_names(&api, Filter::Exists(property_path(AGE)))
+            .await
+            .is_empty()
+    );
+    assert_eq!(
+        query_names(&api, Filter::IsNull(property_path(AGE))).await,
+        ["Alice", "Bob", "Charles"]
+    );
+
+    assert_eq!(
+        query_names(
+            &api,
+            Filter::In(
+                property_path(NAME),
+                ParameterList::Parameters(vec![
+                    Parameter::Any(json!("Alice")),
+                    Parameter::Any(json!("Charles")),
+                    Parameter::Any(json!("Dave")),
+                ]),
+            )
+        )
+        .await,
+        ["Alice", "Charles"]
+    );
+
+    assert_eq!(
+        query_names(
+            &api,
+            Filter::Contains(
+                FilterExpression::Path(EntityQueryPath::Properties(None)),
+                FilterExpression::Parameter(Parameter::Any(json!({ NAME: "Bob" }))),
+            )
+        )
+        .await,
+        ["Bob"]
+    );
+
+    let alice = || {
+        Filter::Equal(
+            Some(property_path(NAME)),
+            Some(FilterExpression::Parameter(Parameter::Any(json!("aLiCe")))),
+        )
+    };
+    assert!(query_names(&api, alice()).await.is_empty());
+    assert_eq!(
+        query_names(&api, Filter::CaseInsensitive(Box::new(alice()))).await,
+        ["Alice"]
+    );
+}
+
+#[tokio::test]
+async fn aggregation() {
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const AGE: &str = "https://blockprotocol.org/@alice/types/property-type/age/";
+    const FRIEND_OF: &str = "https://blockprotocol.org/@alice/types/entity-type/friend-of/";
+    const PERSON: &str = "https://blockprotocol.org/@alice/types/entity-type/person/";
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [property_type::NAME_V1, property_type::AGE_V1],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::PERSON_V1,
+                entity_type::PERSON_V2,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let type_id = |base_url: &str, version| VersionedUrl {
+        base_url: BaseUrl::new(base_url.to_owned()).expect("couldn't construct Base URL"),
+        version,
+    };
+
+    let alice = api
+        .create_entity(
+            serde_json::from_value(json!({ NAME: "Alice", AGE: 30 }))
+                .expect("could not parse entity"),
+            type_id(PERSON, 2),
+            None,
+        )
+        .await
+        .expect("could not create entity")
+        .record_id()
+        .entity_id;
+    let bob = api
+        .create_entity(
+            serde_json::from_value(json!({ NAME: "Bob", AGE: 40 }))
+                .expect("could not parse entity"),
+            type_id(PERSON, 2),
+            None,
+        )
+        .await
+        .expect("could not create entity")
+        .record_id()
+        .entity_id;
+    let charles = api
+        .create_entity(
+            serde_json::from_str(entity::PERSON_CHARLES_V1).expect("could not parse entity"),
+            type_id(PERSON, 1),
+            None,
+        )
+        .await
+        .expect("could not create entity")
+        .record_id()
+        .entity_id;
+
+    for (left_entity_id, right_entity_id) in [(alice, bob), (alice, charles), (bob, charles)] {
+        api.create_link_entity(
+            EntityProperties::empty(),
+            type_id(FRIEND_OF, 1),
+            None,
+            left_entity_id,
+            right_entity_id,
+        )
+        .await
+        .expect("could not create link");
+    }
+
+    let type_path = || EntityQueryPath::EntityTypeEdge {
+        edge_kind: SharedEdgeKind::IsOfType,
+        path: EntityTypeQueryPath::VersionedUrl,
+    };
+    let aggregations = || {
+        vec![Aggregation::Count { path: None }, Aggregation::Sum {
+            path: EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![
+                PathToken::Field(Cow::Borrowed(AGE)),
+            ]))),
+        }]
+    };
+
+    // Links don't have an age, so their sum is absent
+    assert_eq!(
+        api.aggregate_entities(
+            Filter::All(Vec::new()),
+            vec![Grouping { path: type_path() }],
+            aggregations(),
+        )
+        .await
+        .expect("could not aggregate entities"),
+        [
+            AggregationGroup {
+                key: vec![json!(format!("{FRIEND_OF}v/1"))],
+                values: vec![json!(3), Value::Null],
+            },
+            AggregationGroup {
+                key: vec![json!(format!("{PERSON}v/1"))],
+                values: vec![json!(1), Value::Null],
+            },
+            AggregationGroup {
+                key: vec![json!(format!("{PERSON}v/2"))],
+                values: vec![json!(2), json!(70)],
+            },
+        ]
+    );
+
+    // Alice is the left entity of two links, but is only counted once
+    assert_eq!(
+        api.aggregate_entities(
+            Filter::Equal(
+                Some(FilterExpression::Path(EntityQueryPath::EntityEdge {
+                    edge_kind: KnowledgeGraphEdgeKind::HasLeftEntity,
+                    path: Box::new(EntityQueryPath::EntityTypeEdge {
+                        edge_kind: SharedEdgeKind::IsOfType,
+                        path: EntityTypeQueryPath::BaseUrl,
+                    }),
+                    direction: EdgeDirection::Incoming,
+                })),
+                Some(FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(
+                    FRIEND_OF
+                )))),
+            ),
+            vec![
+                Grouping {
+                    path: EntityQueryPath::OwnedById,
+                },
+                Grouping { path: type_path() },
+            ],
+            aggregations(),
+        )
+        .await
+        .expect("could not aggregate entities"),
+        [AggregationGroup {
+            key: vec![
+                json!(alice.owned_by_id.as_uuid()),
+                json!(format!("{PERSON}v/2")),
+            ],
+            values: vec![json!(2), json!(70)],
+        }]
+    );
+}
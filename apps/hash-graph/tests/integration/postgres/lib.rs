@@ -33,7 +33,10 @@ use graph::{
     },
     provenance::{OwnedById, ProvenanceMetadata, RecordCreatedById},
     store::{
-        query::{Filter, FilterExpression, Parameter},
+        query::{
+            Aggregation, AggregationGroup, AggregationQuery, Cursor, Filter, FilterExpression,
+            Grouping, JsonPath, Parameter, PathToken, SortDirection, Sorting,
+        },
         AccountStore, ConflictBehavior, DataTypeStore, DatabaseConnectionInfo, DatabaseType,
         EntityStore, EntityTypeStore, InsertionError, PostgresStore, PostgresStorePool,
         PropertyTypeStore, QueryError, StorePool, UpdateError,
@@ -260,6 +263,9 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await?
             .vertices
@@ -313,6 +319,9 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await?
             .vertices
@@ -369,6 +378,9 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await?
             .vertices
@@ -419,6 +431,68 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
+            })
+            .await?
+            .vertices
+            .entities
+            .into_values()
+            .collect())
+    }
+
+    pub async fn get_sorted_entities(
+        &self,
+        sorting: Vec<Sorting<'_, Entity>>,
+        cursor: Option<Cursor>,
+        limit: usize,
+    ) -> Result<(Vec<Entity>, Option<Cursor>), QueryError> {
+        let mut subgraph = self
+            .store
+            .get_entity(&StructuralQuery {
+                filter: Filter::All(Vec::new()),
+                graph_resolve_depths: GraphResolveDepths::default(),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting,
+                cursor,
+                limit: Some(limit),
+            })
+            .await?;
+
+        let entities = subgraph
+            .roots
+            .into_iter()
+            .filter_map(|vertex_id| match vertex_id {
+                GraphElementVertexId::KnowledgeGraph(vertex_id) => {
+                    subgraph.vertices.entities.remove(&vertex_id)
+                }
+                _ => None,
+            })
+            .collect();
+
+        Ok((entities, subgraph.cursor))
+    }
+
+    pub async fn query_entities(
+        &self,
+        filter: Filter<'_, Entity>,
+    ) -> Result<Vec<Entity>, QueryError> {
+        Ok(self
+            .store
+            .get_entity(&StructuralQuery {
+                filter,
+                graph_resolve_depths: GraphResolveDepths::default(),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await?
             .vertices
@@ -427,6 +501,106 @@ impl DatabaseApi<'_> {
             .collect())
     }
 
+    pub async fn aggregate_entities<'p>(
+        &self,
+        filter: Filter<'p, Entity>,
+        group_by: Vec<Grouping<'p, Entity>>,
+        aggregations: Vec<Aggregation<'p, Entity>>,
+    ) -> Result<Vec<AggregationGroup>, QueryError> {
+        self.store
+            .aggregate_entities(&AggregationQuery {
+                filter,
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                group_by,
+                aggregations,
+            })
+            .await
+    }
+
+    pub async fn search_entities(&self, query: &str) -> Result<Vec<Entity>, QueryError> {
+        let mut subgraph = self
+            .store
+            .get_entity(&StructuralQuery {
+                filter: Filter::Search(
+                    FilterExpression::Path(EntityQueryPath::Properties(None)),
+                    FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(query))),
+                ),
+                graph_resolve_depths: GraphResolveDepths::default(),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting: vec![Sorting {
+                    path: EntityQueryPath::Properties(None),
+                    direction: SortDirection::Descending,
+                    relevance: Some(Cow::Borrowed(query)),
+                    fuzzy: false,
+                }],
+                cursor: None,
+                limit: None,
+            })
+            .await?;
+
+        Ok(subgraph
+            .roots
+            .into_iter()
+            .filter_map(|vertex_id| match vertex_id {
+                GraphElementVertexId::KnowledgeGraph(vertex_id) => {
+                    subgraph.vertices.entities.remove(&vertex_id)
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    pub async fn fuzzy_search_entities(
+        &self,
+        property: &str,
+        query: &str,
+    ) -> Result<Vec<Entity>, QueryError> {
+        let path = || {
+            EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![PathToken::Field(
+                Cow::Borrowed(property),
+            )])))
+        };
+        let mut subgraph = self
+            .store
+            .get_entity(&StructuralQuery {
+                filter: Filter::FuzzySearch(
+                    FilterExpression::Path(path()),
+                    FilterExpression::Parameter(Parameter::Text(Cow::Borrowed(query))),
+                ),
+                graph_resolve_depths: GraphResolveDepths::default(),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting: vec![Sorting {
+                    path: path(),
+                    direction: SortDirection::Descending,
+                    relevance: Some(Cow::Borrowed(query)),
+                    fuzzy: true,
+                }],
+                cursor: None,
+                limit: None,
+            })
+            .await?;
+
+        Ok(subgraph
+            .roots
+            .into_iter()
+            .filter_map(|vertex_id| match vertex_id {
+                GraphElementVertexId::KnowledgeGraph(vertex_id) => {
+                    subgraph.vertices.entities.remove(&vertex_id)
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
     pub async fn get_entity_by_timestamp(
         &self,
         entity_id: EntityId,
@@ -444,6 +618,9 @@ impl DatabaseApi<'_> {
                         Some(LimitedTemporalBound::Inclusive(timestamp)),
                     ),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await?
             .vertices
@@ -464,6 +641,9 @@ impl DatabaseApi<'_> {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await?
             .vertices
@@ -581,6 +761,9 @@ impl DatabaseApi<'_> {
                         None,
                     ),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await?;
 
@@ -641,6 +824,9 @@ impl DatabaseApi<'_> {
                     pinned: PinnedTemporalAxisUnresolved::new(None),
                     variable: VariableTemporalAxisUnresolved::new(None, None),
                 },
+                sorting: Vec::new(),
+                cursor: None,
+                limit: None,
             })
             .await?;
 
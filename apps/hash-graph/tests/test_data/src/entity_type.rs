@@ -10,5 +10,6 @@ pub const ORGANIZATION_V1: &str = include_str!("entity_type/organization.json");
 pub const PAGE_V1: &str = include_str!("entity_type/page_v1.json");
 pub const PAGE_V2: &str = include_str!("entity_type/page_v2.json");
 pub const PERSON_V1: &str = include_str!("entity_type/person.json");
+pub const PERSON_V2: &str = include_str!("entity_type/person_v2.json");
 pub const PLAYLIST_V1: &str = include_str!("entity_type/playlist.json");
 pub const SONG_V1: &str = include_str!("entity_type/song.json");
@@ -6,6 +6,7 @@ use std::fmt::{Display, Formatter, Result};
 use serde::{Deserialize, Serialize};
 
 mod http_compat;
+mod report_compat;
 
 /// The canonical status codes for software within the HASH ecosystem.
 ///
@@ -0,0 +1,34 @@
+use error_stack::Report;
+
+use crate::status_code::StatusCode;
+
+impl StatusCode {
+    /// Returns the `StatusCode` closest to the top of the `report`.
+    ///
+    /// The frames are searched in the order of [`Report::frames`], so a status code attached by a
+    /// caller takes precedence over the status codes of its sources.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::report;
+    /// use hash_status::StatusCode;
+    ///
+    /// let report = report!(Error::from(ErrorKind::NotFound))
+    ///     .attach(StatusCode::NotFound)
+    ///     .attach_printable("could not find user")
+    ///     .attach(StatusCode::PermissionDenied);
+    ///
+    /// let status_code = StatusCode::from_report(&report);
+    /// assert_eq!(status_code, Some(StatusCode::PermissionDenied));
+    /// assert_eq!(status_code.map(|code| code.to_http_code()), Some(403));
+    /// ```
+    #[must_use]
+    pub fn from_report<C>(report: &Report<C>) -> Option<Self> {
+        report
+            .frames()
+            .find_map(|frame| frame.downcast_ref::<Self>().copied())
+    }
+}
@@ -1,3 +1,5 @@
+use core::fmt::{self, Display, Formatter};
+
 /// Basic colors variants
 ///
 /// ## Support
@@ -149,6 +151,12 @@ impl IndexedColor {
     pub const fn new(index: u8) -> Self {
         Self(index)
     }
+
+    /// Index into the color table
+    #[must_use]
+    pub const fn index(self) -> u8 {
+        self.0
+    }
 }
 
 impl From<BasicColor> for IndexedColor {
@@ -260,6 +268,84 @@ impl RgbColor {
     pub const fn new(red: u8, green: u8, blue: u8) -> Self {
         Self { red, green, blue }
     }
+
+    #[must_use]
+    pub const fn red(self) -> u8 {
+        self.red
+    }
+
+    #[must_use]
+    pub const fn green(self) -> u8 {
+        self.green
+    }
+
+    #[must_use]
+    pub const fn blue(self) -> u8 {
+        self.blue
+    }
+}
+
+/// Default color table of xterm for the [`BasicColor`] and [`BrightColor`] variants.
+///
+/// Most terminals allow the user to change these colors, the actual colors may differ.
+const XTERM_COLORS: [RgbColor; 16] = [
+    RgbColor::new(0, 0, 0),
+    RgbColor::new(205, 0, 0),
+    RgbColor::new(0, 205, 0),
+    RgbColor::new(205, 205, 0),
+    RgbColor::new(0, 0, 238),
+    RgbColor::new(205, 0, 205),
+    RgbColor::new(0, 205, 205),
+    RgbColor::new(229, 229, 229),
+    RgbColor::new(127, 127, 127),
+    RgbColor::new(255, 0, 0),
+    RgbColor::new(0, 255, 0),
+    RgbColor::new(255, 255, 0),
+    RgbColor::new(92, 92, 255),
+    RgbColor::new(255, 0, 255),
+    RgbColor::new(0, 255, 255),
+    RgbColor::new(255, 255, 255),
+];
+
+/// Intensities of the red, green and blue component of the color cube of an [`IndexedColor`].
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Resolves the color using the default color table of xterm.
+///
+/// `0` - `15` are the [`BasicColor`] and [`BrightColor`] variants, `16` - `231` are a 6x6x6 color
+/// cube and `232` - `255` are a grayscale from black to white, excluding black and white.
+impl From<IndexedColor> for RgbColor {
+    fn from(IndexedColor(index): IndexedColor) -> Self {
+        match index {
+            0..=15 => XTERM_COLORS[index as usize],
+            16..=231 => {
+                let index = index - 16;
+
+                Self::new(
+                    CUBE_LEVELS[(index / 36) as usize],
+                    CUBE_LEVELS[(index / 6 % 6) as usize],
+                    CUBE_LEVELS[(index % 6) as usize],
+                )
+            }
+            232..=255 => {
+                let level = 8 + (index - 232) * 10;
+
+                Self::new(level, level, level)
+            }
+        }
+    }
+}
+
+impl From<BasicColor> for RgbColor {
+    fn from(value: BasicColor) -> Self {
+        IndexedColor::from(value).into()
+    }
+}
+
+impl From<BrightColor> for RgbColor {
+    fn from(value: BrightColor) -> Self {
+        IndexedColor::from(value).into()
+    }
 }
 
 /// Truecolor 32-bit RGBA support
@@ -305,6 +391,14 @@ impl RgbaColor {
     }
 }
 
+/// Discards the alpha channel of the color.
+#[cfg(feature = "rgba")]
+impl From<RgbaColor> for RgbColor {
+    fn from(value: RgbaColor) -> Self {
+        Self::new(value.red, value.green, value.blue)
+    }
+}
+
 /// Truecolor, 24-bit CMY colors
 ///
 /// CMY is usually used for printers and stands for **C**yan, **M**agenta, **Y**ellow.
@@ -338,6 +432,12 @@ impl CmyColor {
     }
 }
 
+impl From<CmyColor> for RgbColor {
+    fn from(value: CmyColor) -> Self {
+        Self::new(255 - value.cyan, 255 - value.magenta, 255 - value.yellow)
+    }
+}
+
 /// Truecolor, 32-bit CMYK colors
 ///
 /// CMYK is usually used for printers and stands for **C**yan, **M**agenta, **Y**ellow, **K**ey. Key
@@ -374,6 +474,22 @@ impl CmykColor {
     }
 }
 
+impl From<CmykColor> for RgbColor {
+    fn from(value: CmykColor) -> Self {
+        // `(255 - component) * (255 - black) / 255` is always in `0..=255`
+        #[allow(clippy::cast_possible_truncation)]
+        let component = |component: u8| {
+            ((255 - u16::from(component)) * (255 - u16::from(value.black)) / 255) as u8
+        };
+
+        Self::new(
+            component(value.cyan),
+            component(value.magenta),
+            component(value.yellow),
+        )
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct TransparentColor;
 
@@ -471,3 +587,97 @@ impl From<TransparentColor> for Color {
         Self::Transparent(value)
     }
 }
+
+/// Layer a [`Color`] is applied to, which determines the parameters of the escape sequence.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ColorLayer {
+    Foreground,
+    Background,
+    #[cfg(feature = "underline-color")]
+    Underline,
+}
+
+impl ColorLayer {
+    /// Parameter of the first [`BasicColor`] if the layer supports basic colors.
+    #[cfg_attr(not(feature = "underline-color"), allow(clippy::unnecessary_wraps))]
+    const fn basic(self) -> Option<u8> {
+        match self {
+            Self::Foreground => Some(30),
+            Self::Background => Some(40),
+            #[cfg(feature = "underline-color")]
+            Self::Underline => None,
+        }
+    }
+
+    /// Parameter used to introduce an extended color.
+    const fn extended(self) -> u8 {
+        match self {
+            Self::Foreground => 38,
+            Self::Background => 48,
+            #[cfg(feature = "underline-color")]
+            Self::Underline => 58,
+        }
+    }
+}
+
+/// SGR parameter selecting a [`Color`] for a [`ColorLayer`], e.g. `31` or `38:2::255:0:0`.
+///
+/// Layers without support for basic and bright colors (the underline color) fall back to the
+/// respective [`IndexedColor`].
+pub(crate) struct ColorParameter {
+    pub(crate) color: Color,
+    pub(crate) layer: ColorLayer,
+}
+
+impl Display for ColorParameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let extended = self.layer.extended();
+
+        match (self.color, self.layer.basic()) {
+            (Color::Basic(color), Some(base)) => {
+                Display::fmt(&(base + IndexedColor::from(color).0), f)
+            }
+            (Color::Bright(color), Some(base)) => {
+                Display::fmt(&(base + 60 + IndexedColor::from(color.0).0), f)
+            }
+            (Color::Basic(color), None) => {
+                write!(f, "{extended}:5:{}", IndexedColor::from(color).0)
+            }
+            (Color::Bright(color), None) => {
+                write!(f, "{extended}:5:{}", IndexedColor::from(color).0)
+            }
+            (Color::Indexed(IndexedColor(index)), _) => write!(f, "{extended}:5:{index}"),
+            (Color::Transparent(_), _) => write!(f, "{extended}:1"),
+            (Color::Rgb(RgbColor { red, green, blue }), _) => {
+                write!(f, "{extended}:2::{red}:{green}:{blue}")
+            }
+            #[cfg(feature = "rgba")]
+            (
+                Color::Rgba(RgbaColor {
+                    red,
+                    green,
+                    blue,
+                    alpha,
+                }),
+                _,
+            ) => write!(f, "{extended}:6::{red}:{green}:{blue}:{alpha}"),
+            (
+                Color::Cmy(CmyColor {
+                    cyan,
+                    magenta,
+                    yellow,
+                }),
+                _,
+            ) => write!(f, "{extended}:3::{cyan}:{magenta}:{yellow}"),
+            (
+                Color::Cmyk(CmykColor {
+                    cyan,
+                    magenta,
+                    yellow,
+                    black,
+                }),
+                _,
+            ) => write!(f, "{extended}:4::{cyan}:{magenta}:{yellow}:{black}"),
+        }
+    }
+}
@@ -0,0 +1,373 @@
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{Background, BasicColor, Color, Foreground, IndexedColor, RgbColor, Style};
+
+/// Amount of colors supported by a terminal
+///
+/// ## Support
+///
+/// There is no reliable way to query the amount of colors supported by a terminal, instead one
+/// needs to rely on heuristics, like the `TERM` and `COLORTERM` environment variables. It is
+/// advised to use a crate similar to [`supports-color`](https://lib.rs/crates/supports-color) to
+/// determine the color depth.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ColorDepth {
+    /// No colors are supported, every color is removed
+    Monochrome,
+    /// The [`BasicColor`] and [`BrightColor`] variants are supported
+    ///
+    /// [`BrightColor`]: crate::BrightColor
+    Ansi16,
+    /// The 256 colors of [`IndexedColor`] are supported
+    Ansi256,
+    /// 24-bit colors of [`RgbColor`] are supported
+    TrueColor,
+}
+
+const BASIC_COLORS: [BasicColor; 8] = [
+    BasicColor::Black,
+    BasicColor::Red,
+    BasicColor::Green,
+    BasicColor::Yellow,
+    BasicColor::Blue,
+    BasicColor::Magenta,
+    BasicColor::Cyan,
+    BasicColor::White,
+];
+
+/// Perceptual distance between two colors
+///
+/// Uses the "redmean" approximation, which weighs the components according to the mean of the red
+/// component, this is considerably closer to human perception than the euclidean distance, but
+/// does not require a conversion to a different color space.
+///
+/// The value is the squared distance, scaled by `256`.
+fn distance(lhs: RgbColor, rhs: RgbColor) -> u32 {
+    let red_mean = (u32::from(lhs.red()) + u32::from(rhs.red())) / 2;
+
+    let red = u32::from(lhs.red().abs_diff(rhs.red()));
+    let green = u32::from(lhs.green().abs_diff(rhs.green()));
+    let blue = u32::from(lhs.blue().abs_diff(rhs.blue()));
+
+    (512 + red_mean) * red * red + 1024 * green * green + (767 - red_mean) * blue * blue
+}
+
+/// Closest color of the 16 [`BasicColor`] and [`BrightColor`] variants
+///
+/// [`BrightColor`]: crate::BrightColor
+fn nearest_ansi16(color: RgbColor) -> Color {
+    let index = (0..16)
+        .min_by_key(|&index| distance(color, IndexedColor::new(index).into()))
+        .unwrap_or_default();
+
+    ansi16(index)
+}
+
+/// Converts the index `0` - `15` of an [`IndexedColor`] into the respective [`BasicColor`] or
+/// [`BrightColor`].
+///
+/// [`BrightColor`]: crate::BrightColor
+fn ansi16(index: u8) -> Color {
+    let color = BASIC_COLORS[usize::from(index % 8)];
+
+    if index < 8 {
+        Color::Basic(color)
+    } else {
+        Color::Bright(color.bright())
+    }
+}
+
+/// Closest color of the color cube and grayscale of [`IndexedColor`]
+///
+/// The colors `0` - `15` are not considered, as they are often changed by the user.
+fn nearest_ansi256(color: RgbColor) -> IndexedColor {
+    let level = |component: u8| match component {
+        0..=47 => 0,
+        48..=114 => 1,
+        component => (component - 35) / 40,
+    };
+
+    let cube = IndexedColor::new(
+        16 + 36 * level(color.red()) + 6 * level(color.green()) + level(color.blue()),
+    );
+
+    let average = (u16::from(color.red()) + u16::from(color.green()) + u16::from(color.blue())) / 3;
+    // the grayscale ranges from `8` to `238` in steps of `10`
+    #[allow(clippy::cast_possible_truncation)]
+    let gray = IndexedColor::new(232 + (average.saturating_sub(3) / 10).min(23) as u8);
+
+    if distance(color, gray.into()) < distance(color, cube.into()) {
+        gray
+    } else {
+        cube
+    }
+}
+
+impl Color {
+    /// Converts the color into the closest color supported by the given [`ColorDepth`]
+    ///
+    /// Colors that are already supported are returned unchanged. [`Color::Rgba`], [`Color::Cmy`]
+    /// and [`Color::Cmyk`] are converted into [`Color::Rgb`] for [`ColorDepth::TrueColor`], as they
+    /// are not supported by most terminals.
+    ///
+    /// Returns [`None`] if the color cannot be represented, in which case the default color of the
+    /// terminal should be used. This is the case for every color with [`ColorDepth::Monochrome`]
+    /// and for [`Color::Transparent`] with any color depth other than [`ColorDepth::TrueColor`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Color, ColorDepth, IndexedColor, RgbColor};
+    ///
+    /// let color = Color::Rgb(RgbColor::new(250, 10, 20));
+    ///
+    /// assert_eq!(
+    ///     color.downsample(ColorDepth::Ansi256),
+    ///     Some(Color::Indexed(IndexedColor::new(196)))
+    /// );
+    /// assert_eq!(
+    ///     color.downsample(ColorDepth::Ansi16),
+    ///     Some(Color::Bright(BasicColor::Red.bright()))
+    /// );
+    /// assert_eq!(color.downsample(ColorDepth::Monochrome), None);
+    /// ```
+    #[must_use]
+    pub fn downsample(self, depth: ColorDepth) -> Option<Self> {
+        let rgb = match self {
+            Self::Basic(_) | Self::Bright(_) if depth >= ColorDepth::Ansi16 => return Some(self),
+            Self::Indexed(_) if depth >= ColorDepth::Ansi256 => return Some(self),
+            Self::Rgb(_) | Self::Transparent(_) if depth >= ColorDepth::TrueColor => {
+                return Some(self);
+            }
+            Self::Transparent(_) => return None,
+            _ if depth == ColorDepth::Monochrome => return None,
+
+            Self::Indexed(color) if color.index() < 16 => return Some(ansi16(color.index())),
+
+            Self::Basic(color) => RgbColor::from(color),
+            Self::Bright(color) => RgbColor::from(color),
+            Self::Indexed(color) => RgbColor::from(color),
+            Self::Rgb(color) => color,
+            #[cfg(feature = "rgba")]
+            Self::Rgba(color) => RgbColor::from(color),
+            Self::Cmy(color) => RgbColor::from(color),
+            Self::Cmyk(color) => RgbColor::from(color),
+        };
+
+        match depth {
+            ColorDepth::Monochrome => None,
+            ColorDepth::Ansi16 => Some(nearest_ansi16(rgb)),
+            ColorDepth::Ansi256 => Some(Self::Indexed(nearest_ansi256(rgb))),
+            ColorDepth::TrueColor => Some(Self::Rgb(rgb)),
+        }
+    }
+}
+
+impl Style {
+    /// Converts every color of the style into the closest color supported by the given
+    /// [`ColorDepth`]
+    ///
+    /// Colors that cannot be represented are removed, see [`Color::downsample`] for more
+    /// information.
+    #[must_use]
+    pub fn downsample(mut self, depth: ColorDepth) -> Self {
+        self.foreground = self
+            .foreground
+            .and_then(|color| color.color().downsample(depth))
+            .map(Foreground::new);
+        self.background = self
+            .background
+            .and_then(|color| color.color().downsample(depth))
+            .map(Background::new);
+
+        #[cfg(feature = "underline-color")]
+        {
+            self.underline_color = self
+                .underline_color
+                .and_then(|color| color.color().downsample(depth))
+                .map(UnderlineColor::new);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransparentColor;
+
+    const fn cube(red: u8, green: u8, blue: u8) -> IndexedColor {
+        IndexedColor::new(16 + 36 * red + 6 * green + blue)
+    }
+
+    #[test]
+    fn cube_thresholds() {
+        // the levels of the cube are `0x00`, `0x5f`, `0x87`, `0xaf`, `0xd7` and `0xff`, a
+        // component is rounded to the closest level
+        for (component, level) in [
+            (0, 0),
+            (47, 0),
+            (48, 1),
+            (0x5F, 1),
+            (114, 1),
+            (115, 2),
+            (0x87, 2),
+            (154, 2),
+            (155, 3),
+            (0xAF, 3),
+            (194, 3),
+            (195, 4),
+            (0xD7, 4),
+            (234, 4),
+            (235, 5),
+            (0xFF, 5),
+        ] {
+            assert_eq!(
+                nearest_ansi256(RgbColor::new(component, 0, 0xFF)),
+                cube(level, 0, 5),
+                "red component {component:#x} should be level {level}"
+            );
+        }
+    }
+
+    #[test]
+    fn cube_exact() {
+        assert_eq!(nearest_ansi256(RgbColor::new(0, 0, 0)), cube(0, 0, 0));
+        assert_eq!(
+            nearest_ansi256(RgbColor::new(0xFF, 0xFF, 0xFF)),
+            cube(5, 5, 5)
+        );
+        assert_eq!(
+            nearest_ansi256(RgbColor::new(0x5F, 0x87, 0xAF)),
+            cube(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn grayscale_ramp() {
+        // `0x80` is part of the grayscale ramp, but not of the cube
+        assert_eq!(
+            nearest_ansi256(RgbColor::new(0x80, 0x80, 0x80)),
+            IndexedColor::new(244)
+        );
+        assert_eq!(
+            nearest_ansi256(RgbColor::new(8, 8, 8)),
+            IndexedColor::new(232)
+        );
+        assert_eq!(
+            nearest_ansi256(RgbColor::new(238, 238, 238)),
+            IndexedColor::new(255)
+        );
+        // slightly tinted colors are still closer to the grayscale ramp
+        assert_eq!(
+            nearest_ansi256(RgbColor::new(0x78, 0x7C, 0x80)),
+            IndexedColor::new(244)
+        );
+        // gray values of the cube are preferred if they are an exact match
+        assert_eq!(
+            nearest_ansi256(RgbColor::new(0x87, 0x87, 0x87)),
+            cube(2, 2, 2)
+        );
+    }
+
+    #[test]
+    fn redmean_distance() {
+        let color = RgbColor::new(10, 20, 30);
+        assert_eq!(distance(color, color), 0);
+        assert_eq!(
+            distance(color, RgbColor::new(200, 100, 0)),
+            distance(RgbColor::new(200, 100, 0), color)
+        );
+    }
+
+    #[test]
+    fn nearest_ansi16_redmean() {
+        assert_eq!(
+            nearest_ansi16(RgbColor::new(250, 10, 20)),
+            Color::Bright(BasicColor::Red.bright())
+        );
+        assert_eq!(
+            nearest_ansi16(RgbColor::new(200, 0, 0)),
+            Color::Basic(BasicColor::Red)
+        );
+        // using the euclidean distance this would be blue (`0, 0, 238`), but differences in the
+        // green component are perceived stronger than differences in the blue component
+        assert_eq!(
+            nearest_ansi16(RgbColor::new(0, 80, 192)),
+            Color::Bright(BasicColor::Blue.bright())
+        );
+    }
+
+    #[test]
+    fn ansi16_from_indexed() {
+        assert_eq!(ansi16(0), Color::Basic(BasicColor::Black));
+        assert_eq!(ansi16(7), Color::Basic(BasicColor::White));
+        assert_eq!(ansi16(9), Color::Bright(BasicColor::Red.bright()));
+        assert_eq!(ansi16(15), Color::Bright(BasicColor::White.bright()));
+
+        assert_eq!(
+            Color::Indexed(IndexedColor::new(12)).downsample(ColorDepth::Ansi16),
+            Some(Color::Bright(BasicColor::Blue.bright()))
+        );
+    }
+
+    #[test]
+    fn supported_colors_are_unchanged() {
+        let colors = [
+            Color::Basic(BasicColor::Cyan),
+            Color::Bright(BasicColor::Cyan.bright()),
+            Color::Indexed(IndexedColor::new(100)),
+            Color::Rgb(RgbColor::new(1, 2, 3)),
+            Color::Transparent(TransparentColor),
+        ];
+
+        for color in colors {
+            assert_eq!(color.downsample(ColorDepth::TrueColor), Some(color));
+        }
+        assert_eq!(colors[2].downsample(ColorDepth::Ansi256), Some(colors[2]));
+        assert_eq!(colors[0].downsample(ColorDepth::Ansi256), Some(colors[0]));
+    }
+
+    #[test]
+    fn transparent_requires_true_color() {
+        let color = Color::Transparent(TransparentColor);
+
+        assert_eq!(color.downsample(ColorDepth::Ansi256), None);
+        assert_eq!(color.downsample(ColorDepth::Ansi16), None);
+    }
+
+    #[test]
+    fn monochrome_drops_colors() {
+        assert_eq!(
+            Color::Basic(BasicColor::Red).downsample(ColorDepth::Monochrome),
+            None
+        );
+
+        let style = Style::new()
+            .with_foreground(Foreground::new(RgbColor::new(1, 2, 3).into()))
+            .with_background(Background::new(BasicColor::Red.into()));
+
+        let downsampled = style.downsample(ColorDepth::Monochrome);
+        assert_eq!(downsampled.foreground, None);
+        assert_eq!(downsampled.background, None);
+        assert_eq!(downsampled.font, style.font);
+    }
+
+    #[test]
+    fn style_downsample() {
+        let style = Style::new()
+            .with_foreground(Foreground::new(RgbColor::new(0xFF, 0, 0).into()))
+            .with_background(Background::new(IndexedColor::new(196).into()));
+
+        let downsampled = style.downsample(ColorDepth::Ansi16);
+        assert_eq!(
+            downsampled.foreground,
+            Some(Foreground::new(BasicColor::Red.bright().into()))
+        );
+        assert_eq!(
+            downsampled.background,
+            Some(Background::new(BasicColor::Red.bright().into()))
+        );
+    }
+}
@@ -44,6 +44,10 @@ impl AlternativeFontFamily {
     pub fn new(variant: u8) -> Option<Self> {
         (0..9).contains(&variant).then_some(Self(variant))
     }
+
+    pub(crate) const fn index(self) -> u8 {
+        self.0
+    }
 }
 
 /// Font Family
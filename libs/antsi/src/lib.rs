@@ -29,13 +29,21 @@ pub use color::{
     BasicColor, BrightColor, CmyColor, CmykColor, Color, IndexedColor, RgbColor, TransparentColor,
 };
 pub use decorations::{Decorations, Frame};
+pub use downsample::ColorDepth;
 #[cfg(feature = "script")]
 pub use font::FontScript;
 pub use font::{Blinking, Font, FontFamily, FontWeight, Underline};
+#[cfg(feature = "width")]
+pub use parse::width;
+pub use parse::{parse, strip, Spans, Stripped};
+pub use sgr::Transition;
 
 mod color;
 mod decorations;
+mod downsample;
 mod font;
+mod parse;
+mod sgr;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Foreground(Color);
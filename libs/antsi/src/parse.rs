@@ -0,0 +1,667 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::FusedIterator,
+};
+
+#[cfg(feature = "script")]
+use crate::FontScript;
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{
+    font::AlternativeFontFamily, Background, BasicColor, Blinking, CmyColor, CmykColor, Color,
+    Font, FontFamily, FontWeight, Foreground, Frame, IndexedColor, RgbColor, Style,
+    TransparentColor, Underline,
+};
+
+const ESCAPE: char = '\x1b';
+const BELL: char = '\x07';
+
+const BASIC_COLORS: [BasicColor; 8] = [
+    BasicColor::Black,
+    BasicColor::Red,
+    BasicColor::Green,
+    BasicColor::Yellow,
+    BasicColor::Blue,
+    BasicColor::Magenta,
+    BasicColor::Cyan,
+    BasicColor::White,
+];
+
+/// Iterator over the text of a string and the [`Style`] applied to it
+///
+/// Created through [`parse`], see the function for more information.
+#[derive(Debug, Clone)]
+pub struct Spans<'a> {
+    input: &'a str,
+    style: Style,
+}
+
+impl<'a> Spans<'a> {
+    /// Style that is going to be applied to the next span
+    #[must_use]
+    pub const fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Skips the escape sequence at the start of the input and applies it to the current style.
+    fn escape(&mut self) {
+        let input = self.input.strip_prefix(ESCAPE).unwrap_or(self.input);
+        let mut chars = input.char_indices();
+
+        let end = match chars.next() {
+            // Control Sequence Introducer: `ESC[{parameters}{intermediates}{final}`
+            Some((_, '[')) => match chars.find(|(_, char)| ('\x40'..='\x7e').contains(char)) {
+                Some((index, char)) => {
+                    if char == 'm' {
+                        apply(&mut self.style, input.get(1..index).unwrap_or_default());
+                    }
+
+                    index + char.len_utf8()
+                }
+                None => input.len(),
+            },
+            // Operating System Command: `ESC]{command}BEL` or `ESC]{command}ESC\`, e.g. hyperlinks
+            Some((_, ']')) => {
+                let mut end = input.len();
+
+                while let Some((index, char)) = chars.next() {
+                    if char == BELL {
+                        end = index + char.len_utf8();
+                        break;
+                    }
+
+                    if char == ESCAPE && chars.clone().next().map(|(_, char)| char) == Some('\\') {
+                        end = index + 2;
+                        break;
+                    }
+                }
+
+                end
+            }
+            Some((index, char)) => index + char.len_utf8(),
+            None => 0,
+        };
+
+        self.input = input.get(end..).unwrap_or_default();
+    }
+}
+
+impl<'a> Iterator for Spans<'a> {
+    type Item = (Style, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.input.starts_with(ESCAPE) {
+            self.escape();
+        }
+
+        if self.input.is_empty() {
+            return None;
+        }
+
+        let end = self.input.find(ESCAPE).unwrap_or(self.input.len());
+        let (text, input) = self.input.split_at(end);
+        self.input = input;
+
+        Some((self.style, text))
+    }
+}
+
+impl FusedIterator for Spans<'_> {}
+
+/// Parses a string containing ANSI escape sequences into spans of text and their [`Style`]
+///
+/// SGR escape sequences (`ESC[...m`) are applied to the style of the following text, parameters
+/// can either be delimited by `;` or `:`, which means that both, the format of [ISO 8613-6] and the
+/// legacy format used by xterm, are supported. Unknown parameters are ignored. Every other escape
+/// sequence, like cursor movement or hyperlinks, is removed from the text.
+///
+/// The parser is the counterpart of the [`Display`] implementation of [`Style`], every style
+/// rendered by `antsi` is parsed into the same style again. The only exception are basic and bright
+/// underline colors, which are rendered (and therefore parsed) as [`IndexedColor`].
+///
+/// Spans never contain escape sequences and are never empty, but two consecutive spans may have the
+/// same style.
+///
+/// [ISO 8613-6]: https://www.iso.org/standard/22943.html
+///
+/// # Example
+///
+/// ```
+/// use antsi::{Font, FontWeight, Foreground, IndexedColor, Style};
+///
+/// let mut spans = antsi::parse("\x1b[1;38;5;196mError:\x1b[0m file not found");
+///
+/// let error = Style::new()
+///     .with_font(Font::new().with_weight(FontWeight::Bold))
+///     .with_foreground(Foreground::new(IndexedColor::new(196).into()));
+///
+/// assert_eq!(spans.next(), Some((error, "Error:")));
+/// assert_eq!(spans.next(), Some((Style::new(), " file not found")));
+/// assert_eq!(spans.next(), None);
+/// ```
+#[must_use]
+pub const fn parse(input: &str) -> Spans<'_> {
+    Spans {
+        input,
+        style: Style::new(),
+    }
+}
+
+/// Text of a string without any ANSI escape sequences
+///
+/// Created through [`strip`], see the function for more information.
+#[derive(Debug, Copy, Clone)]
+pub struct Stripped<'a>(&'a str);
+
+impl Display for Stripped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        parse(self.0).try_for_each(|(_, text)| f.write_str(text))
+    }
+}
+
+/// Removes all ANSI escape sequences from a string
+///
+/// # Example
+///
+/// ```
+/// let stripped = antsi::strip("\x1b[1mbold\x1b[22m and \x1b[3mitalic\x1b[23m");
+///
+/// assert_eq!(stripped.to_string(), "bold and italic");
+/// ```
+#[must_use]
+pub const fn strip(input: &str) -> Stripped<'_> {
+    Stripped(input)
+}
+
+/// Width of a string when displayed in a terminal, ignoring any ANSI escape sequences
+///
+/// The width of every character is determined according to [Unicode Standard Annex #11], e.g. most
+/// CJK characters occupy two columns, while control characters do not occupy any.
+///
+/// [Unicode Standard Annex #11]: https://www.unicode.org/reports/tr11/
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(antsi::width("\x1b[31mError\x1b[0m"), 5);
+/// assert_eq!(antsi::width("\x1b[1m日本\x1b[0m"), 4);
+/// ```
+#[cfg(feature = "width")]
+#[must_use]
+pub fn width(input: &str) -> usize {
+    parse(input)
+        .map(|(_, text)| unicode_width::UnicodeWidthStr::width(text))
+        .sum()
+}
+
+fn number(parameter: &str) -> Option<u8> {
+    if parameter.is_empty() {
+        Some(0)
+    } else {
+        parameter.parse().ok()
+    }
+}
+
+/// Parses an extended color (`38`, `48` and `58`).
+///
+/// `parameters` are the parameters after the introducer, e.g. `2::255:0:0` or `5:196`. If the
+/// parameters are delimited by `:` the color space identifier may be present.
+fn extended_color<'a>(
+    mut parameters: impl Iterator<Item = &'a str>,
+    subparameters: bool,
+) -> Option<Color> {
+    let mode = number(parameters.next()?)?;
+    let components = match mode {
+        1 => 0,
+        5 => 1,
+        2 | 3 => 3,
+        4 => 4,
+        #[cfg(feature = "rgba")]
+        6 => 4,
+        _ => return None,
+    };
+
+    // color space identifier, 4 components and the tolerance value and color space
+    let mut values = [""; 7];
+    let mut count = 0;
+
+    for (value, parameter) in
+        values
+            .iter_mut()
+            .zip(parameters.take(if subparameters { 7 } else { components }))
+    {
+        *value = parameter;
+        count += 1;
+    }
+
+    if count < components {
+        return None;
+    }
+
+    // `ESC[38:2::{r}:{g}:{b}m` contains the color space identifier, while `ESC[38:2:{r}:{g}:{b}m`
+    // and the legacy format `ESC[38;2;{r};{g};{b}m` do not
+    let offset = usize::from(subparameters && mode != 5 && count > components);
+    let component = |index: usize| number(values[offset + index]);
+
+    let color = match mode {
+        1 => Color::Transparent(TransparentColor),
+        5 => Color::Indexed(IndexedColor::new(component(0)?)),
+        2 => Color::Rgb(RgbColor::new(component(0)?, component(1)?, component(2)?)),
+        3 => Color::Cmy(CmyColor::new(component(0)?, component(1)?, component(2)?)),
+        4 => Color::Cmyk(CmykColor::new(
+            component(0)?,
+            component(1)?,
+            component(2)?,
+            component(3)?,
+        )),
+        #[cfg(feature = "rgba")]
+        6 => Color::Rgba(crate::RgbaColor::new(
+            component(0)?,
+            component(1)?,
+            component(2)?,
+            component(3)?,
+        )),
+        _ => return None,
+    };
+
+    Some(color)
+}
+
+fn underline(parameter: Option<&str>) -> Option<Underline> {
+    match parameter.and_then(number) {
+        None | Some(1) => Some(Underline::Single),
+        Some(0) => None,
+        Some(2) => Some(Underline::Double),
+        #[cfg(feature = "underline-variants")]
+        Some(3) => Some(Underline::Curly),
+        #[cfg(feature = "underline-variants")]
+        Some(4) => Some(Underline::Dotted),
+        #[cfg(feature = "underline-variants")]
+        Some(5) => Some(Underline::Dashed),
+        // fall back to the closest supported underline
+        Some(_) => Some(Underline::Single),
+    }
+}
+
+/// Applies an SGR parameter affecting the font to the font.
+fn apply_font<'a>(font: &mut Font, code: u8, mut parameters: impl Iterator<Item = &'a str>) {
+    match code {
+        1 => font.weight = Some(FontWeight::Bold),
+        2 => font.weight = Some(FontWeight::Faint),
+        22 => font.weight = None,
+        3 => {
+            font.set_italic(true);
+        }
+        23 => {
+            font.set_italic(false);
+            if font.family == Some(FontFamily::Fraktur) {
+                font.family = None;
+            }
+        }
+        4 => font.underline = underline(parameters.next()),
+        21 => font.underline = Some(Underline::Double),
+        24 => font.underline = None,
+        5 => font.blinking = Some(Blinking::Slow),
+        6 => font.blinking = Some(Blinking::Fast),
+        25 => font.blinking = None,
+        7 => {
+            font.set_inverse(true);
+        }
+        27 => {
+            font.set_inverse(false);
+        }
+        #[cfg(feature = "overstrike")]
+        8 if parameters.next() == Some("7") => {
+            font.set_overstrike(true);
+        }
+        8 => {
+            font.set_hidden(true);
+        }
+        28 => {
+            font.set_hidden(false);
+            #[cfg(feature = "overstrike")]
+            font.set_overstrike(false);
+        }
+        9 => {
+            font.set_strikethrough(true);
+        }
+        29 => {
+            font.set_strikethrough(false);
+        }
+        10 => {
+            if matches!(font.family, Some(FontFamily::Alternative(_))) {
+                font.family = None;
+            }
+        }
+        11..=19 => {
+            font.family = AlternativeFontFamily::new(code - 11).map(FontFamily::Alternative);
+        }
+        20 => font.family = Some(FontFamily::Fraktur),
+        53 => {
+            font.set_overline(true);
+        }
+        55 => {
+            font.set_overline(false);
+        }
+        #[cfg(feature = "script")]
+        73 => font.script = Some(FontScript::Super),
+        #[cfg(feature = "script")]
+        74 => font.script = Some(FontScript::Sub),
+        #[cfg(feature = "script")]
+        75 => font.script = None,
+        _ => {}
+    }
+}
+
+/// Applies the parameters of an SGR escape sequence to the style.
+fn apply(style: &mut Style, parameters: &str) {
+    if parameters.is_empty() {
+        *style = Style::new();
+        return;
+    }
+
+    let mut groups = parameters.split(';');
+
+    while let Some(group) = groups.next() {
+        let mut parameters = group.split(':');
+        let subparameters = group.contains(':');
+
+        let code = match parameters.next().and_then(number) {
+            Some(code) => code,
+            None => continue,
+        };
+
+        match code {
+            0 => *style = Style::new(),
+            51 => style.decorations.frame = Some(Frame::Framed),
+            52 => style.decorations.frame = Some(Frame::Encircled),
+            54 => style.decorations.frame = None,
+
+            30..=37 => {
+                style.foreground = Some(Foreground::new(Color::Basic(
+                    BASIC_COLORS[usize::from(code - 30)],
+                )));
+            }
+            90..=97 => {
+                style.foreground = Some(Foreground::new(Color::Bright(
+                    BASIC_COLORS[usize::from(code - 90)].bright(),
+                )));
+            }
+            39 => style.foreground = None,
+            40..=47 => {
+                style.background = Some(Background::new(Color::Basic(
+                    BASIC_COLORS[usize::from(code - 40)],
+                )));
+            }
+            100..=107 => {
+                style.background = Some(Background::new(Color::Bright(
+                    BASIC_COLORS[usize::from(code - 100)].bright(),
+                )));
+            }
+            49 => style.background = None,
+            38 | 48 | 58 => {
+                let color = if subparameters {
+                    extended_color(&mut parameters, true)
+                } else {
+                    extended_color(&mut groups, false)
+                };
+
+                match (code, color) {
+                    (38, Some(color)) => style.foreground = Some(Foreground::new(color)),
+                    (48, Some(color)) => style.background = Some(Background::new(color)),
+                    #[cfg(feature = "underline-color")]
+                    (58, Some(color)) => style.underline_color = Some(UnderlineColor::new(color)),
+                    _ => {}
+                }
+            }
+            #[cfg(feature = "underline-color")]
+            59 => style.underline_color = None,
+            _ => apply_font(&mut style.font, code, &mut parameters),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{format, string::ToString, vec::Vec};
+
+    use super::*;
+
+    fn spans(input: &str) -> Vec<(Style, &str)> {
+        parse(input).collect()
+    }
+
+    const fn foreground(color: Color) -> Style {
+        Style::new().with_foreground(Foreground::new(color))
+    }
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(spans("text"), [(Style::new(), "text")]);
+        assert_eq!(spans(""), []);
+        assert_eq!(spans("\x1b[1m\x1b[0m"), []);
+    }
+
+    #[test]
+    fn consecutive_sequences() {
+        let style = Style::new()
+            .with_font(Font::new().with_weight(FontWeight::Bold))
+            .with_foreground(Foreground::new(BasicColor::Red.into()));
+
+        assert_eq!(spans("\x1b[1m\x1b[31mtext\x1b[m rest"), [
+            (style, "text"),
+            (Style::new(), " rest")
+        ]);
+    }
+
+    #[test]
+    fn semicolon_and_colon_parameters() {
+        let rgb = foreground(RgbColor::new(1, 2, 3).into());
+
+        // legacy xterm format
+        assert_eq!(spans("\x1b[38;2;1;2;3mx"), [(rgb, "x")]);
+        // ISO 8613-6 with and without color space identifier
+        assert_eq!(spans("\x1b[38:2::1:2:3mx"), [(rgb, "x")]);
+        assert_eq!(spans("\x1b[38:2:1:2:3mx"), [(rgb, "x")]);
+
+        // parameters after an extended color delimited by `;` are still applied
+        let style = rgb.with_font(Font::new().with_weight(FontWeight::Bold));
+        assert_eq!(spans("\x1b[38;2;1;2;3;1mx"), [(style, "x")]);
+        assert_eq!(spans("\x1b[38:2::1:2:3;1mx"), [(style, "x")]);
+    }
+
+    #[test]
+    fn extended_colors() {
+        assert_eq!(spans("\x1b[38;5;196mx"), [(
+            foreground(IndexedColor::new(196).into()),
+            "x"
+        )]);
+        assert_eq!(spans("\x1b[48:5:21mx"), [(
+            Style::new().with_background(Background::new(IndexedColor::new(21).into())),
+            "x"
+        )]);
+        assert_eq!(spans("\x1b[38:1mx"), [(
+            foreground(TransparentColor.into()),
+            "x"
+        )]);
+        assert_eq!(spans("\x1b[38:3::10:20:30mx"), [(
+            foreground(CmyColor::new(10, 20, 30).into()),
+            "x"
+        )]);
+        assert_eq!(spans("\x1b[48;4;10;20;30;40mx"), [(
+            Style::new().with_background(Background::new(CmykColor::new(10, 20, 30, 40).into())),
+            "x"
+        )]);
+    }
+
+    #[test]
+    fn invalid_extended_colors() {
+        // missing components
+        assert_eq!(spans("\x1b[38;5mx"), [(Style::new(), "x")]);
+        assert_eq!(spans("\x1b[38:2:1:2mx"), [(Style::new(), "x")]);
+        // unknown color mode, the following parameters are applied as usual
+        assert_eq!(spans("\x1b[38;9mx"), [(Style::new(), "x")]);
+        assert_eq!(spans("\x1b[38;9;1mx"), [(
+            Style::new().with_font(Font::new().with_weight(FontWeight::Bold)),
+            "x"
+        )]);
+        // out of range component
+        assert_eq!(spans("\x1b[38;5;256mx"), [(Style::new(), "x")]);
+    }
+
+    #[test]
+    fn basic_and_bright_colors() {
+        assert_eq!(spans("\x1b[32;104mx"), [(
+            foreground(BasicColor::Green.into())
+                .with_background(Background::new(BasicColor::Blue.bright().into())),
+            "x"
+        )]);
+        assert_eq!(spans("\x1b[32m\x1b[39mx"), [(Style::new(), "x")]);
+    }
+
+    #[test]
+    fn font_parameters() {
+        let font = Font::new()
+            .with_weight(FontWeight::Faint)
+            .with_underline(Underline::Double)
+            .with_blinking(Blinking::Slow)
+            .with_family(FontFamily::Fraktur)
+            .with_inverse()
+            .with_overline();
+
+        assert_eq!(spans("\x1b[2;4:2;5;20;7;53mx"), [(
+            Style::new().with_font(font),
+            "x"
+        )]);
+
+        // `ESC[23m` disables italic and fraktur, `ESC[4:0m` disables the underline
+        assert_eq!(spans("\x1b[3;20;4m\x1b[23;4:0mx"), [(Style::new(), "x")]);
+    }
+
+    #[test]
+    fn unknown_parameters_are_ignored() {
+        let bold = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+
+        assert_eq!(spans("\x1b[200;1mx"), [(bold, "x")]);
+        assert_eq!(spans("\x1b[1000;1mx"), [(bold, "x")]);
+    }
+
+    #[test]
+    fn unknown_csi_sequences_are_removed() {
+        let bold = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+
+        // cursor movement and clearing the screen do not change the style
+        assert_eq!(spans("\x1b[1m\x1b[2J\x1b[10;20Hx"), [(bold, "x")]);
+    }
+
+    #[test]
+    fn truncated_sequences() {
+        // a sequence without final byte consumes the remaining input
+        assert_eq!(spans("text\x1b[1;3"), [(Style::new(), "text")]);
+        assert_eq!(spans("text\x1b]8;;https://example.com"), [(
+            Style::new(),
+            "text"
+        )]);
+        assert_eq!(spans("text\x1b"), [(Style::new(), "text")]);
+    }
+
+    #[test]
+    fn osc_sequences_are_removed() {
+        // hyperlinks terminated by `BEL` and `ESC\`
+        assert_eq!(spans("\x1b]8;;https://example.com\x07link\x1b]8;;\x07"), [
+            (Style::new(), "link")
+        ]);
+        assert_eq!(
+            spans("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\ rest"),
+            [(Style::new(), "link"), (Style::new(), " rest")]
+        );
+    }
+
+    #[test]
+    fn strip_sequences() {
+        assert_eq!(strip("plain").to_string(), "plain");
+        assert_eq!(
+            strip("\x1b[1;38:2::1:2:3mbold\x1b[0m \x1b]8;;url\x07link\x1b]8;;\x07").to_string(),
+            "bold link"
+        );
+        assert_eq!(strip("\x1b[31").to_string(), "");
+    }
+
+    #[test]
+    #[cfg(feature = "width")]
+    fn width_ignores_sequences() {
+        assert_eq!(width(""), 0);
+        assert_eq!(width("\x1b[1;31mabc\x1b[0m"), 3);
+        assert_eq!(width("\x1b]8;;url\x07日本\x1b]8;;\x07"), 4);
+        assert_eq!(width("\x1b[38;5"), 0);
+    }
+
+    #[test]
+    fn round_trip() {
+        let styles = [
+            Style::new(),
+            Style::new()
+                .with_font(Font::new().with_weight(FontWeight::Bold))
+                .with_foreground(Foreground::new(BasicColor::Red.into())),
+            Style::new()
+                .with_foreground(Foreground::new(BasicColor::Cyan.bright().into()))
+                .with_background(Background::new(IndexedColor::new(100).into())),
+            Style::new()
+                .with_foreground(Foreground::new(RgbColor::new(0, 128, 255).into()))
+                .with_background(Background::new(TransparentColor.into())),
+            Style::new()
+                .with_foreground(Foreground::new(CmyColor::new(1, 2, 3).into()))
+                .with_background(Background::new(CmykColor::new(1, 2, 3, 4).into())),
+            Style::new().with_font(
+                Font::new()
+                    .with_weight(FontWeight::Faint)
+                    .with_italic()
+                    .with_family(FontFamily::Fraktur)
+                    .with_underline(Underline::Double)
+                    .with_blinking(Blinking::Fast)
+                    .with_inverse()
+                    .with_hidden()
+                    .with_strikethrough()
+                    .with_overline(),
+            ),
+            Style::new().with_font(Font::new().with_family(FontFamily::Alternative(
+                AlternativeFontFamily::new(3).expect("should be a valid font family"),
+            ))),
+            {
+                let mut style = Style::new();
+                style.decorations.frame = Some(Frame::Encircled);
+                style
+            },
+        ];
+
+        for style in styles {
+            let input = format!("{style}text");
+
+            assert_eq!(spans(&input), [(style, "text")], "{input:?}");
+        }
+    }
+
+    #[test]
+    fn round_trip_transitions() {
+        let bold = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+        let faint_red = Style::new()
+            .with_font(Font::new().with_weight(FontWeight::Faint))
+            .with_foreground(Foreground::new(BasicColor::Red.into()));
+
+        let input = format!(
+            "{bold}a{}b{}c{}d",
+            bold.transition_to(faint_red),
+            faint_red.transition_to(bold),
+            bold.reset()
+        );
+
+        assert_eq!(spans(&input), [
+            (bold, "a"),
+            (faint_red, "b"),
+            (bold, "c"),
+            (Style::new(), "d")
+        ]);
+    }
+}
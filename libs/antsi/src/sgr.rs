@@ -0,0 +1,489 @@
+use core::fmt::{self, Display, Formatter, Write};
+
+#[cfg(feature = "script")]
+use crate::FontScript;
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{
+    color::{ColorLayer, ColorParameter},
+    Background, Blinking, Color, Font, FontFamily, FontWeight, Foreground, Frame, Style, Underline,
+};
+
+/// Writes the parameters of a single SGR escape sequence, e.g. `ESC[1;31m`.
+///
+/// The sequence is only introduced once the first parameter is pushed, if no parameter has been
+/// pushed nothing is written.
+struct Sequence<'a, W> {
+    writer: &'a mut W,
+    empty: bool,
+}
+
+impl<'a, W: Write> Sequence<'a, W> {
+    fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer,
+            empty: true,
+        }
+    }
+
+    fn push(&mut self, parameter: impl Display) -> fmt::Result {
+        self.writer
+            .write_str(if self.empty { "\x1b[" } else { ";" })?;
+        self.empty = false;
+
+        write!(self.writer, "{parameter}")
+    }
+
+    fn finish(self) -> fmt::Result {
+        if self.empty {
+            Ok(())
+        } else {
+            self.writer.write_char('m')
+        }
+    }
+}
+
+/// Counts the bytes written, used to determine the shorter of two sequences.
+struct Length(usize);
+
+impl Write for Length {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+
+        Ok(())
+    }
+}
+
+/// Pushes the parameters required to change an attribute from `from` to `to`.
+fn change<T: Copy + PartialEq, P: Display>(
+    sequence: &mut Sequence<impl Write>,
+    from: Option<T>,
+    to: Option<T>,
+    reset: &str,
+    set: impl FnOnce(T) -> P,
+) -> fmt::Result {
+    if from == to {
+        return Ok(());
+    }
+
+    match to {
+        Some(value) => sequence.push(set(value)),
+        None => sequence.push(reset),
+    }
+}
+
+/// Pushes the parameters required to toggle flags, which share the same reset parameter.
+///
+/// Each flag is given as `(from, to, set)`. If a single flag is disabled the reset parameter
+/// disables all of them, therefore every flag that is still enabled needs to be set again.
+fn toggle(
+    sequence: &mut Sequence<impl Write>,
+    reset: &str,
+    flags: &[(bool, bool, &str)],
+) -> fmt::Result {
+    let reset_required = flags.iter().any(|&(from, to, _)| from && !to);
+
+    if reset_required {
+        sequence.push(reset)?;
+    }
+
+    for &(from, to, set) in flags {
+        if to && (reset_required || !from) {
+            sequence.push(set)?;
+        }
+    }
+
+    Ok(())
+}
+
+const fn alternative_font(font: Font) -> Option<u8> {
+    match font.family {
+        Some(FontFamily::Alternative(family)) => Some(family.index()),
+        _ => None,
+    }
+}
+
+const fn is_fraktur(font: Font) -> bool {
+    matches!(font.family, Some(FontFamily::Fraktur))
+}
+
+fn font(sequence: &mut Sequence<impl Write>, from: Font, to: Font) -> fmt::Result {
+    if from.weight != to.weight {
+        // `ESC[1m` and `ESC[2m` can be combined in some terminals, reset before switching
+        if from.weight.is_some() {
+            sequence.push(22)?;
+        }
+
+        match to.weight {
+            Some(FontWeight::Bold) => sequence.push(1)?,
+            Some(FontWeight::Faint) => sequence.push(2)?,
+            None => {}
+        }
+    }
+
+    change(
+        sequence,
+        alternative_font(from),
+        alternative_font(to),
+        "10",
+        |index| 11 + index,
+    )?;
+    // `ESC[23m` disables both, italic and fraktur
+    toggle(sequence, "23", &[
+        (from.is_italic(), to.is_italic(), "3"),
+        (is_fraktur(from), is_fraktur(to), "20"),
+    ])?;
+
+    change(
+        sequence,
+        from.underline,
+        to.underline,
+        "24",
+        |underline| match underline {
+            Underline::Single => "4",
+            Underline::Double => "21",
+            #[cfg(feature = "underline-variants")]
+            Underline::Curly => "4:3",
+            #[cfg(feature = "underline-variants")]
+            Underline::Dotted => "4:4",
+            #[cfg(feature = "underline-variants")]
+            Underline::Dashed => "4:5",
+        },
+    )?;
+    change(
+        sequence,
+        from.blinking,
+        to.blinking,
+        "25",
+        |blinking| match blinking {
+            Blinking::Slow => "5",
+            Blinking::Fast => "6",
+        },
+    )?;
+
+    toggle(sequence, "27", &[(from.is_inverse(), to.is_inverse(), "7")])?;
+    // `ESC[28m` disables both, hidden and overstrike
+    toggle(sequence, "28", &[
+        (from.is_hidden(), to.is_hidden(), "8"),
+        #[cfg(feature = "overstrike")]
+        (from.is_overstrike(), to.is_overstrike(), "8:7"),
+    ])?;
+    toggle(sequence, "29", &[(
+        from.is_strikethrough(),
+        to.is_strikethrough(),
+        "9",
+    )])?;
+    toggle(sequence, "55", &[(
+        from.is_overline(),
+        to.is_overline(),
+        "53",
+    )])?;
+
+    #[cfg(feature = "script")]
+    change(
+        sequence,
+        from.script,
+        to.script,
+        "75",
+        |script| match script {
+            FontScript::Super => "73",
+            FontScript::Sub => "74",
+        },
+    )?;
+
+    Ok(())
+}
+
+fn color(
+    sequence: &mut Sequence<impl Write>,
+    from: Option<Color>,
+    to: Option<Color>,
+    layer: ColorLayer,
+    reset: &str,
+) -> fmt::Result {
+    change(sequence, from, to, reset, |color| ColorParameter {
+        color,
+        layer,
+    })
+}
+
+fn delta(sequence: &mut Sequence<impl Write>, from: &Style, to: &Style) -> fmt::Result {
+    font(sequence, from.font, to.font)?;
+
+    change(
+        sequence,
+        from.decorations.frame,
+        to.decorations.frame,
+        "54",
+        |frame| match frame {
+            Frame::Framed => "51",
+            Frame::Encircled => "52",
+        },
+    )?;
+
+    color(
+        sequence,
+        from.foreground.map(Foreground::color),
+        to.foreground.map(Foreground::color),
+        ColorLayer::Foreground,
+        "39",
+    )?;
+    color(
+        sequence,
+        from.background.map(Background::color),
+        to.background.map(Background::color),
+        ColorLayer::Background,
+        "49",
+    )?;
+    #[cfg(feature = "underline-color")]
+    color(
+        sequence,
+        from.underline_color.map(UnderlineColor::color),
+        to.underline_color.map(UnderlineColor::color),
+        ColorLayer::Underline,
+        "59",
+    )?;
+
+    Ok(())
+}
+
+fn write_delta(writer: &mut impl Write, from: &Style, to: &Style) -> fmt::Result {
+    let mut sequence = Sequence::new(writer);
+    delta(&mut sequence, from, to)?;
+    sequence.finish()
+}
+
+fn write_reset(writer: &mut impl Write, to: &Style) -> fmt::Result {
+    let mut sequence = Sequence::new(writer);
+    sequence.push(0)?;
+    delta(&mut sequence, &Style::new(), to)?;
+    sequence.finish()
+}
+
+/// Renders the SGR escape sequence required to apply the style to the default style of the
+/// terminal.
+///
+/// Only the attributes that have been set are included, the default style renders as an empty
+/// string.
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, Font, FontWeight, Foreground, Style};
+///
+/// let style = Style::new()
+///     .with_font(Font::new().with_weight(FontWeight::Bold))
+///     .with_foreground(Foreground::new(BasicColor::Red.into()));
+///
+/// assert_eq!(style.to_string(), "\x1b[1;31m");
+/// assert_eq!(Style::new().to_string(), "");
+/// ```
+impl Display for Style {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_delta(f, &Self::new(), self)
+    }
+}
+
+/// Transition between two [`Style`]s
+///
+/// Renders the shortest SGR escape sequence required to change the style of the terminal from one
+/// style to another. This is either the delta between both styles, or a full reset (`ESC[0m`)
+/// followed by the attributes of the target style. If both styles are the same, nothing is
+/// rendered.
+///
+/// Created through [`Style::transition_to`] or [`Style::reset`].
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, Font, FontWeight, Foreground, Style};
+///
+/// let bold = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+/// let bold_red = bold.with_foreground(Foreground::new(BasicColor::Red.into()));
+///
+/// assert_eq!(bold.transition_to(bold_red).to_string(), "\x1b[31m");
+/// assert_eq!(bold_red.transition_to(bold).to_string(), "\x1b[39m");
+/// assert_eq!(bold_red.reset().to_string(), "\x1b[0m");
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Transition {
+    from: Style,
+    to: Style,
+}
+
+impl Transition {
+    #[must_use]
+    pub const fn new(from: Style, to: Style) -> Self {
+        Self { from, to }
+    }
+}
+
+impl Display for Transition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.from == self.to {
+            return Ok(());
+        }
+
+        let mut delta = Length(0);
+        write_delta(&mut delta, &self.from, &self.to)?;
+
+        let mut reset = Length(0);
+        write_reset(&mut reset, &self.to)?;
+
+        if reset.0 < delta.0 {
+            write_reset(f, &self.to)
+        } else {
+            write_delta(f, &self.from, &self.to)
+        }
+    }
+}
+
+impl Style {
+    /// Transition from this style to another style
+    ///
+    /// See [`Transition`] for more information.
+    #[must_use]
+    pub const fn transition_to(self, to: Self) -> Transition {
+        Transition::new(self, to)
+    }
+
+    /// Transition from this style to the default style of the terminal
+    ///
+    /// See [`Transition`] for more information.
+    #[must_use]
+    pub const fn reset(self) -> Transition {
+        Transition::new(self, Self::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::string::{String, ToString};
+
+    use super::*;
+    use crate::{BasicColor, IndexedColor, RgbColor};
+
+    fn delta(from: &Style, to: &Style) -> String {
+        let mut output = String::new();
+        write_delta(&mut output, from, to).expect("writing to a string should not fail");
+        output
+    }
+
+    const fn weight(weight: FontWeight) -> Style {
+        Style::new().with_font(Font::new().with_weight(weight))
+    }
+
+    #[test]
+    fn display_empty() {
+        assert_eq!(Style::new().to_string(), "");
+    }
+
+    #[test]
+    fn display_colors() {
+        let style = Style::new()
+            .with_foreground(Foreground::new(BasicColor::Green.bright().into()))
+            .with_background(Background::new(IndexedColor::new(196).into()));
+        assert_eq!(style.to_string(), "\x1b[92;48:5:196m");
+
+        let style = Style::new().with_foreground(Foreground::new(RgbColor::new(1, 2, 3).into()));
+        assert_eq!(style.to_string(), "\x1b[38:2::1:2:3m");
+    }
+
+    #[test]
+    fn display_font() {
+        let style = Style::new().with_font(
+            Font::new()
+                .with_weight(FontWeight::Faint)
+                .with_underline(Underline::Double)
+                .with_blinking(Blinking::Fast)
+                .with_italic()
+                .with_strikethrough(),
+        );
+
+        assert_eq!(style.to_string(), "\x1b[2;3;21;6;9m");
+    }
+
+    #[test]
+    fn weight_is_reset_before_switching() {
+        // `ESC[22m` disables bold as well as faint, the new weight needs to be set afterwards
+        assert_eq!(
+            delta(&weight(FontWeight::Bold), &weight(FontWeight::Faint)),
+            "\x1b[22;2m"
+        );
+        assert_eq!(
+            delta(&weight(FontWeight::Faint), &weight(FontWeight::Bold)),
+            "\x1b[22;1m"
+        );
+        assert_eq!(delta(&weight(FontWeight::Bold), &Style::new()), "\x1b[22m");
+        assert_eq!(delta(&Style::new(), &weight(FontWeight::Bold)), "\x1b[1m");
+    }
+
+    #[test]
+    fn shared_reset_reapplies_remaining_flags() {
+        let italic = Style::new().with_font(Font::new().with_italic());
+        let fraktur = Style::new().with_font(Font::new().with_family(FontFamily::Fraktur));
+        let both =
+            Style::new().with_font(Font::new().with_italic().with_family(FontFamily::Fraktur));
+
+        // `ESC[23m` disables italic and fraktur
+        assert_eq!(delta(&both, &italic), "\x1b[23;3m");
+        assert_eq!(delta(&both, &fraktur), "\x1b[23;20m");
+        assert_eq!(delta(&both, &Style::new()), "\x1b[23m");
+        // enabling a flag does not require a reset
+        assert_eq!(delta(&italic, &both), "\x1b[20m");
+    }
+
+    #[test]
+    fn transition_between_identical_styles() {
+        let style =
+            weight(FontWeight::Bold).with_foreground(Foreground::new(BasicColor::Red.into()));
+
+        assert_eq!(style.transition_to(style).to_string(), "");
+        assert_eq!(Style::new().transition_to(Style::new()).to_string(), "");
+        assert_eq!(Style::new().reset().to_string(), "");
+    }
+
+    #[test]
+    fn transition_from_and_to_empty() {
+        let style =
+            weight(FontWeight::Bold).with_foreground(Foreground::new(BasicColor::Red.into()));
+
+        assert_eq!(Style::new().transition_to(style).to_string(), "\x1b[1;31m");
+        assert_eq!(style.transition_to(Style::new()).to_string(), "\x1b[0m");
+    }
+
+    #[test]
+    fn reset() {
+        let style = Style::new().with_font(Font::new().with_inverse().with_overline());
+
+        assert_eq!(style.reset().to_string(), "\x1b[0m");
+        assert_eq!(style.reset(), style.transition_to(Style::new()));
+    }
+
+    #[test]
+    fn transition_uses_shorter_sequence() {
+        let from = Style::new()
+            .with_font(
+                Font::new()
+                    .with_weight(FontWeight::Bold)
+                    .with_italic()
+                    .with_underline(Underline::Single),
+            )
+            .with_foreground(Foreground::new(BasicColor::Red.into()));
+        let to = Style::new().with_foreground(Foreground::new(BasicColor::Blue.into()));
+
+        // the delta `ESC[22;23;24;34m` is longer than a reset followed by the target style
+        assert_eq!(from.transition_to(to).to_string(), "\x1b[0;34m");
+        // the delta `ESC[22m` is shorter than `ESC[0;31m`
+        let bold_red =
+            weight(FontWeight::Bold).with_foreground(Foreground::new(BasicColor::Red.into()));
+        assert_eq!(
+            bold_red
+                .transition_to(bold_red.with_font(Font::new()))
+                .to_string(),
+            "\x1b[22m"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use deer::{
+    error::{ArrayAccessError, ArrayLengthError, Variant},
+    Context, Deserialize, Deserializer as _,
+};
+use error_stack::{Report, Result, ResultExt};
+
+use crate::{
+    decode::BREAK,
+    deserializer::Deserializer,
+    error::{Position, SyntaxError},
+};
+
+pub(crate) struct ArrayAccess<'a, 'b, 'de: 'a> {
+    deserializer: &'a mut Deserializer<'b, 'de>,
+
+    dirty: bool,
+    expected: usize,
+
+    length: Option<usize>,
+    /// Number of items that still need to be read, `None` for arrays of indefinite length.
+    remaining: Option<usize>,
+}
+
+impl<'a, 'b, 'de: 'a> ArrayAccess<'a, 'b, 'de> {
+    pub(crate) fn new(deserializer: &'a mut Deserializer<'b, 'de>, length: Option<usize>) -> Self {
+        Self {
+            deserializer,
+            dirty: false,
+            expected: 0,
+            length,
+            remaining: length,
+        }
+    }
+}
+
+impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, '_, 'de> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.deserializer.context()
+    }
+
+    fn next<T>(&mut self) -> Option<Result<T, ArrayAccessError>>
+    where
+        T: Deserialize<'de>,
+    {
+        self.dirty = true;
+
+        match &mut self.remaining {
+            Some(0) => return None,
+            Some(remaining) => *remaining -= 1,
+            // we check for `is_none` here because we could be EOF, in that case we're "done", we
+            // will error out at `.end()`
+            None => match self.deserializer.decoder.peek() {
+                None | Some(BREAK) => return None,
+                Some(_) => {}
+            },
+        }
+
+        self.expected += 1;
+
+        let value = T::deserialize(&mut *self.deserializer);
+
+        Some(value.change_context(ArrayAccessError))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.length
+    }
+
+    fn end(self) -> Result<(), ArrayAccessError> {
+        let decoder = &self.deserializer.decoder;
+
+        let result = match self.remaining {
+            Some(0) => Ok(()),
+            None if decoder.peek() == Some(BREAK) => Ok(()),
+            None if decoder.peek().is_none() => {
+                Err(Report::new(SyntaxError::UnexpectedEof.into_error())
+                    .attach(Position::new(decoder.offset())))
+            }
+            _ => Err(ArrayLengthError::new(&self, self.expected)),
+        };
+
+        // bump until the very end, which ensures that deserialize calls after this might succeed!
+        // errors while skipping are reported once the next value is read
+        let _ = self.deserializer.decoder.skip(self.remaining);
+
+        result.change_context(ArrayAccessError)
+    }
+}
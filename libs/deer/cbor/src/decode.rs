@@ -0,0 +1,208 @@
+use alloc::{vec, vec::Vec};
+
+use deer::error::{Error, Variant};
+use error_stack::{Report, Result};
+
+use crate::error::{Position, SyntaxError};
+
+/// Initial byte which terminates an item of indefinite length.
+pub(crate) const BREAK: u8 = 0xFF;
+
+pub(crate) fn syntax_error(error: SyntaxError, offset: usize) -> Report<Error> {
+    Report::new(error.into_error()).attach(Position::new(offset))
+}
+
+/// Header of a single CBOR data item, as described in [RFC 8949, section 3].
+///
+/// Lengths of `None` denote an item of indefinite length, which is terminated by [`BREAK`].
+///
+/// [RFC 8949, section 3]: https://www.rfc-editor.org/rfc/rfc8949#section-3
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Header {
+    Unsigned(u64),
+    /// Negative integer, the value is `-1 - n`
+    Negative(u64),
+    Bytes(Option<u64>),
+    Text(Option<u64>),
+    Array(Option<u64>),
+    Map(Option<u64>),
+    Tag(u64),
+    Simple(u8),
+    Float(f64),
+    Break,
+}
+
+/// Convert the bits of a half-precision float, `core` has no support for `f16`.
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = u32::from(bits & 0x8000) << 16;
+    let exponent = u32::from((bits >> 10) & 0x1F);
+    let mantissa = u32::from(bits & 0x3FF);
+
+    match exponent {
+        // subnormal numbers are exactly representable as normal `f32`
+        0 => {
+            let value = f32::from(bits & 0x3FF) / 16_777_216.0;
+
+            if sign == 0 { value } else { -value }
+        }
+        // infinity and NaN
+        0x1F => f32::from_bits(sign | 0x7F80_0000 | (mantissa << 13)),
+        // re-bias the exponent from 15 to 127
+        _ => f32::from_bits(sign | ((exponent + 112) << 23) | (mantissa << 13)),
+    }
+}
+
+pub(crate) struct Decoder<'de> {
+    input: &'de [u8],
+    offset: usize,
+}
+
+impl<'de> Decoder<'de> {
+    pub(crate) const fn new(input: &'de [u8]) -> Self {
+        Self { input, offset: 0 }
+    }
+
+    pub(crate) const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.input.get(self.offset).copied()
+    }
+
+    pub(crate) fn read(&mut self, length: usize) -> Result<&'de [u8], Error> {
+        let end = self
+            .offset
+            .checked_add(length)
+            .filter(|end| *end <= self.input.len())
+            .ok_or_else(|| syntax_error(SyntaxError::UnexpectedEof, self.input.len()))?;
+
+        let slice = &self.input[self.offset..end];
+        self.offset = end;
+
+        Ok(slice)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read(N)?);
+
+        Ok(array)
+    }
+
+    /// Consume the [`BREAK`] of an item of indefinite length, if present.
+    pub(crate) fn try_break(&mut self) -> bool {
+        let is_break = self.peek() == Some(BREAK);
+
+        if is_break {
+            self.offset += 1;
+        }
+
+        is_break
+    }
+
+    /// Convert the length of a header at `offset`.
+    pub(crate) fn length(length: u64, offset: usize) -> Result<usize, Error> {
+        usize::try_from(length)
+            .map_err(|error| syntax_error(SyntaxError::LengthOverflow, offset).attach(error))
+    }
+
+    pub(crate) fn header(&mut self) -> Result<Header, Error> {
+        let start = self.offset;
+
+        let [initial] = self.read_array()?;
+        let major = initial >> 5;
+        let info = initial & 0x1F;
+
+        let argument = match info {
+            0..=23 => Some(u64::from(info)),
+            24 => Some(u64::from(u8::from_be_bytes(self.read_array()?))),
+            25 => Some(u64::from(u16::from_be_bytes(self.read_array()?))),
+            26 => Some(u64::from(u32::from_be_bytes(self.read_array()?))),
+            27 => Some(u64::from_be_bytes(self.read_array()?)),
+            31 => None,
+            _ => {
+                return Err(syntax_error(
+                    SyntaxError::InvalidAdditionalInfo(info),
+                    start,
+                ));
+            }
+        };
+
+        // Reason: the size of the argument is determined by `info`, the value always fits
+        #[allow(clippy::cast_possible_truncation)]
+        let header = match (major, argument) {
+            (0, Some(value)) => Header::Unsigned(value),
+            (1, Some(value)) => Header::Negative(value),
+            (2, length) => Header::Bytes(length),
+            (3, length) => Header::Text(length),
+            (4, length) => Header::Array(length),
+            (5, length) => Header::Map(length),
+            (6, Some(tag)) => Header::Tag(tag),
+            (7, None) => Header::Break,
+            (7, Some(value)) => match info {
+                25 => Header::Float(f64::from(f16_to_f32(value as u16))),
+                26 => Header::Float(f64::from(f32::from_bits(value as u32))),
+                27 => Header::Float(f64::from_bits(value)),
+                _ => Header::Simple(value as u8),
+            },
+            _ => {
+                return Err(syntax_error(
+                    SyntaxError::InvalidAdditionalInfo(info),
+                    start,
+                ));
+            }
+        };
+
+        Ok(header)
+    }
+
+    /// Skip `items` complete data items, including every nested item.
+    ///
+    /// If `items` is `None` every item until the next [`BREAK`] is skipped.
+    pub(crate) fn skip(&mut self, items: Option<usize>) -> Result<(), Error> {
+        // explicit stack to not overflow the call stack on deeply nested input
+        let mut stack: Vec<Option<u64>> = vec![items.map(|items| items as u64)];
+
+        while let Some(remaining) = stack.last_mut() {
+            match remaining {
+                Some(0) => {
+                    stack.pop();
+                    continue;
+                }
+                Some(remaining) => *remaining -= 1,
+                None if self.try_break() => {
+                    stack.pop();
+                    continue;
+                }
+                None => {}
+            }
+
+            let start = self.offset;
+
+            match self.header()? {
+                Header::Bytes(Some(length)) | Header::Text(Some(length)) => {
+                    self.read(Self::length(length, start)?)?;
+                }
+                // chunks are skipped as individual items
+                Header::Bytes(None)
+                | Header::Text(None)
+                | Header::Array(None)
+                | Header::Map(None) => {
+                    stack.push(None);
+                }
+                Header::Array(Some(length)) => stack.push(Some(length)),
+                Header::Map(Some(length)) => stack.push(Some(length.saturating_mul(2))),
+                // tags are always followed by the tagged item
+                Header::Tag(_) => stack.push(Some(1)),
+                Header::Break => return Err(syntax_error(SyntaxError::UnexpectedBreak, start)),
+                Header::Unsigned(_)
+                | Header::Negative(_)
+                | Header::Simple(_)
+                | Header::Float(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+}
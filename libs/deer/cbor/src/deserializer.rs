@@ -0,0 +1,477 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::str;
+
+use deer::{
+    error::{
+        DeserializerError, Error, ExpectedLength, ExpectedType, ObjectLengthError, ReceivedType,
+        ReceivedValue, TypeError, ValueError, Variant,
+    },
+    schema::Document,
+    value::NoneDeserializer,
+    Context, Deserialize, EnumVisitor, IdentifierVisitor, Number, OptionalVisitor, Reflection,
+    StructVisitor, Visitor,
+};
+use error_stack::{Report, Result, ResultExt};
+use num_traits::{FromPrimitive, ToPrimitive};
+
+use crate::{
+    array::ArrayAccess,
+    decode::{syntax_error, Decoder, Header},
+    error::SyntaxError,
+    object::ObjectAccess,
+    token::ValueToken,
+};
+
+/// Initial byte of the simple value `null`.
+const NULL: u8 = 0xF6;
+/// Initial byte of the simple value `undefined`.
+const UNDEFINED: u8 = 0xF7;
+/// Major type of maps, stored in the upper three bits of the initial byte.
+const MAJOR_MAP: u8 = 5;
+
+pub struct Deserializer<'a, 'de> {
+    pub(crate) decoder: Decoder<'de>,
+
+    context: &'a Context,
+}
+
+impl<'a, 'de> Deserializer<'a, 'de> {
+    #[must_use]
+    pub const fn new(input: &'de [u8], context: &'a Context) -> Self {
+        Self {
+            decoder: Decoder::new(input),
+            context,
+        }
+    }
+
+    /// Ensure that the input has been fully consumed.
+    pub(crate) fn end(&self) -> Result<(), Error> {
+        match self.decoder.peek() {
+            None => Ok(()),
+            Some(_) => Err(syntax_error(
+                SyntaxError::TrailingBytes,
+                self.decoder.offset(),
+            )),
+        }
+    }
+
+    fn eof(&self) -> Report<DeserializerError> {
+        syntax_error(SyntaxError::UnexpectedEof, self.decoder.offset())
+            .change_context(DeserializerError)
+    }
+
+    /// Concatenate the chunks of a string of indefinite length.
+    fn read_chunks(&mut self, text: bool) -> Result<Vec<u8>, Error> {
+        let mut buffer = Vec::new();
+
+        while !self.decoder.try_break() {
+            let start = self.decoder.offset();
+
+            // every chunk must be a string of definite length of the same type
+            let length = match self.decoder.header()? {
+                Header::Bytes(Some(length)) if !text => length,
+                Header::Text(Some(length)) if text => length,
+                _ => return Err(syntax_error(SyntaxError::InvalidChunk, start)),
+            };
+
+            let chunk = self.decoder.read(Decoder::length(length, start)?)?;
+
+            // chunks are not allowed to split a character
+            if text && str::from_utf8(chunk).is_err() {
+                return Err(syntax_error(SyntaxError::InvalidUtf8Sequence, start));
+            }
+
+            buffer.extend_from_slice(chunk);
+        }
+
+        Ok(buffer)
+    }
+
+    fn read_value(&mut self) -> Result<ValueToken<'de>, Error> {
+        loop {
+            let start = self.decoder.offset();
+
+            let token = match self.decoder.header()? {
+                // tags only carry additional semantic information, which is not used by deer
+                Header::Tag(_) => continue,
+                Header::Unsigned(value) => ValueToken::Number(Number::from(value)),
+                Header::Negative(value) => {
+                    let value = -1 - i128::from(value);
+
+                    Number::from_i128(value).map_or(ValueToken::Integer(value), ValueToken::Number)
+                }
+                Header::Bytes(Some(length)) => ValueToken::Bytes(Cow::Borrowed(
+                    self.decoder.read(Decoder::length(length, start)?)?,
+                )),
+                Header::Bytes(None) => ValueToken::Bytes(Cow::Owned(self.read_chunks(false)?)),
+                Header::Text(Some(length)) => {
+                    let value = self.decoder.read(Decoder::length(length, start)?)?;
+                    let value = str::from_utf8(value).map_err(|error| {
+                        syntax_error(SyntaxError::InvalidUtf8Sequence, start).attach(error)
+                    })?;
+
+                    ValueToken::String(Cow::Borrowed(value))
+                }
+                Header::Text(None) => {
+                    // every chunk has been validated, the concatenation is therefore valid as well
+                    let value = String::from_utf8(self.read_chunks(true)?).map_err(|error| {
+                        syntax_error(SyntaxError::InvalidUtf8Sequence, start).attach(error)
+                    })?;
+
+                    ValueToken::String(Cow::Owned(value))
+                }
+                Header::Array(length) => ValueToken::Array(
+                    length
+                        .map(|length| Decoder::length(length, start))
+                        .transpose()?,
+                ),
+                Header::Map(length) => ValueToken::Map(
+                    length
+                        .map(|length| Decoder::length(length, start))
+                        .transpose()?,
+                ),
+                Header::Simple(20) => ValueToken::Bool(false),
+                Header::Simple(21) => ValueToken::Bool(true),
+                Header::Simple(22) => ValueToken::Null,
+                Header::Simple(23) => ValueToken::Undefined,
+                Header::Simple(value) => {
+                    return Err(syntax_error(SyntaxError::UnknownSimpleValue(value), start));
+                }
+                Header::Float(value) => ValueToken::Number(Number::from(value)),
+                Header::Break => return Err(syntax_error(SyntaxError::UnexpectedBreak, start)),
+            };
+
+            return Ok(token);
+        }
+    }
+
+    fn next_value(&mut self) -> Result<ValueToken<'de>, DeserializerError> {
+        self.read_value().change_context(DeserializerError)
+    }
+
+    /// Skip the items of a composite value, so that deserialization is able to continue.
+    pub(crate) fn recover(&mut self, token: &ValueToken<'de>) {
+        // errors while skipping are reported once the next value is read
+        let _ = match token {
+            ValueToken::Array(length) => self.decoder.skip(*length),
+            ValueToken::Map(length) => self
+                .decoder
+                .skip(length.map(|length| length.saturating_mul(2))),
+            _ => Ok(()),
+        };
+    }
+
+    fn error_invalid_type(
+        &mut self,
+        received: &ValueToken<'de>,
+        expected: Document,
+    ) -> Report<DeserializerError> {
+        self.recover(received);
+
+        Report::new(TypeError.into_error())
+            .attach(ExpectedType::new(expected))
+            .attach(ReceivedType::new(received.schema()))
+            .change_context(DeserializerError)
+    }
+}
+
+impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Null => visitor.visit_null(),
+            ValueToken::Undefined => visitor.visit_none(),
+            ValueToken::Bool(value) => visitor.visit_bool(value),
+            ValueToken::Number(value) => visitor.visit_number(value),
+            ValueToken::Integer(value) => visitor.visit_i128(value),
+            ValueToken::Bytes(Cow::Borrowed(value)) => visitor.visit_borrowed_bytes(value),
+            ValueToken::Bytes(Cow::Owned(value)) => visitor.visit_bytes_buffer(value),
+            ValueToken::String(Cow::Borrowed(value)) => visitor.visit_borrowed_str(value),
+            ValueToken::String(Cow::Owned(value)) => visitor.visit_string(value),
+            ValueToken::Array(length) => visitor.visit_array(ArrayAccess::new(self, length)),
+            ValueToken::Map(length) => visitor.visit_object(ObjectAccess::new(self, length)),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_null<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Null => visitor.visit_null().change_context(DeserializerError),
+            ValueToken::Undefined => visitor.visit_none().change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, <()>::reflection())),
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Bool(value) => visitor.visit_bool(value).change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, bool::reflection())),
+        }
+    }
+
+    fn deserialize_number<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Number(value) => visitor
+                .visit_number(value)
+                .change_context(DeserializerError),
+            ValueToken::Integer(value) => {
+                visitor.visit_i128(value).change_context(DeserializerError)
+            }
+            token => Err(self.error_invalid_type(&token, Number::reflection())),
+        }
+    }
+
+    // `Number` is unable to represent `-2^64`, which is the smallest integer CBOR supports
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        let value = match token {
+            ValueToken::Integer(value) => value,
+            ValueToken::Number(value) => value.to_i128().ok_or_else(|| {
+                Report::new(ValueError.into_error())
+                    .attach(ExpectedType::new(i128::reflection()))
+                    .attach(ReceivedValue::new(value))
+                    .change_context(DeserializerError)
+            })?,
+            token => return Err(self.error_invalid_type(&token, i128::reflection())),
+        };
+
+        visitor.visit_i128(value).change_context(DeserializerError)
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::String(Cow::Borrowed(value)) => visitor.visit_borrowed_str(value),
+            ValueToken::String(Cow::Owned(value)) => visitor.visit_string(value),
+            token => return Err(self.error_invalid_type(&token, str::document())),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Bytes(Cow::Borrowed(value)) => visitor.visit_borrowed_bytes(value),
+            ValueToken::Bytes(Cow::Owned(value)) => visitor.visit_bytes_buffer(value),
+            token => return Err(self.error_invalid_type(&token, <&[u8]>::reflection())),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_bytes_buffer<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_array<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Array(length) => visitor
+                .visit_array(ArrayAccess::new(self, length))
+                .change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, ValueToken::Array(None).schema())),
+        }
+    }
+
+    fn deserialize_object<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Map(length) => visitor
+                .visit_object(ObjectAccess::new(self, length))
+                .change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, ValueToken::Map(None).schema())),
+        }
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        match self.decoder.peek() {
+            None => Err(self.eof()),
+            Some(NULL) => {
+                // we know the value will be `null`, therefore we can just discard the byte
+                let _ = self.decoder.read(1);
+
+                visitor.visit_null().change_context(DeserializerError)
+            }
+            Some(UNDEFINED) => {
+                let _ = self.decoder.read(1);
+
+                visitor.visit_none().change_context(DeserializerError)
+            }
+            Some(_) => visitor.visit_some(self).change_context(DeserializerError),
+        }
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: EnumVisitor<'de>,
+    {
+        // either a single string (unit variant) or a map with a single entry
+        let length = match self.decoder.peek() {
+            None => return Err(self.eof()),
+            Some(byte) if byte >> 5 == MAJOR_MAP => match self.next_value()? {
+                ValueToken::Map(Some(0)) => {
+                    return Err(Report::new(ObjectLengthError.into_error())
+                        .attach(ExpectedLength::new(1))
+                        .change_context(DeserializerError));
+                }
+                ValueToken::Map(length) => Some(length),
+                // the major type is always a map
+                token => return Err(self.error_invalid_type(&token, str::document())),
+            },
+            Some(_) => None,
+        };
+
+        let is_map = length.is_some();
+
+        let result = visitor
+            .visit_discriminant(&mut *self)
+            .change_context(DeserializerError);
+
+        if is_map && result.is_err() {
+            // the key is an error, we need to swallow the value
+            let _ = self.decoder.skip(Some(1));
+        }
+
+        let discriminant = result?;
+
+        let mut value = if is_map {
+            visitor
+                .visit_value(discriminant, &mut *self)
+                .change_context(DeserializerError)
+        } else {
+            visitor
+                .visit_value(discriminant, NoneDeserializer::new(self.context))
+                .change_context(DeserializerError)
+        };
+
+        let is_end = match length {
+            None | Some(Some(1)) => true,
+            Some(None) if self.decoder.try_break() => true,
+            Some(None) => {
+                let _ = self.decoder.skip(None);
+                false
+            }
+            Some(Some(length)) => {
+                let _ = self.decoder.skip(Some((length - 1).saturating_mul(2)));
+                false
+            }
+        };
+
+        if !is_end {
+            // we have received multiple entries, error out
+            let error = Report::new(ObjectLengthError.into_error())
+                .attach(ExpectedLength::new(1))
+                .change_context(DeserializerError);
+
+            match &mut value {
+                Err(value) => value.extend_one(error),
+                value => *value = Err(error),
+            }
+        }
+
+        value
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: StructVisitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Map(length) => visitor
+                .visit_object(ObjectAccess::new(self, length))
+                .change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, ValueToken::Map(None).schema())),
+        }
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        // CBOR commonly uses integers as keys to reduce the size of the payload
+        match token {
+            ValueToken::String(value) => visitor.visit_str(&value),
+            ValueToken::Number(value) => match value.to_u64() {
+                Some(value) => visitor.visit_u64(value),
+                None => {
+                    return Err(
+                        self.error_invalid_type(&ValueToken::Number(value), str::document())
+                    );
+                }
+            },
+            ValueToken::Bytes(value) => visitor.visit_bytes(&value),
+            token => return Err(self.error_invalid_type(&token, str::document())),
+        }
+        .change_context(DeserializerError)
+    }
+}
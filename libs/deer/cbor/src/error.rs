@@ -0,0 +1,82 @@
+use core::fmt::{Display, Formatter};
+
+use deer::{
+    error::{ErrorProperties, ErrorProperty, Id, Location, Namespace, Variant},
+    id,
+};
+
+const NAMESPACE: Namespace = Namespace::new("deer-cbor");
+
+pub(crate) struct Position {
+    offset: usize,
+}
+
+impl Position {
+    pub(crate) const fn new(offset: usize) -> Self {
+        Self { offset }
+    }
+}
+
+impl ErrorProperty for Position {
+    type Value<'a> = Option<usize> where Self: 'a ;
+
+    fn key() -> &'static str {
+        "position"
+    }
+
+    fn value<'a>(mut stack: impl Iterator<Item = &'a Self>) -> Self::Value<'a> {
+        stack.next().map(|Self { offset }| *offset)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum SyntaxError {
+    UnexpectedEof,
+    InvalidAdditionalInfo(u8),
+    UnexpectedBreak,
+    InvalidChunk,
+    InvalidUtf8Sequence,
+    UnknownSimpleValue(u8),
+    LengthOverflow,
+    TrailingBytes,
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnexpectedEof => f.write_str("unexpected end of input"),
+            Self::InvalidAdditionalInfo(info) => {
+                f.write_fmt(format_args!("invalid additional information (`{info}`)"))
+            }
+            Self::UnexpectedBreak => f.write_str("unexpected break outside of indefinite item"),
+            Self::InvalidChunk => f.write_str(
+                "chunks of indefinite strings must be definite strings of the same type",
+            ),
+            Self::InvalidUtf8Sequence => f.write_str("invalid utf-8 sequence"),
+            Self::UnknownSimpleValue(value) => {
+                f.write_fmt(format_args!("unknown simple value (`{value}`)"))
+            }
+            Self::LengthOverflow => f.write_str("length exceeds the address space"),
+            Self::TrailingBytes => f.write_str("trailing bytes after value"),
+        }
+    }
+}
+
+impl Variant for SyntaxError {
+    type Properties = (Location, Position);
+
+    const ID: Id = id!["syntax"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        if let Some(position) = properties.1 {
+            fmt.write_fmt(format_args!("{self} at {position}"))
+        } else {
+            Display::fmt(&self, fmt)
+        }
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(nightly, feature(provide_any, error_in_core))]
+// TODO: once more stable introduce: warning missing_docs, clippy::missing_errors_doc
+#![deny(unsafe_code)]
+mod array;
+mod decode;
+mod deserializer;
+mod error;
+mod object;
+mod token;
+
+extern crate alloc;
+
+use deer::{error::DeserializeError, Context, Deserialize};
+use error_stack::{Result, ResultExt};
+
+pub use crate::deserializer::Deserializer;
+
+/// Deserialize a single [CBOR] data item, the input must not contain any trailing bytes.
+///
+/// Byte strings of definite length are borrowed from the input, which means that types like
+/// `&[u8]` can be deserialized without any allocation.
+///
+/// [CBOR]: https://www.rfc-editor.org/rfc/rfc8949
+pub fn from_slice<'de, T>(slice: &'de [u8], context: &Context) -> Result<T, DeserializeError>
+where
+    T: Deserialize<'de>,
+{
+    let mut deserializer = Deserializer::new(slice, context);
+
+    let value = T::deserialize(&mut deserializer)?;
+
+    deserializer.end().change_context(DeserializeError)?;
+
+    Ok(value)
+}
@@ -0,0 +1,112 @@
+use deer::{
+    error::{ObjectAccessError, ObjectLengthError, Variant},
+    Context, Deserializer as _, FieldVisitor,
+};
+use error_stack::{Report, Result, ResultExt};
+
+use crate::{
+    decode::BREAK,
+    deserializer::Deserializer,
+    error::{Position, SyntaxError},
+};
+
+pub(crate) struct ObjectAccess<'a, 'b, 'de: 'a> {
+    deserializer: &'a mut Deserializer<'b, 'de>,
+
+    dirty: bool,
+    expected: usize,
+
+    length: Option<usize>,
+    /// Number of entries that still need to be read, `None` for maps of indefinite length.
+    remaining: Option<usize>,
+}
+
+impl<'a, 'b, 'de: 'a> ObjectAccess<'a, 'b, 'de> {
+    pub(crate) fn new(deserializer: &'a mut Deserializer<'b, 'de>, length: Option<usize>) -> Self {
+        Self {
+            deserializer,
+            dirty: false,
+            expected: 0,
+            length,
+            remaining: length,
+        }
+    }
+}
+
+impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.deserializer.context()
+    }
+
+    fn try_field<F>(
+        &mut self,
+        visitor: F,
+    ) -> core::result::Result<Result<F::Value, ObjectAccessError>, F>
+    where
+        F: FieldVisitor<'de>,
+    {
+        self.dirty = true;
+
+        match &mut self.remaining {
+            Some(0) => return Err(visitor),
+            Some(remaining) => *remaining -= 1,
+            // we check for `is_none` here because we could be EOF, in that case we still error out
+            // with the visitor as we're "done".
+            None => match self.deserializer.decoder.peek() {
+                None | Some(BREAK) => return Err(visitor),
+                Some(_) => {}
+            },
+        }
+
+        self.expected += 1;
+
+        // in contrast to JSON, keys can be of any type, it is up to the visitor to decide which
+        // keys are valid.
+        let key = visitor.visit_key(&mut *self.deserializer);
+
+        let result = match key {
+            Ok(key) => visitor
+                .visit_value(key, &mut *self.deserializer)
+                .change_context(ObjectAccessError),
+            Err(error) => {
+                // skip value, errors while skipping are reported once the next value is read
+                let _ = self.deserializer.decoder.skip(Some(1));
+
+                Err(error.change_context(ObjectAccessError))
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.length
+    }
+
+    fn end(self) -> Result<(), ObjectAccessError> {
+        let decoder = &self.deserializer.decoder;
+
+        let result = match self.remaining {
+            Some(0) => Ok(()),
+            None if decoder.peek() == Some(BREAK) => Ok(()),
+            None if decoder.peek().is_none() => {
+                Err(Report::new(SyntaxError::UnexpectedEof.into_error())
+                    .attach(Position::new(decoder.offset())))
+            }
+            _ => Err(ObjectLengthError::new(&self, self.expected)),
+        };
+
+        // bump until the very end, which ensures that deserialize calls after this might succeed!
+        // errors while skipping are reported once the next value is read
+        let _ = self
+            .deserializer
+            .decoder
+            .skip(self.remaining.map(|remaining| remaining.saturating_mul(2)));
+
+        result.change_context(ObjectAccessError)
+    }
+}
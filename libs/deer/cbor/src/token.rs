@@ -0,0 +1,52 @@
+use alloc::borrow::Cow;
+
+use deer::{Deserialize, Document, Number, Reflection, Schema};
+
+/// A single value, composite values only contain the length, their items still need to be read.
+///
+/// Strings of definite length are borrowed from the input, strings of indefinite length consist
+/// of multiple chunks and are therefore owned.
+pub(crate) enum ValueToken<'de> {
+    Null,
+    Undefined,
+    Bool(bool),
+    Number(Number),
+    /// Integers which cannot be represented by [`Number`]
+    Integer(i128),
+    Bytes(Cow<'de, [u8]>),
+    String(Cow<'de, str>),
+    /// Array of the given length, `None` for indefinite length
+    Array(Option<usize>),
+    /// Map with the given number of entries, `None` for indefinite length
+    Map(Option<usize>),
+}
+
+struct AnyObject;
+
+impl Reflection for AnyObject {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("object")
+    }
+}
+
+struct AnyArray;
+
+impl Reflection for AnyArray {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("array")
+    }
+}
+
+impl ValueToken<'_> {
+    pub(crate) fn schema(&self) -> Document {
+        match self {
+            Self::Null | Self::Undefined => <() as Deserialize>::reflection(),
+            Self::Bool(_) => bool::reflection(),
+            Self::Number(_) | Self::Integer(_) => Number::reflection(),
+            Self::Bytes(_) => <&[u8]>::reflection(),
+            Self::String(_) => str::document(),
+            Self::Map(_) => AnyObject::document(),
+            Self::Array(_) => AnyArray::document(),
+        }
+    }
+}
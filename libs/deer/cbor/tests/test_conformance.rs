@@ -0,0 +1,100 @@
+use deer::{error::DeserializeError, Context, Deserialize};
+use deer_cbor::from_slice;
+use deer_desert::{
+    conformance::{assert_conformance, Format},
+    Token,
+};
+use error_stack::Result;
+use num_traits::ToPrimitive;
+
+fn header(output: &mut Vec<u8>, major: u8, argument: u64) {
+    let major = major << 5;
+
+    if let Ok(argument) = u8::try_from(argument) {
+        if argument < 24 {
+            output.push(major | argument);
+        } else {
+            output.extend([major | 24, argument]);
+        }
+    } else if let Ok(argument) = u16::try_from(argument) {
+        output.push(major | 25);
+        output.extend(argument.to_be_bytes());
+    } else if let Ok(argument) = u32::try_from(argument) {
+        output.push(major | 26);
+        output.extend(argument.to_be_bytes());
+    } else {
+        output.push(major | 27);
+        output.extend(argument.to_be_bytes());
+    }
+}
+
+fn length(output: &mut Vec<u8>, major: u8, length: Option<usize>, indefinite: bool) {
+    match length {
+        Some(length) if !indefinite => header(output, major, length as u64),
+        _ => output.push((major << 5) | 31),
+    }
+}
+
+/// Encode the tokens, if `INDEFINITE` is set every array and map is of indefinite length.
+struct Cbor<const INDEFINITE: bool>;
+
+impl<const INDEFINITE: bool> Format for Cbor<INDEFINITE> {
+    fn encode(tokens: &[Token]) -> Option<Vec<u8>> {
+        let mut output = Vec::new();
+
+        for token in tokens {
+            match token {
+                Token::Bool(false) => output.push(0xF4),
+                Token::Bool(true) => output.push(0xF5),
+                Token::Null => output.push(0xF6),
+                Token::Number(number) => {
+                    if let Some(value) = number.to_u64() {
+                        header(&mut output, 0, value);
+                    } else if let Some(value) = number.to_i64() {
+                        header(&mut output, 1, u64::try_from(-1 - value).ok()?);
+                    } else {
+                        output.push(0xFB);
+                        output.extend(number.to_f64()?.to_be_bytes());
+                    }
+                }
+                Token::U128(value) => header(&mut output, 0, u64::try_from(*value).ok()?),
+                Token::I128(_) => return None,
+                Token::Char(value) => {
+                    header(&mut output, 3, value.len_utf8() as u64);
+                    output.extend(value.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                Token::Str(value) | Token::BorrowedStr(value) | Token::String(value) => {
+                    header(&mut output, 3, value.len() as u64);
+                    output.extend(value.as_bytes());
+                }
+                Token::Bytes(value) | Token::BorrowedBytes(value) | Token::BytesBuf(value) => {
+                    header(&mut output, 2, value.len() as u64);
+                    output.extend(*value);
+                }
+                Token::Array { length: size } => length(&mut output, 4, *size, INDEFINITE),
+                Token::Object { length: size } => length(&mut output, 5, *size, INDEFINITE),
+                Token::ArrayEnd | Token::ObjectEnd if INDEFINITE => output.push(0xFF),
+                Token::ArrayEnd | Token::ObjectEnd => {}
+            }
+        }
+
+        Some(output)
+    }
+
+    fn decode<'de, T>(input: &'de [u8], context: &Context) -> Result<T, DeserializeError>
+    where
+        T: Deserialize<'de>,
+    {
+        from_slice(input, context)
+    }
+}
+
+#[test]
+fn conformance_definite() {
+    assert_conformance::<Cbor<false>>();
+}
+
+#[test]
+fn conformance_indefinite() {
+    assert_conformance::<Cbor<true>>();
+}
@@ -0,0 +1,127 @@
+use deer::Context;
+use deer_cbor::from_slice;
+use deer_desert::errors;
+use serde_json::json;
+
+#[test]
+fn bytes_borrowed_ok() {
+    let context = Context::new();
+    let input = [0x43, 0x01, 0x02, 0x03];
+
+    let value: &[u8] = from_slice(&input, &context).expect("should deserialize");
+
+    assert_eq!(value, [1, 2, 3]);
+    // the value must point into the input, instead of a copy
+    assert_eq!(value.as_ptr(), input[1..].as_ptr());
+}
+
+#[test]
+fn string_indefinite_ok() {
+    let context = Context::new();
+
+    // (_ "ab", "c")
+    let value: String = from_slice(&[0x7F, 0x62, b'a', b'b', 0x61, b'c', 0xFF], &context)
+        .expect("should deserialize");
+
+    assert_eq!(value, "abc");
+}
+
+#[test]
+fn float_half_precision_ok() {
+    let context = Context::new();
+
+    let value: f64 = from_slice(&[0xF9, 0x3E, 0x00], &context).expect("should deserialize");
+    assert!((value - 1.5).abs() < f64::EPSILON);
+
+    let value: f64 = from_slice(&[0xF9, 0x00, 0x01], &context).expect("should deserialize");
+    assert!((value - 5.960_464_477_539_063e-8).abs() < f64::EPSILON);
+
+    let value: f64 = from_slice(&[0xF9, 0xFC, 0x00], &context).expect("should deserialize");
+    assert!(value.is_infinite() && value.is_sign_negative());
+}
+
+#[test]
+fn tag_ignored_ok() {
+    let context = Context::new();
+
+    // epoch-based date/time (tag 1)
+    let value: u32 =
+        from_slice(&[0xC1, 0x1A, 0x51, 0x4B, 0x67, 0xB0], &context).expect("should deserialize");
+
+    assert_eq!(value, 1_363_896_240);
+}
+
+#[test]
+fn negative_integer_ok() {
+    let context = Context::new();
+
+    let value: i64 = from_slice(
+        &[0x3B, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        &context,
+    )
+    .expect("should deserialize");
+    assert_eq!(value, i64::MIN);
+
+    let value: i128 = from_slice(
+        &[0x3B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        &context,
+    )
+    .expect("should deserialize");
+    assert_eq!(value, -1 - i128::from(u64::MAX));
+}
+
+#[test]
+fn unexpected_eof_err() {
+    let context = Context::new();
+
+    let report = from_slice::<Vec<u8>>(&[0x82, 0x01], &context).expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer-cbor",
+            "id": ["syntax"],
+            "properties": {
+                "location": [{"type": "array", "value": 1}],
+                "position": 2
+            }
+        }])
+    );
+}
+
+#[test]
+fn trailing_bytes_err() {
+    let context = Context::new();
+
+    let report = from_slice::<u8>(&[0x01, 0x02], &context).expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer-cbor",
+            "id": ["syntax"],
+            "properties": {
+                "location": [],
+                "position": 1
+            }
+        }])
+    );
+}
+
+#[test]
+fn recover_after_type_err() {
+    let context = Context::new();
+
+    // [[1, 2], 3], the nested array is skipped, so that `3` is still deserialized
+    let report = from_slice::<Vec<u8>>(&[0x82, 0x82, 0x01, 0x02, 0x18, 0xFF], &context)
+        .expect_err("should fail");
+
+    let errors = errors(report);
+
+    assert_eq!(errors.as_array().expect("should be an array").len(), 1);
+    assert_eq!(errors[0]["id"], json!(["type"]));
+    assert_eq!(
+        errors[0]["properties"]["location"],
+        json!([{"type": "array", "value": 0}])
+    );
+}
@@ -5,7 +5,7 @@ use deer::{
     Context, Deserialize,
 };
 use error_stack::Report;
-use serde_json::to_value;
+use serde_json::{to_value, Value};
 #[cfg(feature = "pretty")]
 use similar_asserts::{assert_eq, assert_serde_eq};
 
@@ -95,3 +95,22 @@ where
 
     T::deserialize(&mut de).expect_err("value of type T should fail serialization")
 }
+
+/// Output of the report without any messages, as those are not stable.
+///
+/// # Panics
+///
+/// if error could not be serialized
+#[must_use]
+pub fn errors<C: error_stack::Context>(report: Report<C>) -> Value {
+    let mut errors = to_value(report.export()).expect("error should serialize");
+
+    for error in errors.as_array_mut().expect("should be an array of errors") {
+        error
+            .as_object_mut()
+            .expect("error should be an object")
+            .remove("message");
+    }
+
+    errors
+}
@@ -0,0 +1,240 @@
+//! Conformance suite shared by every `deer` backend.
+//!
+//! Every case is described by a sequence of [`Token`]s, which are deserialized using the
+//! reference [`Deserializer`] of this crate. The same tokens are then encoded using the
+//! [`Format`] and deserialized again, both results must be identical: values must be equal and
+//! errors must have the same namespace, id and location, as the remaining properties (and message)
+//! are allowed to differ between formats.
+//!
+//! ```ignore
+//! use deer_desert::conformance::{assert_conformance, Format};
+//!
+//! struct Json;
+//!
+//! impl Format for Json {
+//!     // ...
+//! }
+//!
+//! #[test]
+//! fn conformance() {
+//!     assert_conformance::<Json>();
+//! }
+//! ```
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::Debug;
+
+use deer::{
+    error::{DeserializeError, ReportExt},
+    Context, Deserialize, Number,
+};
+use error_stack::{Report, Result};
+use serde_json::{to_value, Value};
+#[cfg(feature = "pretty")]
+use similar_asserts::assert_eq;
+
+use crate::{deserializer::Deserializer, token::Token};
+
+/// A data format which is tested against the conformance suite.
+pub trait Format {
+    /// Encode the tokens of a single value.
+    ///
+    /// Returns [`None`] if the format is unable to represent the value, in that case the case is
+    /// skipped.
+    fn encode(tokens: &[Token]) -> Option<Vec<u8>>;
+
+    /// Deserialize a single value from the encoded input.
+    ///
+    /// # Errors
+    ///
+    /// The value could not be deserialized
+    fn decode<'de, T>(input: &'de [u8], context: &Context) -> Result<T, DeserializeError>
+    where
+        T: Deserialize<'de>;
+}
+
+/// Namespace, id and location of every error in the report, the only properties every format
+/// must agree upon.
+fn errors(report: Report<DeserializeError>) -> Vec<Value> {
+    let errors = to_value(report.export()).expect("error should serialize");
+
+    errors
+        .as_array()
+        .expect("should be an array of errors")
+        .iter()
+        .map(|error| {
+            let mut error = error
+                .as_object()
+                .expect("error should be an object")
+                .clone();
+
+            error.remove("message");
+
+            if let Some(Value::Object(properties)) = error.get_mut("properties") {
+                properties.retain(|key, _| key == "location");
+            }
+
+            Value::Object(error)
+        })
+        .collect()
+}
+
+fn check<'de, F, T>(name: &str, tokens: &'de [Token], input: &'de [u8])
+where
+    F: Format,
+    T: Deserialize<'de> + PartialEq + Debug,
+{
+    let context = Context::new();
+
+    let expected = T::deserialize(&mut Deserializer::new(tokens, &context));
+    let received = F::decode::<T>(input, &context);
+
+    match (expected, received) {
+        (Ok(expected), Ok(received)) => assert_eq!(received, expected, "case `{name}`"),
+        (Err(expected), Err(received)) => {
+            assert_eq!(errors(received), errors(expected), "case `{name}`");
+        }
+        (Ok(expected), Err(received)) => {
+            panic!("case `{name}`: expected {expected:?}, but received error: {received:?}")
+        }
+        (Err(expected), Ok(received)) => {
+            panic!("case `{name}`: expected error: {expected:?}, but received {received:?}")
+        }
+    }
+}
+
+macro_rules! cases {
+    ($format:ty; $($name:literal: $type:ty => [$($token:expr),* $(,)?];)*) => {
+        $({
+            let tokens = [$($token),*];
+
+            if let Some(input) = <$format>::encode(&tokens) {
+                check::<$format, $type>($name, &tokens, &input);
+            }
+        })*
+    };
+}
+
+/// Cases which only consist of a single primitive value.
+fn primitives<F: Format>() {
+    cases![F;
+        "bool": bool => [Token::Bool(true)];
+        "integer": u8 => [Token::Number(Number::from(12))];
+        "negative integer": i64 => [Token::Number(Number::from(-12))];
+        "float": f64 => [Token::Number(Number::from(1.5))];
+        "char": char => [Token::Char('a')];
+        "string": String => [Token::Str("deer")];
+        "borrowed bytes": &[u8] => [Token::BorrowedBytes(&[0x00, 0xFF])];
+        "unit": () => [Token::Null];
+        "option none": Option<u8> => [Token::Null];
+        "option some": Option<u8> => [Token::Number(Number::from(1))];
+    ];
+}
+
+/// Cases which consist of arrays and objects.
+fn composites<F: Format>() {
+    cases![F;
+        "array": Vec<u16> => [
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(2)),
+            Token::Number(Number::from(3)),
+            Token::ArrayEnd,
+        ];
+        "empty array": Vec<u16> => [Token::Array { length: Some(0) }, Token::ArrayEnd];
+        "nested array": Vec<Vec<bool>> => [
+            Token::Array { length: Some(2) },
+            Token::Array { length: Some(1) },
+            Token::Bool(true),
+            Token::ArrayEnd,
+            Token::Array { length: Some(0) },
+            Token::ArrayEnd,
+            Token::ArrayEnd,
+        ];
+        "tuple": (u8, bool, String) => [
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(1)),
+            Token::Bool(false),
+            Token::Str("a"),
+            Token::ArrayEnd,
+        ];
+        "fixed array": [u8; 2] => [
+            Token::Array { length: Some(2) },
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(2)),
+            Token::ArrayEnd,
+        ];
+        "object": BTreeMap<String, u8> => [
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::Str("b"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+        ];
+        "object of arrays": BTreeMap<String, Vec<u8>> => [
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Array { length: Some(2) },
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(2)),
+            Token::ArrayEnd,
+            Token::Str("b"),
+            Token::Array { length: Some(1) },
+            Token::Number(Number::from(3)),
+            Token::ArrayEnd,
+            Token::ObjectEnd,
+        ];
+        "object of options": BTreeMap<String, Option<bool>> => [
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Bool(true),
+            Token::Str("b"),
+            Token::Null,
+            Token::ObjectEnd,
+        ];
+    ];
+}
+
+/// Cases which fail, every error must be reported.
+fn failures<F: Format>() {
+    cases![F;
+        "integer out of range": u8 => [Token::Number(Number::from(256))];
+        "string as integer": u8 => [Token::Str("a")];
+        "integer as bool": bool => [Token::Number(Number::from(1))];
+        "bytes as array": Vec<u8> => [Token::BorrowedBytes(&[0x00])];
+        "array items": Vec<u8> => [
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(1)),
+            Token::Str("a"),
+            Token::Number(Number::from(300)),
+            Token::ArrayEnd,
+        ];
+        "tuple too long": (u8, u8) => [
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(2)),
+            Token::Number(Number::from(3)),
+            Token::ArrayEnd,
+        ];
+        "object values": BTreeMap<String, u8> => [
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Str("x"),
+            Token::Str("b"),
+            Token::Number(Number::from(256)),
+            Token::ObjectEnd,
+        ];
+    ];
+}
+
+/// Run every case of the conformance suite against the format.
+///
+/// # Panics
+///
+/// if the format deserializes any case differently than the reference deserializer
+pub fn assert_conformance<F: Format>() {
+    primitives::<F>();
+    composites::<F>();
+    failures::<F>();
+}
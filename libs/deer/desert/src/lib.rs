@@ -4,6 +4,7 @@ extern crate alloc;
 
 pub(crate) mod array;
 mod assert;
+pub mod conformance;
 mod deserializer;
 pub mod error;
 pub(crate) mod object;
@@ -14,5 +15,6 @@ mod token;
 pub use assert::{
     assert_tokens, assert_tokens_any_error, assert_tokens_deserialize, assert_tokens_error,
     assert_tokens_with_assertion, assert_tokens_with_context, assert_tokens_with_context_error,
+    errors,
 };
 pub use token::Token;
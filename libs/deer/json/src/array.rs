@@ -3,12 +3,13 @@ use deer::{
     Context, Deserialize, Deserializer as _,
 };
 use error_stack::{Report, Result, ResultExt};
-use justjson::parser::{PeekableTokenKind, Token};
+use justjson::parser::PeekableTokenKind;
 
 use crate::{
     deserializer::Deserializer,
     error::{ErrorAccumulator, Position, SyntaxError},
     skip::skip_tokens,
+    token::Token,
 };
 
 pub(crate) struct ArrayAccess<'a, 'b, 'de: 'a> {
@@ -95,7 +96,7 @@ impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, '_, 'de> {
             Some(_) => Err(ArrayLengthError::new(&self, self.expected)),
         };
 
-        skip_tokens(&mut self.deserializer.tokenizer, &Token::Array);
+        skip_tokens(&mut self.deserializer.input, &Token::Array);
 
         result.change_context(ArrayAccessError)
     }
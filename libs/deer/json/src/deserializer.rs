@@ -11,21 +11,18 @@ use deer::{
     StructVisitor, Visitor,
 };
 use error_stack::{Report, Result, ResultExt};
-use justjson::{
-    parser::{PeekableTokenKind, Token, Tokenizer},
-    AnyStr,
-};
+use justjson::{parser::PeekableTokenKind, AnyStr};
 
+#[cfg(feature = "std")]
+use crate::input::ReaderInput;
 use crate::{
     array::ArrayAccess,
-    error::{
-        convert_tokenizer_error, BytesUnsupportedError, ErrorAccumulator, Position,
-        RecursionLimitError, SyntaxError,
-    },
+    error::{BytesUnsupportedError, ErrorAccumulator, Position, RecursionLimitError, SyntaxError},
+    input::{Input, SliceInput},
     number::try_convert_number,
     object::ObjectAccess,
     skip::skip_tokens,
-    token::ValueToken,
+    token::{Token, ValueToken},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -69,48 +66,65 @@ impl Stack {
 }
 
 pub struct Deserializer<'a, 'de> {
-    pub(crate) tokenizer: Tokenizer<'de, false>,
+    pub(crate) input: Input<'de>,
 
     context: &'a Context,
     pub(crate) stack: Stack,
 }
 
 impl<'a, 'de> Deserializer<'a, 'de> {
+    #[must_use]
     pub fn new(slice: &'de [u8], context: &'a Context) -> Self {
+        Self::with_offset(slice, context, 0)
+    }
+
+    pub(crate) fn with_offset(slice: &'de [u8], context: &'a Context, base: usize) -> Self {
+        Self::with_input(Input::Slice(SliceInput::new(slice, base)), context)
+    }
+
+    /// Deserialize from a reader, which is read incrementally, only the current token is kept in
+    /// memory.
+    #[cfg(feature = "std")]
+    pub(crate) fn from_reader(reader: &'de mut dyn std::io::Read, context: &'a Context) -> Self {
+        Self::with_input(Input::Reader(ReaderInput::new(reader)), context)
+    }
+
+    fn with_input(input: Input<'de>, context: &'a Context) -> Self {
         let limit = context
             .request_ref::<StackLimit>()
             .map_or(usize::MAX, StackLimit::limit);
 
         Self {
-            tokenizer: Tokenizer::for_json_bytes(slice),
+            input,
             context,
             stack: Stack::new(limit),
         }
     }
 
     fn next(&mut self) -> Result<Token<'de>, DeserializerError> {
-        let offset = self.tokenizer.offset();
-        let Some(token) = self.tokenizer.next() else {
+        let offset = self.offset();
+        let Some(token) = self.input.next() else {
             return Err(Report::new(SyntaxError::UnexpectedEof.into_error())
                 .attach(Position::new(offset))
                 .change_context(DeserializerError));
         };
 
-        token
-            .map_err(|error| convert_tokenizer_error(&error))
-            .change_context(DeserializerError)
+        token.change_context(DeserializerError)
     }
 
     fn next_value(&mut self) -> Result<ValueToken<'de>, DeserializerError> {
         let token = self.next()?;
 
-        ValueToken::try_from(token).change_context(DeserializerError)
+        ValueToken::try_from(token)
+            // only single byte tokens are invalid values, therefore the token is the previous byte
+            .map_err(|error| error.attach(Position::new(self.offset().saturating_sub(1))))
+            .change_context(DeserializerError)
     }
 
     pub(crate) fn recover(&mut self, token: &ValueToken<'de>) {
         match token {
-            ValueToken::Object => skip_tokens(&mut self.tokenizer, &Token::Object),
-            ValueToken::Array => skip_tokens(&mut self.tokenizer, &Token::Array),
+            ValueToken::Object => skip_tokens(&mut self.input, &Token::Object),
+            ValueToken::Array => skip_tokens(&mut self.input, &Token::Array),
             _ => {}
         }
     }
@@ -118,14 +132,14 @@ impl<'a, 'de> Deserializer<'a, 'de> {
     pub(crate) fn skip(&mut self) -> Range<usize> {
         // `.next()` will only error out if a string or number is malformed
         // we can safely skip those as they do not affect how we skip
-        let start = self.tokenizer.offset();
-        let next = self.tokenizer.next();
+        let start = self.offset();
+        let next = self.input.next();
 
         if let Some(Ok(token)) = next {
-            skip_tokens(&mut self.tokenizer, &token);
+            skip_tokens(&mut self.input, &token);
         }
 
-        start..self.tokenizer.offset()
+        start..self.offset()
     }
 
     pub(crate) fn skip_if(&mut self, token: PeekableTokenKind) -> Option<Range<usize>> {
@@ -146,12 +160,21 @@ impl<'a, 'de> Deserializer<'a, 'de> {
         }
     }
 
+    /// Ensure that the input has been fully consumed, only whitespace may follow the value.
+    pub(crate) fn end(&mut self) -> Result<(), Error> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(Report::new(SyntaxError::TrailingCharacters.into_error())
+                .attach(Position::new(self.offset()))),
+        }
+    }
+
     pub(crate) fn peek(&mut self) -> Option<PeekableTokenKind> {
-        self.tokenizer.peek()
+        self.input.peek()
     }
 
     pub(crate) const fn offset(&self) -> usize {
-        self.tokenizer.offset()
+        self.input.offset()
     }
 
     pub(crate) fn try_stack_push(&mut self, token: &Token) -> Result<(), DeserializerError> {
@@ -159,7 +182,7 @@ impl<'a, 'de> Deserializer<'a, 'de> {
             // we can still recover, we pop us again from the stack as we stopped before and do not
             // commit. We still show the error, but we could continue, so we skip all tokens.
             self.stack.pop();
-            skip_tokens(&mut self.tokenizer, token);
+            skip_tokens(&mut self.input, token);
 
             return Err(error);
         }
@@ -195,7 +218,7 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
         match token {
             ValueToken::Null => visitor.visit_null(),
             ValueToken::Bool(value) => visitor.visit_bool(value),
-            ValueToken::String(value) => match value.decode_if_needed() {
+            ValueToken::String(value) => match value {
                 AnyStr::Owned(value) => visitor.visit_string(value),
                 AnyStr::Borrowed(value) => visitor.visit_borrowed_str(value),
             },
@@ -269,7 +292,7 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
         let token = self.next_value()?;
 
         match token {
-            ValueToken::String(value) => match value.decode_if_needed() {
+            ValueToken::String(value) => match value {
                 AnyStr::Owned(value) => visitor.visit_string(value),
                 AnyStr::Borrowed(value) => visitor.visit_borrowed_str(value),
             }
@@ -324,14 +347,14 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
     where
         V: OptionalVisitor<'de>,
     {
-        let offset = self.tokenizer.offset();
-        match self.tokenizer.peek() {
+        let offset = self.offset();
+        match self.input.peek() {
             None => Err(Report::new(SyntaxError::UnexpectedEof.into_error())
                 .attach(Position::new(offset))
                 .change_context(DeserializerError)),
             Some(PeekableTokenKind::Null) => {
                 // we know the value will be `null`, therefore we can just discard the next token
-                let _ = self.tokenizer.next();
+                let _ = self.input.next();
 
                 visitor.visit_null().change_context(DeserializerError)
             }
@@ -447,11 +470,9 @@ impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
         let token = self.next_value()?;
 
         match token {
-            ValueToken::String(value) => match value.decode_if_needed() {
-                AnyStr::Owned(value) => visitor.visit_str(&value),
-                AnyStr::Borrowed(value) => visitor.visit_str(value),
+            ValueToken::String(value) => {
+                visitor.visit_str(&value).change_context(DeserializerError)
             }
-            .change_context(DeserializerError),
 
             token => Err(self.error_invalid_type(&token, str::document())),
         }
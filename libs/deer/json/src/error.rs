@@ -3,10 +3,8 @@ use core::{
     ops::Range,
 };
 
-#[cfg(not(feature = "arbitrary-precision"))]
-use deer::error::ReceivedValue;
 use deer::{
-    error::{ErrorProperties, ErrorProperty, Id, Location, Namespace, Variant},
+    error::{ErrorProperties, ErrorProperty, Id, Location, Namespace, ReceivedValue, Variant},
     id,
 };
 use error_stack::Report;
@@ -164,8 +162,8 @@ pub(crate) enum SyntaxError {
     ObjectKeyMustBeString,
     InvalidHexadecimal,
     InvalidEscape,
-    // TODO: trailing non whitespace?
     UnclosedString,
+    TrailingCharacters,
 }
 
 impl Display for SyntaxError {
@@ -191,6 +189,7 @@ impl Display for SyntaxError {
             }
             Self::InvalidEscape => f.write_str("invalid escape character"),
             Self::UnclosedString => f.write_str(r#"expected end of string (`"`)"#),
+            Self::TrailingCharacters => f.write_str("trailing characters after value"),
         }
     }
 }
@@ -245,8 +244,11 @@ impl Variant for NativeError {
     }
 }
 
-pub(crate) fn convert_tokenizer_error(error: &justjson::Error) -> Report<deer::error::Error> {
-    let offset = error.offset();
+pub(crate) fn convert_tokenizer_error(
+    error: &justjson::Error,
+    base: usize,
+) -> Report<deer::error::Error> {
+    let offset = base + error.offset();
 
     let error = match error.kind() {
         ErrorKind::Utf8 => SyntaxError::InvalidUtf8Sequence.into_error(),
@@ -297,3 +299,109 @@ impl<C> ErrorAccumulator<C> {
         error
     }
 }
+
+#[derive(Debug)]
+pub(crate) struct WriteError;
+
+impl Display for WriteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unable to write to the underlying writer")
+    }
+}
+
+impl Variant for WriteError {
+    type Properties = (Location,);
+
+    const ID: Id = id!["write"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct NonFiniteNumberError;
+
+impl Display for NonFiniteNumberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("JSON does not support `NaN` or infinite numbers")
+    }
+}
+
+impl Variant for NonFiniteNumberError {
+    type Properties = (Location, ReceivedValue);
+
+    const ID: Id = id!["number", "non-finite"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct KeyMustBeStringError;
+
+impl Display for KeyMustBeStringError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("object keys must be a string or number")
+    }
+}
+
+impl Variant for KeyMustBeStringError {
+    type Properties = (Location,);
+
+    const ID: Id = id!["object", "key"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub(crate) struct IoError(std::io::ErrorKind);
+
+#[cfg(feature = "std")]
+impl IoError {
+    pub(crate) fn new(error: &std::io::Error) -> Self {
+        Self(error.kind())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for IoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unable to read input: {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Variant for IoError {
+    type Properties = (Location, Position);
+
+    const ID: Id = id!["io"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
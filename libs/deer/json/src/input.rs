@@ -0,0 +1,401 @@
+use alloc::borrow::ToOwned;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+use deer::error::Error;
+#[cfg(feature = "std")]
+use deer::error::Variant;
+use error_stack::Report;
+use justjson::{
+    parser::{PeekableTokenKind, Tokenizer},
+    AnyStr, JsonNumber, JsonString,
+};
+
+#[cfg(feature = "std")]
+use crate::error::{IoError, Position};
+use crate::{error::convert_tokenizer_error, token::Token};
+
+fn convert_token<'a, 'b>(
+    token: justjson::parser::Token<'b>,
+    string: impl FnOnce(JsonString<'b>) -> AnyStr<'a>,
+    number: impl FnOnce(JsonNumber<'b>) -> AnyStr<'a>,
+) -> Token<'a> {
+    match token {
+        justjson::parser::Token::Null => Token::Null,
+        justjson::parser::Token::Bool(value) => Token::Bool(value),
+        justjson::parser::Token::String(value) => Token::String(string(value)),
+        justjson::parser::Token::Number(value) => Token::Number(number(value)),
+        justjson::parser::Token::Object => Token::Object,
+        justjson::parser::Token::ObjectEnd => Token::ObjectEnd,
+        justjson::parser::Token::Array => Token::Array,
+        justjson::parser::Token::ArrayEnd => Token::ArrayEnd,
+        justjson::parser::Token::Colon => Token::Colon,
+        justjson::parser::Token::Comma => Token::Comma,
+    }
+}
+
+#[cfg(feature = "std")]
+const fn peek_kind(byte: u8) -> PeekableTokenKind {
+    match byte {
+        b'{' => PeekableTokenKind::Object,
+        b'}' => PeekableTokenKind::ObjectEnd,
+        b'[' => PeekableTokenKind::Array,
+        b']' => PeekableTokenKind::ArrayEnd,
+        b',' => PeekableTokenKind::Comma,
+        b':' => PeekableTokenKind::Colon,
+        b'"' => PeekableTokenKind::String,
+        b'-' | b'0'..=b'9' => PeekableTokenKind::Number,
+        b't' => PeekableTokenKind::True,
+        b'f' => PeekableTokenKind::False,
+        b'n' => PeekableTokenKind::Null,
+        _ => PeekableTokenKind::Unrecognized,
+    }
+}
+
+/// Bytes which end a number or literal, the tokenizer validates the bytes in between.
+#[cfg(feature = "std")]
+const fn is_delimiter(byte: u8) -> bool {
+    matches!(
+        byte,
+        b' ' | b'\n' | b'\t' | b'\r' | b'{' | b'}' | b'[' | b']' | b',' | b':' | b'"'
+    )
+}
+
+/// Tokens of an input, which is completely available in memory.
+pub(crate) struct SliceInput<'de> {
+    slice: &'de [u8],
+    tokenizer: Tokenizer<'de, false>,
+
+    /// Offset of the slice in the complete input, used to report absolute positions if the input
+    /// is deserialized in multiple parts (e.g. JSON lines).
+    base: usize,
+}
+
+impl<'de> SliceInput<'de> {
+    pub(crate) fn new(slice: &'de [u8], base: usize) -> Self {
+        Self {
+            slice,
+            tokenizer: Tokenizer::for_json_bytes(slice),
+            base,
+        }
+    }
+
+    fn next(&mut self) -> Option<Result<Token<'de>, Report<Error>>> {
+        let token = match self.tokenizer.next()? {
+            Ok(token) => token,
+            Err(error) => return Some(Err(convert_tokenizer_error(&error, self.base))),
+        };
+
+        let end = self.tokenizer.offset();
+        let slice = self.slice;
+
+        let token = convert_token(
+            token,
+            |value| value.decode_if_needed(),
+            |value| {
+                // `JsonNumber` does not expose the lifetime of the source, but the number always
+                // ends at the current offset, which allows us to borrow it from the input
+                let source = value.source();
+
+                slice
+                    .get(end - source.len()..end)
+                    .and_then(|number| core::str::from_utf8(number).ok())
+                    .map_or_else(|| AnyStr::Owned(source.to_owned()), AnyStr::Borrowed)
+            },
+        );
+
+        Some(Ok(token))
+    }
+
+    fn peek(&mut self) -> Option<PeekableTokenKind> {
+        self.tokenizer.peek()
+    }
+
+    const fn offset(&self) -> usize {
+        self.base + self.tokenizer.offset()
+    }
+}
+
+/// Tokens of an input, which is read incrementally from a reader.
+///
+/// Only the token that is currently processed is kept in memory, every token is tokenized using
+/// the same tokenizer as [`SliceInput`], therefore both report the same errors at the same
+/// positions.
+#[cfg(feature = "std")]
+pub(crate) struct ReaderInput<'de> {
+    reader: &'de mut dyn Read,
+
+    buffer: Vec<u8>,
+    /// Position of the next unprocessed byte in `buffer`.
+    position: usize,
+    /// Offset of `buffer[0]` in the complete input.
+    offset: usize,
+
+    eof: bool,
+    /// I/O error encountered while peeking, which is reported on the next call to `next`.
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<'de> ReaderInput<'de> {
+    const CHUNK_SIZE: usize = 8 * 1024;
+
+    pub(crate) fn new(reader: &'de mut dyn Read) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            position: 0,
+            offset: 0,
+            eof: false,
+            error: None,
+        }
+    }
+
+    /// Read the next chunk of the input, returns `false` if the input has ended.
+    fn fill(&mut self) -> io::Result<bool> {
+        if self.eof {
+            return Ok(false);
+        }
+
+        // discard everything that has already been processed, this ensures that the buffer only
+        // ever contains the current token (and the start of the next)
+        if self.position > 0 {
+            self.buffer.drain(..self.position);
+            self.offset += self.position;
+            self.position = 0;
+        }
+
+        let length = self.buffer.len();
+        self.buffer.resize(length + Self::CHUNK_SIZE, 0);
+
+        let read = loop {
+            match self.reader.read(&mut self.buffer[length..]) {
+                Ok(read) => break Ok(read),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => break Err(error),
+            }
+        };
+
+        match read {
+            Ok(read) => {
+                self.buffer.truncate(length + read);
+                self.eof = read == 0;
+
+                Ok(!self.eof)
+            }
+            Err(error) => {
+                self.buffer.truncate(length);
+
+                Err(error)
+            }
+        }
+    }
+
+    /// Skip all whitespace and return the next byte without consuming it.
+    fn peek_byte(&mut self) -> io::Result<Option<u8>> {
+        loop {
+            while let Some(&byte) = self.buffer.get(self.position) {
+                if !matches!(byte, b' ' | b'\n' | b'\t' | b'\r') {
+                    return Ok(Some(byte));
+                }
+
+                self.position += 1;
+            }
+
+            if !self.fill()? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Ensure that the complete token at the current position is in the buffer and return its
+    /// length.
+    ///
+    /// The token is not validated, this is left to the tokenizer, which means that the length may
+    /// be longer than the token, e.g. `1-2` is returned as a single token.
+    fn read_token(&mut self, first: u8) -> io::Result<usize> {
+        // relative to `position`, as `fill` may move the contents of the buffer
+        let mut length = 1;
+
+        match first {
+            b'{' | b'}' | b'[' | b']' | b',' | b':' => Ok(length),
+            b'"' => {
+                let mut escaped = false;
+
+                loop {
+                    let remaining = &self.buffer[self.position + length..];
+
+                    if escaped {
+                        if remaining.is_empty() {
+                            if !self.fill()? {
+                                return Ok(length);
+                            }
+
+                            continue;
+                        }
+
+                        length += 1;
+                        escaped = false;
+
+                        continue;
+                    }
+
+                    if let Some(index) = memchr::memchr2(b'"', b'\\', remaining) {
+                        length += index + 1;
+
+                        if remaining[index] == b'"' {
+                            return Ok(length);
+                        }
+
+                        escaped = true;
+                    } else {
+                        length += remaining.len();
+
+                        if !self.fill()? {
+                            return Ok(length);
+                        }
+                    }
+                }
+            }
+            _ => {
+                // the tokenizer reads literals completely, even if they contain a delimiter
+                let literal = match first {
+                    b't' | b'n' => 4,
+                    b'f' => 5,
+                    _ => 1,
+                };
+
+                while self.buffer.len() - self.position < literal {
+                    if !self.fill()? {
+                        break;
+                    }
+                }
+
+                length = literal.min(self.buffer.len() - self.position);
+
+                loop {
+                    let remaining = &self.buffer[self.position + length..];
+
+                    let end = remaining.iter().copied().position(is_delimiter);
+
+                    if let Some(end) = end {
+                        return Ok(length + end);
+                    }
+
+                    length += remaining.len();
+
+                    if !self.fill()? {
+                        return Ok(length);
+                    }
+                }
+            }
+        }
+    }
+
+    fn io_error(&mut self, error: &io::Error) -> Report<Error> {
+        // the input is unusable after an I/O error, therefore treat it as if it has ended, so that
+        // recovery does not need to deal with the same error again
+        self.eof = true;
+        self.buffer.truncate(self.position);
+
+        Report::new(IoError::new(error).into_error()).attach(Position::new(self.offset()))
+    }
+
+    fn next<'a>(&mut self) -> Option<Result<Token<'a>, Report<Error>>> {
+        if let Some(error) = self.error.take() {
+            return Some(Err(self.io_error(&error)));
+        }
+
+        let first = match self.peek_byte() {
+            Ok(first) => first?,
+            Err(error) => return Some(Err(self.io_error(&error))),
+        };
+
+        let length = match self.read_token(first) {
+            Ok(length) => length,
+            Err(error) => return Some(Err(self.io_error(&error))),
+        };
+
+        let start = self.offset();
+        let mut tokenizer =
+            Tokenizer::for_json_bytes(&self.buffer[self.position..self.position + length]);
+
+        // the buffer is reused for the next token, therefore everything needs to be owned
+        let token = match tokenizer.next()? {
+            Ok(token) => Ok(convert_token(
+                token,
+                |value| match value.decode_if_needed() {
+                    AnyStr::Owned(value) => AnyStr::Owned(value),
+                    AnyStr::Borrowed(value) => AnyStr::Owned(value.to_owned()),
+                },
+                |value| AnyStr::Owned(value.source().to_owned()),
+            )),
+            Err(error) => Err(convert_tokenizer_error(&error, start)),
+        };
+
+        // the tokenizer always consumes at least the first byte
+        self.position += tokenizer.offset().max(1);
+
+        Some(token)
+    }
+
+    fn peek(&mut self) -> Option<PeekableTokenKind> {
+        if self.error.is_some() {
+            return Some(PeekableTokenKind::Unrecognized);
+        }
+
+        match self.peek_byte() {
+            Ok(byte) => byte.map(peek_kind),
+            Err(error) => {
+                // `next` will report the error
+                self.error = Some(error);
+
+                Some(PeekableTokenKind::Unrecognized)
+            }
+        }
+    }
+
+    const fn offset(&self) -> usize {
+        self.offset + self.position
+    }
+}
+
+/// Source of the tokens for the [`Deserializer`].
+///
+/// [`Deserializer`]: crate::Deserializer
+pub(crate) enum Input<'de> {
+    Slice(SliceInput<'de>),
+    #[cfg(feature = "std")]
+    Reader(ReaderInput<'de>),
+}
+
+impl<'de> Input<'de> {
+    /// Returns the next token, or `None` if the input has ended.
+    ///
+    /// Errors are reported with their absolute position in the input.
+    pub(crate) fn next(&mut self) -> Option<Result<Token<'de>, Report<Error>>> {
+        match self {
+            Self::Slice(input) => input.next(),
+            #[cfg(feature = "std")]
+            Self::Reader(input) => input.next(),
+        }
+    }
+
+    pub(crate) fn peek(&mut self) -> Option<PeekableTokenKind> {
+        match self {
+            Self::Slice(input) => input.peek(),
+            #[cfg(feature = "std")]
+            Self::Reader(input) => input.peek(),
+        }
+    }
+
+    pub(crate) const fn offset(&self) -> usize {
+        match self {
+            Self::Slice(input) => input.offset(),
+            #[cfg(feature = "std")]
+            Self::Reader(input) => input.offset(),
+        }
+    }
+}
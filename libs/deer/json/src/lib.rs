@@ -5,17 +5,36 @@
 mod array;
 mod deserializer;
 mod error;
+mod input;
+mod lines;
 mod number;
 mod object;
+mod serializer;
 mod skip;
 mod token;
 
 extern crate alloc;
 
-use deer::{error::DeserializeError, Context, Deserialize};
+use alloc::string::String;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::io::{BufReader, Read};
+
+#[cfg(feature = "std")]
+use deer::DeserializeOwned;
+use deer::{
+    error::{DeserializeError, SerializeError},
+    Context, Deserialize, Serialize,
+};
 use error_stack::Result;
 
-pub use crate::deserializer::{Deserializer, StackLimit};
+#[cfg(feature = "std")]
+pub use crate::lines::JsonLinesReader;
+pub use crate::{
+    deserializer::{Deserializer, StackLimit},
+    lines::JsonLines,
+    serializer::{ArraySerializer, ObjectSerializer, Serializer},
+};
 
 pub fn from_slice<'de, T>(slice: &'de [u8], context: &Context) -> Result<T, DeserializeError>
 where
@@ -34,3 +53,55 @@ where
 
     T::deserialize(&mut deserializer)
 }
+
+/// Deserialize a single JSON document from a reader.
+///
+/// The reader is read incrementally while deserializing, only the current token is kept in
+/// memory, errors report the position relative to the start of the input. The input is buffered
+/// internally, wrapping the reader in a [`BufReader`] is not required.
+///
+/// Like [`from_slice`], only the first value is read, any input after it is ignored.
+#[cfg(feature = "std")]
+pub fn from_reader<R, T>(mut reader: R, context: &Context) -> Result<T, DeserializeError>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    let mut deserializer = Deserializer::from_reader(&mut reader, context);
+
+    T::deserialize(&mut deserializer)
+}
+
+/// Deserialize every record of a [JSON lines] input from a reader, one at a time.
+///
+/// Only a single record is kept in memory at any point in time, errors report the position
+/// relative to the start of the input.
+///
+/// [JSON lines]: https://jsonlines.org/
+#[cfg(feature = "std")]
+pub fn from_reader_lines<R, T>(reader: R, context: &Context) -> JsonLinesReader<BufReader<R>, T>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    JsonLinesReader::new(BufReader::new(reader), context)
+}
+
+pub fn to_writer<W, T>(writer: W, value: &T, context: &Context) -> Result<W, SerializeError>
+where
+    W: Write,
+    T: Serialize + ?Sized,
+{
+    let mut serializer = Serializer::new(writer, context);
+
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.into_inner())
+}
+
+pub fn to_string<T>(value: &T, context: &Context) -> Result<String, SerializeError>
+where
+    T: Serialize + ?Sized,
+{
+    to_writer(String::new(), value, context)
+}
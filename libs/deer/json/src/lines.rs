@@ -0,0 +1,195 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+#[cfg(feature = "std")]
+use deer::error::Variant;
+use deer::{error::DeserializeError, Context, DeserializeOwned};
+#[cfg(feature = "std")]
+use error_stack::Report;
+use error_stack::{Result, ResultExt};
+
+#[cfg(feature = "std")]
+use crate::error::{IoError, Position};
+use crate::Deserializer;
+
+/// Deserialize a single record, which starts at `offset` in the complete input.
+///
+/// Records which only consist of whitespace are skipped and return `None`.
+fn deserialize_record<T: DeserializeOwned>(
+    record: &[u8],
+    context: &Context,
+    offset: usize,
+) -> Option<Result<T, DeserializeError>> {
+    if record.iter().all(u8::is_ascii_whitespace) {
+        return None;
+    }
+
+    let mut deserializer = Deserializer::with_offset(record, context, offset);
+
+    let value = T::deserialize(&mut deserializer).and_then(|value| {
+        deserializer
+            .end()
+            .map(|()| value)
+            .change_context(DeserializeError)
+    });
+
+    Some(value)
+}
+
+/// Incremental decoder for [JSON lines], which does not perform any I/O itself.
+///
+/// Input is fed in arbitrarily sized chunks using [`Self::feed`], every complete line is then
+/// deserialized using [`Self::next_record`]. Only the current (incomplete) record is kept in
+/// memory, which makes this suitable for inputs that are too large to be fully loaded, as well as
+/// for any kind of asynchronous reader, as the caller decides how the chunks are read.
+///
+/// Every error reports positions relative to the start of the complete input, not the record.
+///
+/// [JSON lines]: https://jsonlines.org/
+pub struct JsonLines<'a> {
+    context: &'a Context,
+
+    buffer: Vec<u8>,
+    /// Start of the first unprocessed record in `buffer`.
+    start: usize,
+    /// Offset of `buffer[0]` in the complete input.
+    offset: usize,
+}
+
+impl<'a> JsonLines<'a> {
+    #[must_use]
+    pub const fn new(context: &'a Context) -> Self {
+        Self {
+            context,
+            buffer: Vec::new(),
+            start: 0,
+            offset: 0,
+        }
+    }
+
+    /// Append a chunk of input.
+    pub fn feed(&mut self, chunk: &[u8]) {
+        // discard every record that has already been processed, this ensures that the buffer only
+        // ever contains a single record (and the start of the next)
+        if self.start > 0 {
+            self.buffer.drain(..self.start);
+            self.offset += self.start;
+            self.start = 0;
+        }
+
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Deserialize the next complete record.
+    ///
+    /// Returns `None` if no complete record is available, either more input needs to be fed, or
+    /// if the input has ended [`Self::finish`] needs to be called to process the last record.
+    pub fn next_record<T: DeserializeOwned>(&mut self) -> Option<Result<T, DeserializeError>> {
+        loop {
+            let remaining = &self.buffer[self.start..];
+            let length = memchr::memchr(b'\n', remaining)? + 1;
+
+            let start = self.start;
+            self.start += length;
+
+            let record = &self.buffer[start..start + length];
+
+            if let Some(value) = deserialize_record(record, self.context, self.offset + start) {
+                return Some(value);
+            }
+        }
+    }
+
+    /// Deserialize the last record, which is not terminated by a newline.
+    ///
+    /// Every complete record must have been processed using [`Self::next_record`] beforehand.
+    #[must_use]
+    pub fn finish<T: DeserializeOwned>(self) -> Option<Result<T, DeserializeError>> {
+        deserialize_record(
+            &self.buffer[self.start..],
+            self.context,
+            self.offset + self.start,
+        )
+    }
+}
+
+/// Iterator over every record of a [JSON lines] input read from a [`BufRead`].
+///
+/// Created by [`from_reader_lines`], see [`JsonLines`] for details.
+///
+/// I/O errors are reported as an error of the current record, after which iteration stops.
+///
+/// [JSON lines]: https://jsonlines.org/
+/// [`from_reader_lines`]: crate::from_reader_lines
+#[cfg(feature = "std")]
+pub struct JsonLinesReader<'a, R, T> {
+    reader: R,
+    context: &'a Context,
+
+    buffer: Vec<u8>,
+    offset: usize,
+    done: bool,
+
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, R, T> JsonLinesReader<'a, R, T> {
+    pub(crate) const fn new(reader: R, context: &'a Context) -> Self {
+        Self {
+            reader,
+            context,
+            buffer: Vec::new(),
+            offset: 0,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Offset in bytes of the next record in the complete input.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, T> Iterator for JsonLinesReader<'_, R, T>
+where
+    R: BufRead,
+    T: DeserializeOwned,
+{
+    type Item = Result<T, DeserializeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.buffer.clear();
+
+            let length = match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) => {
+                    self.done = true;
+                    return None;
+                }
+                Ok(length) => length,
+                Err(error) => {
+                    self.done = true;
+
+                    return Some(Err(Report::new(IoError::new(&error).into_error())
+                        .attach(Position::new(self.offset + self.buffer.len()))
+                        .change_context(DeserializeError)));
+                }
+            };
+
+            let offset = self.offset;
+            self.offset += length;
+
+            if let Some(value) = deserialize_record(&self.buffer, self.context, offset) {
+                return Some(value);
+            }
+        }
+
+        None
+    }
+}
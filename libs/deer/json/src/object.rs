@@ -3,12 +3,13 @@ use deer::{
     Context, Deserializer as _, FieldVisitor,
 };
 use error_stack::{Report, Result, ResultExt};
-use justjson::parser::{PeekableTokenKind, Token};
+use justjson::parser::PeekableTokenKind;
 
 use crate::{
     deserializer::Deserializer,
     error::{ErrorAccumulator, Position, Span, SyntaxError},
     skip::skip_tokens,
+    token::Token,
 };
 
 pub(crate) struct ObjectAccess<'a, 'b, 'de: 'a> {
@@ -106,29 +107,23 @@ impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
 
         let key = visitor.visit_key(&mut *self.deserializer);
 
+        // key value are separated by `:`, if one forgets we will still error out but _try_ to
+        // deserialize
+        if let Err(skip) = self.try_skip_colon() {
+            errors.extend_one(skip);
+        }
+
         let result = match key {
             Ok(key) => visitor
                 .visit_value(key, &mut *self.deserializer)
                 .change_context(ObjectAccessError),
             Err(error) => {
-                let mut error = error.change_context(ObjectAccessError);
-
-                if let Err(skip) = self.try_skip_colon() {
-                    error.extend_one(skip.change_context(ObjectAccessError));
-                }
-
                 self.deserializer.skip(); // skip value
 
-                Err(error)
+                Err(error.change_context(ObjectAccessError))
             }
         };
 
-        // key value are separated by `:`, if one forgets we will still error out but _try_ to
-        // deserialize
-        if let Err(skip) = self.try_skip_colon() {
-            errors.extend_one(skip);
-        }
-
         // same as `(result, errors).into_result()`
         let result = match (
             result,
@@ -159,7 +154,7 @@ impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
             Some(_) => Err(ObjectLengthError::new(&self, self.expected)),
         };
 
-        skip_tokens(&mut self.deserializer.tokenizer, &Token::Object);
+        skip_tokens(&mut self.deserializer.input, &Token::Object);
 
         result.change_context(ObjectAccessError)
     }
@@ -0,0 +1,323 @@
+#[cfg(not(feature = "arbitrary-precision"))]
+use alloc::format;
+use core::fmt::{self, Write};
+
+use deer::{
+    error::{ReceivedValue, SerializerError, Variant},
+    Context, Number, Serialize, SerializeArray, SerializeObject,
+};
+use error_stack::{Report, Result, ResultExt};
+#[cfg(not(feature = "arbitrary-precision"))]
+use num_traits::ToPrimitive;
+
+use crate::error::{KeyMustBeStringError, NonFiniteNumberError, WriteError};
+
+fn write_error(_: fmt::Error) -> Report<SerializerError> {
+    Report::new(WriteError.into_error()).change_context(SerializerError)
+}
+
+// Reason: every index originates from `char_indices`, and is therefore on a character boundary
+#[allow(clippy::string_slice)]
+fn write_escaped<W: Write>(writer: &mut W, value: &str) -> fmt::Result {
+    writer.write_char('"')?;
+
+    let mut start = 0;
+
+    for (index, char) in value.char_indices() {
+        let escape = match char {
+            '"' => "\\\"",
+            '\\' => "\\\\",
+            '\n' => "\\n",
+            '\r' => "\\r",
+            '\t' => "\\t",
+            '\u{08}' => "\\b",
+            '\u{0C}' => "\\f",
+            '\u{00}'..='\u{1F}' => "",
+            _ => continue,
+        };
+
+        writer.write_str(&value[start..index])?;
+        start = index + char.len_utf8();
+
+        if escape.is_empty() {
+            write!(writer, "\\u{:04x}", u32::from(char))?;
+        } else {
+            writer.write_str(escape)?;
+        }
+    }
+
+    writer.write_str(&value[start..])?;
+    writer.write_char('"')
+}
+
+#[cfg(not(feature = "arbitrary-precision"))]
+fn write_number<W: Write>(writer: &mut W, value: &Number) -> Result<(), SerializerError> {
+    let Some(float) = value.to_f64() else {
+        return write!(writer, "{value}").map_err(write_error);
+    };
+
+    if !float.is_finite() {
+        // non-finite numbers cannot be represented in JSON, which is why the received value is
+        // attached in its textual form (`NaN`, `inf` or `-inf`) instead.
+        return Err(Report::new(NonFiniteNumberError.into_error())
+            .attach(ReceivedValue::new(format!("{float}")))
+            .change_context(SerializerError));
+    }
+
+    // `Debug` always includes the fractional part, which is required by the deserializer to
+    // recognize the value as float, except for values which use the scientific notation.
+    let float = format!("{float:?}");
+
+    match float.split_once('e') {
+        Some((mantissa, exponent)) if !mantissa.contains('.') => {
+            write!(writer, "{mantissa}.0e{exponent}")
+        }
+        _ => writer.write_str(&float),
+    }
+    .map_err(write_error)
+}
+
+#[cfg(feature = "arbitrary-precision")]
+fn write_number<W: Write>(writer: &mut W, value: &Number) -> Result<(), SerializerError> {
+    let mut buffer = alloc::string::String::new();
+    write!(buffer, "{value}").map_err(write_error)?;
+
+    if matches!(buffer.as_str(), "NaN" | "inf" | "-inf") {
+        return Err(Report::new(NonFiniteNumberError.into_error())
+            .attach(ReceivedValue::new(buffer))
+            .change_context(SerializerError));
+    }
+
+    writer.write_str(&buffer).map_err(write_error)
+}
+
+/// Serializer which writes compact JSON into any [`fmt::Write`] implementation.
+pub struct Serializer<'a, W> {
+    writer: W,
+    context: &'a Context,
+}
+
+impl<'a, W: Write> Serializer<'a, W> {
+    pub const fn new(writer: W, context: &'a Context) -> Self {
+        Self { writer, context }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<'a, 'b, W: Write> deer::Serializer for &'b mut Serializer<'a, W> {
+    type SerializeArray = ArraySerializer<'a, 'b, W>;
+    type SerializeObject = ObjectSerializer<'a, 'b, W>;
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn serialize_null(self) -> Result<(), SerializerError> {
+        self.writer.write_str("null").map_err(write_error)
+    }
+
+    fn serialize_bool(self, value: bool) -> Result<(), SerializerError> {
+        self.writer
+            .write_str(if value { "true" } else { "false" })
+            .map_err(write_error)
+    }
+
+    fn serialize_number(self, value: &Number) -> Result<(), SerializerError> {
+        write_number(&mut self.writer, value)
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), SerializerError> {
+        write_escaped(&mut self.writer, value).map_err(write_error)
+    }
+
+    // JSON has no notion of bytes, we write them as an array of numbers instead.
+    fn serialize_bytes(self, value: &[u8]) -> Result<(), SerializerError> {
+        self.collect_array(value)
+    }
+
+    fn serialize_array(self, _: Option<usize>) -> Result<Self::SerializeArray, SerializerError> {
+        self.writer.write_char('[').map_err(write_error)?;
+
+        Ok(ArraySerializer {
+            serializer: self,
+            first: true,
+        })
+    }
+
+    fn serialize_object(self, _: Option<usize>) -> Result<Self::SerializeObject, SerializerError> {
+        self.writer.write_char('{').map_err(write_error)?;
+
+        Ok(ObjectSerializer {
+            serializer: self,
+            first: true,
+        })
+    }
+}
+
+pub struct ArraySerializer<'a, 'b, W> {
+    serializer: &'b mut Serializer<'a, W>,
+    first: bool,
+}
+
+impl<W: Write> SerializeArray for ArraySerializer<'_, '_, W> {
+    fn context(&self) -> &Context {
+        self.serializer.context
+    }
+
+    fn serialize_item<T>(&mut self, value: &T) -> Result<(), SerializerError>
+    where
+        T: Serialize + ?Sized,
+    {
+        if !self.first {
+            self.serializer
+                .writer
+                .write_char(',')
+                .map_err(write_error)?;
+        }
+
+        self.first = false;
+
+        value
+            .serialize(&mut *self.serializer)
+            .change_context(SerializerError)
+    }
+
+    fn end(self) -> Result<(), SerializerError> {
+        self.serializer.writer.write_char(']').map_err(write_error)
+    }
+}
+
+pub struct ObjectSerializer<'a, 'b, W> {
+    serializer: &'b mut Serializer<'a, W>,
+    first: bool,
+}
+
+impl<W: Write> SerializeObject for ObjectSerializer<'_, '_, W> {
+    fn context(&self) -> &Context {
+        self.serializer.context
+    }
+
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<(), SerializerError>
+    where
+        K: Serialize + ?Sized,
+        V: Serialize + ?Sized,
+    {
+        if !self.first {
+            self.serializer
+                .writer
+                .write_char(',')
+                .map_err(write_error)?;
+        }
+
+        self.first = false;
+
+        key.serialize(KeySerializer(&mut *self.serializer))
+            .change_context(SerializerError)?;
+
+        self.serializer
+            .writer
+            .write_char(':')
+            .map_err(write_error)?;
+
+        value
+            .serialize(&mut *self.serializer)
+            .change_context(SerializerError)
+    }
+
+    fn end(self) -> Result<(), SerializerError> {
+        self.serializer.writer.write_char('}').map_err(write_error)
+    }
+}
+
+/// JSON only supports strings as keys, numbers are written as strings, every other value is
+/// rejected.
+struct KeySerializer<'a, 'b, W>(&'b mut Serializer<'a, W>);
+
+fn unsupported_key<T>() -> Result<T, SerializerError> {
+    Err(Report::new(KeyMustBeStringError.into_error()).change_context(SerializerError))
+}
+
+impl<'a, 'b, W: Write> deer::Serializer for KeySerializer<'a, 'b, W> {
+    type SerializeArray = Impossible;
+    type SerializeObject = Impossible;
+
+    fn context(&self) -> &Context {
+        self.0.context
+    }
+
+    fn serialize_null(self) -> Result<(), SerializerError> {
+        unsupported_key()
+    }
+
+    fn serialize_bool(self, _: bool) -> Result<(), SerializerError> {
+        unsupported_key()
+    }
+
+    fn serialize_number(self, value: &Number) -> Result<(), SerializerError> {
+        let writer = &mut self.0.writer;
+
+        writer.write_char('"').map_err(write_error)?;
+        write_number(writer, value)?;
+        writer.write_char('"').map_err(write_error)
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), SerializerError> {
+        self.0.serialize_str(value)
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), SerializerError> {
+        unsupported_key()
+    }
+
+    fn serialize_array(self, _: Option<usize>) -> Result<Self::SerializeArray, SerializerError> {
+        unsupported_key()
+    }
+
+    fn serialize_object(self, _: Option<usize>) -> Result<Self::SerializeObject, SerializerError> {
+        unsupported_key()
+    }
+}
+
+enum Never {}
+
+/// Uninhabited [`SerializeArray`] and [`SerializeObject`], used by serializers which do not
+/// support composite types.
+struct Impossible(Never);
+
+impl SerializeArray for Impossible {
+    fn context(&self) -> &Context {
+        match self.0 {}
+    }
+
+    fn serialize_item<T>(&mut self, _: &T) -> Result<(), SerializerError>
+    where
+        T: Serialize + ?Sized,
+    {
+        match self.0 {}
+    }
+
+    fn end(self) -> Result<(), SerializerError> {
+        match self.0 {}
+    }
+}
+
+impl SerializeObject for Impossible {
+    fn context(&self) -> &Context {
+        match self.0 {}
+    }
+
+    fn serialize_entry<K, V>(&mut self, _: &K, _: &V) -> Result<(), SerializerError>
+    where
+        K: Serialize + ?Sized,
+        V: Serialize + ?Sized,
+    {
+        match self.0 {}
+    }
+
+    fn end(self) -> Result<(), SerializerError> {
+        match self.0 {}
+    }
+}
@@ -1,11 +1,11 @@
-use justjson::parser::{Token, Tokenizer};
+use crate::{input::Input, token::Token};
 
-fn skip_nested(tokenizer: &mut Tokenizer<false>, stop: &Token) {
+fn skip_nested(input: &mut Input, stop: &Token) {
     let mut objects: usize = 0;
     let mut arrays: usize = 0;
 
     loop {
-        let Some(token) = tokenizer.next() else {
+        let Some(token) = input.next() else {
             // we're at the end
             return;
         };
@@ -36,10 +36,10 @@ fn skip_nested(tokenizer: &mut Tokenizer<false>, stop: &Token) {
 
 /// Skips all tokens required for the start token, be aware that the token should already be
 /// consumed.
-pub(crate) fn skip_tokens(tokenizer: &mut Tokenizer<false>, start: &Token) {
+pub(crate) fn skip_tokens(input: &mut Input, start: &Token) {
     match start {
-        Token::Array => skip_nested(tokenizer, &Token::ArrayEnd),
-        Token::Object => skip_nested(tokenizer, &Token::ObjectEnd),
+        Token::Array => skip_nested(input, &Token::ArrayEnd),
+        Token::Object => skip_nested(input, &Token::ObjectEnd),
         _ => {}
     };
 }
@@ -3,16 +3,32 @@ use deer::{
     Deserialize, Document, Number, Reflection, Schema,
 };
 use error_stack::Report;
-use justjson::{parser::Token, JsonNumber, JsonString};
+use justjson::AnyStr;
 
 use crate::error::SyntaxError;
 
-// This is like `justjson::Token` but does not contain syntax tokens like `ArrayEnd` or `ObjectEnd`
+// This is like `justjson::Token`, but strings are already decoded and numbers are only their
+// source, which allows both to be owned if the input is not borrowed (e.g. read from a reader)
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    Null,
+    Bool(bool),
+    String(AnyStr<'a>),
+    Number(AnyStr<'a>),
+    Object,
+    ObjectEnd,
+    Array,
+    ArrayEnd,
+    Colon,
+    Comma,
+}
+
+// This is like `Token` but does not contain syntax tokens like `ArrayEnd` or `ObjectEnd`
 pub(crate) enum ValueToken<'a> {
     Null,
     Bool(bool),
-    String(JsonString<'a>),
-    Number(JsonNumber<'a>),
+    String(AnyStr<'a>),
+    Number(AnyStr<'a>),
     Object,
     Array,
 }
@@ -0,0 +1,69 @@
+use deer::{error::DeserializeError, Context, Deserialize};
+use deer_desert::{
+    conformance::{assert_conformance, Format},
+    Token,
+};
+use deer_json::from_slice;
+use error_stack::Result;
+use serde_json::{Map, Value};
+
+/// Convert the next value of the tokens, `None` if JSON is unable to represent it.
+fn value(tokens: &mut &[Token]) -> Option<Value> {
+    let (token, rest) = tokens.split_first()?;
+    *tokens = rest;
+
+    let value = match token {
+        Token::Bool(value) => Value::from(*value),
+        Token::Number(value) => serde_json::from_str(&value.to_string()).ok()?,
+        Token::Char(value) => Value::from(value.to_string()),
+        Token::Str(value) | Token::BorrowedStr(value) | Token::String(value) => Value::from(*value),
+        Token::Null => Value::Null,
+        Token::Array { .. } => {
+            let mut items = Vec::new();
+
+            while *tokens.first()? != Token::ArrayEnd {
+                items.push(value(tokens)?);
+            }
+
+            *tokens = &tokens[1..];
+            Value::Array(items)
+        }
+        Token::Object { .. } => {
+            let mut entries = Map::new();
+
+            while *tokens.first()? != Token::ObjectEnd {
+                let Value::String(key) = value(tokens)? else {
+                    return None;
+                };
+
+                entries.insert(key, value(tokens)?);
+            }
+
+            *tokens = &tokens[1..];
+            Value::Object(entries)
+        }
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+struct Json;
+
+impl Format for Json {
+    fn encode(mut tokens: &[Token]) -> Option<Vec<u8>> {
+        value(&mut tokens).map(|value| value.to_string().into_bytes())
+    }
+
+    fn decode<'de, T>(input: &'de [u8], context: &Context) -> Result<T, DeserializeError>
+    where
+        T: Deserialize<'de>,
+    {
+        from_slice(input, context)
+    }
+}
+
+#[test]
+fn conformance() {
+    assert_conformance::<Json>();
+}
@@ -0,0 +1,174 @@
+use std::io::{self, Read};
+
+use deer::Context;
+use deer_desert::errors;
+use deer_json::{from_reader, from_reader_lines, JsonLines};
+use error_stack::Result;
+use serde_json::json;
+
+const INPUT: &str = "[1, 2]\n[3]\n\n  \n[]\n[4]";
+
+#[test]
+fn reader_ok() {
+    let context = Context::new();
+
+    let records: Vec<Vec<u8>> = from_reader_lines(INPUT.as_bytes(), &context)
+        .collect::<Result<_, _>>()
+        .expect("should deserialize");
+
+    assert_eq!(records, [vec![1, 2], vec![3], vec![], vec![4]]);
+}
+
+#[test]
+fn reader_offset_err() {
+    let context = Context::new();
+
+    let mut records = from_reader_lines::<_, Vec<u8>>(&b"[1]\n[1,,2]\n[3]\n"[..], &context);
+
+    assert_eq!(
+        records
+            .next()
+            .expect("should have record")
+            .expect("should deserialize"),
+        [1]
+    );
+
+    let report = records
+        .next()
+        .expect("should have record")
+        .expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer-json",
+            "id": ["syntax"],
+            "properties": {
+                "location": [{"type": "array", "value": 1}],
+                "position": 7,
+                "span": null
+            }
+        }])
+    );
+
+    // errors are isolated to a single record
+    assert_eq!(
+        records
+            .next()
+            .expect("should have record")
+            .expect("should deserialize"),
+        [3]
+    );
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn trailing_characters_err() {
+    let context = Context::new();
+
+    let report = from_reader_lines::<_, Vec<u8>>(&b"\n[1] [2]\n"[..], &context)
+        .next()
+        .expect("should have record")
+        .expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer-json",
+            "id": ["syntax"],
+            "properties": {
+                "location": [],
+                "position": 5,
+                "span": null
+            }
+        }])
+    );
+}
+
+struct FailingReader;
+
+impl Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::Other, "failing reader"))
+    }
+}
+
+#[test]
+fn io_err() {
+    let context = Context::new();
+
+    let mut records = from_reader_lines::<_, Vec<u8>>(FailingReader, &context);
+
+    let report = records
+        .next()
+        .expect("should have record")
+        .expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer-json",
+            "id": ["io"],
+            "properties": {
+                "location": [],
+                "position": 0
+            }
+        }])
+    );
+    assert!(records.next().is_none());
+
+    let report = from_reader::<_, Vec<u8>>(FailingReader, &context).expect_err("should fail");
+    assert_eq!(errors(report)[0]["id"], json!(["io"]));
+}
+
+#[test]
+fn decoder_chunked_ok() {
+    let context = Context::new();
+    let mut decoder = JsonLines::new(&context);
+    let mut records: Vec<Vec<u8>> = Vec::new();
+
+    for chunk in INPUT.as_bytes().chunks(3) {
+        decoder.feed(chunk);
+
+        while let Some(record) = decoder.next_record() {
+            records.push(record.expect("should deserialize"));
+        }
+    }
+
+    if let Some(record) = decoder.finish() {
+        records.push(record.expect("should deserialize"));
+    }
+
+    assert_eq!(records, [vec![1, 2], vec![3], vec![], vec![4]]);
+}
+
+#[test]
+fn decoder_offset_err() {
+    let context = Context::new();
+    let mut decoder = JsonLines::new(&context);
+
+    decoder.feed(b"[1]\n[2]\n[");
+    assert!(decoder.next_record::<Vec<u8>>().is_some());
+    assert!(decoder.next_record::<Vec<u8>>().is_some());
+    assert!(decoder.next_record::<Vec<u8>>().is_none());
+
+    decoder.feed(b"true]\n");
+
+    let report = decoder
+        .next_record::<Vec<u8>>()
+        .expect("should have record")
+        .expect_err("should fail");
+
+    let errors = errors(report);
+    assert_eq!(errors[0]["id"], json!(["type"]));
+}
+
+#[test]
+fn from_reader_ok() {
+    let context = Context::new();
+
+    let value: Vec<Vec<u8>> =
+        from_reader(&b"[[1], [2, 3]]"[..], &context).expect("should deserialize");
+
+    assert_eq!(value, [vec![1], vec![2, 3]]);
+}
@@ -0,0 +1,157 @@
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use std::io::{self, Read};
+
+use deer::{
+    error::{DeserializeError, ReportExt},
+    Context, DeserializeOwned,
+};
+use deer_json::{from_reader, from_slice};
+use error_stack::Report;
+use serde_json::{json, to_value, Value};
+
+/// Reader which returns at most a single byte on every call, to ensure that every token can be
+/// split at any position.
+struct ByteReader<'a>(&'a [u8]);
+
+impl Read for ByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let Some((byte, rest)) = self.0.split_first() else {
+            return Ok(0);
+        };
+
+        let Some(target) = buf.first_mut() else {
+            return Ok(0);
+        };
+
+        *target = *byte;
+        self.0 = rest;
+
+        Ok(1)
+    }
+}
+
+/// Reader which fails once the input has been read completely.
+struct FailingAfter<R>(R);
+
+impl<R: Read> Read for FailingAfter<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.0.read(buf)? {
+            0 => Err(io::Error::new(io::ErrorKind::Other, "failing reader")),
+            read => Ok(read),
+        }
+    }
+}
+
+fn export(report: Report<DeserializeError>) -> Value {
+    to_value(report.export()).expect("error should serialize")
+}
+
+/// Deserialize the input from a slice and a reader, both need to yield the same result.
+fn assert_same<T>(input: &str) -> core::result::Result<T, Value>
+where
+    T: DeserializeOwned + PartialEq + core::fmt::Debug,
+{
+    let context = Context::new();
+
+    let slice = from_slice::<T>(input.as_bytes(), &context).map_err(export);
+    let reader = from_reader::<_, T>(ByteReader(input.as_bytes()), &context).map_err(export);
+
+    assert_eq!(slice, reader, "input: {input}");
+
+    reader
+}
+
+#[test]
+fn reader_ok() {
+    let value = assert_same::<BTreeMap<String, Vec<f64>>>(
+        r#" { "a" : [1.5, -2.25, 0.5] , "\u00e9\"\\" : [] , "ü": [12345678.25] } "#,
+    )
+    .expect("should deserialize");
+
+    assert_eq!(
+        value,
+        BTreeMap::from([
+            ("a".to_owned(), vec![1.5, -2.25, 0.5]),
+            ("é\"\\".to_owned(), vec![]),
+            ("ü".to_owned(), vec![12_345_678.25]),
+        ])
+    );
+
+    let value = assert_same::<Vec<Option<bool>>>("[true,null,false]").expect("should deserialize");
+    assert_eq!(value, [Some(true), None, Some(false)]);
+}
+
+#[test]
+fn reader_syntax_err() {
+    for input in [
+        "",
+        "   ",
+        "[1,,2]",
+        "[1 2]",
+        "[1, 2",
+        "[\"abc",
+        "[\"a\\x\"]",
+        "[\"\\u12g4\"]",
+        "[tru]",
+        "[trux]",
+        "[1.]",
+        "[-]",
+        "[1e]",
+        "[1-2]",
+        "[18446744073709551616]",
+        "[1.5]",
+        "{\"a\" 1, 2: 3}",
+        "[\"\u{e9}\", \"a\\",
+    ] {
+        // only the equality of the results is relevant, not whether the input is accepted
+        let _ = assert_same::<Vec<u8>>(input);
+        let _ = assert_same::<Vec<String>>(input);
+    }
+
+    let report = assert_same::<Vec<u8>>("[1, 2, \"3\", 4,, 5]").expect_err("should fail");
+    assert_eq!(
+        report
+            .as_array()
+            .expect("should be an array of errors")
+            .iter()
+            .map(|error| error["properties"]["position"].clone())
+            .collect::<Vec<_>>(),
+        [Value::Null, json!(14)]
+    );
+}
+
+#[test]
+fn reader_invalid_utf8_err() {
+    let context = Context::new();
+    let input = b"[\"a\xff\"]";
+
+    assert_eq!(
+        from_slice::<Vec<String>>(input, &context).map_err(export),
+        from_reader::<_, Vec<String>>(ByteReader(input), &context).map_err(export)
+    );
+}
+
+#[test]
+fn reader_stops_after_value() {
+    let context = Context::new();
+
+    // the reader is only read as far as required, the value is complete before the reader fails
+    let value: Vec<Vec<u8>> = from_reader(FailingAfter(ByteReader(b"[[1], [2, 3]]")), &context)
+        .expect("should deserialize");
+
+    assert_eq!(value, [vec![1], vec![2, 3]]);
+}
+
+#[test]
+fn reader_io_err() {
+    let context = Context::new();
+
+    let report = from_reader::<_, Vec<u8>>(FailingAfter(ByteReader(b"[1, 2, ")), &context)
+        .expect_err("should fail");
+
+    let errors = export(report);
+    assert_eq!(errors[0]["id"], json!(["io"]));
+    assert_eq!(errors[0]["properties"]["position"], json!(7));
+}
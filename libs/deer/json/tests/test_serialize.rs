@@ -0,0 +1,194 @@
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use core::fmt::Debug;
+
+use deer::{
+    error::SerializeError, Context, Deserialize, Document, Reflection, Schema, Serialize,
+    SerializeObject, Serializer,
+};
+use deer_desert::errors;
+use deer_json::{from_str, to_string};
+use error_stack::{Result, ResultExt};
+use serde_json::json;
+
+fn serialize<T: Serialize + ?Sized>(value: &T) -> Result<String, SerializeError> {
+    to_string(value, &Context::new())
+}
+
+fn assert_round_trip<T>(value: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+{
+    let context = Context::new();
+
+    let output = to_string(value, &context).expect("should serialize");
+    let received: T = from_str(&output, &context).expect("should deserialize");
+
+    assert_eq!(&received, value);
+}
+
+#[derive(Debug, PartialEq)]
+struct Example {
+    a: u8,
+    b: Vec<String>,
+    c: Option<f64>,
+}
+
+impl Reflection for Example {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("object")
+    }
+}
+
+impl Serialize for Example {
+    type Reflection = Self;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        let mut object = serializer
+            .serialize_object(Some(3))
+            .change_context(SerializeError)?;
+
+        object
+            .serialize_field("a", &self.a)
+            .change_context(SerializeError)?;
+        object
+            .serialize_field("b", &self.b)
+            .change_context(SerializeError)?;
+        object
+            .serialize_field("c", &self.c)
+            .change_context(SerializeError)?;
+
+        object.end().change_context(SerializeError)
+    }
+}
+
+#[test]
+fn primitive_ok() {
+    assert_eq!(serialize(&12_u8).expect("should serialize"), "12");
+    assert_eq!(serialize(&-12_i64).expect("should serialize"), "-12");
+    assert_eq!(serialize(&1.5_f64).expect("should serialize"), "1.5");
+    assert_eq!(serialize(&true).expect("should serialize"), "true");
+    assert_eq!(serialize(&()).expect("should serialize"), "null");
+    assert_eq!(serialize(&None::<u8>).expect("should serialize"), "null");
+    assert_eq!(serialize(&'a').expect("should serialize"), r#""a""#);
+}
+
+#[cfg(not(feature = "arbitrary-precision"))]
+#[test]
+fn float_always_has_fraction() {
+    assert_eq!(serialize(&1.0_f64).expect("should serialize"), "1.0");
+    assert_eq!(serialize(&1e300_f64).expect("should serialize"), "1.0e300");
+    assert_eq!(
+        serialize(&-2.5e-10_f64).expect("should serialize"),
+        "-2.5e-10"
+    );
+}
+
+#[test]
+fn string_escape_ok() {
+    assert_eq!(
+        serialize("a\"b\\c\nd\u{01}é").expect("should serialize"),
+        r#""a\"b\\c\nd\u0001é""#
+    );
+}
+
+#[test]
+fn composite_ok() {
+    assert_eq!(
+        serialize(&vec![1_u8, 2, 3]).expect("should serialize"),
+        "[1,2,3]"
+    );
+    assert_eq!(
+        serialize(&(1_u8, "a", [true, false])).expect("should serialize"),
+        r#"[1,"a",[true,false]]"#
+    );
+    assert_eq!(
+        serialize(&BTreeMap::from([(1_u8, "a"), (2, "b")])).expect("should serialize"),
+        r#"{"1":"a","2":"b"}"#
+    );
+    assert_eq!(
+        serialize(&Example {
+            a: 1,
+            b: vec!["b".to_owned()],
+            c: None
+        })
+        .expect("should serialize"),
+        r#"{"a":1,"b":["b"],"c":null}"#
+    );
+}
+
+#[test]
+fn round_trip_ok() {
+    assert_round_trip(&u64::MAX);
+    assert_round_trip(&i64::MIN);
+    assert_round_trip(&0.1_f64);
+    assert_round_trip(&-1e300_f64);
+    assert_round_trip(&"tab\tquote\"unicode\u{1F600}".to_owned());
+    assert_round_trip(&vec![Some(1_u16), None]);
+    assert_round_trip(&(1_u8, 'c', false));
+    assert_round_trip(&BTreeMap::from([
+        ("a".to_owned(), vec![1_i32, -1]),
+        ("b".to_owned(), vec![]),
+    ]));
+}
+
+#[test]
+fn non_finite_err() {
+    let report = serialize(&vec![1.0, f64::NAN]).expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer-json",
+            "id": ["number", "non-finite"],
+            "properties": {
+                "location": [{"type": "array", "value": 1}],
+                "received": "NaN"
+            }
+        }])
+    );
+}
+
+#[test]
+fn key_must_be_string_err() {
+    let report = serialize(&BTreeMap::from([((1_u8, 2_u8), 3_u8)])).expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer-json",
+            "id": ["object", "key"],
+            "properties": {
+                "location": []
+            }
+        }])
+    );
+}
+
+#[test]
+fn fail_slow_err() {
+    let report = serialize(&Example {
+        a: 1,
+        b: vec![],
+        c: Some(f64::INFINITY),
+    })
+    .expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer-json",
+            "id": ["number", "non-finite"],
+            "properties": {
+                "location": [{"type": "field", "value": "c"}, {"type": "variant", "value": "Some"}],
+                "received": "inf"
+            }
+        }])
+    );
+
+    let report = serialize(&[f64::NAN, 1.0, f64::INFINITY]).expect_err("should fail");
+    let errors = errors(report);
+
+    assert_eq!(errors.as_array().expect("should be an array").len(), 2);
+}
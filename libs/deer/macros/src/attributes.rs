@@ -0,0 +1,160 @@
+use syn::{meta::ParseNestedMeta, Attribute, Error, LitStr, Path, Result};
+
+use crate::case::RenameRule;
+
+fn set<T>(meta: &ParseNestedMeta, slot: &mut Option<T>, value: T) -> Result<()> {
+    if slot.is_some() {
+        let name = meta
+            .path
+            .get_ident()
+            .map_or_else(String::new, ToString::to_string);
+
+        return Err(meta.error(format!("duplicate `deer({name})` attribute")));
+    }
+
+    *slot = Some(value);
+
+    Ok(())
+}
+
+fn parse_string(meta: &ParseNestedMeta) -> Result<String> {
+    let value: LitStr = meta.value()?.parse()?;
+
+    Ok(value.value())
+}
+
+fn parse(attrs: &[Attribute], mut visit: impl FnMut(&ParseNestedMeta) -> Result<()>) -> Result<()> {
+    let mut errors: Option<Error> = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("deer")) {
+        if let Err(error) = attr.parse_nested_meta(|meta| visit(&meta)) {
+            match &mut errors {
+                Some(errors) => errors.combine(error),
+                errors => *errors = Some(error),
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Attributes on the type deriving `Deserialize`.
+#[derive(Default)]
+pub(crate) struct ContainerAttributes {
+    pub(crate) rename_all: Option<RenameRule>,
+    pub(crate) deny_unknown_fields: bool,
+    pub(crate) tag: Option<String>,
+    pub(crate) content: Option<String>,
+    pub(crate) untagged: bool,
+}
+
+impl ContainerAttributes {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut rename_all = None;
+        let mut deny_unknown_fields = None;
+        let mut tag = None;
+        let mut content = None;
+        let mut untagged = None;
+
+        parse(attrs, |meta| {
+            if meta.path.is_ident("rename_all") {
+                let value: LitStr = meta.value()?.parse()?;
+                set(meta, &mut rename_all, RenameRule::parse(&value)?)
+            } else if meta.path.is_ident("deny_unknown_fields") {
+                set(meta, &mut deny_unknown_fields, ())
+            } else if meta.path.is_ident("tag") {
+                set(meta, &mut tag, parse_string(meta)?)
+            } else if meta.path.is_ident("content") {
+                set(meta, &mut content, parse_string(meta)?)
+            } else if meta.path.is_ident("untagged") {
+                set(meta, &mut untagged, ())
+            } else {
+                Err(meta.error("unknown deer container attribute"))
+            }
+        })?;
+
+        Ok(Self {
+            rename_all,
+            deny_unknown_fields: deny_unknown_fields.is_some(),
+            tag,
+            content,
+            untagged: untagged.is_some(),
+        })
+    }
+}
+
+/// Value used for a field which is not present.
+pub(crate) enum DefaultValue {
+    /// Use [`Default::default`]
+    Default,
+    /// Call the function at the path
+    Path(Path),
+}
+
+/// Attributes on a field of a struct or struct variant.
+#[derive(Default)]
+pub(crate) struct FieldAttributes {
+    pub(crate) rename: Option<String>,
+    pub(crate) default: Option<DefaultValue>,
+    pub(crate) flatten: bool,
+}
+
+impl FieldAttributes {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut rename = None;
+        let mut default = None;
+        let mut flatten = None;
+
+        parse(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                set(meta, &mut rename, parse_string(meta)?)
+            } else if meta.path.is_ident("default") {
+                let value = if meta.input.peek(syn::Token![=]) {
+                    let path: LitStr = meta.value()?.parse()?;
+                    DefaultValue::Path(path.parse()?)
+                } else {
+                    DefaultValue::Default
+                };
+
+                set(meta, &mut default, value)
+            } else if meta.path.is_ident("flatten") {
+                set(meta, &mut flatten, ())
+            } else {
+                Err(meta.error("unknown deer field attribute"))
+            }
+        })?;
+
+        Ok(Self {
+            rename,
+            default,
+            flatten: flatten.is_some(),
+        })
+    }
+}
+
+/// Attributes on an enum variant.
+#[derive(Default)]
+pub(crate) struct VariantAttributes {
+    pub(crate) rename: Option<String>,
+    pub(crate) rename_all: Option<RenameRule>,
+}
+
+impl VariantAttributes {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut rename = None;
+        let mut rename_all = None;
+
+        parse(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                set(meta, &mut rename, parse_string(meta)?)
+            } else if meta.path.is_ident("rename_all") {
+                let value: LitStr = meta.value()?.parse()?;
+                set(meta, &mut rename_all, RenameRule::parse(&value)?)
+            } else {
+                Err(meta.error("unknown deer variant attribute"))
+            }
+        })?;
+
+        Ok(Self { rename, rename_all })
+    }
+}
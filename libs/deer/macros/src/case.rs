@@ -0,0 +1,109 @@
+use syn::{Error, LitStr, Result};
+
+/// Naming convention used by `#[deer(rename_all = "...")]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    const RULES: [(&'static str, Self); 8] = [
+        ("lowercase", Self::Lower),
+        ("UPPERCASE", Self::Upper),
+        ("Pascal", Self::Pascal),
+        ("camelCase", Self::Camel),
+        ("snake_case", Self::Snake),
+        ("SCREAMING_SNAKE_CASE", Self::ScreamingSnake),
+        ("kebab-case", Self::Kebab),
+        ("SCREAMING-KEBAB-CASE", Self::ScreamingKebab),
+    ];
+
+    pub(crate) fn parse(value: &LitStr) -> Result<Self> {
+        let name = value.value();
+
+        Self::RULES
+            .iter()
+            .find(|(rule, _)| *rule == name)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let expected: Vec<_> = Self::RULES
+                    .iter()
+                    .map(|(rule, _)| format!("\"{rule}\""))
+                    .collect();
+
+                Error::new(
+                    value.span(),
+                    format!(
+                        "unknown rename rule `{name}`, expected one of {}",
+                        expected.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// Apply the rule to the name of an enum variant, which is expected to be `Pascal`.
+    pub(crate) fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_owned(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+
+                chars.next().map_or_else(String::new, |first| {
+                    format!("{}{}", first.to_ascii_lowercase(), chars.as_str())
+                })
+            }
+            Self::Snake => {
+                let mut snake = String::with_capacity(variant.len());
+
+                for (index, char) in variant.char_indices() {
+                    if index > 0 && char.is_uppercase() {
+                        snake.push('_');
+                    }
+
+                    snake.push(char.to_ascii_lowercase());
+                }
+
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply_to_variant(variant).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Apply the rule to the name of a field, which is expected to be `snake_case`.
+    pub(crate) fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => field
+                .split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+
+                    chars.next().map_or_else(String::new, |first| {
+                        format!("{}{}", first.to_ascii_uppercase(), chars.as_str())
+                    })
+                })
+                .collect(),
+            Self::Camel => {
+                let pascal = Self::Pascal.apply_to_field(field);
+
+                Self::Camel.apply_to_variant(&pascal)
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
@@ -0,0 +1,375 @@
+mod enums;
+mod fields;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, GenericParam, Generics, Ident, Lifetime, LitByteStr, Result, WherePredicate,
+};
+
+use crate::input::{Body, Container, Style};
+
+/// Shared state of a single expansion.
+pub(crate) struct Context<'a> {
+    container: &'a Container<'a>,
+    /// Generics of the container, where every type parameter is bound by `DeserializeOwned`
+    generics: Generics,
+}
+
+impl<'a> Context<'a> {
+    fn new(container: &'a Container<'a>) -> Self {
+        let mut generics = container.generics.clone();
+
+        let predicates: Vec<WherePredicate> = generics
+            .type_params()
+            .map(|param| {
+                let ident = &param.ident;
+                parse_quote!(#ident: for<'__de> ::deer::Deserialize<'__de> + 'static)
+            })
+            .collect();
+
+        generics.make_where_clause().predicates.extend(predicates);
+
+        Self {
+            container,
+            generics,
+        }
+    }
+
+    /// The generics of the container, prefixed with additional lifetimes.
+    fn with_lifetimes(&self, lifetimes: &[&str]) -> Generics {
+        let mut generics = self.generics.clone();
+
+        for (index, lifetime) in lifetimes.iter().enumerate() {
+            let lifetime = Lifetime::new(lifetime, Span::call_site());
+            generics
+                .params
+                .insert(index, GenericParam::Lifetime(parse_quote!(#lifetime)));
+        }
+
+        generics
+    }
+
+    /// The type of the container, e.g. `Example<T>`
+    fn ty(&self) -> TokenStream {
+        let ident = self.container.ident;
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        quote!(#ident #ty_generics)
+    }
+
+    /// Declare a zero-sized helper type, which is generic over the generics of the container.
+    fn marker(&self, ident: &Ident) -> TokenStream {
+        let ty = self.ty();
+        let (impl_generics, _, where_clause) = self.generics.split_for_impl();
+
+        quote! {
+            struct #ident #impl_generics (
+                ::core::marker::PhantomData<fn() -> *const #ty>
+            ) #where_clause;
+        }
+    }
+
+    /// Construct a helper type declared with [`Self::marker`].
+    fn instantiate(&self, ident: &Ident) -> TokenStream {
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let turbofish = ty_generics.as_turbofish();
+
+        quote!(#ident #turbofish (::core::marker::PhantomData))
+    }
+}
+
+/// Combine multiple results into a single one, if multiple results failed all errors are kept.
+///
+/// Returns the expression and the pattern to destructure the value.
+fn fold(
+    results: impl IntoIterator<Item = (TokenStream, TokenStream)>,
+) -> (TokenStream, TokenStream) {
+    let mut results = results.into_iter();
+    let Some(init) = results.next() else {
+        return (quote!(::core::result::Result::Ok(())), quote!(()));
+    };
+
+    results.fold(init, |(expr, pattern), (next_expr, next_pattern)| {
+        (
+            quote!(::deer::__private::fold(#expr, #next_expr)),
+            quote!((#pattern, #next_pattern)),
+        )
+    })
+}
+
+/// How an identifier should handle values it does not know.
+pub(crate) enum Unknown {
+    /// Error out with an `UnknownFieldError`
+    DenyField,
+    /// Error out with an `UnknownVariantError`
+    DenyVariant,
+    /// Use the `__ignore` variant
+    Ignore,
+    /// Keep the received key in the `__other` variant
+    Collect,
+}
+
+/// Generate an enum used to deserialize the names of fields or variants.
+///
+/// Variants are named `{prefix}{index}` and can be deserialized from their name (as `str` or
+/// bytes) or their index.
+#[allow(clippy::too_many_lines)]
+fn identifier(ident: &Ident, prefix: &str, names: &[&str], unknown: &Unknown) -> TokenStream {
+    let variants: Vec<_> = (0..names.len())
+        .map(|index| format_ident!("{}{}", prefix, index))
+        .collect();
+    let bytes: Vec<_> = names
+        .iter()
+        .map(|name| LitByteStr::new(name.as_bytes(), Span::call_site()))
+        .collect();
+    let indices = (0..names.len()).map(|index| index as u64);
+
+    let (extra, unknown_str, unknown_bytes, unknown_u64) = match unknown {
+        Unknown::DenyField | Unknown::DenyVariant => {
+            let (error, expected, received) = if matches!(unknown, Unknown::DenyField) {
+                (
+                    quote!(UnknownFieldError),
+                    quote!(ExpectedField),
+                    quote!(ReceivedField),
+                )
+            } else {
+                (
+                    quote!(UnknownVariantError),
+                    quote!(ExpectedVariant),
+                    quote!(ReceivedVariant),
+                )
+            };
+
+            let report = quote! {
+                ::deer::export::error_stack::Report::new(::deer::error::#error.into_error())
+                    #(.attach(::deer::error::#expected::new(#names)))*
+            };
+
+            (
+                quote!(),
+                quote! {
+                    Err(#report
+                        .attach(::deer::error::#received::new(value))
+                        .change_context(::deer::error::VisitorError))
+                },
+                quote! {{
+                    let mut error = #report;
+
+                    if let Ok(value) = ::core::str::from_utf8(value) {
+                        error = error.attach(::deer::error::#received::new(value));
+                    }
+
+                    Err(error.change_context(::deer::error::VisitorError))
+                }},
+                quote!(Err(#report.change_context(::deer::error::VisitorError))),
+            )
+        }
+        Unknown::Ignore => (
+            quote!(__ignore,),
+            quote!(Ok(#ident::__ignore)),
+            quote!(Ok(#ident::__ignore)),
+            quote!(Ok(#ident::__ignore)),
+        ),
+        Unknown::Collect => (
+            quote!(__other(::deer::value::Content),),
+            quote! {
+                Ok(#ident::__other(::deer::value::Content::String(
+                    ::deer::export::alloc::string::String::from(value)
+                )))
+            },
+            quote!(Ok(#ident::__other(::deer::value::Content::Bytes(value.to_vec())))),
+            quote! {
+                Ok(#ident::__other(::deer::value::Content::Number(::deer::Number::from(value))))
+            },
+        ),
+    };
+
+    quote! {
+        #[allow(non_camel_case_types)]
+        enum #ident {
+            #(#variants,)*
+            #extra
+        }
+
+        impl ::deer::Reflection for #ident {
+            fn schema(_: &mut ::deer::Document) -> ::deer::Schema {
+                ::deer::Schema::new("string").with("enum", [#(#names),*])
+            }
+        }
+
+        impl<'de> ::deer::Deserialize<'de> for #ident {
+            type Reflection = Self;
+
+            fn deserialize<D>(
+                deserializer: D,
+            ) -> ::deer::export::error_stack::Result<Self, ::deer::error::DeserializeError>
+            where
+                D: ::deer::Deserializer<'de>,
+            {
+                struct IdentifierVisitor;
+
+                impl<'de> ::deer::IdentifierVisitor<'de> for IdentifierVisitor {
+                    type Value = #ident;
+
+                    fn expecting(&self) -> ::deer::Document {
+                        <#ident as ::deer::Reflection>::document()
+                    }
+
+                    fn visit_str(
+                        self,
+                        value: &str,
+                    ) -> ::deer::export::error_stack::Result<Self::Value, ::deer::error::VisitorError> {
+                        match value {
+                            #(#names => Ok(#ident::#variants),)*
+                            value => #unknown_str,
+                        }
+                    }
+
+                    fn visit_bytes(
+                        self,
+                        value: &[u8],
+                    ) -> ::deer::export::error_stack::Result<Self::Value, ::deer::error::VisitorError> {
+                        match value {
+                            #(#bytes => Ok(#ident::#variants),)*
+                            value => #unknown_bytes,
+                        }
+                    }
+
+                    fn visit_u64(
+                        self,
+                        value: u64,
+                    ) -> ::deer::export::error_stack::Result<Self::Value, ::deer::error::VisitorError> {
+                        match value {
+                            #(#indices => Ok(#ident::#variants),)*
+                            value => #unknown_u64,
+                        }
+                    }
+                }
+
+                deserializer
+                    .deserialize_identifier(IdentifierVisitor)
+                    .change_context(::deer::error::DeserializeError)
+            }
+        }
+    }
+}
+
+fn expand_struct(cx: &Context, style: Style, fields: &[crate::input::Field]) -> TokenStream {
+    let ident = cx.container.ident;
+    let ty = cx.ty();
+    let de_generics = cx.with_lifetimes(&["'de"]);
+    let (de_impl_generics, ..) = de_generics.split_for_impl();
+    let (impl_generics, _, where_clause) = cx.generics.split_for_impl();
+
+    match style {
+        Style::Unit => quote! {
+            impl #de_impl_generics ::deer::Deserialize<'de> for #ty #where_clause {
+                type Reflection = <() as ::deer::Deserialize<'de>>::Reflection;
+
+                fn deserialize<D>(
+                    deserializer: D,
+                ) -> ::deer::export::error_stack::Result<Self, ::deer::error::DeserializeError>
+                where
+                    D: ::deer::Deserializer<'de>,
+                {
+                    <() as ::deer::Deserialize<'de>>::deserialize(deserializer).map(|()| #ident)
+                }
+            }
+        },
+        Style::Newtype => {
+            let field_ty = fields[0].ty;
+
+            quote! {
+                impl #de_impl_generics ::deer::Deserialize<'de> for #ty #where_clause {
+                    type Reflection = <#field_ty as ::deer::Deserialize<'de>>::Reflection;
+
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> ::deer::export::error_stack::Result<Self, ::deer::error::DeserializeError>
+                    where
+                        D: ::deer::Deserializer<'de>,
+                    {
+                        <#field_ty as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                            .map(|value| #ident(value))
+                    }
+                }
+            }
+        }
+        Style::Tuple | Style::Named => {
+            let visitor = format_ident!("__Visitor");
+            let expecting = quote!(<#ty as ::deer::Reflection>::document());
+
+            let (items, deserialize, schema) = if style == Style::Tuple {
+                (
+                    fields::tuple(cx, &visitor, fields, &quote!(#ident), &expecting),
+                    quote!(deserialize_array),
+                    fields::array_schema(fields),
+                )
+            } else {
+                (
+                    fields::named(cx, &fields::Named {
+                        prefix: "__",
+                        fields,
+                        construct: quote!(#ident),
+                        expecting,
+                        deny_unknown_fields: cx.container.deny_unknown_fields,
+                    }),
+                    quote!(deserialize_struct),
+                    fields::object_schema(fields, cx.container.deny_unknown_fields),
+                )
+            };
+            let visitor = cx.instantiate(&visitor);
+
+            quote! {
+                #items
+
+                impl #impl_generics ::deer::Reflection for #ty #where_clause {
+                    fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                        #schema
+                    }
+                }
+
+                impl #de_impl_generics ::deer::Deserialize<'de> for #ty #where_clause {
+                    type Reflection = Self;
+
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> ::deer::export::error_stack::Result<Self, ::deer::error::DeserializeError>
+                    where
+                        D: ::deer::Deserializer<'de>,
+                    {
+                        deserializer
+                            .#deserialize(#visitor)
+                            .change_context(::deer::error::DeserializeError)
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn derive_deserialize(input: &syn::DeriveInput) -> Result<TokenStream> {
+    let container = Container::parse(input)?;
+    let cx = Context::new(&container);
+
+    let body = match &container.body {
+        Body::Struct(style, fields) => expand_struct(&cx, *style, fields),
+        Body::Enum(variants) => enums::expand(&cx, variants),
+    };
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals, unused_qualifications, clippy::all)]
+        const _: () = {
+            #[allow(unused_imports)]
+            use ::deer::export::error_stack::ResultExt as _;
+            #[allow(unused_imports)]
+            use ::deer::error::Variant as _;
+            #[allow(unused_imports)]
+            use ::deer::{ArrayAccess as _, Deserializer as _, ObjectAccess as _};
+
+            #body
+        };
+    })
+}
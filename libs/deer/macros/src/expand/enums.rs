@@ -0,0 +1,554 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use super::{
+    fields::{self, array_schema, object_schema},
+    identifier, Context, Unknown,
+};
+use crate::input::{Style, Tagging, Variant};
+
+/// How a unit variant is deserialized.
+#[derive(Copy, Clone)]
+enum Unit {
+    /// No value is present, used if the variant is the key of an object or the value of a tag
+    ExpectNone,
+    /// The variant is represented as `null`
+    Null,
+    /// Any remaining fields are ignored
+    Ignore,
+}
+
+struct Names {
+    discriminant: Ident,
+    visitor: Ident,
+    reflection: Ident,
+    content_reflection: Ident,
+    name_reflection: Ident,
+}
+
+impl Names {
+    fn new(index: usize) -> Self {
+        Self {
+            discriminant: format_ident!("__variant{}", index),
+            visitor: format_ident!("__Variant{}Visitor", index),
+            reflection: format_ident!("__Variant{}Reflection", index),
+            content_reflection: format_ident!("__Variant{}ContentReflection", index),
+            name_reflection: format_ident!("__Variant{}NameReflection", index),
+        }
+    }
+}
+
+/// Generate the visitors used to deserialize the fields of every variant.
+fn visitors(cx: &Context, variants: &[Variant]) -> TokenStream {
+    let ident = cx.container.ident;
+    let (_, ty_generics, _) = cx.generics.split_for_impl();
+
+    variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| {
+            let variant_ident = variant.ident;
+            let names = Names::new(index);
+            let reflection = &names.content_reflection;
+            let expecting = quote!(<#reflection #ty_generics as ::deer::Reflection>::document());
+
+            match variant.style {
+                Style::Unit | Style::Newtype => quote!(),
+                Style::Tuple => fields::tuple(
+                    cx,
+                    &names.visitor,
+                    &variant.fields,
+                    &quote!(#ident::#variant_ident),
+                    &expecting,
+                ),
+                Style::Named => fields::named(cx, &fields::Named {
+                    prefix: &format!("__Variant{index}"),
+                    fields: &variant.fields,
+                    construct: quote!(#ident::#variant_ident),
+                    expecting,
+                    deny_unknown_fields: cx.container.deny_unknown_fields,
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Expression deserializing the content of a variant from the local `deserializer`.
+fn payload(cx: &Context, index: usize, variant: &Variant, unit: Unit) -> TokenStream {
+    let ident = cx.container.ident;
+    let variant_ident = variant.ident;
+    let names = Names::new(index);
+
+    match variant.style {
+        Style::Unit => {
+            let ty = match unit {
+                Unit::ExpectNone => quote!(::deer::helpers::ExpectNone),
+                Unit::Null => quote!(()),
+                Unit::Ignore => quote!(::deer::value::Content),
+            };
+
+            quote! {
+                <#ty as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                    .map(|_| #ident::#variant_ident)
+                    .change_context(::deer::error::VisitorError)
+            }
+        }
+        Style::Newtype => {
+            let ty = variant.fields[0].ty;
+
+            quote! {
+                <#ty as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                    .map(#ident::#variant_ident)
+                    .change_context(::deer::error::VisitorError)
+            }
+        }
+        Style::Tuple | Style::Named => {
+            let method = if variant.style == Style::Tuple {
+                quote!(deserialize_array)
+            } else {
+                quote!(deserialize_struct)
+            };
+            let visitor = cx.instantiate(&names.visitor);
+
+            quote! {
+                ::deer::Deserializer::#method(deserializer, #visitor)
+                    .change_context(::deer::error::VisitorError)
+            }
+        }
+    }
+}
+
+/// Match arms from the discriminant to the deserialization of the variant.
+fn arms(cx: &Context, variants: &[Variant], unit: Unit, location: bool) -> Vec<TokenStream> {
+    variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| {
+            let discriminant = Names::new(index).discriminant;
+            let payload = payload(cx, index, variant, unit);
+            let name = &variant.name;
+
+            if location {
+                quote! {
+                    __Variant::#discriminant => #payload
+                        .attach(::deer::error::Location::Variant(#name)),
+                }
+            } else {
+                quote!(__Variant::#discriminant => #payload,)
+            }
+        })
+        .collect()
+}
+
+/// Reference to the schema of the content of a variant.
+fn payload_reflection(cx: &Context, index: usize, variant: &Variant) -> TokenStream {
+    let (_, ty_generics, _) = cx.generics.split_for_impl();
+
+    match variant.style {
+        Style::Unit => quote!(doc.add::<<() as ::deer::Deserialize<'static>>::Reflection>()),
+        Style::Newtype => {
+            let ty = variant.fields[0].ty;
+            quote!(doc.add::<<#ty as ::deer::Deserialize<'static>>::Reflection>())
+        }
+        Style::Tuple | Style::Named => {
+            let reflection = Names::new(index).content_reflection;
+            quote!(doc.add::<#reflection #ty_generics>())
+        }
+    }
+}
+
+/// Declare a type implementing `Reflection`, which is generic over the generics of the container.
+fn reflection_type(cx: &Context, ident: &Ident, schema: &TokenStream) -> TokenStream {
+    let marker = cx.marker(ident);
+    let (impl_generics, ty_generics, where_clause) = cx.generics.split_for_impl();
+
+    quote! {
+        #marker
+
+        impl #impl_generics ::deer::Reflection for #ident #ty_generics #where_clause {
+            fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                #schema
+            }
+        }
+    }
+}
+
+/// Generate the types implementing `Reflection` for every variant and return the schema of the
+/// enum.
+#[allow(clippy::too_many_lines)]
+fn reflection(cx: &Context, variants: &[Variant]) -> (TokenStream, TokenStream) {
+    let (_, ty_generics, _) = cx.generics.split_for_impl();
+    let mut items = TokenStream::new();
+    let mut references = Vec::new();
+
+    for (index, variant) in variants.iter().enumerate() {
+        let names = Names::new(index);
+        let name = &variant.name;
+        let name_reflection = &names.name_reflection;
+        let unit = variant.style == Style::Unit;
+
+        items.extend(quote! {
+            struct #name_reflection;
+
+            impl ::deer::Reflection for #name_reflection {
+                fn schema(_: &mut ::deer::Document) -> ::deer::Schema {
+                    ::deer::Schema::new("string").with("const", #name)
+                }
+            }
+        });
+
+        let content_schema = match variant.style {
+            Style::Unit | Style::Newtype => None,
+            Style::Tuple => Some(array_schema(&variant.fields)),
+            Style::Named => Some(object_schema(
+                &variant.fields,
+                cx.container.deny_unknown_fields,
+            )),
+        };
+
+        if let Some(schema) = content_schema {
+            items.extend(reflection_type(cx, &names.content_reflection, &schema));
+        }
+
+        let payload = payload_reflection(cx, index, variant);
+
+        let schema = match &cx.container.tagging {
+            Tagging::External if unit => None,
+            Tagging::External => Some(quote! {
+                ::deer::Schema::new("object")
+                    .with("properties", ::deer::helpers::Properties([(#name, #payload)]))
+                    .with("required", [#name])
+                    .with("additionalProperties", false)
+            }),
+            Tagging::Internal { tag } => {
+                let schema = quote! {
+                    ::deer::Schema::new("object")
+                        .with(
+                            "properties",
+                            ::deer::helpers::Properties([(#tag, doc.add::<#name_reflection>())]),
+                        )
+                        .with("required", [#tag])
+                };
+
+                Some(if unit {
+                    schema
+                } else {
+                    quote!(#schema.with("allOf", [#payload]))
+                })
+            }
+            Tagging::Adjacent { tag, content } => Some(if unit {
+                quote! {
+                    ::deer::Schema::new("object")
+                        .with(
+                            "properties",
+                            ::deer::helpers::Properties([(#tag, doc.add::<#name_reflection>())]),
+                        )
+                        .with("required", [#tag])
+                        .with("additionalProperties", false)
+                }
+            } else {
+                quote! {
+                    ::deer::Schema::new("object")
+                        .with(
+                            "properties",
+                            ::deer::helpers::Properties([
+                                (#tag, doc.add::<#name_reflection>()),
+                                (#content, #payload),
+                            ]),
+                        )
+                        .with("required", [#tag, #content])
+                        .with("additionalProperties", false)
+                }
+            }),
+            Tagging::Untagged => None,
+        };
+
+        let reference = match (&cx.container.tagging, schema) {
+            (_, Some(schema)) => {
+                let reflection = &names.reflection;
+                items.extend(reflection_type(cx, reflection, &schema));
+
+                quote!(doc.add::<#reflection #ty_generics>())
+            }
+            (Tagging::Untagged, None) => payload,
+            (_, None) => quote!(doc.add::<#name_reflection>()),
+        };
+
+        references.push(reference);
+    }
+
+    let all_unit = variants.iter().all(|variant| variant.style == Style::Unit);
+    let any_unit = variants.iter().any(|variant| variant.style == Style::Unit);
+
+    let schema = match &cx.container.tagging {
+        Tagging::External if all_unit => {
+            let names = variants.iter().map(|variant| &variant.name);
+
+            quote!(::deer::Schema::new("string").with("enum", [#(#names),*]))
+        }
+        Tagging::External if any_unit => {
+            quote!(::deer::Schema::new("any").with("oneOf", [#(#references),*]))
+        }
+        Tagging::Untagged => quote!(::deer::Schema::new("any").with("oneOf", [#(#references),*])),
+        Tagging::External | Tagging::Internal { .. } | Tagging::Adjacent { .. } => {
+            quote!(::deer::Schema::new("object").with("oneOf", [#(#references),*]))
+        }
+    };
+
+    (items, schema)
+}
+
+/// Body of `visit_object` for internally and adjacently tagged enums.
+fn visit_tagged(
+    cx: &Context,
+    variants: &[Variant],
+    tag: &str,
+    content: Option<&str>,
+) -> TokenStream {
+    let ty = cx.ty();
+
+    let (unit, extract, location) = content.map_or_else(
+        || (Unit::Ignore, quote!(), quote!()),
+        |content| {
+            (
+                Unit::ExpectNone,
+                quote! {
+                    let content = content
+                        .remove_field(#content)
+                        .unwrap_or(::deer::value::Content::None);
+                },
+                quote!(.attach(::deer::error::Location::Field(#content))),
+            )
+        },
+    );
+
+    let arms = arms(cx, variants, unit, content.is_some());
+
+    quote! {
+        let value = ::deer::value::Content::collect_object(&mut object).and_then(|mut content| {
+            let context = object.context();
+
+            let tag = content.remove_field(#tag).ok_or_else(|| {
+                ::deer::export::error_stack::Report::new(::deer::error::MissingError.into_error())
+                    .attach(::deer::error::ExpectedType::new(
+                        <__Variant as ::deer::Deserialize<'de>>::reflection(),
+                    ))
+                    .attach(::deer::error::Location::Field(#tag))
+                    .change_context(::deer::error::ObjectAccessError)
+            })?;
+
+            let discriminant = <__Variant as ::deer::Deserialize<'de>>::deserialize(
+                ::deer::value::ContentDeserializer::new(tag, context),
+            )
+            .attach(::deer::error::Location::Field(#tag))
+            .change_context(::deer::error::ObjectAccessError)?;
+
+            #extract
+
+            let deserializer = ::deer::value::ContentDeserializer::new(content, context);
+
+            let value: ::deer::export::error_stack::Result<#ty, ::deer::error::VisitorError> =
+                match discriminant {
+                    #(#arms)*
+                };
+
+            value #location .change_context(::deer::error::ObjectAccessError)
+        });
+
+        let (value, ()) = ::deer::__private::fold(value, object.end())
+            .change_context(::deer::error::VisitorError)?;
+
+        Ok(value)
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn deserialize(cx: &Context, variants: &[Variant]) -> TokenStream {
+    let ty = cx.ty();
+    let de_generics = cx.with_lifetimes(&["'de"]);
+    let (de_impl_generics, ..) = de_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = cx.generics.split_for_impl();
+
+    let visitor = format_ident!("__Visitor");
+    let marker = cx.marker(&visitor);
+    let instance = cx.instantiate(&visitor);
+
+    let (items, body) = match &cx.container.tagging {
+        Tagging::External => {
+            let arms = arms(cx, variants, Unit::ExpectNone, true);
+
+            (
+                quote! {
+                    #marker
+
+                    impl #de_impl_generics ::deer::EnumVisitor<'de> for #visitor #ty_generics
+                        #where_clause
+                    {
+                        type Discriminant = __Variant;
+                        type Value = #ty;
+
+                        fn expecting(&self) -> ::deer::Document {
+                            <#ty as ::deer::Reflection>::document()
+                        }
+
+                        fn visit_value<D>(
+                            self,
+                            discriminant: Self::Discriminant,
+                            deserializer: D,
+                        ) -> ::deer::export::error_stack::Result<
+                            Self::Value,
+                            ::deer::error::VisitorError,
+                        >
+                        where
+                            D: ::deer::Deserializer<'de>,
+                        {
+                            match discriminant {
+                                #(#arms)*
+                            }
+                        }
+                    }
+                },
+                quote! {
+                    deserializer
+                        .deserialize_enum(#instance)
+                        .change_context(::deer::error::DeserializeError)
+                },
+            )
+        }
+        Tagging::Internal { tag } | Tagging::Adjacent { tag, .. } => {
+            let content = match &cx.container.tagging {
+                Tagging::Adjacent { content, .. } => Some(content.as_str()),
+                _ => None,
+            };
+            let visit_object = visit_tagged(cx, variants, tag, content);
+
+            (
+                quote! {
+                    #marker
+
+                    impl #de_impl_generics ::deer::Visitor<'de> for #visitor #ty_generics
+                        #where_clause
+                    {
+                        type Value = #ty;
+
+                        fn expecting(&self) -> ::deer::Document {
+                            <#ty as ::deer::Reflection>::document()
+                        }
+
+                        fn visit_object<A>(
+                            self,
+                            mut object: A,
+                        ) -> ::deer::export::error_stack::Result<
+                            Self::Value,
+                            ::deer::error::VisitorError,
+                        >
+                        where
+                            A: ::deer::ObjectAccess<'de>,
+                        {
+                            #visit_object
+                        }
+                    }
+                },
+                quote! {
+                    deserializer
+                        .deserialize_object(#instance)
+                        .change_context(::deer::error::DeserializeError)
+                },
+            )
+        }
+        Tagging::Untagged => {
+            let attempts = variants.iter().enumerate().map(|(index, variant)| {
+                let payload = payload(cx, index, variant, Unit::Null);
+
+                quote! {
+                    let deserializer = ::deer::value::ContentDeserializer::new(
+                        ::core::clone::Clone::clone(&content),
+                        &context,
+                    );
+
+                    let value: ::deer::export::error_stack::Result<
+                        #ty,
+                        ::deer::error::VisitorError,
+                    > = #payload;
+
+                    if let Ok(value) = value {
+                        return Ok(value);
+                    }
+                }
+            });
+
+            (quote!(), quote! {
+                // The context of the deserializer is no longer available once the content has
+                // been buffered.
+                let context = ::deer::Context::new();
+                let content =
+                    <::deer::value::Content as ::deer::Deserialize<'de>>::deserialize(deserializer)?;
+
+                #({ #attempts })*
+
+                Err(
+                    ::deer::export::error_stack::Report::new(::deer::error::TypeError.into_error())
+                        .attach(::deer::error::ExpectedType::new(
+                            <#ty as ::deer::Reflection>::document(),
+                        ))
+                        .attach(::deer::error::ReceivedType::new(content.received()))
+                        .change_context(::deer::error::DeserializeError),
+                )
+            })
+        }
+    };
+
+    quote! {
+        #items
+
+        impl #de_impl_generics ::deer::Deserialize<'de> for #ty #where_clause {
+            type Reflection = Self;
+
+            fn deserialize<D>(
+                deserializer: D,
+            ) -> ::deer::export::error_stack::Result<Self, ::deer::error::DeserializeError>
+            where
+                D: ::deer::Deserializer<'de>,
+            {
+                #body
+            }
+        }
+    }
+}
+
+pub(super) fn expand(cx: &Context, variants: &[Variant]) -> TokenStream {
+    let ty = cx.ty();
+    let (impl_generics, _, where_clause) = cx.generics.split_for_impl();
+
+    let names: Vec<_> = variants
+        .iter()
+        .map(|variant| variant.name.as_str())
+        .collect();
+    let identifier = identifier(
+        &format_ident!("__Variant"),
+        "__variant",
+        &names,
+        &Unknown::DenyVariant,
+    );
+
+    let visitors = visitors(cx, variants);
+    let (reflection_items, schema) = reflection(cx, variants);
+    let deserialize = deserialize(cx, variants);
+
+    quote! {
+        #identifier
+
+        #visitors
+
+        #reflection_items
+
+        impl #impl_generics ::deer::Reflection for #ty #where_clause {
+            fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                #schema
+            }
+        }
+
+        #deserialize
+    }
+}
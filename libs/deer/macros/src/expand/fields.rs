@@ -0,0 +1,409 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use super::{fold, identifier, Context, Unknown};
+use crate::{attributes::DefaultValue, input::Field};
+
+fn reflection(field: &Field) -> TokenStream {
+    let ty = field.ty;
+
+    quote!(doc.add::<<#ty as ::deer::Deserialize<'static>>::Reflection>())
+}
+
+/// Schema of an object containing `fields`.
+///
+/// The schema of flattened fields is added using `allOf`.
+pub(super) fn object_schema(fields: &[Field], deny_unknown_fields: bool) -> TokenStream {
+    let (flatten, fields): (Vec<_>, Vec<_>) = fields.iter().partition(|field| field.flatten);
+
+    let names = fields.iter().map(|field| &field.name);
+    let properties = fields.iter().map(|field| reflection(field));
+
+    let mut schema = quote! {
+        ::deer::Schema::new("object").with(
+            "properties",
+            ::deer::helpers::Properties([#((#names, #properties)),*]),
+        )
+    };
+
+    if !flatten.is_empty() {
+        let flatten = flatten.iter().map(|field| reflection(field));
+        schema = quote!(#schema.with("allOf", [#(#flatten),*]));
+    }
+
+    if deny_unknown_fields {
+        schema = quote!(#schema.with("additionalProperties", false));
+    }
+
+    schema
+}
+
+/// Schema of an array, where every item is a field.
+pub(super) fn array_schema(fields: &[Field]) -> TokenStream {
+    let items = fields.iter().map(reflection);
+
+    quote! {
+        ::deer::Schema::new("array")
+            .with("prefixItems", [#(#items),*])
+            .with("items", false)
+    }
+}
+
+/// Body of `visit_array`, which deserializes every field from an item of the array.
+fn visit_array(fields: &[Field], construct: &TokenStream) -> TokenStream {
+    let length = fields.len();
+    let slots: Vec<_> = (0..length)
+        .map(|index| format_ident!("__field{}", index))
+        .collect();
+    let members = fields.iter().map(|field| &field.member);
+
+    let values = fields
+        .iter()
+        .zip(&slots)
+        .enumerate()
+        .map(|(index, (field, slot))| {
+            let ty = field.ty;
+
+            quote! {
+                let #slot = array
+                    .next()
+                    .unwrap_or_else(|| {
+                        <#ty as ::deer::Deserialize<'de>>::deserialize(
+                            ::deer::value::NoneDeserializer::new(array.context()),
+                        )
+                        .change_context(::deer::error::ArrayAccessError)
+                    })
+                    .attach(::deer::error::Location::Tuple(#index));
+            }
+        });
+
+    let (result, pattern) = fold(
+        slots
+            .iter()
+            .map(|slot| (quote!(#slot), quote!(#slot)))
+            .chain([(quote!(array.end()), quote!(()))]),
+    );
+
+    quote! {
+        let mut array = ::deer::ArrayAccess::into_bound(array, #length)
+            .change_context(::deer::error::VisitorError)?;
+
+        #(#values)*
+
+        let #pattern = #result.change_context(::deer::error::VisitorError)?;
+
+        Ok(#construct { #(#members: #slots),* })
+    }
+}
+
+/// Generate a `Visitor`, which deserializes the tuple fields from an array.
+pub(super) fn tuple(
+    cx: &Context,
+    visitor: &Ident,
+    fields: &[Field],
+    construct: &TokenStream,
+    expecting: &TokenStream,
+) -> TokenStream {
+    let ty = cx.ty();
+    let marker = cx.marker(visitor);
+    let de_generics = cx.with_lifetimes(&["'de"]);
+    let (de_impl_generics, ..) = de_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = cx.generics.split_for_impl();
+
+    let body = visit_array(fields, construct);
+
+    quote! {
+        #marker
+
+        impl #de_impl_generics ::deer::Visitor<'de> for #visitor #ty_generics #where_clause {
+            type Value = #ty;
+
+            fn expecting(&self) -> ::deer::Document {
+                #expecting
+            }
+
+            fn visit_array<A>(
+                self,
+                array: A,
+            ) -> ::deer::export::error_stack::Result<Self::Value, ::deer::error::VisitorError>
+            where
+                A: ::deer::ArrayAccess<'de>,
+            {
+                #body
+            }
+        }
+    }
+}
+
+pub(super) struct Named<'a> {
+    /// Prefix of all generated items
+    pub(super) prefix: &'a str,
+    pub(super) fields: &'a [Field<'a>],
+    /// Path used to construct the value
+    pub(super) construct: TokenStream,
+    /// Expression returning the `Document` of the value
+    pub(super) expecting: TokenStream,
+    pub(super) deny_unknown_fields: bool,
+}
+
+/// Generate a `StructVisitor` named `{prefix}Visitor`, which deserializes named fields from an
+/// object or an array.
+///
+/// Arrays are only supported if none of the fields are flattened.
+#[allow(clippy::too_many_lines)]
+pub(super) fn named(cx: &Context, named: &Named) -> TokenStream {
+    let Named {
+        prefix,
+        fields,
+        construct,
+        expecting,
+        deny_unknown_fields,
+    } = named;
+
+    let ty = cx.ty();
+    let identifier_ident = format_ident!("{}Field", prefix);
+    let field_visitor = format_ident!("{}FieldVisitor", prefix);
+    let visitor = format_ident!("{}Visitor", prefix);
+
+    let flatten = fields.iter().any(|field| field.flatten);
+    let unknown = if flatten {
+        Unknown::Collect
+    } else if *deny_unknown_fields {
+        Unknown::DenyField
+    } else {
+        Unknown::Ignore
+    };
+
+    let slots: Vec<_> = (0..fields.len())
+        .map(|index| format_ident!("__field{}", index))
+        .collect();
+    let members = fields.iter().map(|field| &field.member);
+
+    // flattened fields are not part of the identifier
+    let keyed: Vec<_> = fields
+        .iter()
+        .zip(&slots)
+        .filter(|(field, _)| !field.flatten)
+        .collect();
+    let keys: Vec<_> = keyed.iter().map(|(field, _)| field.name.as_str()).collect();
+    let identifier = identifier(&identifier_ident, "__field", &keys, &unknown);
+
+    let slot_tys = keyed.iter().map(|(field, _)| field.ty);
+    let keyed_slots: Vec<_> = keyed.iter().map(|(_, slot)| *slot).collect();
+
+    let arms = keyed.iter().enumerate().map(|(index, (field, slot))| {
+        let ty = field.ty;
+        let name = &field.name;
+        let variant = format_ident!("__field{}", index);
+
+        quote! {
+            #identifier_ident::#variant => {
+                let value = <#ty as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                    .attach(::deer::error::Location::Field(#name))
+                    .change_context(::deer::error::VisitorError)?;
+
+                if self.#slot.is_some() {
+                    return Err(::deer::export::error_stack::Report::new(
+                        ::deer::error::DuplicateFieldError.into_error(),
+                    )
+                    .attach(::deer::error::DuplicateField::new(#name))
+                    .change_context(::deer::error::VisitorError));
+                }
+
+                *self.#slot = Some(value);
+
+                Ok(())
+            }
+        }
+    });
+
+    let unknown_arm = match unknown {
+        Unknown::Ignore => quote! {
+            #identifier_ident::__ignore => {
+                <::deer::value::Content as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                    .map(|_| ())
+                    .change_context(::deer::error::VisitorError)
+            }
+        },
+        Unknown::Collect => quote! {
+            #identifier_ident::__other(key) => {
+                let value =
+                    <::deer::value::Content as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                        .change_context(::deer::error::VisitorError)?;
+
+                self.__flatten.push((key, value));
+
+                Ok(())
+            }
+        },
+        Unknown::DenyField | Unknown::DenyVariant => quote!(),
+    };
+
+    let (flatten_field, flatten_slot, flatten_init) = if flatten {
+        (
+            quote! {
+                __flatten: &'__a mut ::deer::export::alloc::vec::Vec<(
+                    ::deer::value::Content,
+                    ::deer::value::Content,
+                )>,
+            },
+            quote!(__flatten: &mut __flatten,),
+            quote!(let mut __flatten = ::deer::export::alloc::vec::Vec::new();),
+        )
+    } else {
+        (quote!(), quote!(), quote!())
+    };
+
+    let values = fields.iter().zip(&slots).map(|(field, slot)| {
+        let ty = field.ty;
+        let name = &field.name;
+
+        if field.flatten {
+            return quote! {
+                let #slot = <#ty as ::deer::Deserialize<'de>>::deserialize(
+                    ::deer::value::ContentDeserializer::new(
+                        ::deer::value::Content::Object(__flatten.clone()),
+                        object.context(),
+                    ),
+                )
+                .change_context(::deer::error::ObjectAccessError);
+            };
+        }
+
+        let missing = match &field.default {
+            None => quote! {
+                <#ty as ::deer::Deserialize<'de>>::deserialize(
+                    ::deer::value::NoneDeserializer::new(object.context()),
+                )
+                .attach(::deer::error::Location::Field(#name))
+                .change_context(::deer::error::ObjectAccessError)
+            },
+            Some(DefaultValue::Default) => quote!(Ok(::core::default::Default::default())),
+            Some(DefaultValue::Path(path)) => quote!(Ok(#path())),
+        };
+
+        quote! {
+            let #slot = match #slot {
+                Some(value) => Ok(value),
+                None => #missing,
+            };
+        }
+    });
+
+    let (result, pattern) = fold(
+        slots
+            .iter()
+            .map(|slot| (quote!(#slot), quote!(#slot)))
+            .chain([
+                (quote!(__errors), quote!(())),
+                (quote!(object.end()), quote!(())),
+            ]),
+    );
+
+    let visit_array = if flatten {
+        quote!()
+    } else {
+        let body = visit_array(fields, construct);
+
+        quote! {
+            fn visit_array<A>(
+                self,
+                array: A,
+            ) -> ::deer::export::error_stack::Result<Self::Value, ::deer::error::VisitorError>
+            where
+                A: ::deer::ArrayAccess<'de>,
+            {
+                #body
+            }
+        }
+    };
+
+    let marker = cx.marker(&visitor);
+    let de_generics = cx.with_lifetimes(&["'de"]);
+    let (de_impl_generics, ..) = de_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = cx.generics.split_for_impl();
+
+    let a_generics = cx.with_lifetimes(&["'__a"]);
+    let (a_impl_generics, a_ty_generics, _) = a_generics.split_for_impl();
+    let de_a_generics = cx.with_lifetimes(&["'de", "'__a"]);
+    let (de_a_impl_generics, ..) = de_a_generics.split_for_impl();
+
+    quote! {
+        #identifier
+
+        struct #field_visitor #a_impl_generics #where_clause {
+            #(#keyed_slots: &'__a mut Option<#slot_tys>,)*
+            #flatten_field
+            __marker: ::core::marker::PhantomData<fn() -> *const #ty>,
+        }
+
+        impl #de_a_impl_generics ::deer::FieldVisitor<'de> for #field_visitor #a_ty_generics
+            #where_clause
+        {
+            type Key = #identifier_ident;
+            type Value = ();
+
+            fn visit_value<D>(
+                self,
+                key: Self::Key,
+                deserializer: D,
+            ) -> ::deer::export::error_stack::Result<Self::Value, ::deer::error::VisitorError>
+            where
+                D: ::deer::Deserializer<'de>,
+            {
+                match key {
+                    #(#arms)*
+                    #unknown_arm
+                }
+            }
+        }
+
+        #marker
+
+        impl #de_impl_generics ::deer::StructVisitor<'de> for #visitor #ty_generics #where_clause {
+            type Value = #ty;
+
+            fn expecting(&self) -> ::deer::Document {
+                #expecting
+            }
+
+            #visit_array
+
+            fn visit_object<A>(
+                self,
+                mut object: A,
+            ) -> ::deer::export::error_stack::Result<Self::Value, ::deer::error::VisitorError>
+            where
+                A: ::deer::ObjectAccess<'de>,
+            {
+                #(let mut #keyed_slots = None;)*
+                #flatten_init
+
+                let mut __errors: ::deer::export::error_stack::Result<
+                    (),
+                    ::deer::error::ObjectAccessError,
+                > = Ok(());
+
+                while let Some(field) = object.field(#field_visitor {
+                    #(#keyed_slots: &mut #keyed_slots,)*
+                    #flatten_slot
+                    __marker: ::core::marker::PhantomData,
+                }) {
+                    if let Err(error) = field {
+                        match &mut __errors {
+                            Err(errors) => errors.extend_one(error),
+                            errors => *errors = Err(error),
+                        }
+                    }
+                }
+
+                #(#values)*
+
+                let #pattern = #result.change_context(::deer::error::VisitorError)?;
+
+                Ok(#construct { #(#members: #slots),* })
+            }
+        }
+    }
+}
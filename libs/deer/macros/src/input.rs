@@ -0,0 +1,263 @@
+use syn::{
+    ext::IdentExt, spanned::Spanned, Data, DeriveInput, Error, Fields, GenericParam, Generics,
+    Ident, Index, Member, Result, Type,
+};
+
+use crate::{
+    attributes::{ContainerAttributes, DefaultValue, FieldAttributes, VariantAttributes},
+    case::RenameRule,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Style {
+    Unit,
+    Newtype,
+    Tuple,
+    Named,
+}
+
+pub(crate) struct Field<'a> {
+    pub(crate) member: Member,
+    /// Name of the field after applying `rename` or `rename_all`
+    pub(crate) name: String,
+    pub(crate) ty: &'a Type,
+    pub(crate) default: Option<DefaultValue>,
+    pub(crate) flatten: bool,
+}
+
+pub(crate) struct Variant<'a> {
+    pub(crate) ident: &'a Ident,
+    /// Name of the variant after applying `rename` or `rename_all`
+    pub(crate) name: String,
+    pub(crate) style: Style,
+    pub(crate) fields: Vec<Field<'a>>,
+}
+
+pub(crate) enum Body<'a> {
+    Struct(Style, Vec<Field<'a>>),
+    Enum(Vec<Variant<'a>>),
+}
+
+pub(crate) enum Tagging {
+    External,
+    Internal { tag: String },
+    Adjacent { tag: String, content: String },
+    Untagged,
+}
+
+pub(crate) struct Container<'a> {
+    pub(crate) ident: &'a Ident,
+    pub(crate) generics: &'a Generics,
+    pub(crate) deny_unknown_fields: bool,
+    pub(crate) tagging: Tagging,
+    pub(crate) body: Body<'a>,
+}
+
+struct Errors(Option<Error>);
+
+impl Errors {
+    fn push(&mut self, error: Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            errors => *errors = Some(error),
+        }
+    }
+
+    fn ok<T>(&mut self, result: Result<T>) -> Option<T> {
+        result.map_err(|error| self.push(error)).ok()
+    }
+}
+
+fn style(fields: &Fields) -> Style {
+    match fields {
+        Fields::Unit => Style::Unit,
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Style::Newtype,
+        Fields::Unnamed(_) => Style::Tuple,
+        Fields::Named(_) => Style::Named,
+    }
+}
+
+fn parse_fields<'a>(
+    fields: &'a Fields,
+    rename_all: Option<RenameRule>,
+    deny_unknown_fields: bool,
+    errors: &mut Errors,
+) -> Vec<Field<'a>> {
+    let style = style(fields);
+
+    fields
+        .iter()
+        .enumerate()
+        .filter_map(|(index, field)| {
+            let attributes = errors.ok(FieldAttributes::parse(&field.attrs))?;
+
+            if style != Style::Named {
+                if attributes.rename.is_some() {
+                    errors.push(Error::new(
+                        field.span(),
+                        "`deer(rename)` is only supported on named fields",
+                    ));
+                }
+
+                if attributes.default.is_some() {
+                    errors.push(Error::new(
+                        field.span(),
+                        "`deer(default)` is only supported on named fields",
+                    ));
+                }
+
+                if attributes.flatten {
+                    errors.push(Error::new(
+                        field.span(),
+                        "`deer(flatten)` is only supported on named fields",
+                    ));
+                }
+            }
+
+            if attributes.flatten && deny_unknown_fields {
+                errors.push(Error::new(
+                    field.span(),
+                    "`deer(flatten)` cannot be combined with `deer(deny_unknown_fields)`",
+                ));
+            }
+
+            let (member, name) = field.ident.as_ref().map_or_else(
+                || (Member::Unnamed(Index::from(index)), index.to_string()),
+                |ident| {
+                    let name = ident.unraw().to_string();
+                    let name = attributes.rename.clone().unwrap_or_else(|| {
+                        rename_all.map_or_else(|| name.clone(), |rule| rule.apply_to_field(&name))
+                    });
+
+                    (Member::Named(ident.clone()), name)
+                },
+            );
+
+            Some(Field {
+                member,
+                name,
+                ty: &field.ty,
+                default: attributes.default,
+                flatten: attributes.flatten,
+            })
+        })
+        .collect()
+}
+
+impl<'a> Container<'a> {
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn parse(input: &'a DeriveInput) -> Result<Self> {
+        let mut errors = Errors(None);
+
+        for param in &input.generics.params {
+            if let GenericParam::Lifetime(lifetime) = param {
+                errors.push(Error::new(
+                    lifetime.span(),
+                    "deer only deserializes owned data, lifetime parameters are not supported",
+                ));
+            }
+        }
+
+        let attributes = errors
+            .ok(ContainerAttributes::parse(&input.attrs))
+            .unwrap_or_default();
+
+        let tagging = match (attributes.tag, attributes.content, attributes.untagged) {
+            (None, None, false) => Tagging::External,
+            (Some(tag), None, false) => Tagging::Internal { tag },
+            (Some(tag), Some(content), false) => Tagging::Adjacent { tag, content },
+            (None, None, true) => Tagging::Untagged,
+            (None, Some(_), _) => {
+                errors.push(Error::new(
+                    input.span(),
+                    "`deer(content)` requires `deer(tag)` to be set",
+                ));
+                Tagging::External
+            }
+            (..) => {
+                errors.push(Error::new(
+                    input.span(),
+                    "`deer(untagged)` cannot be combined with `deer(tag)` or `deer(content)`",
+                ));
+                Tagging::External
+            }
+        };
+
+        let body = match &input.data {
+            Data::Struct(data) => {
+                if !matches!(tagging, Tagging::External) {
+                    errors.push(Error::new(
+                        input.span(),
+                        "`deer(tag)`, `deer(content)` and `deer(untagged)` are only supported on \
+                         enums",
+                    ));
+                }
+
+                Body::Struct(
+                    style(&data.fields),
+                    parse_fields(
+                        &data.fields,
+                        attributes.rename_all,
+                        attributes.deny_unknown_fields,
+                        &mut errors,
+                    ),
+                )
+            }
+            Data::Enum(data) => Body::Enum(
+                data.variants
+                    .iter()
+                    .filter_map(|variant| {
+                        let variant_attributes =
+                            errors.ok(VariantAttributes::parse(&variant.attrs))?;
+                        let style = style(&variant.fields);
+
+                        if style == Style::Tuple && matches!(tagging, Tagging::Internal { .. }) {
+                            errors.push(Error::new(
+                                variant.span(),
+                                "internally tagged enums do not support tuple variants",
+                            ));
+                        }
+
+                        let name = variant_attributes.rename.unwrap_or_else(|| {
+                            let name = variant.ident.unraw().to_string();
+
+                            attributes
+                                .rename_all
+                                .map_or_else(|| name.clone(), |rule| rule.apply_to_variant(&name))
+                        });
+
+                        Some(Variant {
+                            ident: &variant.ident,
+                            name,
+                            style,
+                            fields: parse_fields(
+                                &variant.fields,
+                                variant_attributes.rename_all,
+                                attributes.deny_unknown_fields,
+                                &mut errors,
+                            ),
+                        })
+                    })
+                    .collect(),
+            ),
+            Data::Union(_) => {
+                return Err(Error::new(
+                    input.span(),
+                    "`Deserialize` cannot be derived for unions",
+                ));
+            }
+        };
+
+        if let Some(error) = errors.0 {
+            return Err(error);
+        }
+
+        Ok(Self {
+            ident: &input.ident,
+            generics: &input.generics,
+            deny_unknown_fields: attributes.deny_unknown_fields,
+            tagging,
+            body,
+        })
+    }
+}
@@ -1,4 +1,41 @@
-//! Intentionally left blank for now!
+//! Derive macros for `deer`.
+//!
+//! `#[derive(Deserialize)]` implements `deer::Deserialize` and `deer::Reflection` for structs,
+//! tuple structs and enums. The behavior can be customized using `#[deer(...)]` attributes:
+//!
+//! ## Container attributes
+//!
+//! - `rename_all = "..."`: rename all fields (or variants of an enum) according to the given
+//!   convention, one of `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`,
+//!   `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` or `"SCREAMING-KEBAB-CASE"`.
+//! - `deny_unknown_fields`: error out on unknown fields instead of ignoring them.
+//! - `tag = "..."`: use the internally tagged representation for an enum, the name of the variant
+//!   is stored in the field `tag`, next to the fields of the variant.
+//! - `tag = "...", content = "..."`: use the adjacently tagged representation for an enum, the name
+//!   of the variant is stored in the field `tag`, while the content is stored in the field
+//!   `content`.
+//! - `untagged`: try to deserialize every variant of an enum in order, the first variant that
+//!   succeeds is used.
+//!
+//! ## Variant attributes
+//!
+//! - `rename = "..."`: use a different name for the variant.
+//! - `rename_all = "..."`: rename all fields of a struct variant.
+//!
+//! ## Field attributes
+//!
+//! - `rename = "..."`: use a different name for the field.
+//! - `default`: use [`Default::default`] if the field is not present.
+//! - `default = "path"`: call the function at `path` if the field is not present.
+//! - `flatten`: deserialize the field from all fields that are not otherwise known, cannot be
+//!   combined with `deny_unknown_fields`.
+//!
+//! Types with lifetime parameters are not supported, as `deer` only deserializes owned data. Every
+//! type parameter is required to implement `DeserializeOwned`.
+//!
+//! Internally tagged, adjacently tagged, untagged and flattened representations need to buffer
+//! the input, untagged enums are unable to pass the `Context` of the `Deserializer` to their
+//! variants.
 
 #![warn(
     missing_docs,
@@ -11,3 +48,23 @@
 #![allow(clippy::redundant_pub_crate)]
 #![allow(clippy::module_name_repetitions)]
 #![forbid(unsafe_code)]
+
+mod attributes;
+mod case;
+mod expand;
+mod input;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive `deer::Deserialize` and `deer::Reflection`.
+///
+/// See the [crate level documentation](crate) for the supported attributes.
+#[proc_macro_derive(Deserialize, attributes(deer))]
+pub fn derive_deserialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand::derive_deserialize(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
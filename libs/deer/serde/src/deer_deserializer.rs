@@ -0,0 +1,665 @@
+//! `serde` [`serde::Deserializer`] which is driven by a `deer` [`Deserializer`].
+use alloc::{string::String, vec::Vec};
+use core::cell::Cell;
+
+use deer::{
+    error::{
+        DeserializerError, ExpectedType, Location, ReceivedValue, ValueError, Variant, VisitorError,
+    },
+    value::{Content, ContentDeserializer, ContentReflection},
+    ArrayAccess, Deserializer, Document, EnumVisitor, FieldVisitor, IdentifierVisitor, Number,
+    ObjectAccess, OptionalVisitor, Reflection, StructVisitor, Visitor,
+};
+use error_stack::{Report, Result, ResultExt};
+use num_traits::ToPrimitive;
+use serde::de::{DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess};
+
+use crate::{error::Error, Serde};
+
+/// Convert the error returned by a `serde` visitor into a `deer` [`Report`].
+fn into_report(error: Error) -> Report<VisitorError> {
+    error.into_inner().change_context(VisitorError)
+}
+
+/// Combine the result of a visitor with the result of ending the access.
+fn finish<T, C: error_stack::Context>(
+    value: Result<T, VisitorError>,
+    end: Result<(), C>,
+) -> Result<T, VisitorError> {
+    match value {
+        Ok(value) => end.map(|()| value).change_context(VisitorError),
+        // `serde` visitors are fail-fast, if the visitor returned early, remaining items will be
+        // reported by `end`, which would just be noise.
+        Err(error) => Err(error),
+    }
+}
+
+/// A `serde` [`serde::Deserializer`] that is driven by any `deer` [`Deserializer`].
+///
+/// `serde` visitors are fail-fast, once a `serde` visitor has returned an error, the remaining
+/// values are skipped.
+///
+/// `serde` requests keys and values of a map separately, while `deer` always deserializes both at
+/// the same time, keys and values are therefore buffered as [`Content`], the same applies to
+/// items of sequences that are deserialized through a [`DeserializeSeed`].
+pub struct DeerDeserializer<D> {
+    deserializer: D,
+}
+
+impl<D> DeerDeserializer<D> {
+    #[must_use]
+    pub const fn new(deserializer: D) -> Self {
+        Self { deserializer }
+    }
+}
+
+macro_rules! forward_to_deer {
+    ($($method:ident => $deer:ident),* $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> core::result::Result<V::Value, Self::Error>
+            where
+                V: serde::de::Visitor<'de>,
+            {
+                self.deserializer
+                    .$deer(VisitorAdapter(visitor))
+                    .map_err(Error::new)
+            }
+        )*
+    };
+}
+
+impl<'de, D> serde::Deserializer<'de> for DeerDeserializer<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = Error;
+
+    forward_to_deer!(
+        deserialize_any => deserialize_any,
+        deserialize_bool => deserialize_bool,
+        deserialize_i8 => deserialize_i8,
+        deserialize_i16 => deserialize_i16,
+        deserialize_i32 => deserialize_i32,
+        deserialize_i64 => deserialize_i64,
+        deserialize_i128 => deserialize_i128,
+        deserialize_u8 => deserialize_u8,
+        deserialize_u16 => deserialize_u16,
+        deserialize_u32 => deserialize_u32,
+        deserialize_u64 => deserialize_u64,
+        deserialize_u128 => deserialize_u128,
+        deserialize_f32 => deserialize_f32,
+        deserialize_f64 => deserialize_f64,
+        deserialize_char => deserialize_char,
+        deserialize_str => deserialize_str,
+        deserialize_string => deserialize_string,
+        deserialize_bytes => deserialize_bytes,
+        deserialize_byte_buf => deserialize_bytes_buffer,
+        deserialize_unit => deserialize_null,
+        deserialize_seq => deserialize_array,
+        deserialize_map => deserialize_object,
+        deserialize_ignored_any => deserialize_any,
+    );
+
+    fn deserialize_option<V>(self, visitor: V) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserializer
+            .deserialize_optional(OptionalVisitorAdapter(visitor))
+            .map_err(Error::new)
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V>(
+        self,
+        _: usize,
+        visitor: V,
+    ) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserializer
+            .deserialize_struct(StructVisitorAdapter(visitor))
+            .map_err(Error::new)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserializer
+            .deserialize_enum(EnumVisitorAdapter(visitor))
+            .map_err(Error::new)
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserializer
+            .deserialize_identifier(IdentifierVisitorAdapter(visitor))
+            .map_err(Error::new)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+}
+
+/// The expected type of a `serde` visitor is only available as a message, we therefore fall back
+/// to accepting any value.
+fn expecting() -> Document {
+    ContentReflection::document()
+}
+
+struct VisitorAdapter<V>(V);
+
+macro_rules! visit {
+    ($($method:ident($ty:ty) => $serde:ident),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Value, VisitorError> {
+                self.0.$serde(value).map_err(into_report)
+            }
+        )*
+    };
+}
+
+impl<'de, V> Visitor<'de> for VisitorAdapter<V>
+where
+    V: serde::de::Visitor<'de>,
+{
+    type Value = V::Value;
+
+    visit!(
+        visit_bool(bool) => visit_bool,
+        visit_char(char) => visit_char,
+        visit_str(&str) => visit_str,
+        visit_borrowed_str(&'de str) => visit_borrowed_str,
+        visit_string(String) => visit_string,
+        visit_bytes(&[u8]) => visit_bytes,
+        visit_borrowed_bytes(&'de [u8]) => visit_borrowed_bytes,
+        visit_bytes_buffer(Vec<u8>) => visit_byte_buf,
+        visit_i8(i8) => visit_i8,
+        visit_i16(i16) => visit_i16,
+        visit_i32(i32) => visit_i32,
+        visit_i64(i64) => visit_i64,
+        visit_i128(i128) => visit_i128,
+        visit_u8(u8) => visit_u8,
+        visit_u16(u16) => visit_u16,
+        visit_u32(u32) => visit_u32,
+        visit_u64(u64) => visit_u64,
+        visit_u128(u128) => visit_u128,
+        visit_f32(f32) => visit_f32,
+        visit_f64(f64) => visit_f64,
+    );
+
+    fn expecting(&self) -> Document {
+        expecting()
+    }
+
+    fn visit_none(self) -> Result<Self::Value, VisitorError> {
+        self.0.visit_none().map_err(into_report)
+    }
+
+    fn visit_null(self) -> Result<Self::Value, VisitorError> {
+        self.0.visit_unit().map_err(into_report)
+    }
+
+    fn visit_number(self, value: Number) -> Result<Self::Value, VisitorError> {
+        // try the smallest representation first, `serde` visitors usually only implement `u64`,
+        // `i64` and `f64`, which will be forwarded to from the other variants.
+        if let Some(value) = value.to_u64() {
+            self.visit_u64(value)
+        } else if let Some(value) = value.to_i64() {
+            self.visit_i64(value)
+        } else if let Some(value) = value.to_u128() {
+            self.visit_u128(value)
+        } else if let Some(value) = value.to_i128() {
+            self.visit_i128(value)
+        } else if let Some(value) = value.to_f64() {
+            self.visit_f64(value)
+        } else {
+            Err(Report::new(ValueError.into_error())
+                .attach(ExpectedType::new(expecting()))
+                .attach(ReceivedValue::new(value))
+                .change_context(VisitorError))
+        }
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut access = SeqAccessAdapter {
+            access: array,
+            index: 0,
+        };
+        let value = self.0.visit_seq(&mut access).map_err(into_report);
+
+        finish(value, access.access.end())
+    }
+
+    fn visit_object<A>(self, object: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        let mut access = MapAccessAdapter {
+            access: object,
+            value: None,
+        };
+        let value = self.0.visit_map(&mut access).map_err(into_report);
+
+        finish(value, access.access.end())
+    }
+}
+
+struct OptionalVisitorAdapter<V>(V);
+
+impl<'de, V> OptionalVisitor<'de> for OptionalVisitorAdapter<V>
+where
+    V: serde::de::Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self) -> Document {
+        expecting()
+    }
+
+    fn visit_none(self) -> Result<Self::Value, VisitorError> {
+        self.0.visit_none().map_err(into_report)
+    }
+
+    fn visit_null(self) -> Result<Self::Value, VisitorError> {
+        self.0.visit_none().map_err(into_report)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, VisitorError>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .visit_some(DeerDeserializer::new(deserializer))
+            .map_err(into_report)
+    }
+}
+
+struct StructVisitorAdapter<V>(V);
+
+impl<'de, V> StructVisitor<'de> for StructVisitorAdapter<V>
+where
+    V: serde::de::Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self) -> Document {
+        expecting()
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        VisitorAdapter(self.0).visit_array(array)
+    }
+
+    fn visit_object<A>(self, object: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        VisitorAdapter(self.0).visit_object(object)
+    }
+}
+
+struct IdentifierVisitorAdapter<V>(V);
+
+impl<'de, V> IdentifierVisitor<'de> for IdentifierVisitorAdapter<V>
+where
+    V: serde::de::Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self) -> Document {
+        expecting()
+    }
+
+    fn visit_u8(self, value: u8) -> Result<Self::Value, VisitorError> {
+        self.0.visit_u64(u64::from(value)).map_err(into_report)
+    }
+
+    fn visit_u64(self, value: u64) -> Result<Self::Value, VisitorError> {
+        self.0.visit_u64(value).map_err(into_report)
+    }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, VisitorError> {
+        self.0.visit_str(value).map_err(into_report)
+    }
+
+    fn visit_bytes(self, value: &[u8]) -> Result<Self::Value, VisitorError> {
+        self.0.visit_bytes(value).map_err(into_report)
+    }
+}
+
+/// The discriminant is buffered, as `serde` only requests the discriminant once the visitor
+/// has been called with the [`EnumAccess`].
+struct EnumVisitorAdapter<V>(V);
+
+impl<'de, V> EnumVisitor<'de> for EnumVisitorAdapter<V>
+where
+    V: serde::de::Visitor<'de>,
+{
+    type Discriminant = Content;
+    type Value = V::Value;
+
+    fn expecting(&self) -> Document {
+        expecting()
+    }
+
+    fn visit_value<D>(
+        self,
+        discriminant: Self::Discriminant,
+        deserializer: D,
+    ) -> Result<Self::Value, VisitorError>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .visit_enum(EnumAccessAdapter {
+                discriminant,
+                deserializer,
+            })
+            .map_err(into_report)
+    }
+}
+
+struct EnumAccessAdapter<D> {
+    discriminant: Content,
+    deserializer: D,
+}
+
+impl<'de, D> EnumAccess<'de> for EnumAccessAdapter<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = Error;
+    type Variant = VariantAccessAdapter<D>;
+
+    fn variant_seed<V>(
+        self,
+        seed: V,
+    ) -> core::result::Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let discriminant = ContentDeserializer::new(self.discriminant, self.deserializer.context());
+        let value = seed.deserialize(DeerDeserializer::new(discriminant))?;
+
+        Ok((value, VariantAccessAdapter(self.deserializer)))
+    }
+}
+
+struct VariantAccessAdapter<D>(D);
+
+/// Visitor for unit variants, which either have no value or a value of null.
+struct UnitVariantVisitor;
+
+impl<'de> OptionalVisitor<'de> for UnitVariantVisitor {
+    type Value = ();
+
+    fn expecting(&self) -> Document {
+        <() as deer::Deserialize>::reflection()
+    }
+
+    fn visit_none(self) -> Result<Self::Value, VisitorError> {
+        Ok(())
+    }
+
+    fn visit_null(self) -> Result<Self::Value, VisitorError> {
+        Ok(())
+    }
+}
+
+impl<'de, D> VariantAccess<'de> for VariantAccessAdapter<D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = Error;
+
+    fn unit_variant(self) -> core::result::Result<(), Self::Error> {
+        self.0
+            .deserialize_optional(UnitVariantVisitor)
+            .map_err(Error::new)
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> core::result::Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(DeerDeserializer::new(self.0))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        serde::Deserializer::deserialize_tuple(DeerDeserializer::new(self.0), len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        serde::Deserializer::deserialize_struct(DeerDeserializer::new(self.0), "", fields, visitor)
+    }
+}
+
+/// `serde` [`SeqAccess`] for a `deer` [`ArrayAccess`].
+struct SeqAccessAdapter<A> {
+    access: A,
+    index: usize,
+}
+
+impl<'de, A> SeqAccess<'de> for SeqAccessAdapter<A>
+where
+    A: ArrayAccess<'de>,
+{
+    type Error = Error;
+
+    fn next_element_seed<T>(
+        &mut self,
+        seed: T,
+    ) -> core::result::Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let Some(content) = self.access.next::<Content>() else {
+            return Ok(None);
+        };
+
+        let index = self.index;
+        self.index += 1;
+
+        let content = content
+            .attach(Location::Array(index))
+            .change_context(DeserializerError)
+            .map_err(Error::new)?;
+
+        seed.deserialize(DeerDeserializer::new(ContentDeserializer::new(
+            content,
+            self.access.context(),
+        )))
+        .map(Some)
+    }
+
+    fn next_element<T>(&mut self) -> core::result::Result<Option<T>, Self::Error>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        // fast-path: `T` does not require any state, we do not need to buffer the value
+        let Some(value) = self.access.next::<Serde<T>>() else {
+            return Ok(None);
+        };
+
+        let index = self.index;
+        self.index += 1;
+
+        value
+            .map(|Serde(value)| Some(value))
+            .attach(Location::Array(index))
+            .change_context(DeserializerError)
+            .map_err(Error::new)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.access
+            .size_hint()
+            .map(|length| length.saturating_sub(self.index))
+    }
+}
+
+/// [`FieldVisitor`] which buffers the key and value of an entry, the key is then deserialized using
+/// `seed`.
+struct FieldVisitorAdapter<K> {
+    seed: Cell<Option<K>>,
+}
+
+impl<'de, K> FieldVisitor<'de> for FieldVisitorAdapter<K>
+where
+    K: DeserializeSeed<'de>,
+{
+    type Key = Content;
+    type Value = (K::Value, Content);
+
+    fn visit_value<D>(self, key: Self::Key, deserializer: D) -> Result<Self::Value, VisitorError>
+    where
+        D: Deserializer<'de>,
+    {
+        let context = deserializer.context();
+
+        let key = self
+            .seed
+            .take()
+            .expect("seed should only be taken once")
+            .deserialize(DeerDeserializer::new(ContentDeserializer::new(
+                key, context,
+            )))
+            .map_err(into_report);
+
+        let value =
+            <Content as deer::Deserialize>::deserialize(deserializer).change_context(VisitorError);
+
+        match (key, value) {
+            (Ok(key), Ok(value)) => Ok((key, value)),
+            (Err(error), Ok(_)) | (Ok(_), Err(error)) => Err(error),
+            (Err(mut error), Err(value)) => {
+                error.extend_one(value);
+                Err(error)
+            }
+        }
+    }
+}
+
+/// `serde` [`MapAccess`] for a `deer` [`ObjectAccess`].
+struct MapAccessAdapter<A> {
+    access: A,
+    value: Option<Content>,
+}
+
+impl<'de, A> MapAccess<'de> for MapAccessAdapter<A>
+where
+    A: ObjectAccess<'de>,
+{
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> core::result::Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let entry = self.access.field(FieldVisitorAdapter {
+            seed: Cell::new(Some(seed)),
+        });
+
+        let Some(entry) = entry else {
+            return Ok(None);
+        };
+
+        let (key, value) = entry
+            .change_context(DeserializerError)
+            .map_err(Error::new)?;
+        self.value = Some(value);
+
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> core::result::Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let value = self
+            .value
+            .take()
+            .expect("`next_value_seed` should only be called after `next_key_seed`");
+
+        seed.deserialize(DeerDeserializer::new(ContentDeserializer::new(
+            value,
+            self.access.context(),
+        )))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.access.size_hint()
+    }
+}
@@ -0,0 +1,172 @@
+use alloc::string::{String, ToString};
+use core::fmt::{self, Debug, Display, Formatter};
+
+use deer::{
+    error::{
+        ArrayLengthError, DeserializeError, DeserializerError, DuplicateField, DuplicateFieldError,
+        ErrorProperties, ExpectedField, ExpectedVariant, Id, Location, MissingError, Namespace,
+        ReceivedField, ReceivedLength, ReceivedVariant, UnknownFieldError, UnknownVariantError,
+        Variant,
+    },
+    id,
+};
+use error_stack::Report;
+
+const NAMESPACE: Namespace = Namespace::new("deer-serde");
+
+/// Error that has been raised by `serde`, which cannot be mapped to any of the errors of `deer`.
+///
+/// `serde` errors are neither `Send` nor `Sync` and may not implement `core::error::Error`,
+/// therefore only their message is retained.
+#[derive(Debug)]
+pub(crate) struct SerdeError {
+    message: String,
+}
+
+impl SerdeError {
+    pub(crate) fn new(message: impl Display) -> Self {
+        Self {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for SerdeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Variant for SerdeError {
+    type Properties = (Location,);
+
+    const ID: Id = id!["serde"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
+
+/// Placeholder error message used when a `deer` error is smuggled through `serde`.
+///
+/// `serde` only allows errors of the type mandated by the deserializer, which means that the
+/// original [`Report`] is stored in a side-channel, while `serde` propagates this message.
+pub(crate) struct Smuggled;
+
+impl Display for Smuggled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("deer encountered one or more errors")
+    }
+}
+
+/// Error returned by a `serde` [`Deserializer`] that is driven by `deer`.
+///
+/// This is a thin wrapper around [`Report`], errors raised by `serde` are converted into their
+/// `deer` counterpart, where possible.
+///
+/// [`Deserializer`]: serde::Deserializer
+pub struct Error(Report<DeserializerError>);
+
+impl Error {
+    pub(crate) const fn new(report: Report<DeserializerError>) -> Self {
+        Self(report)
+    }
+
+    fn variant(variant: impl Variant) -> Report<deer::error::Error> {
+        Report::new(variant.into_error())
+    }
+
+    pub fn into_report(self) -> Report<DeserializeError> {
+        self.0.change_context(DeserializeError)
+    }
+
+    pub(crate) fn into_inner(self) -> Report<DeserializerError> {
+        self.0
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl serde::de::StdError for Error {}
+
+impl serde::de::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        Self(Self::variant(SerdeError::new(msg)).change_context(DeserializerError))
+    }
+
+    fn invalid_length(len: usize, exp: &dyn serde::de::Expected) -> Self {
+        Self(
+            Self::variant(ArrayLengthError)
+                .attach(ReceivedLength::new(len))
+                .attach_printable(exp.to_string())
+                .change_context(DeserializerError),
+        )
+    }
+
+    fn unknown_variant(variant: &str, expected: &'static [&'static str]) -> Self {
+        let mut error = Self::variant(UnknownVariantError).attach(ReceivedVariant::new(variant));
+
+        for &variant in expected {
+            error = error.attach(ExpectedVariant::new(variant));
+        }
+
+        Self(error.change_context(DeserializerError))
+    }
+
+    fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
+        let mut error = Self::variant(UnknownFieldError).attach(ReceivedField::new(field));
+
+        for &field in expected {
+            error = error.attach(ExpectedField::new(field));
+        }
+
+        Self(error.change_context(DeserializerError))
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self(
+            Self::variant(MissingError)
+                .attach(Location::Field(field))
+                .change_context(DeserializerError),
+        )
+    }
+
+    fn duplicate_field(field: &'static str) -> Self {
+        Self(
+            Self::variant(DuplicateFieldError)
+                .attach(DuplicateField::new(field))
+                .change_context(DeserializerError),
+        )
+    }
+}
+
+/// Convert an error raised by `serde` into a [`Report`].
+///
+/// If the error has been raised because of a `deer` error, which has been smuggled through `serde`,
+/// the original [`Report`] is returned instead.
+pub(crate) fn from_serde<E, C>(error: &E, smuggled: Option<Report<C>>, context: C) -> Report<C>
+where
+    E: Display,
+    C: error_stack::Context,
+{
+    smuggled
+        .unwrap_or_else(|| Report::new(SerdeError::new(error).into_error()).change_context(context))
+}
@@ -0,0 +1,116 @@
+//! Compatibility layer between `deer` and `serde`.
+//!
+//! This crate enables the incremental migration from `serde` to `deer`:
+//!
+//! * [`SerdeDeserializer`] lets any `serde` deserializer (`serde_json`, `serde_yaml`, ...) drive a
+//!   type implementing [`deer::Deserialize`], see [`from_serde`].
+//! * [`DeerDeserializer`] lets any `deer` deserializer drive a type implementing
+//!   [`serde::Deserialize`], see [`from_deer`].
+//!
+//! Errors are always reported through `error-stack` and [`DeserializeError`], errors raised by
+//! `serde` are converted to their `deer` equivalent where possible.
+#![cfg_attr(not(feature = "std"), no_std)]
+// TODO: once more stable introduce: warning missing_docs, clippy::missing_errors_doc
+#![deny(unsafe_code)]
+mod deer_deserializer;
+mod error;
+mod serde_deserializer;
+
+extern crate alloc;
+
+use core::fmt::{self, Display, Formatter};
+
+use deer::{error::DeserializeError, value::ContentReflection, Context, Deserialize, Deserializer};
+use error_stack::{Frame, Report, Result};
+
+pub use crate::{
+    deer_deserializer::DeerDeserializer, error::Error, serde_deserializer::SerdeDeserializer,
+};
+
+/// Deserialize a `deer` type from any `serde` deserializer.
+pub fn from_serde<'de, T, D>(deserializer: D, context: &Context) -> Result<T, DeserializeError>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    T::deserialize(SerdeDeserializer::new(deserializer, context))
+}
+
+/// Deserialize a `serde` type from any `deer` deserializer.
+pub fn from_deer<'de, T, D>(deserializer: D) -> Result<T, DeserializeError>
+where
+    T: serde::Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(DeerDeserializer::new(deserializer)).map_err(Error::into_report)
+}
+
+/// Wrapper, which implements [`deer::Deserialize`] for any type implementing
+/// [`serde::Deserialize`].
+///
+/// This is useful if a `serde` type is embedded in a `deer` type. As `serde` types do not provide
+/// any reflection, the schema accepts any value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Serde<T>(pub T);
+
+impl<'de, T> Deserialize<'de> for Serde<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    type Reflection = ContentReflection;
+
+    fn deserialize<D>(deserializer: D) -> Result<Self, DeserializeError>
+    where
+        D: Deserializer<'de>,
+    {
+        from_deer(deserializer).map(Self)
+    }
+}
+
+/// Wrapper, which implements [`serde::Deserialize`] for any type implementing
+/// [`deer::Deserialize`].
+///
+/// This is useful if a `deer` type is embedded in a `serde` type. `serde` has no concept of a
+/// [`Context`], the default context is used instead. `serde` errors are unable to carry a
+/// [`Report`], only the message of the report is retained, prefer [`from_serde`] where possible.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Deer<T>(pub T);
+
+impl<'de, T> serde::Deserialize<'de> for Deer<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let context = Context::new();
+
+        from_serde(deserializer, &context)
+            .map(Self)
+            .map_err(|report| serde::de::Error::custom(DisplayReport(&report)))
+    }
+}
+
+/// Displays every error of a report, separated by a semicolon.
+struct DisplayReport<'a>(&'a Report<DeserializeError>);
+
+impl Display for DisplayReport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut errors = self
+            .0
+            .frames()
+            .filter_map(Frame::downcast_ref::<deer::error::Error>);
+
+        if let Some(error) = errors.next() {
+            Display::fmt(error, f)?;
+        }
+
+        for error in errors {
+            f.write_str("; ")?;
+            Display::fmt(error, f)?;
+        }
+
+        Ok(())
+    }
+}
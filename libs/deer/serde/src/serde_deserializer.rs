@@ -0,0 +1,941 @@
+//! `deer` [`Deserializer`] which is driven by a `serde` [`serde::Deserializer`].
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+
+use deer::{
+    error::{
+        ArrayAccessError, ArrayLengthError, DeserializeError, DeserializerError, ExpectedLength,
+        ObjectAccessError, ObjectLengthError, ReceivedLength, Variant, VisitorError,
+    },
+    value::{NoneDeserializer, StrDeserializer, U64Deserializer},
+    ArrayAccess, Context, Deserialize, Deserializer, EnumVisitor, FieldVisitor, IdentifierVisitor,
+    ObjectAccess, OptionalVisitor, StructVisitor, Visitor,
+};
+use error_stack::{Report, Result, ResultExt};
+use serde::de::{DeserializeSeed, EnumAccess, IgnoredAny, MapAccess, SeqAccess, VariantAccess};
+
+use crate::error::{from_serde, Smuggled};
+
+/// Store `report` in `slot` and return an error which can be propagated through `serde`.
+///
+/// `serde` does not allow us to return our own error, the report is therefore smuggled past
+/// `serde` and recovered once the call into `serde` has returned.
+fn smuggle<E: serde::de::Error, C>(slot: &mut Option<Report<C>>, report: Report<C>) -> E {
+    *slot = Some(report);
+
+    E::custom(Smuggled)
+}
+
+/// A `deer` [`Deserializer`] that is driven by any `serde` [`serde::Deserializer`].
+///
+/// `deer` concepts that have no equivalent in `serde` are mapped to the closest `serde` concept:
+///
+/// * `serde` has no concept of a missing value, values that are `None` and `()` are both visited as
+///   null.
+/// * `deer` enums are either a string or an object with a single key, unit variants of
+///   `serde`-native enums are therefore not supported.
+/// * `deer` structs can be deserialized from arrays and objects, which means that the underlying
+///   format needs to be self-describing.
+///
+/// `deer` is fail-slow, while `serde` is fail-fast, errors raised by `deer` are recoverable and
+/// deserialization continues, while errors raised by `serde` stop deserialization of the
+/// current container, as the state of the `serde` deserializer is undefined.
+pub struct SerdeDeserializer<'a, D> {
+    context: &'a Context,
+    deserializer: D,
+}
+
+impl<'a, D> SerdeDeserializer<'a, D> {
+    #[must_use]
+    pub const fn new(deserializer: D, context: &'a Context) -> Self {
+        Self {
+            context,
+            deserializer,
+        }
+    }
+}
+
+macro_rules! forward_to_serde {
+    ($($method:ident => $serde:ident),* $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+            where
+                V: Visitor<'de>,
+            {
+                let mut error = None;
+
+                self.deserializer
+                    .$serde(VisitorAdapter::new(visitor, self.context, &mut error))
+                    .map_err(|serde| from_serde(&serde, error, VisitorError))
+                    .change_context(DeserializerError)
+            }
+        )*
+    };
+}
+
+impl<'de, D> Deserializer<'de> for SerdeDeserializer<'_, D>
+where
+    D: serde::Deserializer<'de>,
+{
+    forward_to_serde!(
+        deserialize_any => deserialize_any,
+        deserialize_null => deserialize_unit,
+        deserialize_bool => deserialize_bool,
+        // `serde` has no concept of an arbitrary number, we need to rely on the format to be
+        // self-describing.
+        deserialize_number => deserialize_any,
+        deserialize_char => deserialize_char,
+        deserialize_string => deserialize_string,
+        deserialize_str => deserialize_str,
+        deserialize_bytes => deserialize_bytes,
+        deserialize_bytes_buffer => deserialize_byte_buf,
+        deserialize_array => deserialize_seq,
+        deserialize_object => deserialize_map,
+        deserialize_i8 => deserialize_i8,
+        deserialize_i16 => deserialize_i16,
+        deserialize_i32 => deserialize_i32,
+        deserialize_i64 => deserialize_i64,
+        deserialize_i128 => deserialize_i128,
+        deserialize_u8 => deserialize_u8,
+        deserialize_u16 => deserialize_u16,
+        deserialize_u32 => deserialize_u32,
+        deserialize_u64 => deserialize_u64,
+        deserialize_u128 => deserialize_u128,
+        deserialize_f32 => deserialize_f32,
+        deserialize_f64 => deserialize_f64,
+    );
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        let mut error = None;
+
+        self.deserializer
+            .deserialize_option(OptionalVisitorAdapter {
+                context: self.context,
+                visitor,
+                error: &mut error,
+            })
+            .map_err(|serde| from_serde(&serde, error, VisitorError))
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: EnumVisitor<'de>,
+    {
+        let mut error = None;
+
+        self.deserializer
+            .deserialize_any(EnumVisitorAdapter {
+                context: self.context,
+                visitor,
+                error: &mut error,
+            })
+            .map_err(|serde| from_serde(&serde, error, VisitorError))
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: StructVisitor<'de>,
+    {
+        let mut error = None;
+
+        self.deserializer
+            .deserialize_any(StructVisitorAdapter {
+                context: self.context,
+                visitor,
+                error: &mut error,
+            })
+            .map_err(|serde| from_serde(&serde, error, VisitorError))
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        let mut error = None;
+
+        self.deserializer
+            .deserialize_identifier(IdentifierVisitorAdapter {
+                visitor,
+                error: &mut error,
+            })
+            .map_err(|serde| from_serde(&serde, error, VisitorError))
+            .change_context(DeserializerError)
+    }
+}
+
+/// [`DeserializeSeed`] which deserializes `T` using `deer`.
+struct DeserializeAdapter<'a, 'b, T> {
+    context: &'a Context,
+    error: &'b mut Option<Report<DeserializeError>>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'de, T> DeserializeSeed<'de> for DeserializeAdapter<'_, '_, T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(SerdeDeserializer::new(deserializer, self.context))
+            .map_err(|report| smuggle(self.error, report))
+    }
+}
+
+struct VisitorAdapter<'a, 'b, V> {
+    context: &'a Context,
+    visitor: V,
+    error: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'a, 'b, V> VisitorAdapter<'a, 'b, V> {
+    fn new(visitor: V, context: &'a Context, error: &'b mut Option<Report<VisitorError>>) -> Self {
+        Self {
+            context,
+            visitor,
+            error,
+        }
+    }
+}
+
+macro_rules! visit {
+    ($($method:ident($ty:ty) => $deer:ident),* $(,)?) => {
+        $(
+            fn $method<E>(self, value: $ty) -> core::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visitor
+                    .$deer(value)
+                    .map_err(|report| smuggle(self.error, report))
+            }
+        )*
+    };
+}
+
+impl<'de, V> serde::de::Visitor<'de> for VisitorAdapter<'_, '_, V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    visit!(
+        visit_bool(bool) => visit_bool,
+        visit_i8(i8) => visit_i8,
+        visit_i16(i16) => visit_i16,
+        visit_i32(i32) => visit_i32,
+        visit_i64(i64) => visit_i64,
+        visit_i128(i128) => visit_i128,
+        visit_u8(u8) => visit_u8,
+        visit_u16(u16) => visit_u16,
+        visit_u32(u32) => visit_u32,
+        visit_u64(u64) => visit_u64,
+        visit_u128(u128) => visit_u128,
+        visit_f32(f32) => visit_f32,
+        visit_f64(f64) => visit_f64,
+        visit_char(char) => visit_char,
+        visit_str(&str) => visit_str,
+        visit_borrowed_str(&'de str) => visit_borrowed_str,
+        visit_string(String) => visit_string,
+        visit_bytes(&[u8]) => visit_bytes,
+        visit_borrowed_bytes(&'de [u8]) => visit_borrowed_bytes,
+        visit_byte_buf(Vec<u8>) => visit_bytes_buffer,
+    );
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("any value supported by deer")
+    }
+
+    // `serde` is unable to express a missing value, `None` is therefore always interpreted as null
+    fn visit_none<E>(self) -> core::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visitor
+            .visit_null()
+            .map_err(|report| smuggle(self.error, report))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        SerdeDeserializer::new(deserializer, self.context)
+            .deserialize_any(self.visitor)
+            .map_err(|report| smuggle(self.error, report.change_context(VisitorError)))
+    }
+
+    fn visit_unit<E>(self) -> core::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visitor
+            .visit_null()
+            .map_err(|report| smuggle(self.error, report))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.visit_some(deserializer)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.visitor
+            .visit_array(SeqAccessAdapter::new(seq, self.context))
+            .map_err(|report| smuggle(self.error, report))
+    }
+
+    fn visit_map<A>(self, map: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.visitor
+            .visit_object(MapAccessAdapter::new(map, self.context))
+            .map_err(|report| smuggle(self.error, report))
+    }
+}
+
+struct OptionalVisitorAdapter<'a, 'b, V> {
+    context: &'a Context,
+    visitor: V,
+    error: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'de, V> serde::de::Visitor<'de> for OptionalVisitorAdapter<'_, '_, V>
+where
+    V: OptionalVisitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("an optional value")
+    }
+
+    fn visit_none<E>(self) -> core::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visitor
+            .visit_null()
+            .map_err(|report| smuggle(self.error, report))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.visitor
+            .visit_some(SerdeDeserializer::new(deserializer, self.context))
+            .map_err(|report| smuggle(self.error, report))
+    }
+
+    fn visit_unit<E>(self) -> core::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_none()
+    }
+}
+
+struct StructVisitorAdapter<'a, 'b, V> {
+    context: &'a Context,
+    visitor: V,
+    error: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'de, V> serde::de::Visitor<'de> for StructVisitorAdapter<'_, '_, V>
+where
+    V: StructVisitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("an array or object")
+    }
+
+    fn visit_seq<A>(self, seq: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.visitor
+            .visit_array(SeqAccessAdapter::new(seq, self.context))
+            .map_err(|report| smuggle(self.error, report))
+    }
+
+    fn visit_map<A>(self, map: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.visitor
+            .visit_object(MapAccessAdapter::new(map, self.context))
+            .map_err(|report| smuggle(self.error, report))
+    }
+}
+
+struct IdentifierVisitorAdapter<'b, V> {
+    visitor: V,
+    error: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'de, V> serde::de::Visitor<'de> for IdentifierVisitorAdapter<'_, V>
+where
+    V: IdentifierVisitor<'de>,
+{
+    type Value = V::Value;
+
+    visit!(
+        visit_u8(u8) => visit_u8,
+        visit_u64(u64) => visit_u64,
+        visit_str(&str) => visit_str,
+        visit_bytes(&[u8]) => visit_bytes,
+    );
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("an identifier")
+    }
+}
+
+/// [`DeserializeSeed`] which deserializes the discriminant of an enum.
+struct DiscriminantAdapter<'a, 'b, 'c, V> {
+    context: &'a Context,
+    visitor: &'c V,
+    error: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'de, V> DeserializeSeed<'de> for DiscriminantAdapter<'_, '_, '_, V>
+where
+    V: EnumVisitor<'de>,
+{
+    type Value = V::Discriminant;
+
+    fn deserialize<D>(self, deserializer: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.visitor
+            .visit_discriminant(SerdeDeserializer::new(deserializer, self.context))
+            .map_err(|report| smuggle(self.error, report))
+    }
+}
+
+/// [`DeserializeSeed`] which deserializes the value of an enum.
+struct EnumValueAdapter<'a, 'b, V, T> {
+    context: &'a Context,
+    visitor: V,
+    discriminant: T,
+    error: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'de, V, T> DeserializeSeed<'de> for EnumValueAdapter<'_, '_, V, T>
+where
+    V: EnumVisitor<'de, Discriminant = T>,
+{
+    type Value = V::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.visitor
+            .visit_value(
+                self.discriminant,
+                SerdeDeserializer::new(deserializer, self.context),
+            )
+            .map_err(|report| smuggle(self.error, report))
+    }
+}
+
+/// Deserialize an enum, that only consists of a discriminant, like a unit variant.
+fn visit_unit_variant<'de, V, D>(
+    visitor: V,
+    deserializer: D,
+    context: &Context,
+) -> Result<V::Value, VisitorError>
+where
+    V: EnumVisitor<'de>,
+    D: Deserializer<'de>,
+{
+    let discriminant = visitor.visit_discriminant(deserializer)?;
+
+    visitor.visit_value(discriminant, NoneDeserializer::new(context))
+}
+
+/// Visitor for enums, which are represented either as a string (unit variants) or an object with
+/// a single key, the discriminant, and the value of the variant.
+struct EnumVisitorAdapter<'a, 'b, V> {
+    context: &'a Context,
+    visitor: V,
+    error: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'de, V> serde::de::Visitor<'de> for EnumVisitorAdapter<'_, '_, V>
+where
+    V: EnumVisitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a string or an object with a single key")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> core::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        visit_unit_variant(
+            self.visitor,
+            U64Deserializer::new(value, self.context),
+            self.context,
+        )
+        .map_err(|report| smuggle(self.error, report))
+    }
+
+    fn visit_str<E>(self, value: &str) -> core::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        visit_unit_variant(
+            self.visitor,
+            StrDeserializer::new(value, self.context),
+            self.context,
+        )
+        .map_err(|report| smuggle(self.error, report))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let Self {
+            context,
+            visitor,
+            error,
+        } = self;
+
+        let discriminant = map.next_key_seed(DiscriminantAdapter {
+            context,
+            visitor: &visitor,
+            error: &mut *error,
+        })?;
+
+        let Some(discriminant) = discriminant else {
+            return Err(smuggle(
+                error,
+                Report::new(ObjectLengthError.into_error())
+                    .attach(ExpectedLength::new(1))
+                    .attach(ReceivedLength::new(0))
+                    .change_context(VisitorError),
+            ));
+        };
+
+        let value = map.next_value_seed(EnumValueAdapter {
+            context,
+            visitor,
+            discriminant,
+            error: &mut *error,
+        })?;
+
+        let mut received = 1;
+        while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {
+            received += 1;
+        }
+
+        if received > 1 {
+            return Err(smuggle(
+                error,
+                Report::new(ObjectLengthError.into_error())
+                    .attach(ExpectedLength::new(1))
+                    .attach(ReceivedLength::new(received))
+                    .change_context(VisitorError),
+            ));
+        }
+
+        Ok(value)
+    }
+
+    fn visit_enum<A>(self, data: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let Self {
+            context,
+            visitor,
+            error,
+        } = self;
+
+        let (discriminant, variant) = data.variant_seed(DiscriminantAdapter {
+            context,
+            visitor: &visitor,
+            error: &mut *error,
+        })?;
+
+        variant.newtype_variant_seed(EnumValueAdapter {
+            context,
+            visitor,
+            discriminant,
+            error,
+        })
+    }
+}
+
+/// `deer` [`ArrayAccess`] for a `serde` [`SeqAccess`].
+///
+/// Any items that haven't been consumed once the adapter is dropped are skipped, to ensure that
+/// the `serde` deserializer is in a consistent state, even if the visitor has returned early.
+struct SeqAccessAdapter<'a, 'de, A>
+where
+    A: SeqAccess<'de>,
+{
+    access: A,
+    context: &'a Context,
+
+    dirty: bool,
+    consumed: usize,
+    exhausted: bool,
+
+    _marker: PhantomData<fn() -> &'de ()>,
+}
+
+impl<'a, 'de, A> SeqAccessAdapter<'a, 'de, A>
+where
+    A: SeqAccess<'de>,
+{
+    const fn new(access: A, context: &'a Context) -> Self {
+        Self {
+            access,
+            context,
+            dirty: false,
+            consumed: 0,
+            exhausted: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Skip all remaining items, returns the amount of items skipped.
+    fn skip(&mut self) -> core::result::Result<usize, A::Error> {
+        let mut skipped = 0;
+
+        while !self.exhausted {
+            match self.access.next_element::<IgnoredAny>() {
+                Ok(Some(_)) => skipped += 1,
+                Ok(None) => self.exhausted = true,
+                Err(error) => {
+                    self.exhausted = true;
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(skipped)
+    }
+}
+
+impl<'de, A> ArrayAccess<'de> for SeqAccessAdapter<'_, 'de, A>
+where
+    A: SeqAccess<'de>,
+{
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn next<T>(&mut self) -> Option<Result<T, ArrayAccessError>>
+    where
+        T: Deserialize<'de>,
+    {
+        if self.exhausted {
+            return None;
+        }
+
+        self.dirty = true;
+
+        let mut error = None;
+        let value = self.access.next_element_seed(DeserializeAdapter {
+            context: self.context,
+            error: &mut error,
+            _marker: PhantomData,
+        });
+
+        match value {
+            Ok(Some(value)) => {
+                self.consumed += 1;
+                Some(Ok(value))
+            }
+            Ok(None) => {
+                self.exhausted = true;
+                None
+            }
+            Err(serde) => {
+                self.consumed += 1;
+                // errors raised by `serde` itself leave the deserializer in an undefined state
+                self.exhausted = error.is_none();
+
+                Some(Err(
+                    from_serde(&serde, error, DeserializeError).change_context(ArrayAccessError)
+                ))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.access
+            .size_hint()
+            .map(|remaining| remaining + self.consumed)
+    }
+
+    fn end(mut self) -> Result<(), ArrayAccessError> {
+        let expected = self.consumed;
+
+        match self.skip() {
+            Ok(0) => Ok(()),
+            Ok(skipped) => Err(Report::new(ArrayLengthError.into_error())
+                .attach(ExpectedLength::new(expected))
+                .attach(ReceivedLength::new(expected + skipped))
+                .change_context(ArrayAccessError)),
+            Err(serde) => Err(from_serde(&serde, None, ArrayAccessError)),
+        }
+    }
+}
+
+impl<'de, A> Drop for SeqAccessAdapter<'_, 'de, A>
+where
+    A: SeqAccess<'de>,
+{
+    fn drop(&mut self) {
+        // errors are irrelevant at this point, the visitor has already returned
+        _ = self.skip();
+    }
+}
+
+/// [`DeserializeSeed`] which deserializes the key of a field.
+struct FieldKeyAdapter<'a, 'b, 'c, F> {
+    context: &'a Context,
+    visitor: &'c F,
+    error: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'de, F> DeserializeSeed<'de> for FieldKeyAdapter<'_, '_, '_, F>
+where
+    F: FieldVisitor<'de>,
+{
+    type Value = F::Key;
+
+    fn deserialize<D>(self, deserializer: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.visitor
+            .visit_key(SerdeDeserializer::new(deserializer, self.context))
+            .map_err(|report| smuggle(self.error, report))
+    }
+}
+
+/// [`DeserializeSeed`] which deserializes the value of a field.
+struct FieldValueAdapter<'a, 'b, F, K> {
+    context: &'a Context,
+    visitor: F,
+    key: K,
+    error: &'b mut Option<Report<VisitorError>>,
+}
+
+impl<'de, F, K> DeserializeSeed<'de> for FieldValueAdapter<'_, '_, F, K>
+where
+    F: FieldVisitor<'de, Key = K>,
+{
+    type Value = F::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.visitor
+            .visit_value(self.key, SerdeDeserializer::new(deserializer, self.context))
+            .map_err(|report| smuggle(self.error, report))
+    }
+}
+
+/// `deer` [`ObjectAccess`] for a `serde` [`MapAccess`].
+///
+/// Like [`SeqAccessAdapter`], all remaining entries are skipped once the adapter is dropped.
+struct MapAccessAdapter<'a, 'de, A>
+where
+    A: MapAccess<'de>,
+{
+    access: A,
+    context: &'a Context,
+
+    dirty: bool,
+    consumed: usize,
+    exhausted: bool,
+
+    _marker: PhantomData<fn() -> &'de ()>,
+}
+
+impl<'a, 'de, A> MapAccessAdapter<'a, 'de, A>
+where
+    A: MapAccess<'de>,
+{
+    const fn new(access: A, context: &'a Context) -> Self {
+        Self {
+            access,
+            context,
+            dirty: false,
+            consumed: 0,
+            exhausted: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Skip all remaining entries, returns the amount of entries skipped.
+    fn skip(&mut self) -> core::result::Result<usize, A::Error> {
+        let mut skipped = 0;
+
+        while !self.exhausted {
+            match self.access.next_entry::<IgnoredAny, IgnoredAny>() {
+                Ok(Some(_)) => skipped += 1,
+                Ok(None) => self.exhausted = true,
+                Err(error) => {
+                    self.exhausted = true;
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(skipped)
+    }
+}
+
+impl<'de, A> ObjectAccess<'de> for MapAccessAdapter<'_, 'de, A>
+where
+    A: MapAccess<'de>,
+{
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn try_field<F>(
+        &mut self,
+        visitor: F,
+    ) -> core::result::Result<Result<F::Value, ObjectAccessError>, F>
+    where
+        F: FieldVisitor<'de>,
+    {
+        if self.exhausted {
+            return Err(visitor);
+        }
+
+        self.dirty = true;
+
+        let mut error = None;
+        let key = self.access.next_key_seed(FieldKeyAdapter {
+            context: self.context,
+            visitor: &visitor,
+            error: &mut error,
+        });
+
+        let key = match key {
+            Ok(Some(key)) => key,
+            Ok(None) => {
+                self.exhausted = true;
+                return Err(visitor);
+            }
+            Err(serde) => {
+                self.consumed += 1;
+
+                let recoverable = error.is_some();
+                let mut report =
+                    from_serde(&serde, error, VisitorError).change_context(ObjectAccessError);
+
+                if recoverable {
+                    // the key has been consumed, but the value has not, we need to skip it to
+                    // continue with the next entry
+                    if let Err(serde) = self.access.next_value::<IgnoredAny>() {
+                        self.exhausted = true;
+                        report.extend_one(from_serde(&serde, None, ObjectAccessError));
+                    }
+                } else {
+                    self.exhausted = true;
+                }
+
+                return Ok(Err(report));
+            }
+        };
+
+        self.consumed += 1;
+
+        let mut error = None;
+        let value = self.access.next_value_seed(FieldValueAdapter {
+            context: self.context,
+            visitor,
+            key,
+            error: &mut error,
+        });
+
+        Ok(value.map_err(|serde| {
+            self.exhausted = error.is_none();
+
+            from_serde(&serde, error, VisitorError).change_context(ObjectAccessError)
+        }))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.access
+            .size_hint()
+            .map(|remaining| remaining + self.consumed)
+    }
+
+    fn end(mut self) -> Result<(), ObjectAccessError> {
+        let expected = self.consumed;
+
+        match self.skip() {
+            Ok(0) => Ok(()),
+            Ok(skipped) => Err(Report::new(ObjectLengthError.into_error())
+                .attach(ExpectedLength::new(expected))
+                .attach(ReceivedLength::new(expected + skipped))
+                .change_context(ObjectAccessError)),
+            Err(serde) => Err(from_serde(&serde, None, ObjectAccessError)),
+        }
+    }
+}
+
+impl<'de, A> Drop for MapAccessAdapter<'_, 'de, A>
+where
+    A: MapAccess<'de>,
+{
+    fn drop(&mut self) {
+        // errors are irrelevant at this point, the visitor has already returned
+        _ = self.skip();
+    }
+}
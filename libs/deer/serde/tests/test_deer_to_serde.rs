@@ -0,0 +1,186 @@
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use deer::Deserialize as _;
+use deer_desert::{assert_tokens, assert_tokens_error, error, Token};
+use deer_serde::Serde;
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Example {
+    a: u8,
+    b: Vec<u16>,
+    c: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+enum External {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, bool),
+    Struct { a: u8 },
+}
+
+#[test]
+fn primitive_ok() {
+    assert_tokens(&Serde(12_u8), &[Token::Number(12.into())]);
+    assert_tokens(&Serde(-12_i64), &[Token::Number((-12).into())]);
+    assert_tokens(&Serde(String::from("example")), &[Token::Str("example")]);
+    assert_tokens(&Serde(None::<u8>), &[Token::Null]);
+    assert_tokens(&Serde(Some(1_u8)), &[Token::Number(1.into())]);
+    assert_tokens(&Serde(vec![1_u8, 2]), &[
+        Token::Array { length: Some(2) },
+        Token::Number(1.into()),
+        Token::Number(2.into()),
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn map_ok() {
+    let expected = BTreeMap::from([("a".to_owned(), 1_u8), ("b".to_owned(), 2_u8)]);
+
+    assert_tokens(&Serde(expected), &[
+        Token::Object { length: Some(2) },
+        Token::Str("b"),
+        Token::Number(2.into()),
+        Token::Str("a"),
+        Token::Number(1.into()),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn struct_ok() {
+    let expected = Example {
+        a: 1,
+        b: vec![2],
+        c: Some(true),
+    };
+
+    assert_tokens(&Serde(expected), &[
+        Token::Object { length: Some(3) },
+        Token::Str("c"),
+        Token::Bool(true),
+        Token::Str("b"),
+        Token::Array { length: Some(1) },
+        Token::Number(2.into()),
+        Token::ArrayEnd,
+        Token::Str("a"),
+        Token::Number(1.into()),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn enum_ok() {
+    assert_tokens(&Serde(External::Unit), &[Token::Str("Unit")]);
+
+    assert_tokens(&Serde(External::Newtype(12)), &[
+        Token::Object { length: Some(1) },
+        Token::Str("Newtype"),
+        Token::Number(12.into()),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Serde(External::Tuple(12, true)), &[
+        Token::Object { length: Some(1) },
+        Token::Str("Tuple"),
+        Token::Array { length: Some(2) },
+        Token::Number(12.into()),
+        Token::Bool(true),
+        Token::ArrayEnd,
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Serde(External::Struct { a: 12 }), &[
+        Token::Object { length: Some(1) },
+        Token::Str("Struct"),
+        Token::Object { length: Some(1) },
+        Token::Str("a"),
+        Token::Number(12.into()),
+        Token::ObjectEnd,
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn value_err() {
+    assert_tokens_error::<Serde<Vec<u8>>>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{"type": "array", "value": 1}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(2) },
+            Token::Number(1.into()),
+            Token::Number(256.into()),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn unknown_field_err() {
+    assert_tokens_error::<Serde<Example>>(
+        &error!([{
+            ns: "deer",
+            id: ["unknown", "field"],
+            properties: {
+                "expected": ["a", "b", "c"],
+                "received": ["d"],
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("d"),
+            Token::Bool(true),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn missing_field_err() {
+    assert_tokens_error::<Serde<Example>>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": null,
+                "location": [{"type": "field", "value": "b"}]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("a"),
+            Token::Number(1.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn unknown_variant_err() {
+    assert_tokens_error::<Serde<External>>(
+        &error!([{
+            ns: "deer",
+            id: ["unknown", "value"],
+            properties: {
+                "expected": ["Unit", "Newtype", "Tuple", "Struct"],
+                "received": "Other",
+                "location": []
+            }
+        }]),
+        &[Token::Str("Other")],
+    );
+}
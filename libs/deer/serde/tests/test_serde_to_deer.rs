@@ -0,0 +1,188 @@
+use deer::{error::DeserializeError, Context, Deserialize as _};
+use deer_desert::errors;
+use deer_macros::Deserialize;
+use deer_serde::{from_serde, Deer};
+use error_stack::Report;
+use serde_json::json;
+
+fn from_str<'de, T: deer::Deserialize<'de>>(
+    input: &'de str,
+) -> Result<T, Report<DeserializeError>> {
+    let context = Context::new();
+    let mut deserializer = serde_json::Deserializer::from_str(input);
+
+    let value = from_serde(&mut deserializer, &context)?;
+    deserializer.end().expect("input should have been consumed");
+
+    Ok(value)
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Example {
+    a: u8,
+    b: Vec<u16>,
+    c: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+enum External {
+    Unit,
+    Newtype(u8),
+    Struct { a: u8 },
+}
+
+#[test]
+fn primitive_ok() {
+    assert_eq!(from_str::<u8>("12").expect("should deserialize"), 12);
+    assert_eq!(from_str::<i64>("-12").expect("should deserialize"), -12);
+    assert_eq!(
+        from_str::<String>(r#""example""#).expect("should deserialize"),
+        "example"
+    );
+    assert_eq!(
+        from_str::<Option<u8>>("null").expect("should deserialize"),
+        None
+    );
+    assert_eq!(
+        from_str::<Option<u8>>("1").expect("should deserialize"),
+        Some(1)
+    );
+    assert_eq!(
+        from_str::<Vec<u8>>("[1, 2, 3]").expect("should deserialize"),
+        [1, 2, 3]
+    );
+}
+
+#[test]
+fn struct_ok() {
+    let expected = Example {
+        a: 1,
+        b: vec![2, 3],
+        c: None,
+    };
+
+    assert_eq!(
+        from_str::<Example>(r#"{"b": [2, 3], "unknown": {"d": [4]}, "a": 1}"#)
+            .expect("should deserialize"),
+        expected
+    );
+
+    assert_eq!(
+        from_str::<Example>("[1, [2, 3], null]").expect("should deserialize"),
+        expected
+    );
+}
+
+#[test]
+fn enum_ok() {
+    assert_eq!(
+        from_str::<External>(r#""Unit""#).expect("should deserialize"),
+        External::Unit
+    );
+    assert_eq!(
+        from_str::<External>(r#"{"Newtype": 12}"#).expect("should deserialize"),
+        External::Newtype(12)
+    );
+    assert_eq!(
+        from_str::<External>(r#"{"Struct": {"a": 12}}"#).expect("should deserialize"),
+        External::Struct { a: 12 }
+    );
+}
+
+#[test]
+fn fail_slow_err() {
+    let report = from_str::<Vec<u8>>("[256, 1, -1]").expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer",
+            "id": ["value"],
+            "properties": {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{"type": "array", "value": 0}]
+            }
+        }, {
+            "namespace": "deer",
+            "id": ["value"],
+            "properties": {
+                "expected": u8::reflection(),
+                "received": -1,
+                "location": [{"type": "array", "value": 2}]
+            }
+        }])
+    );
+}
+
+#[test]
+fn missing_field_err() {
+    let report = from_str::<Example>(r#"{"a": 1}"#).expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer",
+            "id": ["value", "missing"],
+            "properties": {
+                "expected": <Vec<u16>>::reflection(),
+                "location": [{"type": "field", "value": "b"}]
+            }
+        }])
+    );
+}
+
+#[test]
+fn array_length_err() {
+    let report = from_str::<(u8, bool)>("[1, true, false, null]").expect_err("should fail");
+
+    assert_eq!(
+        errors(report),
+        json!([{
+            "namespace": "deer",
+            "id": ["array", "length"],
+            "properties": {
+                "expected": 2,
+                "received": 4,
+                "location": []
+            }
+        }])
+    );
+}
+
+#[test]
+fn serde_err() {
+    let report = from_str::<Vec<u8>>("[1, 2,").expect_err("should fail");
+
+    let errors = errors(report);
+    let errors = errors.as_array().expect("should be an array");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["namespace"], json!("deer-serde"));
+    assert_eq!(errors[0]["id"], json!(["serde"]));
+}
+
+#[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+struct Outer {
+    inner: Deer<Example>,
+}
+
+#[test]
+fn embedded_ok() {
+    let outer: Outer = serde_json::from_str(r#"{"inner": {"a": 1, "b": [], "c": true}}"#)
+        .expect("should deserialize");
+
+    assert_eq!(outer.inner.0, Example {
+        a: 1,
+        b: vec![],
+        c: Some(true),
+    });
+}
+
+#[test]
+fn embedded_err() {
+    let error = serde_json::from_str::<Outer>(r#"{"inner": {"a": 256, "b": []}}"#)
+        .expect_err("should fail");
+
+    assert!(error.to_string().contains("value"));
+}
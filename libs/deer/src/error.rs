@@ -462,6 +462,22 @@ error!(
     ArrayAccessError: "array access encountered one or more errors during access"
 );
 
+error!(
+    /// Every [`Serialize`] implementation must return this error, this is just a wrapper context,
+    /// which is used to aid error recovery. The actual error should implement [`Error`] instead.
+    ///
+    /// [`Serialize`]: crate::Serialize
+    SerializeError: "serialize failed"
+);
+
+error!(
+    /// Every [`Serializer`] implementation must return this error, this is just a wrapper context,
+    /// which is used to aid error recovery. The actual error should implement [`Error`] instead.
+    ///
+    /// [`Serializer`]: crate::Serializer
+    SerializerError: "serializer encountered unrecoverable error"
+);
+
 pub trait ReportExt<C: Context> {
     fn export(self) -> Export<C>;
 }
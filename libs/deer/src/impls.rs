@@ -2,7 +2,10 @@ use error_stack::Result;
 
 use crate::{error::VisitorError, Deserialize, Document, OptionalVisitor};
 
+mod alloc;
 mod core;
+#[cfg(feature = "std")]
+mod std;
 
 pub(crate) struct UnitVariantVisitor;
 
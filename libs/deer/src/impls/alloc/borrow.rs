@@ -0,0 +1,32 @@
+use alloc::borrow::{Cow, ToOwned};
+
+use error_stack::Result;
+
+use crate::{
+    error::{DeserializeError, SerializeError},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+// Borrowing from the input is not possible in general, we always deserialize the owned variant.
+impl<'de, T> Deserialize<'de> for Cow<'_, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: Deserialize<'de>,
+{
+    type Reflection = <T::Owned as Deserialize<'de>>::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        T::Owned::deserialize(deserializer).map(Cow::Owned)
+    }
+}
+
+impl<T> Serialize for Cow<'_, T>
+where
+    T: Serialize + ToOwned + ?Sized,
+{
+    type Reflection = T::Reflection;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        T::serialize(self, serializer)
+    }
+}
@@ -0,0 +1,40 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use error_stack::Result;
+
+use crate::{
+    error::{DeserializeError, SerializeError},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Box<T> {
+    type Reflection = T::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<str> {
+    type Reflection = <String as Deserialize<'de>>::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        String::deserialize(deserializer).map(String::into_boxed_str)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Box<[T]> {
+    type Reflection = <Vec<T> as Deserialize<'de>>::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        Vec::deserialize(deserializer).map(Vec::into_boxed_slice)
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for Box<T> {
+    type Reflection = T::Reflection;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        T::serialize(self, serializer)
+    }
+}
@@ -0,0 +1,137 @@
+use core::marker::PhantomData;
+
+use error_stack::{Report, Result, ResultExt};
+
+use crate::{
+    error::{
+        ArrayAccessError, DuplicateKeyError, Location, ObjectAccessError, Variant, VisitorError,
+    },
+    ext::TupleExt,
+    ArrayAccess, Deserialize, Document, ObjectAccess, Reflection, Schema,
+};
+
+mod binary_heap;
+mod btree_map;
+mod btree_set;
+mod vec_deque;
+
+/// Upper bound of items allocated upfront.
+///
+/// The size hint is provided by the input, we do not want to trust it blindly, as a malicious input
+/// could lead to excessive allocations.
+const MAX_CAPACITY: usize = 4096;
+
+pub(crate) fn capacity(size_hint: Option<usize>) -> usize {
+    size_hint.map_or(0, |size_hint| size_hint.min(MAX_CAPACITY))
+}
+
+/// Deserialize every item of `array` and call `insert` with it.
+///
+/// `insert` returns `false` if the item was already present, which will be reported as a
+/// [`DuplicateKeyError`]. Every error is annotated with the index of the item, deserialization
+/// continues after an item has failed, so that all errors are reported.
+pub(crate) fn visit_array<'de, A, T>(
+    mut array: A,
+    mut insert: impl FnMut(T) -> bool,
+) -> Result<(), VisitorError>
+where
+    A: ArrayAccess<'de>,
+    T: Deserialize<'de>,
+{
+    let mut result: Result<(), ArrayAccessError> = Ok(());
+    let mut index = 0;
+
+    while let Some(item) = array.next::<T>() {
+        let item = item
+            .and_then(|item| {
+                if insert(item) {
+                    Ok(())
+                } else {
+                    Err(Report::new(DuplicateKeyError.into_error())
+                        .change_context(ArrayAccessError))
+                }
+            })
+            .attach(Location::Array(index));
+
+        if let Err(error) = item {
+            match &mut result {
+                Err(result) => result.extend_one(error),
+                result => *result = Err(error),
+            }
+        }
+
+        index += 1;
+    }
+
+    (result, array.end())
+        .fold_reports()
+        .map(|_| ())
+        .change_context(VisitorError)
+}
+
+/// Deserialize every entry of `object` and call `insert` with it.
+///
+/// `insert` returns `false` if the key was already present, which will be reported as a
+/// [`DuplicateKeyError`], deserialization continues after an entry has failed, so that all errors
+/// are reported.
+pub(crate) fn visit_object<'de, A, K, V>(
+    mut object: A,
+    mut insert: impl FnMut(K, V) -> bool,
+) -> Result<(), VisitorError>
+where
+    A: ObjectAccess<'de>,
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    let mut result: Result<(), ObjectAccessError> = Ok(());
+
+    while let Some(entry) = object.next::<K, V>() {
+        let entry = entry.and_then(|(key, value)| {
+            if insert(key, value) {
+                Ok(())
+            } else {
+                Err(Report::new(DuplicateKeyError.into_error()).change_context(ObjectAccessError))
+            }
+        });
+
+        if let Err(error) = entry {
+            match &mut result {
+                Err(result) => result.extend_one(error),
+                result => *result = Err(error),
+            }
+        }
+    }
+
+    (result, object.end())
+        .fold_reports()
+        .map(|_| ())
+        .change_context(VisitorError)
+}
+
+pub struct SequenceReflection<T: ?Sized>(PhantomData<fn() -> *const T>);
+
+impl<T: Reflection + ?Sized> Reflection for SequenceReflection<T> {
+    fn schema(doc: &mut Document) -> Schema {
+        Schema::new("array").with("items", doc.add::<T>())
+    }
+}
+
+pub struct SetReflection<T: ?Sized>(PhantomData<fn() -> *const T>);
+
+impl<T: Reflection + ?Sized> Reflection for SetReflection<T> {
+    fn schema(doc: &mut Document) -> Schema {
+        Schema::new("array")
+            .with("items", doc.add::<T>())
+            .with("uniqueItems", true)
+    }
+}
+
+pub struct MapReflection<K: ?Sized, V: ?Sized>(PhantomData<fn() -> (*const K, *const V)>);
+
+impl<K: Reflection + ?Sized, V: Reflection + ?Sized> Reflection for MapReflection<K, V> {
+    fn schema(doc: &mut Document) -> Schema {
+        // TODO: JSON-Schema is only able to express keys that are strings, `K` could be any type,
+        //  until `Schema` is able to express this we do not include the schema of the key.
+        Schema::new("object").with("additionalProperties", doc.add::<V>())
+    }
+}
@@ -0,0 +1,53 @@
+use alloc::collections::BinaryHeap;
+use core::marker::PhantomData;
+
+use error_stack::{Result, ResultExt};
+
+use super::{capacity, visit_array, SequenceReflection};
+use crate::{
+    error::{DeserializeError, SerializeError, VisitorError},
+    ArrayAccess, Deserialize, Deserializer, Document, Serialize, Serializer, Visitor,
+};
+
+struct BinaryHeapVisitor<T>(PhantomData<fn() -> *const T>);
+
+impl<'de, T: Deserialize<'de> + Ord> Visitor<'de> for BinaryHeapVisitor<T> {
+    type Value = BinaryHeap<T>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut heap = BinaryHeap::with_capacity(capacity(array.size_hint()));
+
+        visit_array(array, |item| {
+            heap.push(item);
+            true
+        })
+        .map(|()| heap)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Ord> Deserialize<'de> for BinaryHeap<T> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_array(BinaryHeapVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
+
+impl<T: Serialize> Serialize for BinaryHeap<T> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .collect_array(self)
+            .change_context(SerializeError)
+    }
+}
@@ -0,0 +1,72 @@
+use alloc::collections::BTreeMap;
+use core::marker::PhantomData;
+
+use error_stack::{Result, ResultExt};
+
+use super::{visit_array, visit_object, MapReflection};
+use crate::{
+    error::{DeserializeError, SerializeError, VisitorError},
+    ArrayAccess, Deserialize, Deserializer, Document, ObjectAccess, Serialize, Serializer, Visitor,
+};
+
+struct BTreeMapVisitor<K, V>(PhantomData<fn() -> BTreeMap<K, V>>);
+
+impl<'de, K, V> Visitor<'de> for BTreeMapVisitor<K, V>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    type Value = BTreeMap<K, V>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    /// Maps whose keys cannot be represented in the format are stored as an array of entries,
+    /// where every entry is a `[key, value]` pair.
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut map = BTreeMap::new();
+
+        visit_array(array, |(key, value)| map.insert(key, value).is_none()).map(|()| map)
+    }
+
+    fn visit_object<A>(self, object: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        let mut map = BTreeMap::new();
+
+        visit_object(object, |key, value| map.insert(key, value).is_none()).map(|()| map)
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for BTreeMap<K, V>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    type Reflection = MapReflection<K::Reflection, V::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_object(BTreeMapVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
+
+impl<K, V> Serialize for BTreeMap<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    type Reflection = MapReflection<K::Reflection, V::Reflection>;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .collect_object(self)
+            .change_context(SerializeError)
+    }
+}
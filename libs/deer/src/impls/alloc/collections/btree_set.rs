@@ -0,0 +1,49 @@
+use alloc::collections::BTreeSet;
+use core::marker::PhantomData;
+
+use error_stack::{Result, ResultExt};
+
+use super::{visit_array, SetReflection};
+use crate::{
+    error::{DeserializeError, SerializeError, VisitorError},
+    ArrayAccess, Deserialize, Deserializer, Document, Serialize, Serializer, Visitor,
+};
+
+struct BTreeSetVisitor<T>(PhantomData<fn() -> *const T>);
+
+impl<'de, T: Deserialize<'de> + Ord> Visitor<'de> for BTreeSetVisitor<T> {
+    type Value = BTreeSet<T>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut set = BTreeSet::new();
+
+        visit_array(array, |item| set.insert(item)).map(|()| set)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Ord> Deserialize<'de> for BTreeSet<T> {
+    type Reflection = SetReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_array(BTreeSetVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
+
+impl<T: Serialize> Serialize for BTreeSet<T> {
+    type Reflection = SetReflection<T::Reflection>;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .collect_array(self)
+            .change_context(SerializeError)
+    }
+}
@@ -0,0 +1,53 @@
+use alloc::collections::VecDeque;
+use core::marker::PhantomData;
+
+use error_stack::{Result, ResultExt};
+
+use super::{capacity, visit_array, SequenceReflection};
+use crate::{
+    error::{DeserializeError, SerializeError, VisitorError},
+    ArrayAccess, Deserialize, Deserializer, Document, Serialize, Serializer, Visitor,
+};
+
+struct VecDequeVisitor<T>(PhantomData<fn() -> *const T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for VecDequeVisitor<T> {
+    type Value = VecDeque<T>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut deque = VecDeque::with_capacity(capacity(array.size_hint()));
+
+        visit_array(array, |item| {
+            deque.push_back(item);
+            true
+        })
+        .map(|()| deque)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for VecDeque<T> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_array(VecDequeVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
+
+impl<T: Serialize> Serialize for VecDeque<T> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .collect_array(self)
+            .change_context(SerializeError)
+    }
+}
@@ -0,0 +1,50 @@
+use alloc::{borrow::ToOwned, string::String};
+
+use error_stack::{Result, ResultExt};
+
+use crate::{
+    error::{DeserializeError, SerializeError, VisitorError},
+    Deserialize, Deserializer, Document, Serialize, Serializer, Visitor,
+};
+
+struct StringVisitor;
+
+impl<'de> Visitor<'de> for StringVisitor {
+    type Value = String;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_char(self, value: char) -> Result<Self::Value, VisitorError> {
+        Ok(String::from(value))
+    }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, VisitorError> {
+        Ok(value.to_owned())
+    }
+
+    fn visit_string(self, value: String) -> Result<Self::Value, VisitorError> {
+        Ok(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for String {
+    type Reflection = str;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_string(StringVisitor)
+            .change_context(DeserializeError)
+    }
+}
+
+impl Serialize for String {
+    type Reflection = str;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .serialize_str(self)
+            .change_context(SerializeError)
+    }
+}
@@ -0,0 +1,25 @@
+use alloc::sync::Arc;
+
+use error_stack::Result;
+
+use crate::{
+    error::{DeserializeError, SerializeError},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+// Values are never shared after deserialization, every `Arc` has a strong count of one.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Arc<T> {
+    type Reflection = T::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for Arc<T> {
+    type Reflection = T::Reflection;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        T::serialize(self, serializer)
+    }
+}
@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use error_stack::{Result, ResultExt};
+
+use super::collections::{capacity, visit_array, SequenceReflection};
+use crate::{
+    error::{DeserializeError, SerializeError, VisitorError},
+    ArrayAccess, Deserialize, Deserializer, Document, Serialize, Serializer, Visitor,
+};
+
+struct VecVisitor<T>(PhantomData<fn() -> *const T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for VecVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut vec = Vec::with_capacity(capacity(array.size_hint()));
+
+        visit_array(array, |item| {
+            vec.push(item);
+            true
+        })
+        .map(|()| vec)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec<T> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_array(VecVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
+
+impl<T: Serialize> Serialize for Vec<T> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+// Slices are part of `core`, but share the reflection with `Vec`, which is only available with
+// `alloc`.
+impl<T: Serialize> Serialize for [T] {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .collect_array(self)
+            .change_context(SerializeError)
+    }
+}
@@ -5,9 +5,10 @@ use error_stack::{Report, Result, ResultExt};
 use crate::{
     error::{
         ArrayAccessError, ArrayLengthError, DeserializeError, ExpectedLength, Location,
-        ReceivedLength, Variant, VisitorError,
+        ReceivedLength, SerializeError, Variant, VisitorError,
     },
-    ArrayAccess, Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+    ArrayAccess, Deserialize, Deserializer, Document, Reflection, Schema, Serialize, Serializer,
+    Visitor,
 };
 
 struct ArrayVisitor<'de, T: Deserialize<'de>, const N: usize>(PhantomData<fn(&'de ()) -> [T; N]>);
@@ -169,3 +170,13 @@ impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for [T; N] {
             .change_context(DeserializeError)
     }
 }
+
+impl<T: Serialize, const N: usize> Serialize for [T; N] {
+    type Reflection = ArrayReflection<T::Reflection, N>;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .collect_array(self)
+            .change_context(SerializeError)
+    }
+}
@@ -1,8 +1,8 @@
 use error_stack::{Result, ResultExt};
 
 use crate::{
-    error::{DeserializeError, VisitorError},
-    Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+    error::{DeserializeError, SerializeError, VisitorError},
+    Deserialize, Deserializer, Document, Reflection, Schema, Serialize, Serializer, Visitor,
 };
 
 struct BoolVisitor;
@@ -34,3 +34,13 @@ impl<'de> Deserialize<'de> for bool {
             .change_context(DeserializeError)
     }
 }
+
+impl Serialize for bool {
+    type Reflection = Self;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .serialize_bool(*self)
+            .change_context(SerializeError)
+    }
+}
@@ -2,8 +2,12 @@ use error_stack::{Report, Result, ResultExt};
 use num_traits::ToPrimitive;
 
 use crate::{
-    error::{DeserializeError, ExpectedType, ReceivedValue, ValueError, Variant, VisitorError},
-    Deserialize, Deserializer, Document, Number, Reflection, Schema, Visitor,
+    error::{
+        DeserializeError, ExpectedType, ReceivedValue, SerializeError, ValueError, Variant,
+        VisitorError,
+    },
+    Deserialize, Deserializer, Document, Number, Reflection, Schema, Serialize, Serializer,
+    Visitor,
 };
 
 macro_rules! impl_reflection {
@@ -33,3 +37,23 @@ impl_num!(
     num_number!(f64::to_f64);
     num_as_lossy!(i64::visit_i64, i128::visit_i128, u64::visit_u64, u128::visit_u128);
 );
+
+impl Serialize for f32 {
+    type Reflection = Self;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .serialize_f32(*self)
+            .change_context(SerializeError)
+    }
+}
+
+impl Serialize for f64 {
+    type Reflection = Self;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .serialize_f64(*self)
+            .change_context(SerializeError)
+    }
+}
@@ -4,8 +4,12 @@ use error_stack::{Report, Result, ResultExt};
 use num_traits::ToPrimitive;
 
 use crate::{
-    error::{DeserializeError, ExpectedType, ReceivedValue, ValueError, Variant, VisitorError},
-    Deserialize, Deserializer, Document, Number, Reflection, Schema, Visitor,
+    error::{
+        DeserializeError, ExpectedType, ReceivedValue, SerializeError, ValueError, Variant,
+        VisitorError,
+    },
+    Deserialize, Deserializer, Document, Number, Reflection, Schema, Serialize, Serializer,
+    Visitor,
 };
 
 macro_rules! impl_reflection {
@@ -189,3 +193,34 @@ impl<'de> Deserialize<'de> for isize {
         i128::deserialize(de).map(|value| value as Self)
     }
 }
+
+macro_rules! impl_serialize {
+    ($($primitive:ident::$method:ident),* $(,)?) => {
+        $(
+            impl Serialize for $primitive {
+                type Reflection = Self;
+
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+                    serializer
+                        .$method(*self)
+                        .change_context(SerializeError)
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize![
+    u8::serialize_u8,
+    u16::serialize_u16,
+    u32::serialize_u32,
+    u64::serialize_u64,
+    u128::serialize_u128,
+    usize::serialize_usize,
+    i8::serialize_i8,
+    i16::serialize_i16,
+    i32::serialize_i32,
+    i64::serialize_i64,
+    i128::serialize_i128,
+    isize::serialize_isize,
+];
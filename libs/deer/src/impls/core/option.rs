@@ -3,8 +3,9 @@ use core::marker::PhantomData;
 use error_stack::{Result, ResultExt};
 
 use crate::{
-    error::{DeserializeError, Location, VisitorError},
-    Deserialize, Deserializer, Document, OptionalVisitor, Reflection, Schema,
+    error::{DeserializeError, Location, SerializeError, VisitorError},
+    Deserialize, Deserializer, Document, OptionalVisitor, Reflection, Schema, Serialize,
+    Serializer,
 };
 
 struct OptionVisitor<T>(PhantomData<fn() -> *const T>);
@@ -56,3 +57,16 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for Option<T> {
             .change_context(DeserializeError)
     }
 }
+
+impl<T: Serialize> Serialize for Option<T> {
+    type Reflection = OptionReflection<T::Reflection>;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        match self {
+            None => serializer.serialize_null().change_context(SerializeError),
+            Some(value) => value
+                .serialize(serializer)
+                .attach(Location::Variant("Some")),
+        }
+    }
+}
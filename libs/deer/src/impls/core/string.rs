@@ -3,8 +3,11 @@ use core::marker::PhantomData;
 use error_stack::{Report, Result, ResultExt};
 
 use crate::{
-    error::{DeserializeError, ExpectedType, ReceivedType, TypeError, Variant, VisitorError},
-    Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+    error::{
+        DeserializeError, ExpectedType, ReceivedType, SerializeError, TypeError, Variant,
+        VisitorError,
+    },
+    Deserialize, Deserializer, Document, Reflection, Schema, Serialize, Serializer, Visitor,
 };
 
 struct StrVisitor<'a>(PhantomData<fn() -> &'a ()>);
@@ -85,3 +88,23 @@ impl<'de> Deserialize<'de> for char {
             .change_context(DeserializeError)
     }
 }
+
+impl Serialize for str {
+    type Reflection = Self;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .serialize_str(self)
+            .change_context(SerializeError)
+    }
+}
+
+impl Serialize for char {
+    type Reflection = Self;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+        serializer
+            .serialize_char(*self)
+            .change_context(SerializeError)
+    }
+}
@@ -4,11 +4,12 @@ use error_stack::{Report, Result, ResultExt};
 
 use crate::{
     error::{
-        ArrayLengthError, DeserializeError, ExpectedLength, Location, ReceivedLength, Variant,
-        VisitorError,
+        ArrayLengthError, DeserializeError, ExpectedLength, Location, ReceivedLength,
+        SerializeError, Variant, VisitorError,
     },
     ext::TupleExt,
-    ArrayAccess, Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+    ArrayAccess, Deserialize, Deserializer, Document, Reflection, Schema, Serialize,
+    SerializeArray, Serializer, Visitor,
 };
 
 #[rustfmt::skip]
@@ -109,6 +110,38 @@ macro_rules! impl_tuple {
                             .change_context(DeserializeError)
             }
         }
+
+        $(#[$meta])*
+        impl<$($elem,)*> Serialize for ($($elem,)*)
+        where
+            $($elem: Serialize),*
+        {
+            type Reflection = $reflection<$($elem::Reflection),*>;
+
+            // Reason: the last increment of `index` is never read
+            #[allow(non_snake_case, unused_assignments)]
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<(), SerializeError> {
+                let ($($elem,)*) = self;
+
+                let mut array = serializer
+                    .serialize_array(Some($expected))
+                    .change_context(SerializeError)?;
+
+                let mut index = 0;
+
+                $(
+                let $elem = array.serialize_item($elem).attach(Location::Tuple(index));
+                index += 1;
+                )*
+
+                let value = ($($elem,)*).fold_reports();
+
+                (value, array.end())
+                    .fold_reports()
+                    .map(|_| ())
+                    .change_context(SerializeError)
+            }
+        }
     };
 }
 
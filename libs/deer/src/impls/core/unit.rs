@@ -1,8 +1,8 @@
 use error_stack::ResultExt;
 
 use crate::{
-    error::{DeserializeError, VisitorError},
-    Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+    error::{DeserializeError, SerializeError, VisitorError},
+    Deserialize, Deserializer, Document, Reflection, Schema, Serialize, Serializer, Visitor,
 };
 
 struct UnitVisitor;
@@ -44,3 +44,11 @@ impl<'de> Deserialize<'de> for () {
 
 // we do not implement for `!` (never type), as that type is *never* supposed to be instantiated/be
 // present, a `!` value also has no type
+
+impl Serialize for () {
+    type Reflection = UnitReflection;
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> error_stack::Result<(), SerializeError> {
+        serializer.serialize_null().change_context(SerializeError)
+    }
+}
@@ -0,0 +1,4 @@
+mod collections;
+mod ffi;
+mod path;
+mod time;
@@ -0,0 +1,77 @@
+use core::{
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+use std::collections::HashMap;
+
+use error_stack::{Result, ResultExt};
+
+use crate::{
+    error::{DeserializeError, SerializeError, VisitorError},
+    impls::alloc::collections::{capacity, visit_array, visit_object, MapReflection},
+    ArrayAccess, Deserialize, Deserializer, Document, ObjectAccess, Serialize, Serializer, Visitor,
+};
+
+struct HashMapVisitor<K, V, S>(PhantomData<HashMap<K, V, S>>);
+
+impl<'de, K, V, S> Visitor<'de> for HashMapVisitor<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    type Value = HashMap<K, V, S>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    /// Maps whose keys cannot be represented in the format are stored as an array of entries,
+    /// where every entry is a `[key, value]` pair.
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut map = HashMap::with_capacity_and_hasher(capacity(array.size_hint()), S::default());
+
+        visit_array(array, |(key, value)| map.insert(key, value).is_none()).map(|()| map)
+    }
+
+    fn visit_object<A>(self, object: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        let mut map = HashMap::with_capacity_and_hasher(capacity(object.size_hint()), S::default());
+
+        visit_object(object, |key, value| map.insert(key, value).is_none()).map(|()| map)
+    }
+}
+
+impl<'de, K, V, S> Deserialize<'de> for HashMap<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    type Reflection = MapReflection<K::Reflection, V::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_object(HashMapVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
+
+impl<K, V, S> Serialize for HashMap<K, V, S>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    type Reflection = MapReflection<K::Reflection, V::Reflection>;
+
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<(), SerializeError> {
+        serializer
+            .collect_object(self)
+            .change_context(SerializeError)
+    }
+}
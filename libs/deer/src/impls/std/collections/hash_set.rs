@@ -0,0 +1,60 @@
+use core::{
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+use std::collections::HashSet;
+
+use error_stack::{Result, ResultExt};
+
+use crate::{
+    error::{DeserializeError, SerializeError, VisitorError},
+    impls::alloc::collections::{capacity, visit_array, SetReflection},
+    ArrayAccess, Deserialize, Deserializer, Document, Serialize, Serializer, Visitor,
+};
+
+struct HashSetVisitor<T, S>(PhantomData<fn() -> HashSet<T, S>>);
+
+impl<'de, T, S> Visitor<'de> for HashSetVisitor<T, S>
+where
+    T: Deserialize<'de> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Value = HashSet<T, S>;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut set = HashSet::with_capacity_and_hasher(capacity(array.size_hint()), S::default());
+
+        visit_array(array, |item| set.insert(item)).map(|()| set)
+    }
+}
+
+impl<'de, T, S> Deserialize<'de> for HashSet<T, S>
+where
+    T: Deserialize<'de> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Reflection = SetReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_array(HashSetVisitor(PhantomData))
+            .change_context(DeserializeError)
+    }
+}
+
+impl<T: Serialize, S> Serialize for HashSet<T, S> {
+    type Reflection = SetReflection<T::Reflection>;
+
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<(), SerializeError> {
+        serializer
+            .collect_array(self)
+            .change_context(SerializeError)
+    }
+}
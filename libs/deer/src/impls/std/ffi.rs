@@ -0,0 +1,58 @@
+use std::ffi::{OsStr, OsString};
+
+use error_stack::{Result, ResultExt};
+
+use crate::{
+    error::{DeserializeError, VisitorError},
+    Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+};
+
+struct OsStringVisitor;
+
+impl<'de> Visitor<'de> for OsStringVisitor {
+    type Value = OsString;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, VisitorError> {
+        Ok(OsString::from(value))
+    }
+
+    fn visit_string(self, value: String) -> Result<Self::Value, VisitorError> {
+        Ok(OsString::from(value))
+    }
+
+    // On unix platforms an `OsString` is an arbitrary sequence of bytes, which might not be valid
+    // UTF-8, these can only be represented as bytes.
+    #[cfg(unix)]
+    fn visit_bytes(self, value: &[u8]) -> Result<Self::Value, VisitorError> {
+        use std::os::unix::ffi::OsStrExt;
+
+        Ok(OsStr::from_bytes(value).to_os_string())
+    }
+
+    #[cfg(unix)]
+    fn visit_bytes_buffer(self, value: Vec<u8>) -> Result<Self::Value, VisitorError> {
+        use std::os::unix::ffi::OsStringExt;
+
+        Ok(OsString::from_vec(value))
+    }
+}
+
+impl Reflection for OsStr {
+    fn schema(doc: &mut Document) -> Schema {
+        str::schema(doc)
+    }
+}
+
+impl<'de> Deserialize<'de> for OsString {
+    type Reflection = OsStr;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        deserializer
+            .deserialize_string(OsStringVisitor)
+            .change_context(DeserializeError)
+    }
+}
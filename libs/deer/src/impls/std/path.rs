@@ -0,0 +1,22 @@
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use error_stack::Result;
+
+use crate::{error::DeserializeError, Deserialize, Deserializer, Document, Reflection, Schema};
+
+impl Reflection for Path {
+    fn schema(doc: &mut Document) -> Schema {
+        str::schema(doc)
+    }
+}
+
+impl<'de> Deserialize<'de> for PathBuf {
+    type Reflection = Path;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        OsString::deserialize(deserializer).map(Self::from)
+    }
+}
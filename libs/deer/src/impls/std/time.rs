@@ -0,0 +1,26 @@
+use core::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use error_stack::{Report, Result};
+
+use crate::{
+    error::{DeserializeError, ExpectedType, ReceivedValue, ValueError, Variant},
+    Deserialize, Deserializer,
+};
+
+/// A [`SystemTime`] is represented as the (fractional) seconds since the [`UNIX_EPOCH`], equivalent
+/// to the representation of [`Duration`].
+impl<'de> Deserialize<'de> for SystemTime {
+    type Reflection = <Duration as Deserialize<'de>>::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
+        let duration = Duration::deserialize(deserializer)?;
+
+        UNIX_EPOCH.checked_add(duration).ok_or_else(|| {
+            Report::new(ValueError.into_error())
+                .attach(ExpectedType::new(Self::reflection()))
+                .attach(ReceivedValue::new(duration.as_secs_f64()))
+                .change_context(DeserializeError)
+        })
+    }
+}
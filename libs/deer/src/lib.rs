@@ -19,6 +19,8 @@
 use alloc::{string::String, vec::Vec};
 use core::marker::PhantomData;
 
+#[cfg(feature = "derive")]
+pub use deer_macros::Deserialize;
 use error_stack::{Report, Result, ResultExt};
 use num_traits::{FromPrimitive, ToPrimitive};
 pub use schema::{Document, Reflection, Schema};
@@ -32,7 +34,11 @@ use crate::{
     },
     schema::visitor,
 };
-pub use crate::{context::Context, number::Number};
+pub use crate::{
+    context::Context,
+    number::Number,
+    ser::{Serialize, SerializeArray, SerializeObject, Serializer},
+};
 
 mod context;
 pub mod error;
@@ -44,6 +50,7 @@ mod ext;
 pub mod helpers;
 mod number;
 pub mod schema;
+mod ser;
 pub mod value;
 
 extern crate alloc;
@@ -60,6 +67,26 @@ pub mod export {
     pub use error_stack;
 }
 
+// Implementation details of `deer-macros`, not public API.
+#[doc(hidden)]
+pub mod __private {
+    use error_stack::{Context, Report};
+
+    use crate::ext::TupleExt;
+
+    /// Combine two results, if both failed the reports are merged.
+    ///
+    /// # Errors
+    ///
+    /// Either result is an error
+    pub fn fold<T, U, C: Context>(
+        lhs: Result<T, Report<C>>,
+        rhs: Result<U, Report<C>>,
+    ) -> Result<(T, U), Report<C>> {
+        (lhs, rhs).fold_reports()
+    }
+}
+
 struct GenericFieldVisitor<T, U>(PhantomData<fn() -> *const (T, U)>);
 
 impl<'de, T: Deserialize<'de>, U: Deserialize<'de>> FieldVisitor<'de>
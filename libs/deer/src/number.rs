@@ -1,8 +1,6 @@
 #[cfg(all(not(feature = "std"), feature = "arbitrary-precision"))]
 use alloc::string::{String, ToString};
 use core::fmt::{Display, Formatter};
-#[cfg(not(feature = "arbitrary-precision"))]
-use core::ops::Neg;
 
 use error_stack::ResultExt;
 use num_traits::{FromPrimitive, ToPrimitive};
@@ -195,7 +193,7 @@ impl ToPrimitive for Number {
     fn to_isize(&self) -> Option<isize> {
         match self.0 {
             OpaqueNumber::PosInt(int) => isize::try_from(int).ok(),
-            OpaqueNumber::NegInt(int) => isize::try_from(int).ok().map(Neg::neg),
+            OpaqueNumber::NegInt(int) => isize::try_from(-i128::from(int)).ok(),
             OpaqueNumber::Float(_) => None,
         }
     }
@@ -208,11 +206,11 @@ impl ToPrimitive for Number {
     #[cfg(not(feature = "arbitrary-precision"))]
     fn to_i64(&self) -> Option<i64> {
         // we cannot guarantee that post neg and pos ints actually fit into i64, as they both take a
-        // single bit more, we therefore convert both first to i64 (or at least try to) and then
-        // negate the negative numbers
+        // single bit more, negative numbers are negated in `i128` first, as `i64::MIN` has no
+        // positive counterpart in `i64`
         match self.0 {
             OpaqueNumber::PosInt(int) => i64::try_from(int).ok(),
-            OpaqueNumber::NegInt(int) => i64::try_from(int).ok().map(Neg::neg),
+            OpaqueNumber::NegInt(int) => i64::try_from(-i128::from(int)).ok(),
             OpaqueNumber::Float(_) => None,
         }
     }
@@ -6,6 +6,9 @@ use core::any::{type_name, TypeId};
 
 use serde::{ser::SerializeMap, Serialize, Serializer};
 
+#[cfg(feature = "schema-export")]
+pub mod export;
+
 pub trait Reflection: 'static {
     fn schema(doc: &mut Document) -> Schema;
 
@@ -41,6 +44,7 @@ impl Serialize for SerializeSchema<'_> {
 //  The problem here mainly is: which crate to use, one can use utoipa (but that has significant
 //  overhead)  there's no real library out there that properly just provides the types
 //  necessary.
+//  `export` translates the schema to JSON-Schema (draft 2020-12) and OpenAPI 3.1 after the fact.
 // `Serialize` is not implemented to ensure that one does not accidentally create a forever
 // recursing type definition
 pub struct Schema {
@@ -0,0 +1,316 @@
+//! Export of a [`Document`] as a [JSON Schema (draft 2020-12)] or as [OpenAPI 3.1] components.
+//!
+//! Schemas created through [`Reflection`] are allowed to use types which are not part of JSON
+//! Schema (`any`, `none`, `bytes` and `binary`), these are translated to their closest standard
+//! equivalent. Definitions are renamed, so that they are valid as part of a URI fragment and as
+//! name of an `OpenAPI` component, e.g. `0001-alloc::vec::Vec<u8>` is exported as
+//! `0001-alloc.vec.Vec_u8_`.
+//!
+//! [JSON Schema (draft 2020-12)]: https://json-schema.org/draft/2020-12/json-schema-core.html
+//! [OpenAPI 3.1]: https://spec.openapis.org/oas/v3.1.0#components-object
+//! [`Reflection`]: crate::Reflection
+
+use alloc::{borrow::ToOwned, collections::BTreeMap, format, string::String};
+
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::{
+    schema::{Reference, SerializeSchema},
+    Document,
+};
+
+/// Meta-schema of every exported JSON Schema.
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Prefix of every path created by [`Reference::as_path`].
+const DEFINITIONS: &str = "#/$defs/";
+const COMPONENTS: &str = "#/components/schemas/";
+
+/// Name of a definition, restricted to the characters allowed in `OpenAPI` component names
+/// (`^[a-zA-Z0-9.\-_]+$`), which are also valid in a URI fragment without any escaping.
+fn sanitize(bare: &str) -> String {
+    bare.replace("::", ".")
+        .chars()
+        .map(|char| {
+            if char.is_ascii_alphanumeric() || matches!(char, '.' | '-' | '_') {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Rewrite every `$ref` in `value` to point to the definition under `prefix`.
+fn rewrite_references(value: &mut Value, prefix: &str) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in &mut *object {
+                match value {
+                    Value::String(path) if key == "$ref" => {
+                        if let Some(bare) = path.strip_prefix(DEFINITIONS) {
+                            *path = format!("{prefix}{}", sanitize(bare));
+                        }
+                    }
+                    value => rewrite_references(value, prefix),
+                }
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                rewrite_references(value, prefix);
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {}
+    }
+}
+
+/// Replace the types of a schema which are not part of JSON Schema.
+fn translate_type(schema: &mut Map<String, Value>) {
+    match schema.get("type").and_then(Value::as_str) {
+        // every value is accepted, which is the same as not constraining the type
+        Some("any") => {
+            schema.remove("type");
+        }
+        // the absence of a value is represented as `null` in JSON
+        Some("none") => {
+            schema.insert("type".to_owned(), Value::from("null"));
+        }
+        Some("bytes" | "binary") => {
+            schema.insert("type".to_owned(), Value::from("string"));
+            schema.insert(
+                "contentMediaType".to_owned(),
+                Value::from("application/octet-stream"),
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Every definition of the document, keyed by their sanitized name.
+fn definitions(
+    document: &Document,
+    prefix: &str,
+) -> Result<BTreeMap<String, Value>, serde_json::Error> {
+    document
+        .references
+        .iter()
+        .filter_map(|(id, reference)| document.schemas.get(id).map(|schema| (reference, schema)))
+        .map(|(reference, schema)| {
+            let mut value = serde_json::to_value(SerializeSchema(schema))?;
+
+            if let Value::Object(schema) = &mut value {
+                translate_type(schema);
+            }
+
+            rewrite_references(&mut value, prefix);
+
+            Ok((sanitize(&reference.as_bare()), value))
+        })
+        .collect()
+}
+
+fn root(document: &Document) -> &Reference {
+    document
+        .references
+        .get(&document.id)
+        .expect("`new()` should have created a schema for the main schema")
+}
+
+/// Create a standalone JSON Schema (draft 2020-12) of the document.
+///
+/// The main schema is referenced through `$ref`, while every schema is part of `$defs`.
+///
+/// # Errors
+///
+/// Any value attached to a [`Schema`] fails to serialize, this is the case for integers outside
+/// the range of `i64` and `u64`, unless the `arbitrary_precision` feature of `serde_json` is
+/// enabled.
+///
+/// [`Schema`]: crate::Schema
+pub fn json_schema(document: &Document) -> Result<Value, serde_json::Error> {
+    let mut schema = Map::new();
+
+    schema.insert("$schema".to_owned(), Value::from(DIALECT));
+    schema.insert(
+        "$ref".to_owned(),
+        Value::from(format!(
+            "{DEFINITIONS}{}",
+            sanitize(&root(document).as_bare())
+        )),
+    );
+    schema.insert(
+        "$defs".to_owned(),
+        Value::Object(definitions(document, DEFINITIONS)?.into_iter().collect()),
+    );
+
+    Ok(Value::Object(schema))
+}
+
+/// Schemas of a document, which are used as `components` of an `OpenAPI` 3.1 specification.
+///
+/// Serializes as `{"schemas": {...}}`, which can be merged into the `components` of a
+/// specification, the main schema is referenced through [`Self::reference`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    root: String,
+    schemas: BTreeMap<String, Value>,
+}
+
+impl Components {
+    /// Name of the component of the main schema.
+    #[must_use]
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    /// Path to the main schema, to be used as `$ref`.
+    #[must_use]
+    pub fn reference(&self) -> String {
+        format!("{COMPONENTS}{}", self.root)
+    }
+
+    #[must_use]
+    pub const fn schemas(&self) -> &BTreeMap<String, Value> {
+        &self.schemas
+    }
+
+    #[must_use]
+    pub fn into_schemas(self) -> BTreeMap<String, Value> {
+        self.schemas
+    }
+}
+
+impl Serialize for Components {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct SerializeComponents<'a> {
+            schemas: &'a BTreeMap<String, Value>,
+        }
+
+        SerializeComponents {
+            schemas: &self.schemas,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Create the `OpenAPI` 3.1 components of the document.
+///
+/// Every reference points to `#/components/schemas/`.
+///
+/// # Errors
+///
+/// Any value attached to a [`Schema`] fails to serialize, see [`json_schema`].
+///
+/// [`Schema`]: crate::Schema
+pub fn openapi(document: &Document) -> Result<Components, serde_json::Error> {
+    Ok(Components {
+        root: sanitize(&root(document).as_bare()),
+        schemas: definitions(document, COMPONENTS)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use serde_json::{json, to_value};
+
+    use super::{json_schema, openapi, sanitize};
+    use crate::{Deserialize, Document, Reflection, Schema};
+
+    struct Bytes;
+
+    impl Reflection for Bytes {
+        fn schema(_: &mut Document) -> Schema {
+            Schema::new("bytes").with("maxLength", 4)
+        }
+    }
+
+    struct Record;
+
+    impl Reflection for Record {
+        fn schema(doc: &mut Document) -> Schema {
+            let mut properties = BTreeMap::new();
+            properties.insert("data", doc.add::<Bytes>());
+            properties.insert("items", doc.add::<<Vec<u8> as Deserialize>::Reflection>());
+
+            Schema::new("object").with("properties", properties)
+        }
+    }
+
+    #[test]
+    fn sanitize_name() {
+        assert_eq!(
+            sanitize("0001-alloc::vec::Vec<u8>"),
+            "0001-alloc.vec.Vec_u8_"
+        );
+        assert_eq!(sanitize("0002-[u8]"), "0002-_u8_");
+    }
+
+    #[test]
+    fn json_schema_document() {
+        let schema = json_schema(&Record::document()).expect("should be able to export");
+
+        assert_eq!(
+            schema,
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "$ref": "#/$defs/0000-deer.schema.export.tests.Record",
+                "$defs": {
+                    "0000-deer.schema.export.tests.Record": {
+                        "type": "object",
+                        "properties": {
+                            "data": {"$ref": "#/$defs/0001-deer.schema.export.tests.Bytes"},
+                            "items": {"$ref": "#/$defs/0002-deer.impls.alloc.collections.SequenceReflection_u8_"}
+                        }
+                    },
+                    "0001-deer.schema.export.tests.Bytes": {
+                        "type": "string",
+                        "contentMediaType": "application/octet-stream",
+                        "maxLength": 4
+                    },
+                    "0002-deer.impls.alloc.collections.SequenceReflection_u8_": {
+                        "type": "array",
+                        "items": {"$ref": "#/$defs/0003-u8"}
+                    },
+                    "0003-u8": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "maximum": 255
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn openapi_components() {
+        let components = openapi(&Record::document()).expect("should be able to export");
+
+        assert_eq!(components.root(), "0000-deer.schema.export.tests.Record");
+        assert_eq!(
+            components.reference(),
+            "#/components/schemas/0000-deer.schema.export.tests.Record"
+        );
+
+        let value = to_value(&components).expect("should be able to serialize");
+
+        assert_eq!(
+            value["schemas"]["0002-deer.impls.alloc.collections.SequenceReflection_u8_"],
+            json!({
+                "type": "array",
+                "items": {"$ref": "#/components/schemas/0003-u8"}
+            })
+        );
+        assert_eq!(
+            value["schemas"]["0000-deer.schema.export.tests.Record"]["properties"]["items"],
+            json!({"$ref": "#/components/schemas/0002-deer.impls.alloc.collections.SequenceReflection_u8_"})
+        );
+    }
+}
@@ -0,0 +1,348 @@
+use error_stack::{Report, Result, ResultExt};
+use num_traits::FromPrimitive;
+
+use crate::{
+    error::{
+        ExpectedType, Location, ReceivedValue, ResultExtPrivate, SerializeError, SerializerError,
+        ValueError, Variant,
+    },
+    Context, Number, Reflection,
+};
+
+macro_rules! derive_to_number {
+    [$($method:ident ($primitive:ident) <- $from:ident,)*] => {
+        $(derive_to_number!(#internal, $method; $primitive, $from);)*
+    };
+
+    (#internal, $method:ident; $primitive:ident, $from:ident) => {
+        /// Automatically implemented convenience method, which converts the primitive to a
+        /// [`Number`] and uses [`Self::serialize_number`], will otherwise error out.
+        ///
+        /// # Errors
+        ///
+        /// The value is not representable as [`Number`] or the value could not be written
+        fn $method(self, value: $primitive) -> Result<(), SerializerError> {
+            let number = Number::$from(value).ok_or_else(|| {
+                Report::new(ValueError.into_error())
+                    .attach(ExpectedType::new(Number::document()))
+                    .attach(ReceivedValue::new(value))
+                    .change_context(SerializerError)
+            })?;
+
+            self.serialize_number(&number)
+        }
+    };
+}
+
+/// A **data format** that can serialize any data structure which is supported by deer.
+///
+/// This trait is the counterpart to [`Deserializer`] and uses the same data model, values are
+/// written using one of the primitives (`null`, `bool`, `number`, `string`, `bytes`) or one of the
+/// composite types (`array`, `object`). `none` has no representation, as a missing value is never
+/// written.
+///
+/// Every method consumes the [`Serializer`], composite types return a [`SerializeArray`] or
+/// [`SerializeObject`], which is used to write the individual items.
+///
+/// [`Deserializer`]: crate::Deserializer
+#[must_use]
+pub trait Serializer: Sized {
+    type SerializeArray: SerializeArray;
+    type SerializeObject: SerializeObject;
+
+    fn context(&self) -> &Context;
+
+    fn is_human_readable(&self) -> bool {
+        true
+    }
+
+    /// Serialize a `null` value, which signals the explicit absence of a value.
+    ///
+    /// # Errors
+    ///
+    /// The value could not be written
+    fn serialize_null(self) -> Result<(), SerializerError>;
+
+    /// Serialize a [`bool`] value.
+    ///
+    /// # Errors
+    ///
+    /// The value could not be written
+    fn serialize_bool(self, value: bool) -> Result<(), SerializerError>;
+
+    /// Serialize a [`Number`] value.
+    ///
+    /// Every primitive number type is converted to [`Number`] and then written using this method,
+    /// formats which do not support a specific number (e.g. `NaN` in `JSON`) should error out.
+    ///
+    /// # Errors
+    ///
+    /// The number is not supported by the format or could not be written
+    fn serialize_number(self, value: &Number) -> Result<(), SerializerError>;
+
+    /// Serialize a [`char`] value.
+    ///
+    /// The default implementation writes the character as a string of length one.
+    ///
+    /// # Errors
+    ///
+    /// The value could not be written
+    fn serialize_char(self, value: char) -> Result<(), SerializerError> {
+        let mut buffer = [0; 4];
+
+        self.serialize_str(value.encode_utf8(&mut buffer))
+    }
+
+    /// Serialize a string value.
+    ///
+    /// # Errors
+    ///
+    /// The value could not be written
+    fn serialize_str(self, value: &str) -> Result<(), SerializerError>;
+
+    /// Serialize a sequence of bytes.
+    ///
+    /// Formats which do not support bytes natively are free to choose any representation, as long
+    /// as the [`Deserializer`] of the same format is able to read it again.
+    ///
+    /// # Errors
+    ///
+    /// The value is not supported by the format or could not be written
+    ///
+    /// [`Deserializer`]: crate::Deserializer
+    fn serialize_bytes(self, value: &[u8]) -> Result<(), SerializerError>;
+
+    /// Begin to serialize an `array`.
+    ///
+    /// The `length` is only a hint, formats which require the length upfront should error out if
+    /// it is not present. Every item is written using the returned [`SerializeArray`].
+    ///
+    /// # Errors
+    ///
+    /// The array could not be started
+    fn serialize_array(
+        self,
+        length: Option<usize>,
+    ) -> Result<Self::SerializeArray, SerializerError>;
+
+    /// Begin to serialize an `object`.
+    ///
+    /// The `length` is only a hint, formats which require the length upfront should error out if
+    /// it is not present. Every entry is written using the returned [`SerializeObject`].
+    ///
+    /// # Errors
+    ///
+    /// The object could not be started
+    fn serialize_object(
+        self,
+        length: Option<usize>,
+    ) -> Result<Self::SerializeObject, SerializerError>;
+
+    derive_to_number![
+        serialize_i8(i8) <- from_i8,
+        serialize_i16(i16) <- from_i16,
+        serialize_i32(i32) <- from_i32,
+        serialize_i64(i64) <- from_i64,
+        serialize_i128(i128) <- from_i128,
+        serialize_isize(isize) <- from_isize,
+
+        serialize_u8(u8) <- from_u8,
+        serialize_u16(u16) <- from_u16,
+        serialize_u32(u32) <- from_u32,
+        serialize_u64(u64) <- from_u64,
+        serialize_u128(u128) <- from_u128,
+        serialize_usize(usize) <- from_usize,
+
+        serialize_f32(f32) <- from_f32,
+        serialize_f64(f64) <- from_f64,
+    ];
+
+    /// Serialize every item of the iterator as an `array`.
+    ///
+    /// Errors of individual items do not abort serialization, every error is collected and the
+    /// [`Location`] of the item is attached.
+    ///
+    /// # Errors
+    ///
+    /// One or more items could not be serialized
+    fn collect_array<I>(self, iter: I) -> Result<(), SerializerError>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let iter = iter.into_iter();
+        let (lower, upper) = iter.size_hint();
+        let length = upper.filter(|upper| *upper == lower);
+
+        let mut array = self.serialize_array(length)?;
+        let mut result: Result<(), SerializerError> = Ok(());
+
+        for (index, item) in iter.enumerate() {
+            if let Err(error) = array.serialize_item(&item) {
+                result.extend_one(error.attach(Location::Array(index)));
+            }
+        }
+
+        if let Err(error) = array.end() {
+            result.extend_one(error);
+        }
+
+        result
+    }
+
+    /// Serialize every key-value pair of the iterator as an `object`.
+    ///
+    /// Errors of individual entries do not abort serialization, every error is collected.
+    ///
+    /// # Errors
+    ///
+    /// One or more entries could not be serialized
+    fn collect_object<K, V, I>(self, iter: I) -> Result<(), SerializerError>
+    where
+        K: Serialize,
+        V: Serialize,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let iter = iter.into_iter();
+        let (lower, upper) = iter.size_hint();
+        let length = upper.filter(|upper| *upper == lower);
+
+        let mut object = self.serialize_object(length)?;
+        let mut result: Result<(), SerializerError> = Ok(());
+
+        for (key, value) in iter {
+            if let Err(error) = object.serialize_entry(&key, &value) {
+                result.extend_one(error);
+            }
+        }
+
+        if let Err(error) = object.end() {
+            result.extend_one(error);
+        }
+
+        result
+    }
+}
+
+/// Returned from [`Serializer::serialize_array`], used to write every item of an `array`.
+pub trait SerializeArray {
+    fn context(&self) -> &Context;
+
+    /// Serialize a single item of the array.
+    ///
+    /// # Errors
+    ///
+    /// The item could not be serialized
+    fn serialize_item<T>(&mut self, value: &T) -> Result<(), SerializerError>
+    where
+        T: Serialize + ?Sized;
+
+    /// Finish the array.
+    ///
+    /// # Errors
+    ///
+    /// The array could not be closed
+    fn end(self) -> Result<(), SerializerError>;
+}
+
+/// Returned from [`Serializer::serialize_object`], used to write every entry of an `object`.
+pub trait SerializeObject {
+    fn context(&self) -> &Context;
+
+    /// Serialize a single key-value pair of the object.
+    ///
+    /// # Errors
+    ///
+    /// Either the key or value could not be serialized
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<(), SerializerError>
+    where
+        K: Serialize + ?Sized,
+        V: Serialize + ?Sized;
+
+    /// Serialize a static field of a struct or enum variant.
+    ///
+    /// The default implementation uses [`Self::serialize_entry`] and attaches the [`Location`] of
+    /// the field on error.
+    ///
+    /// # Errors
+    ///
+    /// The value could not be serialized
+    fn serialize_field<V>(&mut self, name: &'static str, value: &V) -> Result<(), SerializerError>
+    where
+        V: Serialize + ?Sized,
+    {
+        self.serialize_entry(name, value)
+            .attach(Location::Field(name))
+    }
+
+    /// Finish the object.
+    ///
+    /// # Errors
+    ///
+    /// The object could not be closed
+    fn end(self) -> Result<(), SerializerError>;
+}
+
+/// A **data-structure** that can be serialized into any format supported by deer.
+///
+/// This is the counterpart to [`Deserialize`], the [`Reflection`] of a type should describe the
+/// values written by [`Self::serialize`], for most types this is the same [`Reflection`] that is
+/// used by [`Deserialize`].
+///
+/// In contrast to [`Deserialize`] there's no `reflection()` convenience method, as it would be
+/// ambiguous for types implementing both traits, use [`Reflection::document`] instead.
+///
+/// [`Deserialize`]: crate::Deserialize
+pub trait Serialize {
+    type Reflection: Reflection + ?Sized;
+
+    /// Serialize this value into the given `deer` serializer.
+    ///
+    /// # Errors
+    ///
+    /// Serialization was unsuccessful
+    fn serialize<S>(&self, serializer: S) -> Result<(), SerializeError>
+    where
+        S: Serializer;
+}
+
+impl<T> Serialize for &T
+where
+    T: Serialize + ?Sized,
+{
+    type Reflection = T::Reflection;
+
+    fn serialize<S>(&self, serializer: S) -> Result<(), SerializeError>
+    where
+        S: Serializer,
+    {
+        T::serialize(self, serializer)
+    }
+}
+
+impl<T> Serialize for &mut T
+where
+    T: Serialize + ?Sized,
+{
+    type Reflection = T::Reflection;
+
+    fn serialize<S>(&self, serializer: S) -> Result<(), SerializeError>
+    where
+        S: Serializer,
+    {
+        T::serialize(self, serializer)
+    }
+}
+
+impl Serialize for Number {
+    type Reflection = Self;
+
+    fn serialize<S>(&self, serializer: S) -> Result<(), SerializeError>
+    where
+        S: Serializer,
+    {
+        serializer
+            .serialize_number(self)
+            .change_context(SerializeError)
+    }
+}
@@ -705,11 +705,13 @@ impl<'de> Deserializer<'de> for NullDeserializer<'_> {
 // down here so that they can make use of the macros
 mod array;
 mod bytes;
+mod content;
 mod object;
 mod string;
 
 pub use array::ArrayAccessDeserializer;
 pub use bytes::{BorrowedBytesDeserializer, BytesBufferDeserializer, BytesDeserializer};
+pub use content::{Content, ContentDeserializer, ContentReflection};
 pub use object::ObjectAccessDeserializer;
 pub use string::{BorrowedStrDeserializer, StrDeserializer, StringDeserializer};
 
@@ -0,0 +1,486 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use error_stack::{Report, Result, ResultExt};
+use num_traits::{FromPrimitive, ToPrimitive};
+
+use crate::{
+    error::{
+        ArrayAccessError, ArrayLengthError, DeserializeError, DeserializerError, ExpectedType,
+        MissingError, ObjectAccessError, ObjectLengthError, ReceivedType, TypeError, Variant,
+        VisitorError,
+    },
+    schema::visitor::{ArraySchema, BinarySchema, BoolSchema, ObjectSchema, StringSchema},
+    value::{EnumUnitDeserializer, ObjectAccessDeserializer},
+    ArrayAccess, Context, Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor,
+    IdentifierVisitor, Number, ObjectAccess, OptionalVisitor, Reflection, Schema, StructVisitor,
+    Visitor,
+};
+
+/// Buffered representation of any value a [`Deserializer`] is able to produce.
+///
+/// Some types need to look at a value more than once before they know how to deserialize it,
+/// e.g. internally tagged enums need to find the tag before they are able to deserialize the
+/// remaining fields. [`Content`] captures the value, which can then be deserialized (repeatedly)
+/// using the [`ContentDeserializer`].
+///
+/// Objects are stored as a list of entries to preserve the order in which they have been
+/// received.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content {
+    None,
+    Null,
+    Bool(bool),
+    Number(Number),
+    Char(char),
+    String(String),
+    Bytes(Vec<u8>),
+    Array(Vec<Content>),
+    Object(Vec<(Content, Content)>),
+}
+
+impl Content {
+    /// Collect all remaining entries of the object.
+    ///
+    /// This does **not** call [`ObjectAccess::end`], which allows the caller to continue to use
+    /// the [`Context`] of `object`.
+    ///
+    /// # Errors
+    ///
+    /// Any key or value could not be deserialized
+    pub fn collect_object<'de, A>(object: &mut A) -> Result<Self, ObjectAccessError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        let mut entries = Vec::new();
+        let mut errors: Result<(), ObjectAccessError> = Ok(());
+
+        while let Some(entry) = object.next() {
+            match (entry, &mut errors) {
+                (Ok(entry), _) => entries.push(entry),
+                (Err(error), Err(errors)) => errors.extend_one(error),
+                (Err(error), errors) => *errors = Err(error),
+            }
+        }
+
+        errors.map(|()| Self::Object(entries))
+    }
+
+    /// Remove the entry with the key `key` from an object and return its value.
+    ///
+    /// Returns [`None`] if `self` is not an object or the key is not present.
+    pub fn remove_field(&mut self, key: &str) -> Option<Self> {
+        let Self::Object(entries) = self else {
+            return None;
+        };
+
+        let index = entries
+            .iter()
+            .position(|(entry, _)| matches!(entry, Self::String(entry) if entry == key))?;
+
+        Some(entries.remove(index).1)
+    }
+
+    /// Schema of the kind of value, used as the [`ReceivedType`] of errors.
+    #[must_use]
+    pub fn received(&self) -> Document {
+        match self {
+            Self::None | Self::Null => <()>::reflection(),
+            Self::Bool(_) => BoolSchema::document(),
+            Self::Number(_) => Number::document(),
+            Self::Char(_) | Self::String(_) => StringSchema::document(),
+            Self::Bytes(_) => BinarySchema::document(),
+            Self::Array(_) => ArraySchema::document(),
+            Self::Object(_) => ObjectSchema::document(),
+        }
+    }
+}
+
+pub struct ContentReflection;
+
+impl Reflection for ContentReflection {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("any")
+    }
+}
+
+struct ContentVisitor;
+
+impl ContentVisitor {
+    fn collect_array<'de, A>(mut array: A) -> Result<Content, ArrayAccessError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut items = Vec::new();
+        let mut errors: Result<(), ArrayAccessError> = Ok(());
+
+        while let Some(item) = array.next() {
+            match (item, &mut errors) {
+                (Ok(item), _) => items.push(item),
+                (Err(error), Err(errors)) => errors.extend_one(error),
+                (Err(error), errors) => *errors = Err(error),
+            }
+        }
+
+        match (errors, array.end()) {
+            (Ok(()), Ok(())) => Ok(Content::Array(items)),
+            (Err(mut errors), Err(error)) => {
+                errors.extend_one(error);
+                Err(errors)
+            }
+            (Err(error), _) | (_, Err(error)) => Err(error),
+        }
+    }
+
+    fn collect_object<'de, A>(mut object: A) -> Result<Content, ObjectAccessError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        match (Content::collect_object(&mut object), object.end()) {
+            (Ok(content), Ok(())) => Ok(content),
+            (Err(mut errors), Err(error)) => {
+                errors.extend_one(error);
+                Err(errors)
+            }
+            (Err(error), _) | (_, Err(error)) => Err(error),
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content;
+
+    fn expecting(&self) -> Document {
+        ContentReflection::document()
+    }
+
+    fn visit_none(self) -> Result<Self::Value, VisitorError> {
+        Ok(Content::None)
+    }
+
+    fn visit_null(self) -> Result<Self::Value, VisitorError> {
+        Ok(Content::Null)
+    }
+
+    fn visit_bool(self, value: bool) -> Result<Self::Value, VisitorError> {
+        Ok(Content::Bool(value))
+    }
+
+    fn visit_number(self, value: Number) -> Result<Self::Value, VisitorError> {
+        Ok(Content::Number(value))
+    }
+
+    fn visit_char(self, value: char) -> Result<Self::Value, VisitorError> {
+        Ok(Content::Char(value))
+    }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, VisitorError> {
+        Ok(Content::String(String::from(value)))
+    }
+
+    fn visit_string(self, value: String) -> Result<Self::Value, VisitorError> {
+        Ok(Content::String(value))
+    }
+
+    fn visit_bytes(self, value: &[u8]) -> Result<Self::Value, VisitorError> {
+        Ok(Content::Bytes(value.to_vec()))
+    }
+
+    fn visit_bytes_buffer(self, value: Vec<u8>) -> Result<Self::Value, VisitorError> {
+        Ok(Content::Bytes(value))
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ArrayAccess<'de>,
+    {
+        Self::collect_array(array).change_context(VisitorError)
+    }
+
+    fn visit_object<A>(self, object: A) -> Result<Self::Value, VisitorError>
+    where
+        A: ObjectAccess<'de>,
+    {
+        Self::collect_object(object).change_context(VisitorError)
+    }
+
+    fn visit_i128(self, value: i128) -> Result<Self::Value, VisitorError> {
+        Number::from_i128(value)
+            .map(Content::Number)
+            .ok_or_else(|| {
+                Report::new(TypeError.into_error())
+                    .attach(ReceivedType::new(i128::reflection()))
+                    .attach(ExpectedType::new(self.expecting()))
+                    .change_context(VisitorError)
+            })
+    }
+
+    fn visit_u128(self, value: u128) -> Result<Self::Value, VisitorError> {
+        Number::from_u128(value)
+            .map(Content::Number)
+            .ok_or_else(|| {
+                Report::new(TypeError.into_error())
+                    .attach(ReceivedType::new(u128::reflection()))
+                    .attach(ExpectedType::new(self.expecting()))
+                    .change_context(VisitorError)
+            })
+    }
+}
+
+impl<'de> Deserialize<'de> for Content {
+    type Reflection = ContentReflection;
+
+    fn deserialize<D>(deserializer: D) -> Result<Self, DeserializeError>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_any(ContentVisitor)
+            .change_context(DeserializeError)
+    }
+}
+
+/// [`Deserializer`] which replays a previously buffered [`Content`].
+#[derive(Debug)]
+pub struct ContentDeserializer<'a> {
+    context: &'a Context,
+    value: Content,
+}
+
+impl<'a> ContentDeserializer<'a> {
+    #[must_use]
+    pub const fn new(value: Content, context: &'a Context) -> Self {
+        Self { context, value }
+    }
+}
+
+impl<'de> Deserializer<'de> for ContentDeserializer<'_> {
+    forward_to_deserialize_any!(
+        null
+        bool
+        number
+        i8 i16 i32 i64 i128
+        u8 u16 u32 u64 u128
+        f32 f64
+        char str string
+        bytes bytes_buffer
+        array object
+    );
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Content::None => visitor.visit_none(),
+            Content::Null => visitor.visit_null(),
+            Content::Bool(value) => visitor.visit_bool(value),
+            Content::Number(value) => visitor.visit_number(value),
+            Content::Char(value) => visitor.visit_char(value),
+            Content::String(value) => visitor.visit_string(value),
+            Content::Bytes(value) => visitor.visit_bytes_buffer(value),
+            Content::Array(items) => {
+                visitor.visit_array(ContentArrayAccess::new(items, self.context))
+            }
+            Content::Object(entries) => {
+                visitor.visit_object(ContentObjectAccess::new(entries, self.context))
+            }
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        match self.value {
+            Content::None => visitor.visit_none(),
+            Content::Null => visitor.visit_null(),
+            _ => visitor.visit_some(self),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: EnumVisitor<'de>,
+    {
+        let context = self.context;
+
+        match self.value {
+            Content::Object(entries) => {
+                ObjectAccessDeserializer::new(context, ContentObjectAccess::new(entries, context))
+                    .deserialize_enum(visitor)
+            }
+            value => EnumUnitDeserializer::new(context, Self::new(value, context))
+                .deserialize_enum(visitor),
+        }
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: StructVisitor<'de>,
+    {
+        match self.value {
+            Content::Array(items) => {
+                visitor.visit_array(ContentArrayAccess::new(items, self.context))
+            }
+            Content::Object(entries) => {
+                visitor.visit_object(ContentObjectAccess::new(entries, self.context))
+            }
+            Content::None => Err(Report::new(MissingError.into_error())
+                .attach(ExpectedType::new(visitor.expecting()))
+                .change_context(VisitorError)),
+            value => Err(Report::new(TypeError.into_error())
+                .attach(ExpectedType::new(visitor.expecting()))
+                .attach(ReceivedType::new(value.received()))
+                .change_context(VisitorError)),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        let value = match self.value {
+            Content::Number(number) => match number.to_u64() {
+                Some(value) => return visitor.visit_u64(value).change_context(DeserializerError),
+                None => Content::Number(number),
+            },
+            value => value,
+        };
+
+        match value {
+            Content::String(value) => visitor.visit_str(&value),
+            Content::Char(value) => visitor.visit_str(value.encode_utf8(&mut [0; 4])),
+            Content::Bytes(value) => visitor.visit_bytes(&value),
+            value => Err(Report::new(TypeError.into_error())
+                .attach(ExpectedType::new(visitor.expecting()))
+                .attach(ReceivedType::new(value.received()))
+                .change_context(VisitorError)),
+        }
+        .change_context(DeserializerError)
+    }
+}
+
+struct ContentArrayAccess<'a> {
+    context: &'a Context,
+    items: vec::IntoIter<Content>,
+    consumed: usize,
+    length: usize,
+}
+
+impl<'a> ContentArrayAccess<'a> {
+    fn new(items: Vec<Content>, context: &'a Context) -> Self {
+        Self {
+            context,
+            length: items.len(),
+            items: items.into_iter(),
+            consumed: 0,
+        }
+    }
+}
+
+impl<'de> ArrayAccess<'de> for ContentArrayAccess<'_> {
+    fn is_dirty(&self) -> bool {
+        self.consumed > 0
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn next<T>(&mut self) -> Option<Result<T, ArrayAccessError>>
+    where
+        T: Deserialize<'de>,
+    {
+        let item = self.items.next()?;
+        self.consumed += 1;
+
+        Some(
+            T::deserialize(ContentDeserializer::new(item, self.context))
+                .change_context(ArrayAccessError),
+        )
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length)
+    }
+
+    fn end(self) -> Result<(), ArrayAccessError> {
+        if self.items.len() == 0 {
+            Ok(())
+        } else {
+            Err(ArrayLengthError::new(&self, self.consumed).change_context(ArrayAccessError))
+        }
+    }
+}
+
+struct ContentObjectAccess<'a> {
+    context: &'a Context,
+    entries: vec::IntoIter<(Content, Content)>,
+    consumed: usize,
+    length: usize,
+}
+
+impl<'a> ContentObjectAccess<'a> {
+    fn new(entries: Vec<(Content, Content)>, context: &'a Context) -> Self {
+        Self {
+            context,
+            length: entries.len(),
+            entries: entries.into_iter(),
+            consumed: 0,
+        }
+    }
+}
+
+impl<'de> ObjectAccess<'de> for ContentObjectAccess<'_> {
+    fn is_dirty(&self) -> bool {
+        self.consumed > 0
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn try_field<F>(
+        &mut self,
+        visitor: F,
+    ) -> core::result::Result<Result<F::Value, ObjectAccessError>, F>
+    where
+        F: FieldVisitor<'de>,
+    {
+        let Some((key, value)) = self.entries.next() else {
+            return Err(visitor);
+        };
+        self.consumed += 1;
+
+        let key = match visitor
+            .visit_key(ContentDeserializer::new(key, self.context))
+            .change_context(ObjectAccessError)
+        {
+            Ok(key) => key,
+            Err(error) => return Ok(Err(error)),
+        };
+
+        Ok(visitor
+            .visit_value(key, ContentDeserializer::new(value, self.context))
+            .change_context(ObjectAccessError))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length)
+    }
+
+    fn end(self) -> Result<(), ObjectAccessError> {
+        if self.entries.len() == 0 {
+            Ok(())
+        } else {
+            Err(ObjectLengthError::new(&self, self.consumed).change_context(ObjectAccessError))
+        }
+    }
+}
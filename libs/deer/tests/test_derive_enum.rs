@@ -0,0 +1,290 @@
+use deer::Deserialize as _;
+use deer_desert::{assert_tokens, assert_tokens_error, error, Token};
+use deer_macros::Deserialize;
+use serde_json::{json, to_value};
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[deer(rename_all = "lowercase")]
+enum Color {
+    Red,
+    Green,
+    #[deer(rename = "BLUE")]
+    Blue,
+}
+
+#[test]
+fn unit_ok() {
+    assert_tokens(&Color::Red, &[Token::Str("red")]);
+    assert_tokens(&Color::Blue, &[Token::Str("BLUE")]);
+}
+
+#[test]
+fn unit_unknown_err() {
+    assert_tokens_error::<Color>(
+        &error!([{
+            ns: "deer",
+            id: ["unknown", "value"],
+            properties: {
+                "expected": ["red", "green", "BLUE"],
+                "received": "blue",
+                "location": []
+            }
+        }]),
+        &[Token::Str("blue")],
+    );
+}
+
+#[test]
+fn unit_reflection() {
+    let document = to_value(Color::reflection()).expect("should be valid json");
+
+    let definition = document["$defs"]
+        .as_object()
+        .and_then(|definitions| definitions.iter().find(|(name, _)| name.ends_with("Color")))
+        .map(|(_, definition)| definition)
+        .expect("should contain the definition of `Color`");
+
+    assert_eq!(definition["type"], json!("string"));
+    assert_eq!(definition["enum"], json!(["red", "green", "BLUE"]));
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+enum External {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, bool),
+    Struct { a: u8, b: bool },
+}
+
+#[test]
+fn external_ok() {
+    assert_tokens(&External::Unit, &[Token::Str("Unit")]);
+
+    assert_tokens(&External::Newtype(12), &[
+        Token::Object { length: Some(1) },
+        Token::Str("Newtype"),
+        Token::Number(12.into()),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&External::Tuple(12, true), &[
+        Token::Object { length: Some(1) },
+        Token::Str("Tuple"),
+        Token::Array { length: Some(2) },
+        Token::Number(12.into()),
+        Token::Bool(true),
+        Token::ArrayEnd,
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&External::Struct { a: 12, b: true }, &[
+        Token::Object { length: Some(1) },
+        Token::Str("Struct"),
+        Token::Object { length: Some(2) },
+        Token::Str("b"),
+        Token::Bool(true),
+        Token::Str("a"),
+        Token::Number(12.into()),
+        Token::ObjectEnd,
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn external_missing_field_err() {
+    assert_tokens_error::<External>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": bool::reflection(),
+                "location": [
+                    {"type": "variant", "value": "Struct"},
+                    {"type": "field", "value": "b"}
+                ]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Struct"),
+            Token::Object { length: Some(1) },
+            Token::Str("a"),
+            Token::Number(12.into()),
+            Token::ObjectEnd,
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[deer(tag = "type")]
+enum Internal {
+    Unit,
+    Newtype(External),
+    Struct { a: u8 },
+}
+
+#[test]
+fn internal_ok() {
+    assert_tokens(&Internal::Unit, &[
+        Token::Object { length: Some(1) },
+        Token::Str("type"),
+        Token::Str("Unit"),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Internal::Struct { a: 12 }, &[
+        Token::Object { length: Some(2) },
+        Token::Str("a"),
+        Token::Number(12.into()),
+        Token::Str("type"),
+        Token::Str("Struct"),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Internal::Newtype(External::Struct { a: 1, b: false }), &[
+        Token::Object { length: Some(2) },
+        Token::Str("type"),
+        Token::Str("Newtype"),
+        Token::Str("Struct"),
+        Token::Object { length: Some(2) },
+        Token::Str("a"),
+        Token::Number(1.into()),
+        Token::Str("b"),
+        Token::Bool(false),
+        Token::ObjectEnd,
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn internal_missing_tag_err() {
+    assert_tokens_error::<Internal>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": {
+                    "$defs": {
+                        "0000-test_derive_enum::_::__Variant": {
+                            "type": "string",
+                            "enum": ["Unit", "Newtype", "Struct"]
+                        }
+                    },
+                    "$ref": "#/$defs/0000-test_derive_enum::_::__Variant"
+                },
+                "location": [{"type": "field", "value": "type"}]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("a"),
+            Token::Number(12.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[deer(tag = "t", content = "c")]
+enum Adjacent {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, bool),
+    Struct { a: u8 },
+}
+
+#[test]
+fn adjacent_ok() {
+    assert_tokens(&Adjacent::Unit, &[
+        Token::Object { length: Some(1) },
+        Token::Str("t"),
+        Token::Str("Unit"),
+        Token::ObjectEnd,
+    ]);
+
+    // the content is allowed to come before the tag
+    assert_tokens(&Adjacent::Newtype(12), &[
+        Token::Object { length: Some(2) },
+        Token::Str("c"),
+        Token::Number(12.into()),
+        Token::Str("t"),
+        Token::Str("Newtype"),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Adjacent::Tuple(12, true), &[
+        Token::Object { length: Some(2) },
+        Token::Str("t"),
+        Token::Str("Tuple"),
+        Token::Str("c"),
+        Token::Array { length: Some(2) },
+        Token::Number(12.into()),
+        Token::Bool(true),
+        Token::ArrayEnd,
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Adjacent::Struct { a: 12 }, &[
+        Token::Object { length: Some(2) },
+        Token::Str("t"),
+        Token::Str("Struct"),
+        Token::Str("c"),
+        Token::Object { length: Some(1) },
+        Token::Str("a"),
+        Token::Number(12.into()),
+        Token::ObjectEnd,
+        Token::ObjectEnd,
+    ]);
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[deer(untagged)]
+enum Untagged {
+    Struct { a: u8 },
+    Tuple(u8, bool),
+    Newtype(bool),
+    Unit,
+}
+
+#[test]
+fn untagged_ok() {
+    assert_tokens(&Untagged::Struct { a: 12 }, &[
+        Token::Object { length: Some(1) },
+        Token::Str("a"),
+        Token::Number(12.into()),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Untagged::Tuple(12, true), &[
+        Token::Array { length: Some(2) },
+        Token::Number(12.into()),
+        Token::Bool(true),
+        Token::ArrayEnd,
+    ]);
+
+    assert_tokens(&Untagged::Newtype(true), &[Token::Bool(true)]);
+
+    assert_tokens(&Untagged::Unit, &[Token::Null]);
+}
+
+#[test]
+fn untagged_no_match_err() {
+    assert_tokens_error::<Untagged>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": Untagged::reflection(),
+                "received": {
+                    "$defs": {
+                        "0000-deer::schema::visitor::StringSchema": {"type": "string"}
+                    },
+                    "$ref": "#/$defs/0000-deer::schema::visitor::StringSchema"
+                },
+                "location": []
+            }
+        }]),
+        &[Token::Str("Unit")],
+    );
+}
@@ -0,0 +1,355 @@
+use deer::Deserialize as _;
+use deer_desert::{assert_tokens, assert_tokens_error, error, Token};
+use deer_macros::Deserialize;
+use serde_json::{json, to_value};
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Example {
+    a: u8,
+    b: u16,
+    c: u32,
+}
+
+#[test]
+fn named_object_ok() {
+    assert_tokens(&Example { a: 2, b: 3, c: 4 }, &[
+        Token::Object { length: Some(3) },
+        Token::Str("c"),
+        Token::Number(4.into()),
+        Token::Str("a"),
+        Token::Number(2.into()),
+        Token::Str("b"),
+        Token::Number(3.into()),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn named_array_ok() {
+    assert_tokens(&Example { a: 2, b: 3, c: 4 }, &[
+        Token::Array { length: Some(3) },
+        Token::Number(2.into()),
+        Token::Number(3.into()),
+        Token::Number(4.into()),
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn named_unknown_field_ignored() {
+    assert_tokens(&Example { a: 2, b: 3, c: 4 }, &[
+        Token::Object { length: Some(4) },
+        Token::Str("a"),
+        Token::Number(2.into()),
+        Token::Str("d"),
+        Token::Array { length: Some(1) },
+        Token::Bool(true),
+        Token::ArrayEnd,
+        Token::Str("b"),
+        Token::Number(3.into()),
+        Token::Str("c"),
+        Token::Number(4.into()),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn named_missing_err() {
+    assert_tokens_error::<Example>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u16::reflection(),
+                "location": [{"type": "field", "value": "b"}]
+            }
+        },{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u32::reflection(),
+                "location": [{"type": "field", "value": "c"}]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn named_duplicate_err() {
+    assert_tokens_error::<Example>(
+        &error!([{
+            ns: "deer",
+            id: ["duplicate", "field"],
+            properties: {
+                "field": "a",
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: Some(4) },
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::Str("a"),
+            Token::Number(2.into()),
+            Token::Str("b"),
+            Token::Number(3.into()),
+            Token::Str("c"),
+            Token::Number(4.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn named_reflection() {
+    let document = to_value(Example::reflection()).expect("should be valid json");
+
+    let definition = document["$defs"]
+        .as_object()
+        .and_then(|definitions| {
+            definitions
+                .iter()
+                .find(|(name, _)| name.ends_with("Example"))
+        })
+        .map(|(_, definition)| definition)
+        .expect("should contain the definition of `Example`");
+
+    assert_eq!(definition["type"], json!("object"));
+    assert_eq!(
+        definition["properties"]
+            .as_object()
+            .map(|properties| properties.keys().cloned().collect::<Vec<_>>()),
+        Some(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()])
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[deer(rename_all = "camelCase", deny_unknown_fields)]
+struct Renamed {
+    first_name: u8,
+    #[deer(rename = "surname")]
+    last_name: u8,
+}
+
+#[test]
+fn rename_ok() {
+    assert_tokens(
+        &Renamed {
+            first_name: 1,
+            last_name: 2,
+        },
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("firstName"),
+            Token::Number(1.into()),
+            Token::Str("surname"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn deny_unknown_fields_err() {
+    assert_tokens_error::<Renamed>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u8::reflection(),
+                "location": [{"type": "field", "value": "surname"}]
+            }
+        }, {
+            ns: "deer",
+            id: ["unknown", "field"],
+            properties: {
+                "expected": ["firstName", "surname"],
+                "received": ["last_name"],
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("firstName"),
+            Token::Number(1.into()),
+            Token::Str("last_name"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+const fn answer() -> u8 {
+    42
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Defaults {
+    #[deer(default)]
+    a: u8,
+    #[deer(default = "answer")]
+    b: u8,
+    c: Option<u8>,
+}
+
+#[test]
+fn default_ok() {
+    assert_tokens(
+        &Defaults {
+            a: 0,
+            b: 42,
+            c: None,
+        },
+        &[Token::Object { length: Some(0) }, Token::ObjectEnd],
+    );
+
+    assert_tokens(
+        &Defaults {
+            a: 1,
+            b: 2,
+            c: Some(3),
+        },
+        &[
+            Token::Object { length: Some(3) },
+            Token::Str("a"),
+            Token::Number(1.into()),
+            Token::Str("b"),
+            Token::Number(2.into()),
+            Token::Str("c"),
+            Token::Number(3.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Inner {
+    b: u8,
+    c: u8,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Flatten {
+    a: u8,
+    #[deer(flatten)]
+    inner: Inner,
+}
+
+#[test]
+fn flatten_ok() {
+    assert_tokens(
+        &Flatten {
+            a: 1,
+            inner: Inner { b: 2, c: 3 },
+        },
+        &[
+            Token::Object { length: Some(3) },
+            Token::Str("b"),
+            Token::Number(2.into()),
+            Token::Str("a"),
+            Token::Number(1.into()),
+            Token::Str("c"),
+            Token::Number(3.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn flatten_missing_err() {
+    assert_tokens_error::<Flatten>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u8::reflection(),
+                "location": [{"type": "field", "value": "c"}]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("b"),
+            Token::Number(2.into()),
+            Token::Str("a"),
+            Token::Number(1.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Generic<T> {
+    value: T,
+}
+
+#[test]
+fn generic_ok() {
+    assert_tokens(&Generic { value: true }, &[
+        Token::Object { length: Some(1) },
+        Token::Str("value"),
+        Token::Bool(true),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Tuple(u8, bool);
+
+#[test]
+fn tuple_ok() {
+    assert_tokens(&Tuple(1, true), &[
+        Token::Array { length: Some(2) },
+        Token::Number(1.into()),
+        Token::Bool(true),
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn tuple_too_many_err() {
+    assert_tokens_error::<Tuple>(
+        &error!([{
+            ns: "deer",
+            id: ["array", "length"],
+            properties: {
+                "expected": 2,
+                "received": 3,
+                "location": []
+            }
+        }]),
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(1.into()),
+            Token::Bool(true),
+            Token::Bool(false),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Newtype(u8);
+
+#[test]
+fn newtype_ok() {
+    assert_tokens(&Newtype(12), &[Token::Number(12.into())]);
+
+    assert_eq!(
+        to_value(Newtype::reflection()).expect("should be valid json"),
+        to_value(u8::reflection()).expect("should be valid json")
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+struct Unit;
+
+#[test]
+fn unit_ok() {
+    assert_tokens(&Unit, &[Token::Null]);
+}
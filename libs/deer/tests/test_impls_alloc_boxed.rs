@@ -0,0 +1,48 @@
+extern crate alloc;
+
+use alloc::{borrow::Cow, rc::Rc, sync::Arc};
+
+use deer::Number;
+use deer_desert::{assert_tokens, Token};
+use proptest::prelude::*;
+
+#[cfg(not(miri))]
+proptest! {
+    #[test]
+    fn box_ok(value in any::<u8>()) {
+        assert_tokens(&Box::new(value), &[Token::Number(Number::from(value))]);
+    }
+
+    #[test]
+    fn rc_ok(value in any::<u8>()) {
+        assert_tokens(&Rc::new(value), &[Token::Number(Number::from(value))]);
+    }
+
+    #[test]
+    fn arc_ok(value in any::<u8>()) {
+        assert_tokens(&Arc::new(value), &[Token::Number(Number::from(value))]);
+    }
+}
+
+#[test]
+fn box_str_ok() {
+    let expected: Box<str> = Box::from("example");
+
+    assert_tokens(&expected, &[Token::Str("example")]);
+}
+
+#[test]
+fn cow_ok() {
+    let expected: Cow<str> = Cow::Owned(String::from("example"));
+
+    assert_tokens(&expected, &[Token::BorrowedStr("example")]);
+
+    let expected: Cow<[u8]> = Cow::Owned(vec![1, 2]);
+
+    assert_tokens(&expected, &[
+        Token::Array { length: Some(2) },
+        Token::Number(Number::from(1)),
+        Token::Number(Number::from(2)),
+        Token::ArrayEnd,
+    ]);
+}
@@ -0,0 +1,132 @@
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use deer::{Deserialize, Number};
+use deer_desert::{assert_tokens, assert_tokens_error, error, Token};
+use serde_json::json;
+
+#[test]
+fn btree_map_ok() {
+    let expected = BTreeMap::from([(String::from("a"), 1u8), (String::from("b"), 2)]);
+
+    assert_tokens(&expected, &[
+        Token::Object { length: Some(2) },
+        Token::Str("b"),
+        Token::Number(Number::from(2)),
+        Token::Str("a"),
+        Token::Number(Number::from(1)),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn btree_map_entries_ok() {
+    let expected = BTreeMap::from([(1u8, true), (2, false)]);
+
+    assert_tokens(&expected, &[
+        Token::Array { length: Some(2) },
+        Token::Array { length: Some(2) },
+        Token::Number(Number::from(1)),
+        Token::Bool(true),
+        Token::ArrayEnd,
+        Token::Array { length: Some(2) },
+        Token::Number(Number::from(2)),
+        Token::Bool(false),
+        Token::ArrayEnd,
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn btree_map_entries_err_length() {
+    assert_tokens_error::<BTreeMap<u8, bool>>(
+        &error!([{
+            ns: "deer",
+            id: ["array", "length"],
+            properties: {
+                "expected": 2,
+                "received": 3,
+                "location": [{"type": "array", "value": 0}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(1) },
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(1)),
+            Token::Bool(true),
+            Token::Bool(false),
+            Token::ArrayEnd,
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn btree_map_err_duplicate() {
+    assert_tokens_error::<BTreeMap<String, u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["duplicate", "key"],
+            properties: {
+                "key": null,
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::Str("a"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn btree_set_ok() {
+    let expected = BTreeSet::from([1u8, 2, 3]);
+
+    assert_tokens(&expected, &[
+        Token::Array { length: Some(3) },
+        Token::Number(Number::from(3)),
+        Token::Number(Number::from(1)),
+        Token::Number(Number::from(2)),
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn btree_set_err_duplicate() {
+    assert_tokens_error::<BTreeSet<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["duplicate", "key"],
+            properties: {
+                "key": null,
+                "location": [{"type": "array", "value": 2}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(2)),
+            Token::Number(Number::from(1)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn btree_set_reflection() {
+    let document =
+        serde_json::to_value(BTreeSet::<u8>::reflection()).expect("should be valid json");
+    let reference = document["$ref"]
+        .as_str()
+        .and_then(|reference| reference.strip_prefix("#/$defs/"))
+        .expect("should be a reference");
+
+    assert_eq!(document["$defs"][reference]["type"], json!("array"));
+    assert_eq!(document["$defs"][reference]["uniqueItems"], json!(true));
+}
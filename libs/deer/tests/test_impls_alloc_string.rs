@@ -0,0 +1,37 @@
+use deer::Deserialize;
+use deer_desert::{assert_tokens, assert_tokens_error, error, Token};
+use proptest::prelude::*;
+use serde_json::json;
+
+#[cfg(not(miri))]
+proptest! {
+    #[test]
+    fn char_ok(value in any::<char>()) {
+        assert_tokens(&String::from(value), &[Token::Char(value)]);
+    }
+}
+
+#[test]
+fn string_ok() {
+    let expected = String::from("example");
+
+    assert_tokens(&expected, &[Token::Str("example")]);
+    assert_tokens(&expected, &[Token::BorrowedStr("example")]);
+    assert_tokens(&expected, &[Token::String("example")]);
+}
+
+#[test]
+fn string_err() {
+    assert_tokens_error::<String>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": String::reflection(),
+                "received": bool::reflection(),
+                "location": []
+            }
+        }]),
+        &[Token::Bool(true)],
+    );
+}
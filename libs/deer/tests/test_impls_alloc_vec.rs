@@ -0,0 +1,115 @@
+extern crate alloc;
+
+use alloc::collections::{BinaryHeap, VecDeque};
+
+use deer::{Deserialize, Number};
+use deer_desert::{assert_tokens, assert_tokens_error, assert_tokens_with_assertion, error, Token};
+use serde_json::json;
+
+#[test]
+fn vec_ok() {
+    assert_tokens(&vec![1u8, 2, 3], &[
+        Token::Array { length: Some(3) },
+        Token::Number(Number::from(1)),
+        Token::Number(Number::from(2)),
+        Token::Number(Number::from(3)),
+        Token::ArrayEnd,
+    ]);
+
+    assert_tokens(&Vec::<u8>::new(), &[
+        Token::Array { length: None },
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn vec_err_inner() {
+    assert_tokens_error::<Vec<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{"type": "array", "value": 1}]
+            }
+        }, {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 257,
+                "location": [{"type": "array", "value": 3}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(4) },
+            Token::Number(Number::from(0)),
+            Token::Number(Number::from(256)),
+            Token::Number(Number::from(2)),
+            Token::Number(Number::from(257)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn vec_reflection() {
+    assert_eq!(
+        serde_json::to_value(Vec::<u8>::reflection()).expect("should be valid json"),
+        json!({
+            "$ref": "#/$defs/0000-deer::impls::alloc::collections::SequenceReflection<u8>",
+            "$defs": {
+                "0000-deer::impls::alloc::collections::SequenceReflection<u8>": {
+                    "type": "array",
+                    "items": {"$ref": "#/$defs/0001-u8"}
+                },
+                "0001-u8": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 255
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn vec_deque_ok() {
+    assert_tokens(&VecDeque::from([1u8, 2, 3]), &[
+        Token::Array { length: Some(3) },
+        Token::Number(Number::from(1)),
+        Token::Number(Number::from(2)),
+        Token::Number(Number::from(3)),
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn binary_heap_ok() {
+    // `BinaryHeap` does not implement `PartialEq`
+    assert_tokens_with_assertion(
+        |received: BinaryHeap<u8>| {
+            assert_eq!(received.into_sorted_vec(), [1, 2, 3]);
+        },
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(2)),
+            Token::Number(Number::from(3)),
+            Token::Number(Number::from(1)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn boxed_slice_ok() {
+    let expected: Box<[u8]> = Box::new([1, 2]);
+
+    assert_tokens(&expected, &[
+        Token::Array { length: Some(2) },
+        Token::Number(Number::from(1)),
+        Token::Number(Number::from(2)),
+        Token::ArrayEnd,
+    ]);
+}
@@ -0,0 +1,86 @@
+use std::collections::{HashMap, HashSet};
+
+use deer::{Deserialize, Number};
+use deer_desert::{assert_tokens, assert_tokens_error, error, Token};
+use serde_json::json;
+
+#[test]
+fn hash_map_ok() {
+    let expected = HashMap::from([(String::from("a"), 1u8), (String::from("b"), 2)]);
+
+    assert_tokens(&expected, &[
+        Token::Object { length: Some(2) },
+        Token::Str("a"),
+        Token::Number(Number::from(1)),
+        Token::Str("b"),
+        Token::Number(Number::from(2)),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn hash_map_err_duplicate() {
+    assert_tokens_error::<HashMap<String, u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["duplicate", "key"],
+            properties: {
+                "key": null,
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::Str("a"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn hash_map_reflection() {
+    let document =
+        serde_json::to_value(HashMap::<String, u8>::reflection()).expect("should be valid json");
+    let reference = document["$ref"]
+        .as_str()
+        .and_then(|reference| reference.strip_prefix("#/$defs/"))
+        .expect("should be a reference");
+
+    assert_eq!(document["$defs"][reference]["type"], json!("object"));
+    assert!(document["$defs"][reference]["additionalProperties"]["$ref"].is_string());
+}
+
+#[test]
+fn hash_set_ok() {
+    let expected = HashSet::from([1u8, 2]);
+
+    assert_tokens(&expected, &[
+        Token::Array { length: Some(2) },
+        Token::Number(Number::from(2)),
+        Token::Number(Number::from(1)),
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn hash_set_err_duplicate() {
+    assert_tokens_error::<HashSet<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["duplicate", "key"],
+            properties: {
+                "key": null,
+                "location": [{"type": "array", "value": 1}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(2) },
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(1)),
+            Token::ArrayEnd,
+        ],
+    );
+}
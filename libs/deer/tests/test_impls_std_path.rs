@@ -0,0 +1,25 @@
+use std::{ffi::OsString, path::PathBuf};
+
+use deer_desert::{assert_tokens, Token};
+
+#[test]
+fn path_buf_ok() {
+    assert_tokens(&PathBuf::from("/tmp/example"), &[Token::Str(
+        "/tmp/example",
+    )]);
+}
+
+#[test]
+fn os_string_ok() {
+    assert_tokens(&OsString::from("example"), &[Token::String("example")]);
+}
+
+#[cfg(unix)]
+#[test]
+fn os_string_bytes_ok() {
+    use std::os::unix::ffi::OsStringExt;
+
+    assert_tokens(&OsString::from_vec(vec![0xFF, 0x00]), &[Token::Bytes(&[
+        0xFF, 0x00,
+    ])]);
+}
@@ -0,0 +1,32 @@
+use core::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use deer::{Deserialize, Number};
+use deer_desert::{assert_tokens, assert_tokens_error, error, Token};
+use serde_json::json;
+
+#[test]
+fn system_time_ok() {
+    assert_tokens(&(UNIX_EPOCH + Duration::from_secs(60)), &[Token::Number(
+        Number::from(60.0),
+    )]);
+    assert_tokens(&(UNIX_EPOCH + Duration::from_millis(1500)), &[
+        Token::Number(1.5.into()),
+    ]);
+}
+
+#[test]
+fn system_time_err_overflow() {
+    assert_tokens_error::<SystemTime>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": SystemTime::reflection(),
+                "received": 1e19,
+                "location": []
+            }
+        }]),
+        &[Token::Number(Number::from(1e19))],
+    );
+}
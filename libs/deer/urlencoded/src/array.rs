@@ -0,0 +1,66 @@
+use alloc::{borrow::Cow, vec, vec::Vec};
+
+use deer::{
+    error::{ArrayAccessError, ArrayLengthError},
+    Context, Deserialize,
+};
+use error_stack::{Result, ResultExt};
+
+use crate::value::ValueDeserializer;
+
+pub(crate) struct ArrayAccess<'a, 'de> {
+    values: vec::IntoIter<Cow<'de, str>>,
+    context: &'a Context,
+
+    dirty: bool,
+    expected: usize,
+    length: usize,
+}
+
+impl<'a, 'de> ArrayAccess<'a, 'de> {
+    pub(crate) fn new(values: Vec<Cow<'de, str>>, context: &'a Context) -> Self {
+        Self {
+            length: values.len(),
+            values: values.into_iter(),
+            context,
+            dirty: false,
+            expected: 0,
+        }
+    }
+}
+
+impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, 'de> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn next<T>(&mut self) -> Option<Result<T, ArrayAccessError>>
+    where
+        T: Deserialize<'de>,
+    {
+        self.dirty = true;
+
+        let value = self.values.next()?;
+        self.expected += 1;
+
+        let value = T::deserialize(ValueDeserializer::single(value, self.context));
+
+        Some(value.change_context(ArrayAccessError))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length)
+    }
+
+    fn end(self) -> Result<(), ArrayAccessError> {
+        if self.values.len() == 0 {
+            Ok(())
+        } else {
+            Err(ArrayLengthError::new(&self, self.expected).change_context(ArrayAccessError))
+        }
+    }
+}
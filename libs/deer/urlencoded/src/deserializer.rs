@@ -0,0 +1,106 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec, vec::Vec};
+
+use deer::{
+    error::DeserializerError, Context, EnumVisitor, IdentifierVisitor, OptionalVisitor, Reflection,
+    StructVisitor, Visitor,
+};
+use error_stack::{Result, ResultExt};
+
+use crate::{
+    object::{Entry, ObjectAccess},
+    value::{invalid_type, AnyObject},
+};
+
+macro_rules! invalid_type {
+    ($($method:ident: $visitor:ident),*) => {
+        $(
+        fn $method<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+        where
+            V: $visitor<'de>,
+        {
+            Err(invalid_type(visitor.expecting(), AnyObject::document()))
+        }
+        )*
+    };
+}
+
+/// Deserializer for `application/x-www-form-urlencoded` input, like the query string of an URL.
+///
+/// The input is always an object, every value is a string. Keys which are present multiple times
+/// (e.g. `a=1&a=2`) are combined into an array. Primitives, like numbers or booleans are parsed
+/// from their string representation once requested.
+pub struct Deserializer<'a, 'de> {
+    entries: Vec<Entry<'de>>,
+    context: &'a Context,
+}
+
+impl<'a, 'de> Deserializer<'a, 'de> {
+    #[must_use]
+    pub fn new(input: &'de [u8], context: &'a Context) -> Self {
+        let mut entries: Vec<Entry<'de>> = Vec::new();
+        let mut index: BTreeMap<Cow<'de, str>, usize> = BTreeMap::new();
+
+        for (key, value) in form_urlencoded::parse(input) {
+            if let Some(position) = index.get(&key) {
+                entries[*position].1.push(value);
+            } else {
+                index.insert(key.clone(), entries.len());
+                entries.push((key, vec![value]));
+            }
+        }
+
+        Self { entries, context }
+    }
+}
+
+impl<'de> deer::Deserializer<'de> for Deserializer<'_, 'de> {
+    invalid_type!(
+        deserialize_null: Visitor,
+        deserialize_bool: Visitor,
+        deserialize_number: Visitor,
+        deserialize_char: Visitor,
+        deserialize_string: Visitor,
+        deserialize_str: Visitor,
+        deserialize_bytes: Visitor,
+        deserialize_bytes_buffer: Visitor,
+        deserialize_array: Visitor,
+        deserialize_enum: EnumVisitor,
+        deserialize_identifier: IdentifierVisitor
+    );
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_object(visitor)
+    }
+
+    fn deserialize_object<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        visitor
+            .visit_object(ObjectAccess::new(self.entries, self.context))
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        visitor.visit_some(self).change_context(DeserializerError)
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: StructVisitor<'de>,
+    {
+        visitor
+            .visit_object(ObjectAccess::new(self.entries, self.context))
+            .change_context(DeserializerError)
+    }
+}
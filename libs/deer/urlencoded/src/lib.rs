@@ -0,0 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(nightly, feature(provide_any, error_in_core))]
+// TODO: once more stable introduce: warning missing_docs, clippy::missing_errors_doc
+#![deny(unsafe_code)]
+mod array;
+mod deserializer;
+mod object;
+mod value;
+
+extern crate alloc;
+
+use deer::{error::DeserializeError, Context, Deserialize};
+use error_stack::Result;
+
+pub use crate::deserializer::Deserializer;
+
+pub fn from_slice<'de, T>(slice: &'de [u8], context: &Context) -> Result<T, DeserializeError>
+where
+    T: Deserialize<'de>,
+{
+    T::deserialize(Deserializer::new(slice, context))
+}
+
+/// Deserialize a query string, which must not include the leading `?`.
+pub fn from_str<'de, T>(value: &'de str, context: &Context) -> Result<T, DeserializeError>
+where
+    T: Deserialize<'de>,
+{
+    from_slice(value.as_bytes(), context)
+}
@@ -0,0 +1,81 @@
+use alloc::{borrow::Cow, vec, vec::Vec};
+
+use deer::{
+    error::{ObjectAccessError, ObjectLengthError},
+    Context, FieldVisitor,
+};
+use error_stack::{Result, ResultExt};
+
+use crate::value::ValueDeserializer;
+
+pub(crate) type Entry<'de> = (Cow<'de, str>, Vec<Cow<'de, str>>);
+
+pub(crate) struct ObjectAccess<'a, 'de> {
+    entries: vec::IntoIter<Entry<'de>>,
+    context: &'a Context,
+
+    dirty: bool,
+    expected: usize,
+    length: usize,
+}
+
+impl<'a, 'de> ObjectAccess<'a, 'de> {
+    pub(crate) fn new(entries: Vec<Entry<'de>>, context: &'a Context) -> Self {
+        Self {
+            length: entries.len(),
+            entries: entries.into_iter(),
+            context,
+            dirty: false,
+            expected: 0,
+        }
+    }
+}
+
+impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, 'de> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn try_field<F>(
+        &mut self,
+        visitor: F,
+    ) -> core::result::Result<Result<F::Value, ObjectAccessError>, F>
+    where
+        F: FieldVisitor<'de>,
+    {
+        self.dirty = true;
+
+        let Some((key, values)) = self.entries.next() else {
+            return Err(visitor);
+        };
+
+        self.expected += 1;
+
+        let key = visitor.visit_key(ValueDeserializer::single(key, self.context));
+
+        let result = match key {
+            Ok(key) => visitor
+                .visit_value(key, ValueDeserializer::new(values, self.context))
+                .change_context(ObjectAccessError),
+            Err(error) => Err(error.change_context(ObjectAccessError)),
+        };
+
+        Ok(result)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length)
+    }
+
+    fn end(self) -> Result<(), ObjectAccessError> {
+        if self.entries.len() == 0 {
+            Ok(())
+        } else {
+            Err(ObjectLengthError::new(&self, self.expected).change_context(ObjectAccessError))
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use alloc::{borrow::Cow, vec, vec::Vec};
+
+use deer::{
+    error::{DeserializerError, ExpectedType, ReceivedType, TypeError, Variant, VisitorError},
+    value::NoneDeserializer,
+    Context, Deserialize, Document, EnumVisitor, IdentifierVisitor, Number, OptionalVisitor,
+    Reflection, Schema, StructVisitor, Visitor,
+};
+use error_stack::{Report, Result, ResultExt};
+
+use crate::array::ArrayAccess;
+
+pub(crate) struct AnyObject;
+
+impl Reflection for AnyObject {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("object")
+    }
+}
+
+pub(crate) struct AnyArray;
+
+impl Reflection for AnyArray {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("array")
+    }
+}
+
+pub(crate) fn invalid_type(expected: Document, received: Document) -> Report<DeserializerError> {
+    Report::new(TypeError.into_error())
+        .attach(ExpectedType::new(expected))
+        .attach(ReceivedType::new(received))
+        .change_context(DeserializerError)
+}
+
+fn visit_str<'de, V>(visitor: V, value: Cow<'de, str>) -> Result<V::Value, VisitorError>
+where
+    V: Visitor<'de>,
+{
+    match value {
+        Cow::Borrowed(value) => visitor.visit_borrowed_str(value),
+        Cow::Owned(value) => visitor.visit_string(value),
+    }
+}
+
+/// Parse a number, only the number syntax of JSON is accepted, as Rust would otherwise also
+/// accept values like `inf` or `+1`.
+fn parse_number(value: &str) -> Option<Number> {
+    let is_number = value
+        .bytes()
+        .next()
+        .map_or(false, |first| first == b'-' || first.is_ascii_digit())
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || matches!(byte, b'-' | b'+' | b'.' | b'e' | b'E'));
+
+    if !is_number {
+        return None;
+    }
+
+    value
+        .parse::<u64>()
+        .map(Number::from)
+        .or_else(|_error| value.parse::<i64>().map(Number::from))
+        .ok()
+        .or_else(|| {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(Number::from)
+        })
+}
+
+/// Every value of a single key, a key which is present multiple times is an array of values.
+///
+/// There is always at least a single value.
+pub(crate) struct ValueDeserializer<'a, 'de> {
+    values: Vec<Cow<'de, str>>,
+    context: &'a Context,
+}
+
+impl<'a, 'de> ValueDeserializer<'a, 'de> {
+    pub(crate) const fn new(values: Vec<Cow<'de, str>>, context: &'a Context) -> Self {
+        Self { values, context }
+    }
+
+    pub(crate) fn single(value: Cow<'de, str>, context: &'a Context) -> Self {
+        Self::new(vec![value], context)
+    }
+
+    fn received(&self) -> Document {
+        if self.values.len() == 1 {
+            str::document()
+        } else {
+            AnyArray::document()
+        }
+    }
+
+    /// The only value, errors out if the key has been present multiple times.
+    fn into_value(self, expected: Document) -> Result<Cow<'de, str>, DeserializerError> {
+        let received = self.received();
+
+        <[_; 1]>::try_from(self.values)
+            .map(|[value]| value)
+            .map_err(|_values| invalid_type(expected, received))
+    }
+}
+
+impl<'de> deer::Deserializer<'de> for ValueDeserializer<'_, 'de> {
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        if self.values.len() == 1 {
+            self.deserialize_str(visitor)
+        } else {
+            self.deserialize_array(visitor)
+        }
+    }
+
+    fn deserialize_null<V>(self, _: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        Err(invalid_type(<()>::reflection(), self.received()))
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let value = self.into_value(bool::reflection())?;
+
+        match value.as_ref() {
+            "true" => visitor.visit_bool(true),
+            "false" => visitor.visit_bool(false),
+            _ => return Err(invalid_type(bool::reflection(), str::document())),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_number<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let value = self.into_value(Number::reflection())?;
+
+        let number = parse_number(&value)
+            .ok_or_else(|| invalid_type(Number::reflection(), str::document()))?;
+
+        visitor
+            .visit_number(number)
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let value = self.into_value(str::document())?;
+
+        visit_str(visitor, value).change_context(DeserializerError)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let value = self.into_value(<&[u8]>::reflection())?;
+
+        match value {
+            Cow::Borrowed(value) => visitor.visit_borrowed_bytes(value.as_bytes()),
+            Cow::Owned(value) => visitor.visit_bytes_buffer(value.into_bytes()),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_bytes_buffer<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_array<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        visitor
+            .visit_array(ArrayAccess::new(self.values, self.context))
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_object<V>(self, _: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        Err(invalid_type(AnyObject::document(), self.received()))
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        // a missing value is represented by a missing key, every key that is present has a value
+        visitor.visit_some(self).change_context(DeserializerError)
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: EnumVisitor<'de>,
+    {
+        let context = self.context;
+
+        // only unit variants can be represented
+        let value = self.into_value(visitor.expecting())?;
+
+        let discriminant = visitor
+            .visit_discriminant(Self::single(value, context))
+            .change_context(DeserializerError)?;
+
+        visitor
+            .visit_value(discriminant, NoneDeserializer::new(context))
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_struct<V>(self, _: V) -> Result<V::Value, DeserializerError>
+    where
+        V: StructVisitor<'de>,
+    {
+        Err(invalid_type(AnyObject::document(), self.received()))
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        let value = self.into_value(str::document())?;
+
+        visitor.visit_str(&value).change_context(DeserializerError)
+    }
+}
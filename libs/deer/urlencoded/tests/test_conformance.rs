@@ -0,0 +1,83 @@
+use deer::{error::DeserializeError, Context, Deserialize};
+use deer_desert::{
+    conformance::{assert_conformance, Format},
+    Token,
+};
+use deer_urlencoded::from_slice;
+use error_stack::Result;
+
+fn scalar(token: &Token) -> Option<String> {
+    match token {
+        Token::Bool(value) => Some(value.to_string()),
+        Token::Number(value) => Some(value.to_string()),
+        Token::Char(value) => Some(value.to_string()),
+        Token::Str(value) | Token::BorrowedStr(value) | Token::String(value) => {
+            Some((*value).to_owned())
+        }
+        _ => None,
+    }
+}
+
+/// Only objects of scalars or non-empty arrays of scalars are representable.
+struct UrlEncoded;
+
+impl Format for UrlEncoded {
+    fn encode(tokens: &[Token]) -> Option<Vec<u8>> {
+        let (Token::Object { .. }, mut tokens) = tokens.split_first()? else {
+            return None;
+        };
+
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+
+        loop {
+            let (key, rest) = tokens.split_first()?;
+            tokens = rest;
+
+            if *key == Token::ObjectEnd {
+                break;
+            }
+
+            let key = scalar(key)?;
+
+            let (value, rest) = tokens.split_first()?;
+            tokens = rest;
+
+            if !matches!(value, Token::Array { .. }) {
+                serializer.append_pair(&key, &scalar(value)?);
+                continue;
+            }
+
+            let mut length = 0;
+
+            loop {
+                let (item, rest) = tokens.split_first()?;
+                tokens = rest;
+
+                if *item == Token::ArrayEnd {
+                    break;
+                }
+
+                serializer.append_pair(&key, &scalar(item)?);
+                length += 1;
+            }
+
+            if length == 0 {
+                return None;
+            }
+        }
+
+        tokens.is_empty().then(|| serializer.finish().into_bytes())
+    }
+
+    fn decode<'de, T>(input: &'de [u8], context: &Context) -> Result<T, DeserializeError>
+    where
+        T: Deserialize<'de>,
+    {
+        from_slice(input, context)
+    }
+}
+
+#[test]
+fn conformance() {
+    assert_conformance::<UrlEncoded>();
+}
@@ -0,0 +1,71 @@
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use deer::Context;
+use deer_desert::errors;
+use deer_macros::Deserialize;
+use deer_urlencoded::from_str;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Query {
+    q: String,
+    page: u32,
+    tags: Vec<String>,
+    exact: Option<bool>,
+}
+
+#[test]
+fn struct_ok() {
+    let context = Context::new();
+
+    let query: Query =
+        from_str("q=hello+world%21&tags=a&page=2&tags=b", &context).expect("should deserialize");
+
+    assert_eq!(query, Query {
+        q: "hello world!".to_owned(),
+        page: 2,
+        tags: vec!["a".to_owned(), "b".to_owned()],
+        exact: None,
+    });
+}
+
+#[test]
+fn single_value_array_ok() {
+    let context = Context::new();
+
+    let query: BTreeMap<String, Vec<u8>> = from_str("a=1", &context).expect("should deserialize");
+
+    assert_eq!(query, BTreeMap::from([("a".to_owned(), vec![1])]));
+}
+
+#[test]
+fn number_syntax_err() {
+    let context = Context::new();
+
+    // Rust would parse `inf` as float, but it is not a number
+    let report = from_str::<BTreeMap<String, f64>>("a=inf", &context).expect_err("should fail");
+
+    assert_eq!(errors(report)[0]["id"], json!(["type"]));
+}
+
+#[test]
+fn repeated_key_err() {
+    let context = Context::new();
+
+    let report = from_str::<Query>("q=a&q=b&page=1&tags=c", &context).expect_err("should fail");
+    let errors = errors(report);
+
+    let error = errors
+        .as_array()
+        .expect("should be an array")
+        .iter()
+        .find(|error| error["id"] == json!(["type"]))
+        .expect("should have a type error");
+
+    assert_eq!(
+        error["properties"]["location"],
+        json!([{"type": "field", "value": "q"}])
+    );
+}
@@ -7,7 +7,7 @@
     clippy::std_instead_of_alloc,
     clippy::std_instead_of_core
 )]
-//! Example of using `attach` to set a custom exit code. Requires nightly and std feature.
+//! Example of using `attach` to set a custom exit code. Requires std feature.
 
 use std::process::{ExitCode, Termination};
 
@@ -0,0 +1,10 @@
+#![allow(clippy::unwrap_used)]
+use rustc_version::{version_meta, Channel};
+
+fn main() {
+    // `Context::provide` is only available on nightly, the generated implementation needs to
+    // match the one of `error-stack`, which uses the same detection.
+    if version_meta().unwrap().channel == Channel::Nightly {
+        println!("cargo:rustc-cfg=nightly");
+    }
+}
@@ -0,0 +1,254 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    spanned::Spanned, Attribute, Data, DeriveInput, Error, Expr, Fields, Ident, LitStr, Result,
+};
+
+/// Attributes which are valid on a struct or on the variant of an enum.
+struct ContextAttributes {
+    display: LitStr,
+    attach: Vec<Expr>,
+}
+
+impl ContextAttributes {
+    fn parse(attributes: &[Attribute], span: Span) -> Result<Self> {
+        let mut display = None;
+        let mut attach = Vec::new();
+
+        for attribute in attributes {
+            if attribute.path().is_ident("display") {
+                if display.is_some() {
+                    return Err(Error::new_spanned(
+                        attribute,
+                        "duplicate `#[display(...)]` attribute",
+                    ));
+                }
+
+                display = Some(attribute.parse_args::<LitStr>()?);
+            } else if attribute.path().is_ident("attach") {
+                attach.push(attribute.parse_args::<Expr>()?);
+            } else if attribute.path().is_ident("provide") {
+                return Err(Error::new_spanned(
+                    attribute,
+                    "`#[provide]` is only supported on fields",
+                ));
+            }
+        }
+
+        let display = display.ok_or_else(|| {
+            Error::new(
+                span,
+                "missing `#[display(\"...\")]` attribute, which is required to implement `Display`",
+            )
+        })?;
+
+        Ok(Self { display, attach })
+    }
+}
+
+/// A struct or the variant of an enum, which is going to be matched on.
+struct Arm<'a> {
+    /// The path used in the pattern, either `Self` or `Self::Variant`
+    path: TokenStream,
+    fields: &'a Fields,
+    attributes: ContextAttributes,
+}
+
+impl<'a> Arm<'a> {
+    const fn new(path: TokenStream, fields: &'a Fields, attributes: ContextAttributes) -> Self {
+        Self {
+            path,
+            fields,
+            attributes,
+        }
+    }
+
+    fn binding(index: usize, field: &syn::Field) -> Ident {
+        field
+            .ident
+            .clone()
+            .unwrap_or_else(|| format_ident!("_{}", index))
+    }
+
+    /// Pattern, which binds every field by reference using the name of the field, or `_{index}`
+    /// for unnamed fields.
+    fn pattern(&self) -> TokenStream {
+        let path = &self.path;
+        let bindings = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| Self::binding(index, field));
+
+        match self.fields {
+            Fields::Named(_) => quote!(#path { #(ref #bindings),* }),
+            Fields::Unnamed(_) => quote!(#path ( #(ref #bindings),* )),
+            Fields::Unit => quote!(#path),
+        }
+    }
+
+    fn provided_fields(&self) -> Result<Vec<Ident>> {
+        let mut provided = Vec::new();
+
+        for (index, field) in self.fields.iter().enumerate() {
+            let mut attributes = field
+                .attrs
+                .iter()
+                .filter(|attribute| attribute.path().is_ident("provide"));
+
+            if let Some(attribute) = attributes.next() {
+                attribute.meta.require_path_only()?;
+                provided.push(Self::binding(index, field));
+            }
+
+            if let Some(attribute) = attributes.next() {
+                return Err(Error::new_spanned(
+                    attribute,
+                    "duplicate `#[provide]` attribute",
+                ));
+            }
+        }
+
+        Ok(provided)
+    }
+
+    fn display(&self) -> TokenStream {
+        let pattern = self.pattern();
+        let display = &self.attributes.display;
+        let format = LitStr::new(&rename_positional(&display.value()), display.span());
+
+        quote!(#pattern => ::core::write!(fmt, #format))
+    }
+
+    fn provide(&self) -> Result<Option<TokenStream>> {
+        let provided = self.provided_fields()?;
+        let attach = &self.attributes.attach;
+
+        if provided.is_empty() && attach.is_empty() {
+            return Ok(None);
+        }
+
+        let pattern = self.pattern();
+
+        Ok(Some(quote! {
+            #pattern => {
+                #(demand.provide_value(#attach);)*
+                #(demand.provide_ref(#provided);)*
+            }
+        }))
+    }
+}
+
+/// Replaces positional arguments (`{0}`) in a format string with the name of the binding of the
+/// corresponding unnamed field (`{_0}`), so that they can be captured implicitly.
+fn rename_positional(format: &str) -> String {
+    let mut output = String::with_capacity(format.len());
+    let mut chars = format.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        output.push(char);
+
+        if char != '{' {
+            continue;
+        }
+
+        if chars.peek() == Some(&'{') {
+            // escaped brace
+            output.extend(chars.next());
+            continue;
+        }
+
+        if chars.peek().map_or(false, char::is_ascii_digit) {
+            output.push('_');
+        }
+    }
+
+    output
+}
+
+fn arms(input: &DeriveInput) -> Result<Vec<Arm<'_>>> {
+    match &input.data {
+        Data::Struct(data) => Ok(vec![Arm::new(
+            quote!(Self),
+            &data.fields,
+            ContextAttributes::parse(&input.attrs, input.ident.span())?,
+        )]),
+        Data::Enum(data) => {
+            if let Some(attribute) = input
+                .attrs
+                .iter()
+                .find(|attribute| attribute.path().is_ident("display"))
+            {
+                return Err(Error::new_spanned(
+                    attribute,
+                    "`#[display(...)]` needs to be specified on each variant of an enum",
+                ));
+            }
+
+            data.variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+
+                    Ok(Arm::new(
+                        quote!(Self::#ident),
+                        &variant.fields,
+                        ContextAttributes::parse(&variant.attrs, variant.span())?,
+                    ))
+                })
+                .collect()
+        }
+        Data::Union(data) => Err(Error::new(
+            data.union_token.span,
+            "`Context` cannot be derived for unions",
+        )),
+    }
+}
+
+pub(crate) fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let arms = arms(input)?;
+
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let display = arms.iter().map(Arm::display);
+    let provide = arms
+        .iter()
+        .map(Arm::provide)
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    // `Context::provide` is only available on nightly, on stable the attributes are accepted, but
+    // have no effect.
+    let provide = (cfg!(nightly) && !provide.is_empty()).then(|| {
+        quote! {
+            #[allow(unused_variables)]
+            fn provide<'a>(&'a self, demand: &mut ::core::any::Demand<'a>) {
+                #[allow(unreachable_patterns)]
+                match *self {
+                    #(#provide)*
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Display for #ident #type_generics #where_clause {
+            #[allow(unused_variables)]
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match *self {
+                    #(#display,)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::error_stack::Context for #ident #type_generics #where_clause {
+            #provide
+        }
+    })
+}
@@ -1 +1,79 @@
 #![doc = include_str!("../README.md")]
+
+mod context;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives [`Display`] and [`Context`] for a struct or an enum.
+///
+/// The [`Display`] output is specified by a format string through the `#[display("...")]`
+/// attribute. For structs the attribute is placed on the struct itself, for enums it is placed on
+/// every variant. Fields can be referenced by their name (`{code}`), or, for tuple structs and
+/// tuple variants, by their index (`{0}`). All formatting options supported by [`format!`] are
+/// available.
+///
+/// The [`Debug`] implementation, which is required by [`Context`], is not derived.
+///
+/// ## Providing values
+///
+/// On nightly, values can additionally be [`provide`]d by the [`Context`], which makes them
+/// available through [`Report::request_ref`] and [`Report::request_value`]:
+///
+/// - `#[attach(expr)]` on a struct or a variant provides the value of `expr`, e.g. a suggestion.
+/// - `#[provide]` on a field provides a reference to the field.
+///
+/// As [`Context::provide`] is only available on nightly, these attributes are accepted but have no
+/// effect on stable. On nightly, the crate using them needs to enable the `provide_any` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #![cfg_attr(nightly, feature(provide_any))]
+/// use error_stack::{Context, Report};
+///
+/// #[derive(Debug)]
+/// pub struct Suggestion(&'static str);
+///
+/// #[derive(Debug, Context)]
+/// pub enum ConfigError {
+///     #[display("could not find the configuration file at {path:?}")]
+///     #[attach(Suggestion("create the file or specify a different path"))]
+///     NotFound { path: &'static str },
+///     #[display("invalid value {1:?} for key {0:?}")]
+///     InvalidValue(&'static str, i64),
+///     #[display("the configuration is empty")]
+///     Empty,
+/// }
+///
+/// #[derive(Debug, Context)]
+/// #[display("could not parse the configuration at line {line}")]
+/// pub struct ParseError {
+///     line: usize,
+/// }
+///
+/// let report = Report::new(ParseError { line: 42 })
+///     .change_context(ConfigError::InvalidValue("threads", -1));
+///
+/// assert_eq!(report.to_string(), r#"invalid value -1 for key "threads""#);
+/// assert_eq!(
+///     format!("{:#}", report),
+///     r#"invalid value -1 for key "threads": could not parse the configuration at line 42"#
+/// );
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+/// [`Debug`]: core::fmt::Debug
+/// [`Context`]: https://docs.rs/error-stack/latest/error_stack/trait.Context.html
+/// [`Context::provide`]: https://docs.rs/error-stack/latest/error_stack/trait.Context.html#method.provide
+/// [`provide`]: https://docs.rs/error-stack/latest/error_stack/trait.Context.html#method.provide
+/// [`Report::request_ref`]: https://docs.rs/error-stack/latest/error_stack/struct.Report.html#method.request_ref
+/// [`Report::request_value`]: https://docs.rs/error-stack/latest/error_stack/struct.Report.html#method.request_value
+#[proc_macro_derive(Context, attributes(display, attach, provide))]
+pub fn derive_context(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    context::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
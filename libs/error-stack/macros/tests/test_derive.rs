@@ -0,0 +1,118 @@
+#![cfg_attr(nightly, feature(provide_any))]
+
+use core::fmt;
+
+use error_stack::{Context, Report};
+
+#[derive(Debug, Context)]
+#[display("unit struct")]
+struct UnitStruct;
+
+#[derive(Debug, Context)]
+#[display("named struct with code {code} and {{escaped}} braces")]
+struct NamedStruct {
+    code: u16,
+}
+
+#[derive(Debug, Context)]
+#[display("tuple struct with {1:?} and {0:>3}")]
+struct TupleStruct(u8, &'static str);
+
+#[derive(Debug, Context)]
+enum Enum {
+    #[display("unit variant")]
+    Unit,
+    #[display("named variant with {value}")]
+    Named { value: i32, unused: bool },
+    #[display("tuple variant with {0}")]
+    Tuple(&'static str),
+}
+
+#[derive(Debug, Context)]
+#[display("generic struct with {value}")]
+struct Generic<T: fmt::Display + fmt::Debug + Send + Sync + 'static> {
+    value: T,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Suggestion(&'static str);
+
+#[derive(Debug, PartialEq, Eq)]
+struct ErrorCode(u16);
+
+#[derive(Debug, Context)]
+enum Provided {
+    #[display("not found")]
+    #[attach(Suggestion("check the path"))]
+    NotFound {
+        #[provide]
+        code: ErrorCode,
+    },
+    #[display("other")]
+    Other,
+}
+
+#[test]
+fn display() {
+    assert_eq!(UnitStruct.to_string(), "unit struct");
+    assert_eq!(
+        NamedStruct { code: 404 }.to_string(),
+        "named struct with code 404 and {escaped} braces"
+    );
+    assert_eq!(
+        TupleStruct(1, "a").to_string(),
+        r#"tuple struct with "a" and   1"#
+    );
+    assert_eq!(Enum::Unit.to_string(), "unit variant");
+    assert_eq!(
+        Enum::Named {
+            value: -1,
+            unused: true
+        }
+        .to_string(),
+        "named variant with -1"
+    );
+    assert_eq!(Enum::Tuple("b").to_string(), "tuple variant with b");
+    assert_eq!(
+        Generic { value: 1.5 }.to_string(),
+        "generic struct with 1.5"
+    );
+}
+
+#[test]
+fn context() {
+    let report = Report::new(NamedStruct { code: 404 }).change_context(Enum::Unit);
+
+    assert_eq!(
+        format!("{report:#}"),
+        "unit variant: named struct with code 404 and {escaped} braces"
+    );
+    assert!(report.contains::<NamedStruct>());
+}
+
+#[test]
+#[cfg(nightly)]
+fn provide() {
+    let report = Report::new(Provided::NotFound {
+        code: ErrorCode(404),
+    });
+
+    assert_eq!(report.request_value::<Suggestion>().collect::<Vec<_>>(), [
+        Suggestion("check the path")
+    ]);
+    assert_eq!(report.request_ref::<ErrorCode>().collect::<Vec<_>>(), [
+        &ErrorCode(404)
+    ]);
+
+    let report = Report::new(Provided::Other);
+
+    assert_eq!(report.request_value::<Suggestion>().count(), 0);
+    assert_eq!(report.request_ref::<ErrorCode>().count(), 0);
+}
+
+#[cfg_attr(not(nightly), ignore = "Outputs are different across toolchains")]
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
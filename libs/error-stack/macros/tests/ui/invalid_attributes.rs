@@ -0,0 +1,27 @@
+use error_stack::Context;
+
+#[derive(Debug, Context)]
+#[display("enum")]
+enum Enum {
+    #[display("variant")]
+    A,
+}
+
+#[derive(Debug, Context)]
+#[display("first")]
+#[display("second")]
+struct Duplicate;
+
+#[derive(Debug, Context)]
+#[display("{missing}")]
+struct MissingField {
+    field: u32,
+}
+
+#[derive(Debug, Context)]
+#[display("union")]
+union Union {
+    a: u32,
+}
+
+fn main() {}
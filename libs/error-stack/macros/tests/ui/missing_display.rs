@@ -0,0 +1,13 @@
+use error_stack::Context;
+
+#[derive(Debug, Context)]
+struct Struct;
+
+#[derive(Debug, Context)]
+enum Enum {
+    #[display("variant a")]
+    A,
+    B,
+}
+
+fn main() {}
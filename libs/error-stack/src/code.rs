@@ -0,0 +1,212 @@
+use alloc::borrow::Cow;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::process::ExitCode;
+
+use crate::{Frame, Report};
+
+/// A stable, machine-readable code identifying an error.
+///
+/// Unlike the [`Display`] output of a [`Context`], which is meant for humans and may change at any
+/// time, an `ErrorCode` is meant to be matched on by other programs, e.g. a client of an API.
+///
+/// An `ErrorCode` can be attached to a [`Report`] using [`Report::attach`], or be provided by a
+/// [`Context`] using [`Context::provide`]. The code closest to the top of the [`Report`] is
+/// returned by [`Report::error_code`].
+///
+/// [`Display`]: core::fmt::Display
+/// [`Context`]: crate::Context
+/// [`Context::provide`]: crate::Context::provide
+///
+/// # Example
+///
+/// ```
+/// use std::io::{Error, ErrorKind};
+///
+/// use error_stack::{report, ErrorCode};
+///
+/// const INVALID_CONFIG: ErrorCode = ErrorCode::new("config.invalid");
+///
+/// let report = report!(Error::from(ErrorKind::InvalidInput)).attach(INVALID_CONFIG);
+///
+/// assert_eq!(report.error_code(), Some(INVALID_CONFIG));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErrorCode(Cow<'static, str>);
+
+impl ErrorCode {
+    /// Creates a new `ErrorCode` from a static string.
+    #[must_use]
+    pub const fn new(code: &'static str) -> Self {
+        Self(Cow::Borrowed(code))
+    }
+
+    /// Creates a new `ErrorCode` from a string created at runtime.
+    #[must_use]
+    pub fn from_string(code: impl Into<Cow<'static, str>>) -> Self {
+        Self(code.into())
+    }
+
+    /// Returns the code as string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.0)
+    }
+}
+
+/// The status code of an HTTP response describing an error.
+///
+/// An `HttpStatusCode` can be attached to a [`Report`] using [`Report::attach`], or be provided by
+/// a [`Context`] using [`Context::provide`]. The status code closest to the top of the [`Report`]
+/// is returned by [`Report::http_status_code`]. Crates with their own status model, e.g. gRPC-like
+/// status codes, can convert them into an `HttpStatusCode` when attaching them.
+///
+/// [`Context`]: crate::Context
+/// [`Context::provide`]: crate::Context::provide
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HttpStatusCode(u16);
+
+impl HttpStatusCode {
+    /// Creates a new `HttpStatusCode` from its numeric value, e.g. `404`.
+    #[must_use]
+    pub const fn new(code: u16) -> Self {
+        Self(code)
+    }
+
+    /// Returns the numeric value of the status code.
+    #[must_use]
+    pub const fn as_u16(self) -> u16 {
+        self.0
+    }
+}
+
+impl fmt::Display for HttpStatusCode {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+/// Returns the value of type `T` held or provided by `frame`.
+fn request<T: Clone + Send + Sync + 'static>(frame: &Frame) -> Option<T> {
+    #[cfg(nightly)]
+    {
+        frame
+            .request_ref::<T>()
+            .cloned()
+            .or_else(|| frame.request_value::<T>())
+            .or_else(|| frame.downcast_ref::<T>().cloned())
+    }
+
+    #[cfg(not(nightly))]
+    frame.downcast_ref::<T>().cloned()
+}
+
+impl<C> Report<C> {
+    /// Returns the value of type `T` of the frame closest to the top of the `Report`.
+    ///
+    /// This is used to resolve values, which are meant to describe the `Report` as a whole, like
+    /// an [`ExitCode`] or an [`ErrorCode`]. The frames are searched in the order of
+    /// [`Report::frames`], which means, that the most recently added context or attachment takes
+    /// precedence over the ones below it, so that a caller is able to override the value of a
+    /// source. If the `Report` has multiple sources, the sources are searched in order.
+    ///
+    /// A frame holds a value of type `T` if the value is an attachment or a context, or, on
+    /// nightly, if the value is provided by a [`Context`] through [`Context::provide`].
+    ///
+    /// [`ExitCode`]: std::process::ExitCode
+    /// [`Context`]: crate::Context
+    /// [`Context::provide`]: crate::Context::provide
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::report;
+    ///
+    /// #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    /// struct StatusCode(u16);
+    ///
+    /// let report = report!(Error::from(ErrorKind::NotFound))
+    ///     .attach(StatusCode(404))
+    ///     .attach_printable("could not find user")
+    ///     .attach(StatusCode(400));
+    ///
+    /// assert_eq!(
+    ///     report.request_closest::<StatusCode>(),
+    ///     Some(StatusCode(400))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn request_closest<T>(&self) -> Option<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        self.frames().find_map(request::<T>)
+    }
+
+    /// Returns the [`ErrorCode`] closest to the top of the `Report`.
+    ///
+    /// See [`Report::request_closest`] for the resolution rules.
+    #[must_use]
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        self.request_closest()
+    }
+
+    /// Returns the [`ExitCode`] closest to the top of the `Report`.
+    ///
+    /// See [`Report::request_closest`] for the resolution rules. This is also used by the
+    /// [`Termination`] implementation of `Report`, which falls back to [`ExitCode::FAILURE`].
+    ///
+    /// [`Termination`]: std::process::Termination
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::{
+    ///     io::{Error, ErrorKind},
+    ///     process::ExitCode,
+    /// };
+    ///
+    /// use error_stack::report;
+    ///
+    /// let report = report!(Error::from(ErrorKind::InvalidInput)).attach(ExitCode::from(100));
+    ///
+    /// assert!(report.exit_code().is_some());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn exit_code(&self) -> Option<ExitCode> {
+        self.request_closest()
+    }
+
+    /// Returns the [`HttpStatusCode`] closest to the top of the `Report`.
+    ///
+    /// See [`Report::request_closest`] for the resolution rules. This is used to map a `Report` to
+    /// the response of an HTTP service.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{report, HttpStatusCode};
+    ///
+    /// let report = report!(Error::from(ErrorKind::NotFound))
+    ///     .attach(HttpStatusCode::new(404))
+    ///     .attach_printable("could not find user")
+    ///     .attach(HttpStatusCode::new(403));
+    ///
+    /// assert_eq!(report.http_status_code(), Some(HttpStatusCode::new(403)));
+    /// ```
+    #[must_use]
+    pub fn http_status_code(&self) -> Option<HttpStatusCode> {
+        self.request_closest()
+    }
+}
@@ -297,7 +297,13 @@ mod config;
 #[cfg(any(feature = "std", feature = "hooks"))]
 mod hook;
 mod location;
+mod output;
 mod r#override;
+mod structured;
+#[cfg(feature = "std")]
+// only used by the builtin hooks for `Backtrace` and `SpanTrace`
+#[cfg_attr(not(any(rust_1_65, feature = "spantrace")), allow(dead_code))]
+mod trace;
 
 use alloc::{
     borrow::ToOwned,
@@ -321,11 +327,15 @@ pub use hook::HookContext;
 pub(crate) use hook::{install_builtin_hooks, Format, Hooks};
 #[cfg(not(any(feature = "std", feature = "hooks")))]
 use location::LocationDisplay;
+pub use output::OutputMode;
+#[cfg(feature = "std")]
+pub use trace::TraceFilter;
 
 use crate::{
     fmt::{
         color::{Color, DisplayStyle, Style},
         config::Config,
+        structured::Structured,
     },
     AttachmentKind, Context, Frame, FrameKind, Report,
 };
@@ -1083,6 +1093,23 @@ fn debug_frame(root: &Frame, prefix: &[&Frame], config: &mut Config) -> Vec<Line
 
 impl<C> Debug for Report<C> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match OutputMode::load() {
+            OutputMode::Tree => {}
+            OutputMode::Json => {
+                return Display::fmt(
+                    &Structured::new(self.current_frames(), fmt.alternate()),
+                    fmt,
+                );
+            }
+            #[cfg(feature = "sarif")]
+            OutputMode::Sarif => {
+                let log = Structured::new(self.current_frames(), fmt.alternate()).to_sarif_log();
+                let log = serde_json::to_string(&log).map_err(|_error| fmt::Error)?;
+
+                return fmt.write_str(&log);
+            }
+        }
+
         let mut config = Config::load(fmt.alternate());
 
         let color = config.color_mode();
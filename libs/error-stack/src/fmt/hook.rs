@@ -467,6 +467,8 @@ mod default {
     #[cfg(feature = "spantrace")]
     use tracing_error::SpanTrace;
 
+    #[cfg(any(all(feature = "std", rust_1_65), feature = "spantrace"))]
+    use crate::fmt::trace;
     use crate::{
         fmt::{hook::HookContext, location::LocationDisplay},
         Report,
@@ -512,9 +514,11 @@ mod default {
 
     #[cfg(all(feature = "std", rust_1_65))]
     fn backtrace(backtrace: &Backtrace, context: &mut HookContext<Backtrace>) {
-        let idx = context.increment_counter();
+        let (idx, trace) = trace::prepare(&backtrace.to_string(), context);
 
-        context.push_appendix(format!("backtrace no. {}\n{backtrace}", idx + 1));
+        if let Some(trace) = trace {
+            context.push_appendix(format!("backtrace no. {}\n{trace}", idx + 1));
+        }
         #[cfg(nightly)]
         context.push_body(format!(
             "backtrace with {} frames ({})",
@@ -527,7 +531,7 @@ mod default {
 
     #[cfg(feature = "spantrace")]
     fn span_trace(span_trace: &SpanTrace, context: &mut HookContext<SpanTrace>) {
-        let idx = context.increment_counter();
+        let (idx, trace) = trace::prepare(&span_trace.to_string(), context);
 
         let mut span = 0;
         span_trace.with_spans(|_, _| {
@@ -535,7 +539,9 @@ mod default {
             true
         });
 
-        context.push_appendix(format!("span trace No. {}\n{span_trace}", idx + 1));
+        if let Some(trace) = trace {
+            context.push_appendix(format!("span trace No. {}\n{trace}", idx + 1));
+        }
         context.push_body(format!("span trace with {span} frames ({})", idx + 1));
     }
 }
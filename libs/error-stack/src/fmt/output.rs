@@ -0,0 +1,111 @@
+use crate::{
+    fmt::r#override::{AtomicOverride, AtomicPreference},
+    Report,
+};
+
+/// The format used by the [`Debug`] implementation of a [`Report`]
+///
+/// Set via [`Report::set_output_mode`].
+///
+/// Some variants are only available behind a feature flag, e.g. `Sarif` requires the `sarif`
+/// feature, so matching on this enum requires a wildcard arm.
+///
+/// [`Debug`]: core::fmt::Debug
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub enum OutputMode {
+    /// Human readable tree of all frames, optionally using colors and box-drawing characters
+    ///
+    /// This is the default if no output mode has been explicitly set.
+    #[default]
+    Tree,
+
+    /// A single JSON document, which is meant to be consumed by tools like log aggregators
+    ///
+    /// The document contains the same information as the tree: every context with the output of
+    /// the hooks of its attachments, the number of opaque attachments, its sources, and the
+    /// appendix.
+    ///
+    /// ```json
+    /// {
+    ///     "frames": [{
+    ///         "context": "context display output",
+    ///         "type": "type name of the context",
+    ///         "attachments": ["output", "of", "the", "hooks"],
+    ///         "opaque": 0,
+    ///         "sources": []
+    ///     }],
+    ///     "appendix": []
+    /// }
+    /// ```
+    Json,
+
+    /// A [SARIF] log, the format used by static analysis tools and CI systems
+    ///
+    /// See [`Report::to_sarif_log`] for details on how a report is represented.
+    ///
+    /// [SARIF]: https://sarifweb.azurewebsites.net/
+    #[cfg(feature = "sarif")]
+    Sarif,
+}
+
+impl OutputMode {
+    pub(super) fn load() -> Self {
+        OUTPUT_MODE_OVERRIDE.load()
+    }
+}
+
+/// Value layout:
+/// `0x00`: `OutputMode::Tree`
+/// `0x01`: `OutputMode::Json`
+/// `0x02`: `OutputMode::Sarif`
+///
+/// all others: default to [`Self::default`]
+impl AtomicPreference for OutputMode {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0x00 => Self::Tree,
+            0x01 => Self::Json,
+            #[cfg(feature = "sarif")]
+            0x02 => Self::Sarif,
+            _ => Self::default(),
+        }
+    }
+
+    fn into_u8(self) -> u8 {
+        match self {
+            Self::Tree => 0x00,
+            Self::Json => 0x01,
+            #[cfg(feature = "sarif")]
+            Self::Sarif => 0x02,
+        }
+    }
+}
+
+static OUTPUT_MODE_OVERRIDE: AtomicOverride<OutputMode> = AtomicOverride::new();
+
+impl Report<()> {
+    /// Set the output mode preference
+    ///
+    /// The value defaults to [`OutputMode::Tree`]. Hooks are invoked for every output mode, colors
+    /// are always disabled when rendering into a machine-readable format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{fmt::OutputMode, report, Report};
+    ///
+    /// let report = report!(Error::from(ErrorKind::InvalidInput)).attach_printable("unable to parse");
+    ///
+    /// Report::set_output_mode(OutputMode::Json);
+    /// let output = format!("{report:?}");
+    /// # Report::set_output_mode(OutputMode::Tree);
+    ///
+    /// assert!(output.starts_with(r#"{"frames":[{"context":"invalid input parameter""#));
+    /// ```
+    pub fn set_output_mode(mode: OutputMode) {
+        OUTPUT_MODE_OVERRIDE.store(mode);
+    }
+}
@@ -0,0 +1,336 @@
+//! Machine-readable rendering of the frame tree, used by [`OutputMode::Json`] and
+//! [`OutputMode::Sarif`].
+//!
+//! The tree is built the same way as the human readable tree, which means, that the same hooks are
+//! invoked and the same contexts are grouped together, only the rendering differs.
+//!
+//! [`OutputMode::Json`]: crate::fmt::OutputMode::Json
+//! [`OutputMode::Sarif`]: crate::fmt::OutputMode::Sarif
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+use alloc::borrow::ToOwned;
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    iter::once,
+};
+
+use crate::{
+    fmt::{collect, config::Config, debug_attachments_invoke, partition, Charset, ColorMode},
+    Frame, FrameKind,
+};
+
+/// A context and everything rendered alongside of it.
+struct Node {
+    context: String,
+    type_name: &'static str,
+    attachments: Vec<String>,
+    opaque: usize,
+    sources: Vec<Node>,
+}
+
+fn structured_frame(root: &Frame, prefix: &[&Frame], config: &mut Config) -> Vec<Node> {
+    let (stack, sources) = collect(root, prefix);
+    let (stack, prefix) = partition(&stack);
+
+    let mut contexts = stack
+        .into_iter()
+        .map(|(head, mut body)| {
+            let context = match head.kind() {
+                FrameKind::Context(context) => context.to_string(),
+                FrameKind::Attachment(_) => unreachable!(),
+            };
+
+            // reverse all attachments, to make it more logical relative to the attachment order
+            body.reverse();
+            let (opaque, attachments) = debug_attachments_invoke(once(head).chain(body), config);
+
+            Node {
+                context,
+                type_name: head.type_name(),
+                attachments,
+                opaque: opaque.0,
+                sources: Vec::new(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let sources = sources
+        .iter()
+        .flat_map(|source| structured_frame(source, &prefix, config))
+        .collect::<Vec<_>>();
+
+    // if there is no context, this is considered a "transparent" group, and just directly returns
+    // all sources
+    let mut node = match contexts.pop() {
+        Some(node) => node,
+        None => return sources,
+    };
+
+    // every context is the only source of the context before it
+    node.sources = sources;
+    while let Some(mut parent) = contexts.pop() {
+        parent.sources = vec![node];
+        node = parent;
+    }
+
+    vec![node]
+}
+
+/// The frame tree of a [`Report`] together with the appendix of all invoked hooks.
+///
+/// [`Report`]: crate::Report
+pub(super) struct Structured {
+    frames: Vec<Node>,
+    appendix: Vec<String>,
+}
+
+impl Structured {
+    pub(super) fn new(frames: &[Frame], alternate: bool) -> Self {
+        // colors would only add escape sequences, which are meaningless for machines
+        let mut config = Config::new(ColorMode::None, Charset::load(), alternate);
+
+        let frames = frames
+            .iter()
+            .flat_map(|frame| structured_frame(frame, &[], &mut config))
+            .collect();
+
+        #[cfg(any(feature = "std", feature = "hooks"))]
+        let appendix = config
+            .context::<Frame>()
+            .appendix()
+            .iter()
+            .map(|snippet| snippet.trim_end_matches('\n').to_owned())
+            .collect();
+        #[cfg(not(any(feature = "std", feature = "hooks")))]
+        let appendix = Vec::new();
+
+        Self { frames, appendix }
+    }
+}
+
+/// Writes a string as JSON string literal, escaping all characters as required by RFC 8259.
+struct JsonString<'a>(&'a str);
+
+impl Display for JsonString<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_char('"')?;
+
+        for char in self.0.chars() {
+            match char {
+                '"' => fmt.write_str("\\\"")?,
+                '\\' => fmt.write_str("\\\\")?,
+                '\n' => fmt.write_str("\\n")?,
+                '\r' => fmt.write_str("\\r")?,
+                '\t' => fmt.write_str("\\t")?,
+                char if char.is_control() => write!(fmt, "\\u{:04x}", u32::from(char))?,
+                char => fmt.write_char(char)?,
+            }
+        }
+
+        fmt.write_char('"')
+    }
+}
+
+fn json_array<T>(
+    fmt: &mut Formatter<'_>,
+    values: &[T],
+    mut write: impl FnMut(&T, &mut Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    fmt.write_char('[')?;
+
+    for (index, value) in values.iter().enumerate() {
+        if index > 0 {
+            fmt.write_char(',')?;
+        }
+
+        write(value, fmt)?;
+    }
+
+    fmt.write_char(']')
+}
+
+impl Display for Node {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            r#"{{"context":{},"type":{},"attachments":"#,
+            JsonString(&self.context),
+            JsonString(self.type_name)
+        )?;
+        json_array(fmt, &self.attachments, |attachment, fmt| {
+            JsonString(attachment).fmt(fmt)
+        })?;
+        write!(fmt, r#","opaque":{},"sources":"#, self.opaque)?;
+        json_array(fmt, &self.sources, Self::fmt)?;
+        fmt.write_char('}')
+    }
+}
+
+impl Display for Structured {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(r#"{"frames":"#)?;
+        json_array(fmt, &self.frames, Node::fmt)?;
+        fmt.write_str(r#","appendix":"#)?;
+        json_array(fmt, &self.appendix, |snippet, fmt| {
+            JsonString(snippet).fmt(fmt)
+        })?;
+        fmt.write_char('}')
+    }
+}
+
+#[cfg(feature = "sarif")]
+mod sarif_log {
+    use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+    use sarif::schema::{
+        Level, Location, Message, MultiformatMessageString, PropertyBag, ReportingDescriptor,
+        Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    use super::{Node, Structured};
+    use crate::Report;
+
+    impl Node {
+        fn descriptors<'a>(&'a self, descriptors: &mut BTreeMap<&'static str, &'a str>) {
+            descriptors.entry(self.type_name).or_insert(&self.context);
+
+            for source in &self.sources {
+                source.descriptors(descriptors);
+            }
+        }
+
+        /// Appends a result for this context and all of its sources in pre-order and returns the
+        /// index of the result for this context.
+        fn results(
+            &self,
+            rules: &BTreeMap<&'static str, &str>,
+            results: &mut Vec<Result<'static>>,
+        ) -> usize {
+            let index = results.len();
+
+            let mut result = Result::new(Message::new(self.context.clone()))
+                .with_rule_id(self.type_name)
+                .with_level(Level::Error);
+            if let Some(rule_index) = rules.keys().position(|rule| *rule == self.type_name) {
+                result = result.with_rule_index(rule_index);
+            }
+            for (id, attachment) in self.attachments.iter().enumerate() {
+                result = result.with_related_location(
+                    Location::new()
+                        .with_id(id)
+                        .with_message(Message::new(attachment.clone())),
+                );
+            }
+            results.push(result);
+
+            let sources = self
+                .sources
+                .iter()
+                .map(|source| source.results(rules, results))
+                .collect::<Vec<_>>();
+
+            let mut properties = PropertyBag::new();
+            if self.opaque > 0 {
+                properties = properties.with_property("opaque", self.opaque);
+            }
+            if !sources.is_empty() {
+                properties = properties.with_property("sources", sources);
+            }
+            results[index].properties = properties;
+
+            index
+        }
+    }
+
+    impl Structured {
+        pub(in crate::fmt) fn to_sarif_log(&self) -> SarifLog<'static> {
+            let mut descriptors = BTreeMap::new();
+            for frame in &self.frames {
+                frame.descriptors(&mut descriptors);
+            }
+
+            let mut results = Vec::new();
+            for frame in &self.frames {
+                frame.results(&descriptors, &mut results);
+            }
+
+            let rules = descriptors.into_iter().map(|(type_name, context)| {
+                ReportingDescriptor::new(type_name).with_short_description(
+                    MultiformatMessageString {
+                        text: Cow::Owned(context.into()),
+                        markdown: None,
+                        properties: PropertyBag::new(),
+                    },
+                )
+            });
+
+            let driver = ToolComponent::new("error-stack")
+                .with_version(env!("CARGO_PKG_VERSION"))
+                .with_rules(rules);
+
+            let tool = if self.appendix.is_empty() {
+                Tool::new(driver)
+            } else {
+                Tool::new(driver).with_properties(|properties| {
+                    properties.with_property("appendix", self.appendix.clone())
+                })
+            };
+
+            SarifLog::new(SchemaVersion::V2_1_0).with_run(Run::new(tool).with_results(results))
+        }
+    }
+
+    impl<C> Report<C> {
+        /// Converts the `Report` into a [SARIF] log.
+        ///
+        /// The log consists of a single run of the `error-stack` tool. Every type of context in
+        /// the report is added as a rule, using the type name as identifier and the first
+        /// encountered [`Display`] output as description.
+        ///
+        /// Every context is reported as a result of the rule of its type with the [`Display`]
+        /// output as message. The output of the hooks of its attachments are added as related
+        /// locations. Results are ordered depth-first, starting with the outermost context, and the
+        /// `sources` property of a result lists the indices of the results of its sources. The
+        /// number of opaque attachments is added as `opaque` property, and the appendix, as
+        /// described in [`OutputMode::Json`], as `appendix` property of the tool.
+        ///
+        /// Setting [`OutputMode::Sarif`] serializes the log as [`Debug`] output of the report.
+        ///
+        /// [SARIF]: https://sarifweb.azurewebsites.net/
+        /// [`Display`]: core::fmt::Display
+        /// [`Debug`]: core::fmt::Debug
+        /// [`OutputMode::Json`]: crate::fmt::OutputMode::Json
+        /// [`OutputMode::Sarif`]: crate::fmt::OutputMode::Sarif
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use std::io::{Error, ErrorKind};
+        ///
+        /// use error_stack::report;
+        ///
+        /// let report = report!(Error::from(ErrorKind::InvalidInput));
+        ///
+        /// let log = report.to_sarif_log();
+        /// let run = &log.runs.as_ref().expect("should have a single run")[0];
+        /// assert_eq!(run.tool.driver.name, "error-stack");
+        /// assert_eq!(run.tool.driver.rules[0].id, "std::io::error::Error");
+        ///
+        /// let results = run.results.as_ref().expect("should have results");
+        /// assert_eq!(results[0].rule_id.as_deref(), Some("std::io::error::Error"));
+        /// assert_eq!(
+        ///     results[0].message.text.as_deref(),
+        ///     Some("invalid input parameter")
+        /// );
+        /// ```
+        #[must_use]
+        pub fn to_sarif_log(&self) -> SarifLog<'static> {
+            Structured::new(self.current_frames(), false).to_sarif_log()
+        }
+    }
+}
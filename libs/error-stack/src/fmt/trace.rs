@@ -0,0 +1,269 @@
+use alloc::{borrow::Cow, format, string::String, vec, vec::Vec};
+use std::sync::RwLock;
+
+use crate::{fmt::HookContext, Report};
+
+/// Filter applied to the [`Backtrace`]s and [`SpanTrace`]s rendered in the appendix of the
+/// [`Debug`] output.
+///
+/// Frames are attributed to a crate through the first segment of their symbol (or span) name.
+/// Frames of hidden crates are removed from the output, while consecutive frames of collapsed
+/// crates are replaced by a single line. If deduplication is enabled, traces which render
+/// identically after filtering are only added to the appendix once and referenced by every
+/// attachment containing them, which is common for sibling sources created by
+/// [`Report::extend_one`].
+///
+/// By default no frames are filtered and traces are not deduplicated, set the filter via
+/// [`Report::set_trace_filter`].
+///
+/// [`Backtrace`]: std::backtrace::Backtrace
+/// [`SpanTrace`]: tracing_error::SpanTrace
+/// [`Debug`]: core::fmt::Debug
+///
+/// # Example
+///
+/// ```
+/// use error_stack::{fmt::TraceFilter, Report};
+///
+/// Report::set_trace_filter(
+///     TraceFilter::new()
+///         .hide_std()
+///         .hide("tokio")
+///         .collapse("tower")
+///         .deduplicate(true),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TraceFilter {
+    hidden: Vec<Cow<'static, str>>,
+    collapsed: Vec<Cow<'static, str>>,
+    deduplicate: bool,
+}
+
+impl TraceFilter {
+    /// Creates a filter, which keeps all frames and does not deduplicate traces.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            hidden: Vec::new(),
+            collapsed: Vec::new(),
+            deduplicate: false,
+        }
+    }
+
+    /// Removes all frames of the crate with the given name.
+    #[must_use]
+    pub fn hide(mut self, krate: impl Into<Cow<'static, str>>) -> Self {
+        self.hidden.push(krate.into());
+        self
+    }
+
+    /// Removes all frames of the standard library, which are frames of `std`, `core`, and `alloc`.
+    #[must_use]
+    pub fn hide_std(self) -> Self {
+        self.hide("std").hide("core").hide("alloc")
+    }
+
+    /// Replaces consecutive frames of the crate with the given name by a single line.
+    #[must_use]
+    pub fn collapse(mut self, krate: impl Into<Cow<'static, str>>) -> Self {
+        self.collapsed.push(krate.into());
+        self
+    }
+
+    /// Adds identical traces to the appendix only once.
+    #[must_use]
+    pub const fn deduplicate(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = deduplicate;
+        self
+    }
+
+    fn is_hidden(&self, krate: &str) -> bool {
+        self.hidden.iter().any(|hidden| hidden == krate)
+    }
+
+    fn is_collapsed(&self, krate: &str) -> bool {
+        self.collapsed.iter().any(|collapsed| collapsed == krate)
+    }
+
+    /// Applies the filter to the rendered trace.
+    ///
+    /// Both, backtraces and span traces, are rendered as a list of frames, where each frame starts
+    /// with a line `{index}: {name}`, followed by lines with additional information, like the
+    /// location.
+    fn apply(&self, trace: &str) -> String {
+        if self.hidden.is_empty() && self.collapsed.is_empty() {
+            return trace.into();
+        }
+
+        let mut output = Vec::new();
+        // the crate of the current frame and if the frame is omitted
+        let mut current: Option<(&str, bool)> = None;
+        // the crate and number of the frames, which are currently collapsed
+        let mut collapsed: Option<(&str, usize)> = None;
+
+        for line in trace.lines() {
+            if let Some(name) = frame_name(line) {
+                let krate = crate_name(name);
+
+                if collapsed.map_or(false, |(collapsed, _)| collapsed != krate)
+                    && !self.is_hidden(krate)
+                {
+                    output.extend(collapsed.take().map(collapsed_line));
+                }
+
+                if self.is_hidden(krate) {
+                    current = Some((krate, true));
+                } else if self.is_collapsed(krate) {
+                    let (_, count) = collapsed.get_or_insert((krate, 0));
+                    *count += 1;
+                    current = Some((krate, true));
+                } else {
+                    current = Some((krate, false));
+                }
+            }
+
+            if !current.map_or(false, |(_, omitted)| omitted) {
+                output.push(line.into());
+            }
+        }
+
+        output.extend(collapsed.map(collapsed_line));
+        output.join("\n")
+    }
+}
+
+/// Returns the name of the frame if the line is the first line of a frame.
+fn frame_name(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    let (index, name) = line.split_once(": ")?;
+
+    (!index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit())).then_some(name)
+}
+
+/// Returns the name of the crate of a symbol, e.g. `core` for
+/// `<alloc::boxed::Box<F,A> as core::ops::function::FnOnce<Args>>::call_once`.
+fn crate_name(name: &str) -> &str {
+    let name = name.trim_start_matches(|char| char == '<' || char == '&');
+    let name = name.strip_prefix("dyn ").unwrap_or(name);
+
+    name.split("::").next().unwrap_or(name)
+}
+
+fn collapsed_line((krate, count): (&str, usize)) -> String {
+    match count {
+        1 => format!("      [1 frame of {krate} collapsed]"),
+        count => format!("      [{count} frames of {krate} collapsed]"),
+    }
+}
+
+static TRACE_FILTER: RwLock<TraceFilter> = RwLock::new(TraceFilter::new());
+
+/// Traces that have already been added to the appendix together with their number.
+struct Rendered(Vec<(String, isize)>);
+
+/// Filters the trace and determines its number in the appendix.
+///
+/// Returns the number of the trace and the filtered trace if it needs to be added to the appendix,
+/// or [`None`] if an identical trace has already been added.
+pub(crate) fn prepare<T: 'static>(
+    trace: &str,
+    context: &mut HookContext<T>,
+) -> (isize, Option<String>) {
+    let filter = TRACE_FILTER.read().expect("should not be poisoned");
+    let trace = filter.apply(trace);
+
+    if !filter.deduplicate {
+        return (context.increment_counter(), Some(trace));
+    }
+
+    if let Some((_, idx)) = context
+        .get::<Rendered>()
+        .and_then(|Rendered(rendered)| rendered.iter().find(|(rendered, _)| *rendered == trace))
+    {
+        return (*idx, None);
+    }
+
+    let idx = context.increment_counter();
+    match context.get_mut::<Rendered>() {
+        Some(Rendered(rendered)) => rendered.push((trace.clone(), idx)),
+        None => {
+            context.insert(Rendered(vec![(trace.clone(), idx)]));
+        }
+    }
+
+    (idx, Some(trace))
+}
+
+impl Report<()> {
+    /// Set the filter for backtraces and span traces in the appendix of the [`Debug`] output.
+    ///
+    /// The value defaults to [`TraceFilter::new`], which keeps all frames.
+    ///
+    /// [`Debug`]: core::fmt::Debug
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous call panicked while the filter was being modified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![cfg_attr(nightly, feature(error_generic_member_access))]
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{fmt::TraceFilter, report, Report};
+    ///
+    /// Report::set_trace_filter(TraceFilter::new().hide_std().deduplicate(true));
+    ///
+    /// let mut report = report!(Error::from(ErrorKind::InvalidInput));
+    /// report.extend_one(report!(Error::from(ErrorKind::InvalidInput)));
+    ///
+    /// // identical backtraces (if captured) are only added once to the appendix
+    /// println!("{report:?}");
+    /// ```
+    pub fn set_trace_filter(filter: TraceFilter) {
+        *TRACE_FILTER.write().expect("should not be poisoned") = filter;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TraceFilter;
+
+    const TRACE: &str = "   0: std::backtrace::Backtrace::create
+             at /rustc/library/std/src/backtrace.rs:331:13
+   1: error_stack::report::Report<C>::new
+             at ./src/report.rs:257:17
+   2: tokio::runtime::task::core::Core<T,S>::poll
+   3: tokio::runtime::task::harness::poll_future
+             at /tokio/src/runtime/task/harness.rs:473:19
+   4: <core::future::from_generator::GenFuture<T> as core::future::Future>::poll
+   5: app::main";
+
+    #[test]
+    fn unfiltered() {
+        assert_eq!(TraceFilter::new().apply(TRACE), TRACE);
+    }
+
+    #[test]
+    fn hide() {
+        assert_eq!(
+            TraceFilter::new().hide_std().hide("tokio").apply(TRACE),
+            "   1: error_stack::report::Report<C>::new
+             at ./src/report.rs:257:17
+   5: app::main"
+        );
+    }
+
+    #[test]
+    fn collapse() {
+        assert_eq!(
+            TraceFilter::new().hide_std().collapse("tokio").apply(TRACE),
+            "   1: error_stack::report::Report<C>::new
+             at ./src/report.rs:257:17
+      [2 frames of tokio collapsed]
+   5: app::main"
+        );
+    }
+}
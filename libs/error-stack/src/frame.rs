@@ -95,6 +95,15 @@ impl Frame {
     pub fn type_id(&self) -> TypeId {
         self.frame.as_any().type_id()
     }
+
+    /// Returns the name of the type of the held context or attachment by this frame.
+    ///
+    /// The name is obtained through [`core::any::type_name`] and is therefore only meant for
+    /// diagnostic purposes, the exact contents are not guaranteed to be stable.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.frame.type_name()
+    }
 }
 
 #[cfg(nightly)]
@@ -13,6 +13,9 @@ pub(super) trait FrameImpl: Send + Sync + 'static {
 
     fn as_any_mut(&mut self) -> &mut dyn Any;
 
+    /// Returns the name of the type of the held context or attachment.
+    fn type_name(&self) -> &'static str;
+
     /// Provide values which can then be requested.
     #[cfg(nightly)]
     fn provide<'a>(&'a self, demand: &mut Demand<'a>);
@@ -36,6 +39,10 @@ impl<C: Context> FrameImpl for ContextFrame<C> {
         &mut self.context
     }
 
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<C>()
+    }
+
     #[cfg(nightly)]
     fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
         Context::provide(&self.context, demand);
@@ -60,6 +67,10 @@ impl<A: 'static + Send + Sync> FrameImpl for AttachmentFrame<A> {
         &mut self.attachment
     }
 
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<A>()
+    }
+
     #[cfg(nightly)]
     fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
         demand.provide_ref(&self.attachment);
@@ -86,6 +97,10 @@ impl<A: 'static + fmt::Debug + fmt::Display + Send + Sync> FrameImpl
         &mut self.attachment
     }
 
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<A>()
+    }
+
     #[cfg(nightly)]
     fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
         demand.provide_ref(&self.attachment);
@@ -133,6 +148,10 @@ impl FrameImpl for AnyhowContext {
         &mut self.0
     }
 
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<anyhow::Error>()
+    }
+
     #[cfg(nightly)]
     #[inline]
     fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
@@ -180,6 +199,10 @@ impl FrameImpl for EyreContext {
         &mut self.0
     }
 
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<eyre::Report>()
+    }
+
     #[cfg(nightly)]
     #[inline]
     fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
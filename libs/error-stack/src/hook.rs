@@ -2,6 +2,8 @@ pub(crate) mod context;
 
 use alloc::vec::Vec;
 
+#[cfg(feature = "serde")]
+use crate::serde::SerializeHooks;
 use crate::{
     fmt::{install_builtin_hooks, Hooks},
     Report,
@@ -17,6 +19,9 @@ type RwLock<T> = spin::rwlock::RwLock<T>;
 
 static FMT_HOOK: RwLock<Hooks> = RwLock::new(Hooks { inner: Vec::new() });
 
+#[cfg(feature = "serde")]
+static SERIALIZE_HOOK: RwLock<SerializeHooks> = RwLock::new(SerializeHooks { inner: Vec::new() });
+
 impl Report<()> {
     /// Can be used to globally set a [`Debug`] format hook, for a specific type `T`.
     ///
@@ -181,4 +186,85 @@ impl Report<()> {
 
         closure(&hook)
     }
+
+    /// Can be used to globally set a serialization hook, for a specific type `T`.
+    ///
+    /// This hook will be called when serializing a [`Report`], if a context or attachment with the
+    /// same type has been found. The value returned by the hook is serialized in place of the
+    /// context or attachment.
+    ///
+    /// Without a hook, contexts are serialized using their [`Display`] output and type name only,
+    /// printable attachments are serialized using their [`Display`] output, and opaque attachments
+    /// are omitted.
+    ///
+    /// Installing a hook for a type, which already has a hook installed, will replace the previous
+    /// hook.
+    ///
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous hook panicked while the hooks were being modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{report, Report};
+    /// use serde::Serialize;
+    ///
+    /// struct Suggestion(&'static str);
+    ///
+    /// #[derive(Serialize)]
+    /// struct HttpError {
+    ///     code: u16,
+    /// }
+    ///
+    /// struct ErrorCode(u16);
+    ///
+    /// Report::install_serde_hook::<Suggestion, _>(|Suggestion(value)| *value);
+    /// Report::install_serde_hook::<ErrorCode, _>(|ErrorCode(code)| HttpError { code: *code });
+    ///
+    /// let report = report!(Error::from(ErrorKind::InvalidInput))
+    ///     .attach(Suggestion("try again"))
+    ///     .attach(ErrorCode(404));
+    ///
+    /// let value = serde_json::to_value(&report)?;
+    /// assert_eq!(
+    ///     value[0]["attachments"],
+    ///     serde_json::json!(["try again", { "code": 404 }])
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn install_serde_hook<T, U>(hook: impl Fn(&T) -> U + Send + Sync + 'static)
+    where
+        T: Send + Sync + 'static,
+        U: serde::Serialize + 'static,
+    {
+        #[cfg(feature = "std")]
+        let mut lock = SERIALIZE_HOOK.write().expect("should not be poisoned");
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let mut lock = SERIALIZE_HOOK.write();
+
+        lock.insert(hook);
+    }
+
+    /// Returns the hooks that were previously set by [`install_serde_hook`]
+    ///
+    /// [`install_serde_hook`]: Self::install_serde_hook
+    #[cfg(feature = "serde")]
+    pub(crate) fn invoke_serde_hook<T>(closure: impl FnOnce(&SerializeHooks) -> T) -> T {
+        #[cfg(feature = "std")]
+        let hook = SERIALIZE_HOOK.read().expect("should not be poisoned");
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let hook = SERIALIZE_HOOK.read();
+
+        closure(&hook)
+    }
 }
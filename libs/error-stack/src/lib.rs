@@ -417,6 +417,9 @@
 //! charset used, you can use [`Report::set_charset`]. The default color mode is emphasis.
 //! The default charset is `UTF-8`.
 //!
+//! Instead of the tree, the [`Debug`] output can be rendered as JSON document or as SARIF log for
+//! further processing by tools, see [`Report::set_output_mode`].
+//!
 //! To automatically detect support if your target output supports unicode and colors you can check
 //! out the `detect.rs` example.
 //!
@@ -427,6 +430,9 @@
 //! `std`          | Enables support for [`Error`], and, on Rust 1.65+, [`Backtrace`]   | enabled
 //! `spantrace`    | Enables automatic capturing of [`SpanTrace`]s                      | disabled
 //! `hooks`        | Enables hooks on `no-std` platforms using spin locks               | disabled
+//! `serde`        | Enables serialization support for [`Report`], including hooks      | disabled
+//! `macros`       | Provides `#[derive(Context)]` for implementing [`Context`]         | disabled
+//! `sarif`        | Enables rendering a [`Report`] as SARIF log (requires nightly)     | disabled
 //! `anyhow`       | Provides `into_report` to convert [`anyhow::Error`] to [`Report`]  | disabled
 //! `eyre`         | Provides `into_report` to convert [`eyre::Report`] to [`Report`]   | disabled
 //!
@@ -456,6 +462,7 @@ extern crate alloc;
 pub mod future;
 pub mod iter;
 
+mod code;
 mod compat;
 mod frame;
 mod macros;
@@ -471,7 +478,13 @@ mod hook;
 #[cfg(feature = "serde")]
 mod serde;
 
+#[cfg(feature = "macros")]
+pub use error_stack_macros::Context;
+
+#[cfg(feature = "serde")]
+pub use self::serde::{RemoteContext, RemoteFrame, RemoteReport, RemoteValue};
 pub use self::{
+    code::{ErrorCode, HttpStatusCode},
     compat::IntoReportCompat,
     context::Context,
     frame::{AttachmentKind, Frame, FrameKind},
@@ -482,7 +495,7 @@ pub use self::{
 #[doc(inline)]
 pub use self::{
     future::FutureExt,
-    result::{IntoReport, ResultExt},
+    result::{AccumulateResultExt, IntoReport, ResultExt, TryReportIteratorExt},
 };
 
 #[cfg(test)]
@@ -274,6 +274,16 @@ impl<C> Report<C> {
         Self::from_frame(Frame::from_context(context, Box::new([])))
     }
 
+    /// Creates a `Report` from already existing frames without capturing any additional
+    /// information, such as the [`Location`] or [`Backtrace`].
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_frames(frames: Vec<Frame>) -> Self {
+        Self {
+            frames: Box::new(frames),
+            _context: PhantomData,
+        }
+    }
+
     #[track_caller]
     pub(crate) fn from_frame(frame: Frame) -> Self {
         #[cfg(nightly)]
@@ -695,14 +705,7 @@ impl<C: 'static> From<Report<C>> for Box<dyn Error + Send + Sync> {
 #[cfg(feature = "std")]
 impl<Context> std::process::Termination for Report<Context> {
     fn report(self) -> ExitCode {
-        #[cfg(not(nightly))]
-        return ExitCode::FAILURE;
-
-        #[cfg(nightly)]
-        self.request_ref::<ExitCode>()
-            .next()
-            .copied()
-            .unwrap_or(ExitCode::FAILURE)
+        self.exit_code().unwrap_or(ExitCode::FAILURE)
     }
 }
 
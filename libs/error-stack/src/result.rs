@@ -1,3 +1,4 @@
+use alloc::{borrow::ToOwned, format};
 use core::fmt;
 
 use crate::{Context, Report};
@@ -177,6 +178,191 @@ impl<T, C> ResultExt for Result<T, C> {
     }
 }
 
+/// Extension trait for [`Result`][core::result::Result] to accumulate [`Report`]s instead of
+/// short-circuiting on the first one.
+pub trait AccumulateResultExt<C> {
+    /// Type of the [`Ok`] value in the [`Result`]
+    type Ok;
+
+    /// Accumulates the [`Report`] inside the [`Result`] into `report` instead of returning it.
+    ///
+    /// Returns the [`Ok`] value if present, otherwise the [`Report`] is either stored in `report`,
+    /// or, if `report` already contains a [`Report`], added to it as a sibling using
+    /// [`Report::extend_one`]. This allows processing all items of an iterator instead of
+    /// short-circuiting on the first error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[derive(Debug)] struct ParseError;
+    /// # impl core::fmt::Display for ParseError {
+    /// #    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { fmt.write_str("parse error") }
+    /// # }
+    /// # impl error_stack::Context for ParseError {}
+    /// use error_stack::{AccumulateResultExt, Report, Result};
+    ///
+    /// fn parse(value: &str) -> Result<u8, ParseError> {
+    ///     value.parse().map_err(|_| Report::new(ParseError))
+    /// }
+    ///
+    /// let mut report = None;
+    /// let values = ["1", "a", "3", "b"]
+    ///     .into_iter()
+    ///     .filter_map(|value| parse(value).accumulate_into(&mut report))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(values, [1, 3]);
+    /// assert_eq!(report.expect("should have errors").current_frames().len(), 2);
+    /// ```
+    fn accumulate_into(self, report: &mut Option<Report<C>>) -> Option<Self::Ok>;
+}
+
+impl<T, C> AccumulateResultExt<C> for Result<T, C> {
+    type Ok = T;
+
+    fn accumulate_into(self, report: &mut Option<Report<C>>) -> Option<T> {
+        match self {
+            Ok(ok) => Some(ok),
+            Err(error) => {
+                match report {
+                    Some(report) => report.extend_one(error),
+                    None => *report = Some(error),
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Extension trait for iterators over [`Result`]s to collect all [`Report`]s instead of
+/// short-circuiting on the first one.
+///
+/// Collecting into a [`Result`] using [`Iterator::collect`] stops at the first [`Err`], the
+/// methods of this trait instead consume the whole iterator and combine all [`Report`]s using
+/// [`Report::extend_one`], so that every error is part of the returned [`Report`].
+pub trait TryReportIteratorExt<C>: Iterator {
+    /// Type of the [`Ok`] values of the [`Result`]s yielded by the iterator
+    type Ok;
+
+    /// Collects all [`Ok`] values into `A` or combines all [`Report`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[derive(Debug)] struct ParseError;
+    /// # impl core::fmt::Display for ParseError {
+    /// #    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { fmt.write_str("parse error") }
+    /// # }
+    /// # impl error_stack::Context for ParseError {}
+    /// use error_stack::{Report, Result, TryReportIteratorExt};
+    ///
+    /// fn parse(value: &str) -> Result<u8, ParseError> {
+    ///     value.parse().map_err(|_| Report::new(ParseError))
+    /// }
+    ///
+    /// let values: Result<Vec<_>, _> = ["1", "2", "3"].into_iter().map(parse).try_collect_reports();
+    /// assert_eq!(values.expect("should be valid"), [1, 2, 3]);
+    ///
+    /// let values: Result<Vec<_>, _> = ["1", "a", "b"].into_iter().map(parse).try_collect_reports();
+    /// assert_eq!(values.expect_err("should be invalid").current_frames().len(), 2);
+    /// ```
+    fn try_collect_reports<A>(self) -> Result<A, C>
+    where
+        A: FromIterator<Self::Ok>;
+
+    /// Collects all [`Ok`] values into `A` or combines up to `max` [`Report`]s.
+    ///
+    /// The iterator is consumed completely, but only the first `max` [`Report`]s are retained,
+    /// which avoids unbounded growth when validating large batches. If [`Report`]s are dropped,
+    /// the number of omitted [`Report`]s is attached as printable attachment. At least one
+    /// [`Report`] is always retained.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[derive(Debug)] struct ParseError;
+    /// # impl core::fmt::Display for ParseError {
+    /// #    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { fmt.write_str("parse error") }
+    /// # }
+    /// # impl error_stack::Context for ParseError {}
+    /// use error_stack::{Report, Result, TryReportIteratorExt};
+    ///
+    /// fn parse(value: &str) -> Result<u8, ParseError> {
+    ///     value.parse().map_err(|_| Report::new(ParseError))
+    /// }
+    ///
+    /// let report = ["a", "b", "c", "d"]
+    ///     .into_iter()
+    ///     .map(parse)
+    ///     .try_collect_reports_bounded::<Vec<_>>(2)
+    ///     .expect_err("should be invalid");
+    ///
+    /// assert_eq!(report.current_frames()[0].sources().len(), 2);
+    /// assert_eq!(
+    ///     report.downcast_ref::<String>().map(String::as_str),
+    ///     Some("2 more errors omitted")
+    /// );
+    /// ```
+    fn try_collect_reports_bounded<A>(self, max: usize) -> Result<A, C>
+    where
+        A: FromIterator<Self::Ok>;
+}
+
+impl<I, T, C> TryReportIteratorExt<C> for I
+where
+    I: Iterator<Item = Result<T, C>>,
+{
+    type Ok = T;
+
+    fn try_collect_reports<A>(self) -> Result<A, C>
+    where
+        A: FromIterator<T>,
+    {
+        self.try_collect_reports_bounded(usize::MAX)
+    }
+
+    fn try_collect_reports_bounded<A>(self, max: usize) -> Result<A, C>
+    where
+        A: FromIterator<T>,
+    {
+        let mut report: Option<Report<C>> = None;
+        let mut retained = 0_usize;
+        let mut omitted = 0_usize;
+
+        let values = self
+            .filter_map(|result| match result {
+                // values are not needed anymore after the first error
+                Ok(value) => report.is_none().then_some(value),
+                Err(error) => {
+                    if let Some(report) = &mut report {
+                        if retained < max {
+                            report.extend_one(error);
+                            retained += 1;
+                        } else {
+                            omitted += 1;
+                        }
+                    } else {
+                        report = Some(error);
+                        retained += 1;
+                    }
+                    None
+                }
+            })
+            .collect();
+
+        let report = match report {
+            Some(report) => report,
+            None => return Ok(values),
+        };
+
+        match omitted {
+            0 => Err(report),
+            1 => Err(report.attach_printable("1 more error omitted".to_owned())),
+            omitted => Err(report.attach_printable(format!("{omitted} more errors omitted"))),
+        }
+    }
+}
+
 /// Extends [`Result`] to convert the [`Err`] variant to a [`Report`]
 pub trait IntoReport: Sized {
     /// Type of the [`Ok`] value in the [`Result`]
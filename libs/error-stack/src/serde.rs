@@ -1,48 +1,93 @@
 //! Implementation of general [`Report`] serialization.
 //!
-//! The value can be of any type, currently only printable attachments and context are supported, in
-//! the near future any values will be supported through the use of hooks.
+//! The value can be of any type. Contexts are always serialized using their [`Display`] output
+//! and their type name, printable attachments are serialized using their [`Display`] output.
+//! Any context or attachment can be serialized as a custom value by installing a hook via
+//! [`Report::install_serde_hook`], which is also the only way to serialize opaque attachments, as
+//! they are omitted otherwise.
 //!
 //! The serialized [`Report`] is a list of all current sources with the following output:
 //!
 //! ```json
 //! {
 //!     "context": "context display output",
+//!     "type": "type name of the context",
+//!     "value": "value returned by the hook of the context (omitted if no hook is installed)",
 //!     "attachments": ["all", "attachments", "leading", "up", "to", "this", "context"],
 //!     "sources": [] // recursive render using `frame.sources()`
 //! }
 //! ```
+//!
+//! The output can be deserialized into a [`RemoteReport`], which can be converted back into a
+//! [`Report`], e.g. to use an error received from another service as the source of a local error.
+//!
+//! [`Display`]: core::fmt::Display
 
-use alloc::{format, vec, vec::Vec};
+#[cfg(any(feature = "std", feature = "hooks"))]
+mod hook;
+mod remote;
+
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 use serde::{ser::SerializeMap, Serialize, Serializer};
 
+#[cfg(any(feature = "std", feature = "hooks"))]
+pub(crate) use self::hook::SerializeHooks;
+pub use self::remote::{RemoteContext, RemoteFrame, RemoteReport, RemoteValue};
 use crate::{AttachmentKind, Context, Frame, FrameKind, Report};
 
-struct SerializeAttachment<'a>(&'a Frame);
+/// Returns the value produced by the hook installed for the value held by `frame`, if any.
+#[cfg(any(feature = "std", feature = "hooks"))]
+fn call_hook(frame: &Frame) -> Option<Box<dyn erased_serde::Serialize>> {
+    Report::invoke_serde_hook(|hooks| hooks.call(frame))
+}
 
-impl<'a> Serialize for SerializeAttachment<'a> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let Self(frame) = self;
+#[cfg(not(any(feature = "std", feature = "hooks")))]
+fn call_hook(_: &Frame) -> Option<Box<dyn erased_serde::Serialize>> {
+    None
+}
+
+enum SerializeAttachment {
+    Hook(Box<dyn erased_serde::Serialize>),
+    Printable(String),
+}
+
+impl SerializeAttachment {
+    fn new(frame: &Frame) -> Option<Self> {
+        if let Some(value) = call_hook(frame) {
+            return Some(Self::Hook(value));
+        }
+
+        // values of a `RemoteReport` are serialized as they were received
+        if let Some(value) = frame.downcast_ref::<RemoteValue>() {
+            return Some(Self::Hook(Box::new(value.clone())));
+        }
 
-        #[allow(clippy::match_same_arms)]
         match frame.kind() {
-            FrameKind::Context(_) => {
-                // TODO: for now `Context` is unsupported, upcoming PR will fix via hooks
-                // `SerializeAttachmentList` ensures that no context is ever serialized
-                unimplemented!()
-            }
-            FrameKind::Attachment(AttachmentKind::Opaque(_)) => {
-                // TODO: for now opaque attachments are unsupported, upcoming PR will fix that
-                // `SerializeAttachmentList` ensures that no such attachment is added
-                unimplemented!()
-            }
             FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
-                format!("{attachment}").serialize(serializer)
+                Some(Self::Printable(attachment.to_string()))
             }
+            // opaque attachments without a hook cannot be serialized, therefore they are ignored,
+            // contexts are never part of the attachment list, see `find_next`
+            FrameKind::Attachment(AttachmentKind::Opaque(_)) | FrameKind::Context(_) => None,
+        }
+    }
+}
+
+impl Serialize for SerializeAttachment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Hook(value) => erased_serde::serialize(value.as_ref(), serializer),
+            Self::Printable(attachment) => attachment.serialize(serializer),
         }
     }
 }
@@ -54,26 +99,14 @@ impl<'a, 'b> Serialize for SerializeAttachmentList<'a, 'b> {
     where
         S: Serializer,
     {
-        serializer.collect_seq(
-            self.0
-                .iter()
-                .copied()
-                .filter(|attachment| {
-                    // for now opaque attachments are ignored
-                    !matches!(
-                        attachment.kind(),
-                        FrameKind::Attachment(AttachmentKind::Opaque(_))
-                    )
-                })
-                .map(SerializeAttachment),
-        )
+        serializer.collect_seq(self.0.iter().copied().filter_map(SerializeAttachment::new))
     }
 }
 
 struct SerializeContext<'a> {
     attachments: Vec<&'a Frame>,
     context: &'a dyn Context,
-    sources: &'a [Frame],
+    frame: &'a Frame,
 }
 
 impl<'a> Serialize for SerializeContext<'a> {
@@ -82,15 +115,35 @@ impl<'a> Serialize for SerializeContext<'a> {
         S: Serializer,
     {
         let Self {
-            context,
             attachments,
-            sources,
+            context,
+            frame,
         } = self;
 
-        let mut map = serializer.serialize_map(Some(3))?;
+        // contexts of a `RemoteReport` are serialized as they were received
+        let remote = frame.downcast_ref::<RemoteContext>();
+        let type_name = remote.map_or_else(|| Some(frame.type_name()), RemoteContext::type_name);
+        let value = remote.map_or_else(
+            || call_hook(frame),
+            |remote| {
+                remote
+                    .value()
+                    .map(|value| Box::new(value.clone()) as Box<dyn erased_serde::Serialize>)
+            },
+        );
+
+        let len = 3 + usize::from(type_name.is_some()) + usize::from(value.is_some());
+
+        let mut map = serializer.serialize_map(Some(len))?;
         map.serialize_entry("context", &format!("{context}").as_str())?;
+        if let Some(type_name) = type_name {
+            map.serialize_entry("type", type_name)?;
+        }
+        if let Some(value) = value {
+            map.serialize_entry("value", &SerializeAttachment::Hook(value))?;
+        }
         map.serialize_entry("attachments", &SerializeAttachmentList(attachments))?;
-        map.serialize_entry("sources", &SerializeSources(sources))?;
+        map.serialize_entry("sources", &SerializeSources(frame.sources()))?;
 
         map.end()
     }
@@ -120,7 +173,7 @@ fn find_next<'a>(head: &[&'a Frame], mut current: &'a Frame) -> Vec<SerializeCon
             return vec![SerializeContext {
                 attachments,
                 context,
-                sources: current.sources(),
+                frame: current,
             }];
         } else if current.sources().len() > 1 {
             // current is an attachment, add to attachments and recursively probe
@@ -0,0 +1,65 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::any::TypeId;
+
+use serde::Serialize;
+
+use crate::Frame;
+
+type BoxedSerializeHook =
+    Box<dyn Fn(&Frame) -> Option<Box<dyn erased_serde::Serialize>> + Send + Sync>;
+
+fn into_boxed_hook<T, U>(hook: impl Fn(&T) -> U + Send + Sync + 'static) -> BoxedSerializeHook
+where
+    T: Send + Sync + 'static,
+    U: Serialize + 'static,
+{
+    Box::new(move |frame: &Frame| {
+        // Unlike `Debug` hooks, serialize hooks are not invoked for values provided through the
+        // Provider API, as a frame is always serialized as exactly one value.
+        frame
+            .downcast_ref::<T>()
+            .map(|value| Box::new(hook(value)) as Box<dyn erased_serde::Serialize>)
+    })
+}
+
+/// Holds list of hooks.
+///
+/// These are used to serialize contexts and attachments, which would otherwise only be serialized
+/// through their [`Display`] output, if any.
+///
+/// Hooks are added via [`.insert()`], which will wrap the function in an additional closure.
+/// This closure will downcast the [`Frame`] to the requested type.
+///
+/// [`Display`]: core::fmt::Display
+/// [`.insert()`]: SerializeHooks::insert
+pub(crate) struct SerializeHooks {
+    // We use `Vec`, instead of `HashMap` or `BTreeMap`, so that ordering is consistent with the
+    // insertion order of types.
+    pub(crate) inner: Vec<(TypeId, BoxedSerializeHook)>,
+}
+
+impl SerializeHooks {
+    pub(crate) fn insert<T, U>(&mut self, hook: impl Fn(&T) -> U + Send + Sync + 'static)
+    where
+        T: Send + Sync + 'static,
+        U: Serialize + 'static,
+    {
+        let type_id = TypeId::of::<T>();
+
+        // make sure that previous hooks of the same TypeId are deleted.
+        self.inner.retain(|(id, _)| *id != type_id);
+        // push new hook onto the stack
+        self.inner.push((type_id, into_boxed_hook(hook)));
+    }
+
+    /// Calls the hook registered for the type of the value held by `frame`, returning the value
+    /// that is going to be serialized in place of the frame.
+    pub(crate) fn call(&self, frame: &Frame) -> Option<Box<dyn erased_serde::Serialize>> {
+        let type_id = frame.type_id();
+
+        self.inner
+            .iter()
+            .find(|(id, _)| *id == type_id)
+            .and_then(|(_, hook)| hook(frame))
+    }
+}
@@ -0,0 +1,522 @@
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+#[cfg(nightly)]
+use core::any::Demand;
+use core::fmt;
+
+use serde::{
+    de::{Error as _, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Context, Frame, Report};
+
+/// A [`Report`] received from a remote source, deserialized from the output of the [`Serialize`]
+/// implementation of [`Report`].
+///
+/// A `RemoteReport` is a tree of [`RemoteFrame`]s, which can be navigated via [`frames()`]. To
+/// continue using the report locally, it can be converted into a [`Report<RemoteContext>`], which
+/// keeps all contexts, attachments and branches of the original report, and can then be used like
+/// any other [`Report`], e.g. by calling [`change_context()`].
+///
+/// Serializing a `RemoteReport` (or a [`Report`] created from it) results in the same output the
+/// `RemoteReport` was deserialized from.
+///
+/// # Examples
+///
+/// ```
+/// use std::{
+///     fmt,
+///     io::{Error, ErrorKind},
+/// };
+///
+/// use error_stack::{Context, RemoteContext, RemoteReport, Report};
+///
+/// #[derive(Debug)]
+/// struct RequestError;
+///
+/// impl fmt::Display for RequestError {
+///     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         fmt.write_str("could not execute request")
+///     }
+/// }
+///
+/// impl Context for RequestError {}
+///
+/// let report = Report::new(Error::from(ErrorKind::NotFound)).attach_printable("file.txt");
+///
+/// // The report is sent to another process ...
+/// let json = serde_json::to_string(&report)?;
+/// let remote: RemoteReport = serde_json::from_str(&json)?;
+///
+/// assert_eq!(remote.frames()[0].context(), "entity not found");
+/// assert_eq!(remote.frames()[0].attachments()[0].to_string(), "file.txt");
+///
+/// // ... and is used as the source of a local error.
+/// let report = Report::<RemoteContext>::from(remote).change_context(RequestError);
+///
+/// assert_eq!(report.frames().count(), 4);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [`frames()`]: Self::frames
+/// [`change_context()`]: Report::change_context
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteReport {
+    frames: Vec<RemoteFrame>,
+}
+
+impl RemoteReport {
+    /// Returns the frames at the top of the report, this corresponds to
+    /// [`Report::current_frames()`].
+    #[must_use]
+    pub fn frames(&self) -> &[RemoteFrame] {
+        &self.frames
+    }
+
+    /// Converts the report into the frames at the top of the report.
+    #[must_use]
+    pub fn into_frames(self) -> Vec<RemoteFrame> {
+        self.frames
+    }
+}
+
+impl<'de> Deserialize<'de> for RemoteReport {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let frames = Vec::<RemoteFrame>::deserialize(deserializer)?;
+
+        if frames.is_empty() {
+            return Err(D::Error::invalid_length(0, &"at least one frame"));
+        }
+
+        Ok(Self { frames })
+    }
+}
+
+impl Serialize for RemoteReport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.frames.serialize(serializer)
+    }
+}
+
+impl From<RemoteReport> for Report<RemoteContext> {
+    fn from(report: RemoteReport) -> Self {
+        Self::from_frames(
+            report
+                .frames
+                .into_iter()
+                .map(RemoteFrame::into_frame)
+                .collect(),
+        )
+    }
+}
+
+/// A single context of a [`RemoteReport`] together with the attachments leading up to it.
+///
+/// The attachments are ordered from the closest to the context to the furthest away. If the
+/// original report branched (e.g. through [`Report::extend_one()`]), the branches are contained
+/// in [`sources()`].
+///
+/// [`sources()`]: Self::sources
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteFrame {
+    context: RemoteContext,
+    attachments: Vec<RemoteValue>,
+    sources: Vec<RemoteFrame>,
+}
+
+impl RemoteFrame {
+    /// Returns the [`Display`] output of the original context.
+    ///
+    /// [`Display`]: core::fmt::Display
+    #[must_use]
+    pub fn context(&self) -> &str {
+        &self.context.context
+    }
+
+    /// Returns the name of the type of the original context, if it was serialized.
+    #[must_use]
+    pub fn type_name(&self) -> Option<&str> {
+        self.context.type_name.as_deref()
+    }
+
+    /// Returns the value the original context was serialized as, if a hook was installed for it.
+    #[must_use]
+    pub const fn value(&self) -> Option<&RemoteValue> {
+        self.context.value.as_ref()
+    }
+
+    /// Returns the attachments, which belong to this context.
+    #[must_use]
+    pub fn attachments(&self) -> &[RemoteValue] {
+        &self.attachments
+    }
+
+    /// Returns the sources of this context.
+    #[must_use]
+    pub fn sources(&self) -> &[Self] {
+        &self.sources
+    }
+
+    fn into_frame(self) -> Frame {
+        let sources = self
+            .sources
+            .into_iter()
+            .map(Self::into_frame)
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+
+        // attachments are ordered from the closest to the furthest away from the context
+        self.attachments.into_iter().fold(
+            Frame::from_context(self.context, sources),
+            |frame, attachment| Frame::from_printable_attachment(attachment, Box::new([frame])),
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for RemoteFrame {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RemoteFrameVisitor;
+
+        impl<'de> Visitor<'de> for RemoteFrameVisitor {
+            type Value = RemoteFrame;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a serialized context of a report")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut context = None;
+                let mut type_name = None;
+                let mut value = None;
+                let mut attachments = None;
+                let mut sources = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "context" if context.is_none() => context = Some(map.next_value()?),
+                        "type" if type_name.is_none() => type_name = Some(map.next_value()?),
+                        "value" if value.is_none() => value = Some(map.next_value()?),
+                        "attachments" if attachments.is_none() => {
+                            attachments = Some(map.next_value()?);
+                        }
+                        "sources" if sources.is_none() => sources = Some(map.next_value()?),
+                        "context" | "type" | "value" | "attachments" | "sources" => {
+                            return Err(A::Error::custom(format_args!("duplicate field `{key}`")));
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                Ok(RemoteFrame {
+                    context: RemoteContext {
+                        context: context.ok_or_else(|| A::Error::missing_field("context"))?,
+                        type_name,
+                        value,
+                    },
+                    attachments: attachments.unwrap_or_default(),
+                    sources: sources.unwrap_or_default(),
+                })
+            }
+        }
+
+        deserializer.deserialize_map(RemoteFrameVisitor)
+    }
+}
+
+impl Serialize for RemoteFrame {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let RemoteContext {
+            context,
+            type_name,
+            value,
+        } = &self.context;
+
+        let len = 3 + usize::from(type_name.is_some()) + usize::from(value.is_some());
+
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("context", context)?;
+        if let Some(type_name) = type_name {
+            map.serialize_entry("type", type_name)?;
+        }
+        if let Some(value) = value {
+            map.serialize_entry("value", value)?;
+        }
+        map.serialize_entry("attachments", &self.attachments)?;
+        map.serialize_entry("sources", &self.sources)?;
+
+        map.end()
+    }
+}
+
+/// The [`Context`] of a [`Report`] created from a [`RemoteReport`].
+///
+/// Its [`Display`] output is the same as the one of the original context. If the original context
+/// was serialized through a hook, the value is [`provide`]d as [`RemoteValue`].
+///
+/// [`Display`]: core::fmt::Display
+/// [`provide`]: Context::provide
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteContext {
+    context: String,
+    type_name: Option<String>,
+    value: Option<RemoteValue>,
+}
+
+impl RemoteContext {
+    /// Returns the name of the type of the original context, if it was serialized.
+    #[must_use]
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    /// Returns the value the original context was serialized as, if a hook was installed for it.
+    #[must_use]
+    pub const fn value(&self) -> Option<&RemoteValue> {
+        self.value.as_ref()
+    }
+}
+
+impl fmt::Display for RemoteContext {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.context)
+    }
+}
+
+impl Context for RemoteContext {
+    #[cfg(nightly)]
+    fn provide<'a>(&'a self, demand: &mut Demand<'a>) {
+        if let Some(value) = &self.value {
+            demand.provide_ref(value);
+        }
+    }
+}
+
+/// A value of a [`RemoteReport`], which was either serialized from a printable attachment, or
+/// through a hook.
+///
+/// The [`Display`] output of a [`RemoteValue::String`] is the string itself, all other values are
+/// displayed in a JSON-like format.
+///
+/// [`Display`]: core::fmt::Display
+#[derive(Debug, Clone, PartialEq)]
+pub enum RemoteValue {
+    /// A value without any content, e.g. `()` or `None`.
+    Null,
+    /// A boolean value.
+    Bool(bool),
+    /// An unsigned integer.
+    Unsigned(u64),
+    /// A signed integer.
+    Signed(i64),
+    /// A floating point number.
+    Float(f64),
+    /// A string, this is the output of printable attachments.
+    String(String),
+    /// A sequence of bytes.
+    Bytes(Vec<u8>),
+    /// A sequence of values.
+    Array(Vec<RemoteValue>),
+    /// A sequence of key-value pairs in the order they were serialized.
+    Map(Vec<(RemoteValue, RemoteValue)>),
+}
+
+impl RemoteValue {
+    fn fmt_nested(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(value) => fmt::Debug::fmt(value, fmt),
+            value => fmt::Display::fmt(value, fmt),
+        }
+    }
+}
+
+impl fmt::Display for RemoteValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => fmt.write_str("null"),
+            Self::Bool(value) => fmt::Display::fmt(value, fmt),
+            Self::Unsigned(value) => fmt::Display::fmt(value, fmt),
+            Self::Signed(value) => fmt::Display::fmt(value, fmt),
+            Self::Float(value) => fmt::Display::fmt(value, fmt),
+            Self::String(value) => fmt.write_str(value),
+            Self::Bytes(value) => fmt::Debug::fmt(value, fmt),
+            Self::Array(values) => {
+                fmt.write_str("[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        fmt.write_str(", ")?;
+                    }
+                    value.fmt_nested(fmt)?;
+                }
+                fmt.write_str("]")
+            }
+            Self::Map(entries) => {
+                fmt.write_str("{")?;
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        fmt.write_str(", ")?;
+                    }
+                    key.fmt_nested(fmt)?;
+                    fmt.write_str(": ")?;
+                    value.fmt_nested(fmt)?;
+                }
+                fmt.write_str("}")
+            }
+        }
+    }
+}
+
+impl Serialize for RemoteValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Null => serializer.serialize_unit(),
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::Unsigned(value) => serializer.serialize_u64(*value),
+            Self::Signed(value) => serializer.serialize_i64(*value),
+            Self::Float(value) => serializer.serialize_f64(*value),
+            Self::String(value) => serializer.serialize_str(value),
+            Self::Bytes(value) => serializer.serialize_bytes(value),
+            Self::Array(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(value)?;
+                }
+                seq.end()
+            }
+            Self::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+/// Upper bound of items allocated upfront.
+///
+/// The size hint is provided by the input, we do not want to trust it blindly, as a malicious input
+/// could lead to excessive allocations.
+const MAX_CAPACITY: usize = 4096;
+
+fn capacity(size_hint: Option<usize>) -> usize {
+    size_hint.map_or(0, |size_hint| size_hint.min(MAX_CAPACITY))
+}
+
+impl<'de> Deserialize<'de> for RemoteValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RemoteValueVisitor;
+
+        impl<'de> Visitor<'de> for RemoteValueVisitor {
+            type Value = RemoteValue;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("any value")
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+                Ok(RemoteValue::Bool(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E> {
+                Ok(RemoteValue::Signed(value))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(RemoteValue::Unsigned(value))
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
+                Ok(RemoteValue::Float(value))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(RemoteValue::String(value.to_owned()))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+                Ok(RemoteValue::String(value))
+            }
+
+            fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E> {
+                Ok(RemoteValue::Bytes(value.to_vec()))
+            }
+
+            fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(RemoteValue::Bytes(value))
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E> {
+                Ok(RemoteValue::Null)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                RemoteValue::deserialize(deserializer)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E> {
+                Ok(RemoteValue::Null)
+            }
+
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                RemoteValue::deserialize(deserializer)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut values = Vec::with_capacity(capacity(seq.size_hint()));
+                while let Some(value) = seq.next_element()? {
+                    values.push(value);
+                }
+
+                Ok(RemoteValue::Array(values))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries = Vec::with_capacity(capacity(map.size_hint()));
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+
+                Ok(RemoteValue::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(RemoteValueVisitor)
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg_attr(nightly, feature(provide_any))]
+#![cfg_attr(all(nightly, feature = "std"), feature(error_generic_member_access))]
+
+mod common;
+
+#[allow(clippy::wildcard_imports)]
+use common::*;
+use error_stack::{ErrorCode, HttpStatusCode};
+
+const NOT_FOUND: ErrorCode = ErrorCode::new("not-found");
+const INVALID: ErrorCode = ErrorCode::new("invalid");
+
+#[test]
+fn closest_attachment() {
+    let report = create_report()
+        .attach(NOT_FOUND)
+        .change_context(ContextA(0))
+        .attach(INVALID)
+        .attach_printable(PrintableA(0));
+
+    assert_eq!(report.error_code(), Some(INVALID));
+    assert_eq!(report.request_closest::<AttachmentA>().map(|a| a.0), None);
+}
+
+#[test]
+fn closest_source() {
+    let mut report = create_report().attach(INVALID);
+    report.extend_one(create_report().attach(NOT_FOUND));
+    let report = report.change_context(ContextA(0));
+
+    assert_eq!(report.error_code(), Some(INVALID));
+}
+
+#[test]
+fn closest_context() {
+    let report = create_report()
+        .change_context(ContextA(10))
+        .change_context(ContextA(20));
+
+    assert_eq!(report.request_closest::<ContextA>(), Some(ContextA(20)));
+}
+
+#[test]
+#[cfg(nightly)]
+fn closest_provided() {
+    let report = create_report().attach(20_u64).change_context(ContextA(10));
+
+    // `ContextA` provides its value as `u32` reference and `u64` value
+    assert_eq!(report.request_closest::<u32>(), Some(10));
+    assert_eq!(report.request_closest::<u64>(), Some(10));
+}
+
+#[test]
+fn missing() {
+    let report = create_report().attach_printable(PrintableA(0));
+
+    assert_eq!(report.error_code(), None);
+}
+
+#[test]
+fn closest_http_status_code() {
+    let mut report = create_report().attach(HttpStatusCode::new(404));
+    report.extend_one(create_report().attach(HttpStatusCode::new(500)));
+    let report = report.change_context(ContextA(0));
+
+    assert_eq!(report.http_status_code(), Some(HttpStatusCode::new(404)));
+    assert_eq!(
+        report
+            .attach(HttpStatusCode::new(400))
+            .http_status_code()
+            .map(HttpStatusCode::as_u16),
+        Some(400)
+    );
+    assert_eq!(create_report().http_status_code(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn termination() {
+    use std::process::{ExitCode, Termination};
+
+    let report = create_report()
+        .attach(ExitCode::from(100))
+        .change_context(ContextA(0))
+        .attach(ExitCode::from(200));
+
+    assert_eq!(
+        format!("{:?}", report.report()),
+        format!("{:?}", ExitCode::from(200))
+    );
+    assert_eq!(
+        format!("{:?}", create_report().report()),
+        format!("{:?}", ExitCode::FAILURE)
+    );
+}
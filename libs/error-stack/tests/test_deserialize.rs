@@ -0,0 +1,181 @@
+#![cfg(all(feature = "std", feature = "serde"))]
+#![cfg_attr(all(nightly, feature = "std"), feature(error_generic_member_access))]
+#![cfg_attr(nightly, feature(provide_any))]
+
+mod common;
+
+use error_stack::{Context, Frame, RemoteContext, RemoteReport, RemoteValue, Report};
+use serde::{
+    de::{value, DeserializeSeed, IntoDeserializer, SeqAccess},
+    Deserialize,
+};
+use serde_json::json;
+
+use crate::common::{create_report, ContextA, ContextB, PrintableA, PrintableC};
+
+fn round_trip<C: Context>(report: &Report<C>) -> RemoteReport {
+    let value = serde_json::to_value(report).expect("should be able to serialize report");
+    let remote: RemoteReport =
+        serde_json::from_value(value.clone()).expect("should be able to deserialize report");
+
+    assert_eq!(
+        serde_json::to_value(&remote).expect("should be able to serialize remote report"),
+        value
+    );
+
+    remote
+}
+
+#[test]
+fn context() {
+    let report = create_report()
+        .attach_printable(PrintableA(1))
+        .change_context(ContextA(2));
+
+    let remote = round_trip(&report);
+
+    let [frame] = remote.frames() else {
+        panic!("expected exactly one frame");
+    };
+    assert_eq!(frame.context(), "context A");
+    assert_eq!(
+        frame.type_name(),
+        Some("test_deserialize::common::ContextA")
+    );
+    assert!(frame.attachments().is_empty());
+
+    let [source] = frame.sources() else {
+        panic!("expected exactly one source");
+    };
+    assert_eq!(source.context(), "root error");
+    assert_eq!(source.attachments(), [RemoteValue::String(
+        "printable A".to_owned()
+    )]);
+    assert!(source.sources().is_empty());
+}
+
+#[test]
+fn multiple_sources() {
+    let mut report = create_report().attach_printable(PrintableC(1));
+    report.extend_one(create_report().attach_printable(PrintableC(2)));
+
+    let report = report
+        .attach_printable(PrintableC(3))
+        .change_context(ContextA(4));
+
+    let remote = round_trip(&report);
+
+    let [frame] = remote.frames() else {
+        panic!("expected exactly one frame");
+    };
+    let attachments = frame
+        .sources()
+        .iter()
+        .map(|source| {
+            source
+                .attachments()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(attachments, [["printable C: 1", "printable C: 3"], [
+        "printable C: 2",
+        "printable C: 3"
+    ]]);
+}
+
+#[test]
+fn into_report() {
+    let mut report = create_report()
+        .attach_printable(PrintableA(1))
+        .change_context(ContextA(1));
+    report.extend_one(create_report().change_context(ContextA(2)));
+
+    let remote = round_trip(&report);
+    let expected = serde_json::to_value(&remote).expect("should be able to serialize report");
+
+    let report = Report::<RemoteContext>::from(remote);
+    assert_eq!(
+        serde_json::to_value(&report).expect("should be able to serialize report"),
+        expected
+    );
+    assert_eq!(report.current_frames().len(), 2);
+
+    let report = report.change_context(ContextB(3));
+    let value = serde_json::to_value(&report).expect("should be able to serialize report");
+
+    assert_eq!(value[0]["context"], json!("context B"));
+    assert_eq!(value[0]["sources"], expected);
+    assert_eq!(
+        report
+            .frames()
+            .filter_map(Frame::downcast_ref::<RemoteContext>)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        ["context A", "root error", "context A", "root error"]
+    );
+}
+
+#[test]
+fn value() {
+    let remote: RemoteReport = serde_json::from_value(json!([{
+        "context": "remote error",
+        "value": { "code": 404, "retry": [1.5, null, true] },
+        "attachments": [-1, "suggestion"],
+        "unknown": "ignored",
+    }]))
+    .expect("should be able to deserialize report");
+
+    let [frame] = remote.frames() else {
+        panic!("expected exactly one frame");
+    };
+    assert_eq!(frame.type_name(), None);
+    assert_eq!(
+        frame.value().map(ToString::to_string).as_deref(),
+        Some(r#"{"code": 404, "retry": [1.5, null, true]}"#)
+    );
+    assert_eq!(frame.attachments(), [
+        RemoteValue::Signed(-1),
+        RemoteValue::String("suggestion".to_owned())
+    ]);
+}
+
+#[test]
+fn empty() {
+    serde_json::from_value::<RemoteReport>(json!([]))
+        .expect_err("a report needs at least one frame");
+    serde_json::from_value::<RemoteReport>(json!([{ "attachments": [] }]))
+        .expect_err("a frame needs a context");
+}
+
+/// A sequence of a single boolean, which claims to contain `usize::MAX` elements.
+struct LyingSeq(bool);
+
+impl<'de> SeqAccess<'de> for LyingSeq {
+    type Error = value::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if self.0 {
+            self.0 = false;
+            seed.deserialize(true.into_deserializer()).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(usize::MAX)
+    }
+}
+
+#[test]
+fn untrusted_size_hint() {
+    let value = RemoteValue::deserialize(value::SeqAccessDeserializer::new(LyingSeq(true)))
+        .expect("should not allocate the size hint upfront");
+    assert_eq!(value, RemoteValue::Array(vec![RemoteValue::Bool(true)]));
+}
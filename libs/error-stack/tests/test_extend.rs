@@ -6,7 +6,7 @@ use core::fmt::{Display, Formatter};
 
 #[allow(clippy::wildcard_imports)]
 use common::*;
-use error_stack::{report, Context, Report};
+use error_stack::{report, AccumulateResultExt, Context, Report, TryReportIteratorExt};
 
 #[derive(Debug)]
 struct Error;
@@ -77,3 +77,73 @@ fn collect_none() {
 
     assert!(report.is_none());
 }
+
+fn results() -> Vec<error_stack::Result<u32, Error>> {
+    vec![
+        Ok(1),
+        Err(report!(Error)),
+        Ok(2),
+        Err(report!(Error)),
+        Err(report!(Error)),
+    ]
+}
+
+#[test]
+fn accumulate_into() {
+    let mut report = None;
+    let values = results()
+        .into_iter()
+        .filter_map(|result| result.accumulate_into(&mut report))
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, [1, 2]);
+
+    let report = report.expect("should be some");
+    assert_eq!(report.current_frames().len(), 3);
+    assert_eq!(report.frames().count(), expect_count(1) * 3);
+}
+
+#[test]
+fn try_collect_reports() {
+    let report = results()
+        .into_iter()
+        .try_collect_reports::<Vec<_>>()
+        .expect_err("should have errors");
+
+    assert_eq!(report.current_frames().len(), 3);
+    assert_eq!(report.frames().count(), expect_count(1) * 3);
+}
+
+#[test]
+fn try_collect_reports_ok() {
+    let values = results()
+        .into_iter()
+        .filter(Result::is_ok)
+        .try_collect_reports::<Vec<_>>()
+        .expect("should not have errors");
+
+    assert_eq!(values, [1, 2]);
+}
+
+#[test]
+fn try_collect_reports_bounded() {
+    let report = results()
+        .into_iter()
+        .try_collect_reports_bounded::<Vec<_>>(2)
+        .expect_err("should have errors");
+
+    assert_eq!(report.current_frames().len(), 1);
+    assert_eq!(report.current_frames()[0].sources().len(), 2);
+    assert_eq!(messages(&report)[0], "1 more error omitted");
+}
+
+#[test]
+fn try_collect_reports_bounded_zero() {
+    let report = results()
+        .into_iter()
+        .try_collect_reports_bounded::<Vec<_>>(0)
+        .expect_err("should have errors");
+
+    assert_eq!(report.current_frames()[0].sources().len(), 1);
+    assert_eq!(messages(&report)[0], "2 more errors omitted");
+}
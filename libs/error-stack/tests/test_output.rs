@@ -0,0 +1,176 @@
+#![cfg(feature = "std")]
+// can be considered safe, because we only check the output, which in itself does not use **any**
+// unsafe code.
+#![cfg(not(miri))]
+#![cfg_attr(all(nightly, feature = "std"), feature(error_generic_member_access))]
+#![cfg_attr(nightly, feature(provide_any))]
+#![allow(clippy::std_instead_of_core)]
+
+mod common;
+
+use std::sync::{Mutex, MutexGuard};
+
+#[allow(clippy::wildcard_imports)]
+use common::*;
+use error_stack::{
+    fmt::{ColorMode, OutputMode},
+    Report,
+};
+use serde_json::Value;
+
+/// The output mode is a global setting, tests changing it must not run concurrently.
+fn prepare(mode: OutputMode) -> MutexGuard<'static, ()> {
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    let guard = LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    std::env::set_var("RUST_LIB_BACKTRACE", "0");
+    Report::set_output_mode(mode);
+
+    guard
+}
+
+fn create_sources() -> Report<ContextB> {
+    let mut report = create_report()
+        .attach_printable(PrintableA(0))
+        .attach(AttachmentA(0))
+        .change_context(ContextA(0));
+    report.extend_one(
+        create_report()
+            .attach_printable("quote \" and\nnewline")
+            .change_context(ContextA(1)),
+    );
+
+    report.change_context(ContextB(0))
+}
+
+fn strings(value: &Value) -> Vec<&str> {
+    value
+        .as_array()
+        .expect("should be an array")
+        .iter()
+        .map(|value| value.as_str().expect("should be a string"))
+        .collect()
+}
+
+#[test]
+fn json() {
+    let _guard = prepare(OutputMode::Json);
+
+    let report = create_sources();
+    let output: Value = serde_json::from_str(&format!("{report:?}")).expect("should be valid JSON");
+
+    let frames = output["frames"].as_array().expect("should be an array");
+    assert_eq!(frames.len(), 1);
+
+    let context_b = &frames[0];
+    assert_eq!(context_b["context"], "context B");
+    assert_eq!(context_b["type"], "test_output::common::ContextB");
+    assert_eq!(context_b["opaque"], 0);
+
+    assert_eq!(context_b["sources"].as_array().map(Vec::len), Some(2));
+
+    let context_a = &context_b["sources"][0];
+    assert_eq!(context_a["context"], "context A");
+
+    let root = &context_a["sources"][0];
+    assert_eq!(root["context"], "root error");
+    assert_eq!(root["opaque"], 1);
+    assert!(strings(&root["attachments"]).contains(&"printable A"));
+
+    let root = &context_b["sources"][1]["sources"][0];
+    assert_eq!(root["context"], "root error");
+    assert!(strings(&root["attachments"]).contains(&"quote \" and\nnewline"));
+}
+
+#[test]
+fn json_appendix() {
+    let _guard = prepare(OutputMode::Json);
+
+    Report::install_debug_hook::<AttachmentB>(|AttachmentB(value), context| {
+        context.push_appendix(format!("attachment B: {value}\n"));
+    });
+
+    let report = create_report().attach(AttachmentB(42));
+    let output: Value = serde_json::from_str(&format!("{report:?}")).expect("should be valid JSON");
+
+    assert_eq!(strings(&output["appendix"]), ["attachment B: 42"]);
+}
+
+#[test]
+fn tree() {
+    let _guard = prepare(OutputMode::Tree);
+    Report::set_color_mode(ColorMode::None);
+
+    let report = create_sources();
+    assert!(format!("{report:?}").starts_with("context B\n"));
+}
+
+#[test]
+#[cfg(feature = "sarif")]
+fn sarif() {
+    let _guard = prepare(OutputMode::Sarif);
+
+    let report = create_sources();
+    let output: Value = serde_json::from_str(&format!("{report:?}")).expect("should be valid JSON");
+
+    assert_eq!(output["version"], "2.1.0");
+
+    let tool = &output["runs"][0]["tool"];
+    assert_eq!(tool["driver"]["name"], "error-stack");
+
+    let rules = tool["driver"]["rules"]
+        .as_array()
+        .expect("should be an array")
+        .iter()
+        .map(|rule| rule["id"].as_str().expect("should be a string"))
+        .collect::<Vec<_>>();
+    assert_eq!(rules, [
+        "test_output::common::ContextA",
+        "test_output::common::ContextB",
+        "test_output::common::RootError"
+    ]);
+
+    let results = output["runs"][0]["results"]
+        .as_array()
+        .expect("should be an array");
+    let contexts = results
+        .iter()
+        .map(|result| {
+            result["message"]["text"]
+                .as_str()
+                .expect("should be a string")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(contexts, [
+        "context B",
+        "context A",
+        "root error",
+        "context A",
+        "root error"
+    ]);
+
+    let context_b = &results[0];
+    assert_eq!(context_b["ruleId"], "test_output::common::ContextB");
+    assert_eq!(context_b["ruleIndex"], 1);
+    assert_eq!(context_b["level"], "error");
+    assert_eq!(
+        context_b["properties"]["sources"],
+        serde_json::json!([1, 3])
+    );
+
+    let root = &results[2];
+    assert_eq!(root["properties"]["opaque"], 1);
+    assert!(
+        root["relatedLocations"]
+            .as_array()
+            .expect("should be an array")
+            .iter()
+            .any(|location| location["message"]["text"] == "printable A")
+    );
+    assert!(tool.get("properties").is_none());
+
+    assert_eq!(report.to_sarif_log().runs.map(|runs| runs.len()), Some(1));
+}
@@ -8,9 +8,13 @@
 #![cfg_attr(nightly, feature(provide_any))]
 #![allow(clippy::std_instead_of_core)]
 
+use core::fmt;
+
+use error_stack::{Context, Report};
 use insta::assert_ron_snapshot;
+use serde::Serialize;
 
-use crate::common::{create_report, ContextA, PrintableA, PrintableC};
+use crate::common::{create_report, AttachmentA, ContextA, PrintableA, PrintableB, PrintableC};
 
 mod common;
 
@@ -70,3 +74,66 @@ fn multiple_sources_at_root() {
 
     assert_ron_snapshot!(a);
 }
+
+#[derive(Debug)]
+struct ContextWithCode {
+    code: u16,
+}
+
+impl fmt::Display for ContextWithCode {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("context with code")
+    }
+}
+
+impl Context for ContextWithCode {}
+
+#[derive(Serialize)]
+struct Code {
+    code: u16,
+}
+
+struct Secret;
+
+#[test]
+fn context_hook() {
+    let _guard = prepare();
+
+    Report::install_serde_hook::<ContextWithCode, _>(|context| Code { code: context.code });
+
+    let report = create_report()
+        .attach_printable(PrintableA(2))
+        .change_context(ContextWithCode { code: 404 });
+
+    assert_ron_snapshot!(report);
+}
+
+#[test]
+fn opaque_attachment_hook() {
+    let _guard = prepare();
+
+    Report::install_serde_hook::<AttachmentA, _>(|AttachmentA(value)| *value);
+
+    let report = create_report()
+        .attach(AttachmentA(1))
+        .attach(Secret)
+        .attach_printable(PrintableA(2))
+        .change_context(ContextA(3));
+
+    assert_ron_snapshot!(report);
+}
+
+#[test]
+fn printable_attachment_hook() {
+    let _guard = prepare();
+
+    Report::install_serde_hook::<PrintableB, _>(|PrintableB(value)| Code {
+        code: u16::try_from(*value).expect("should fit into `u16`"),
+    });
+
+    let report = create_report()
+        .attach_printable(PrintableA(1))
+        .attach_printable(PrintableB(2));
+
+    assert_ron_snapshot!(report);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "spantrace")]
+#![cfg(not(miri))]
+#![cfg_attr(nightly, feature(provide_any))]
+#![cfg_attr(all(nightly, feature = "std"), feature(error_generic_member_access))]
+#![allow(clippy::std_instead_of_core)]
+
+mod common;
+
+#[allow(clippy::wildcard_imports)]
+use common::*;
+use error_stack::{
+    fmt::{ColorMode, TraceFilter},
+    Report,
+};
+use tracing_error::ErrorLayer;
+use tracing_subscriber::layer::SubscriberExt;
+
+fn prepare() {
+    std::env::set_var("RUST_LIB_BACKTRACE", "0");
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::Registry::default().with(ErrorLayer::default()),
+    )
+    .expect("Could not set tracing subscriber");
+
+    Report::set_color_mode(ColorMode::None);
+}
+
+#[tracing::instrument]
+fn create_sources() -> Report<RootError> {
+    let mut report = create_report();
+    report.extend_one(create_report());
+    report
+}
+
+#[tracing::instrument]
+fn nested() -> Report<RootError> {
+    create_sources()
+}
+
+// all assertions are done in a single test, as both, the filter and the subscriber, are global
+#[test]
+fn filter() {
+    prepare();
+
+    let report = nested();
+
+    Report::set_trace_filter(TraceFilter::new());
+    let output = format!("{report:?}");
+    assert!(output.contains("span trace No. 2"));
+    assert!(output.contains("test_trace_filter::nested"));
+
+    Report::set_trace_filter(TraceFilter::new().deduplicate(true));
+    let output = format!("{report:?}");
+    assert_eq!(output.matches("span trace with 2 frames (1)").count(), 2);
+    assert!(!output.contains("span trace No. 2"));
+
+    Report::set_trace_filter(TraceFilter::new().hide("test_trace_filter"));
+    let output = format!("{report:?}");
+    assert!(!output.contains("test_trace_filter::nested"));
+
+    Report::set_trace_filter(TraceFilter::new().collapse("test_trace_filter"));
+    let output = format!("{report:?}");
+    assert!(output.contains("[2 frames of test_trace_filter collapsed]"));
+}
@@ -0,0 +1,576 @@
+//! Comparison of results against a baseline.
+//!
+//! To report only results, which were introduced since a previous analysis, the results of a
+//! [`SarifLog`] are compared to the results of a previous log, the _baseline_. Results are
+//! identified by their [`fingerprints`] and [`partial_fingerprints`]. Tools usually know best how
+//! to fingerprint their results, but if they don't, a fingerprint is derived from the content of
+//! the result, see [`SarifLog::compute_partial_fingerprints`].
+//!
+//! [`fingerprints`]: Result::fingerprints
+//! [`partial_fingerprints`]: Result::partial_fingerprints
+
+use alloc::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::mem;
+
+use crate::{
+    builder::RunBuilder,
+    schema::{BaselineState, Result, Run, SarifLog},
+};
+
+/// The key of the partial fingerprint derived by [`SarifLog::compute_partial_fingerprints`].
+pub const CONTENT_FINGERPRINT: &str = "contentHash/v1";
+
+/// A 64-bit FNV-1a hasher.
+///
+/// The derived fingerprints are compared across different versions of this crate and the Rust
+/// compiler, so neither the `Hash` implementations of the standard library nor its hashers can be
+/// used.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const fn new() -> Self {
+        Self(0xCBF2_9CE4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01B3);
+        }
+    }
+
+    fn write_field(&mut self, field: Option<&str>) {
+        if let Some(field) = field {
+            self.write(field.as_bytes());
+        }
+        // Separates the fields, `0xFF` never occurs in UTF-8
+        self.write(&[0xFF]);
+    }
+}
+
+/// Returns the id of the rule of a result, either specified directly or by index.
+fn rule_id<'r>(run: &'r Run, result: &'r Result) -> Option<&'r str> {
+    result.rule_id.as_deref().or_else(|| {
+        result
+            .rule_index
+            .and_then(|index| run.tool.driver.rules.get(index))
+            .map(|rule| &*rule.id)
+    })
+}
+
+/// Derives the content fingerprint of every result in the run.
+///
+/// The fingerprint is a hash of the rule, the artifact of the primary location and either the
+/// snippet of the primary location, with whitespace normalized, or the message of the result.
+/// Line and column numbers are deliberately not part of the hash, so a result keeps its
+/// fingerprint when unrelated code is added above it. Identical results within the run are
+/// distinguished by an occurrence count appended to the hash.
+fn content_fingerprints(run: &Run) -> Vec<String> {
+    let mut occurrences = BTreeMap::<u64, usize>::new();
+
+    run.results
+        .iter()
+        .flatten()
+        .map(|result| {
+            let mut hasher = Fnv1a::new();
+            hasher.write_field(rule_id(run, result));
+
+            let physical_location = result
+                .locations
+                .first()
+                .and_then(|location| location.physical_location.as_ref());
+            let artifact_location = physical_location
+                .and_then(|location| location.artifact_location.as_ref())
+                .and_then(|location| {
+                    if location.uri.is_some() {
+                        Some(location)
+                    } else {
+                        location
+                            .index
+                            .and_then(|index| run.artifacts.get(index))
+                            .and_then(|artifact| artifact.location.as_ref())
+                    }
+                });
+            hasher.write_field(
+                artifact_location.and_then(|location| location.uri_base_id.as_deref()),
+            );
+            hasher.write_field(artifact_location.and_then(|location| location.uri.as_deref()));
+
+            let snippet = physical_location
+                .and_then(|location| location.region.as_ref())
+                .and_then(|region| region.snippet.as_ref())
+                .and_then(|snippet| snippet.text.as_deref());
+            if let Some(snippet) = snippet {
+                for word in snippet.split_whitespace() {
+                    hasher.write_field(Some(word));
+                }
+            } else {
+                hasher.write_field(result.message.text.as_deref());
+                hasher.write_field(result.message.id.as_deref());
+                for argument in &result.message.arguments {
+                    hasher.write_field(Some(argument));
+                }
+            }
+
+            let hash = hasher.0;
+            let occurrence = occurrences.entry(hash).or_default();
+            *occurrence += 1;
+            format!("{hash:016x}:{occurrence}")
+        })
+        .collect()
+}
+
+/// Compares the fingerprints, which are present in both results.
+///
+/// Returns [`None`] if the results do not have a fingerprint in common.
+fn compare_fingerprints<'a>(
+    lhs: &BTreeMap<Cow<str>, Cow<str>>,
+    rhs: impl Fn(&str) -> Option<&'a str>,
+) -> Option<bool> {
+    let mut common = false;
+    for (key, lhs) in lhs {
+        if let Some(rhs) = rhs(key) {
+            if lhs != rhs {
+                return Some(false);
+            }
+            common = true;
+        }
+    }
+
+    common.then_some(true)
+}
+
+/// A result of a baseline run, which may be matched by a result of the current log.
+struct Candidate<'b, 's> {
+    run: usize,
+    result: &'b Result<'s>,
+    content_fingerprint: String,
+    matched: bool,
+}
+
+impl Candidate<'_, '_> {
+    fn is_match(&self, result: &Result) -> bool {
+        let baseline = self.result;
+        compare_fingerprints(&result.fingerprints, |key| {
+            baseline.fingerprints.get(key).map(|value| &**value)
+        })
+        .or_else(|| {
+            compare_fingerprints(&result.partial_fingerprints, |key| {
+                baseline
+                    .partial_fingerprints
+                    .get(key)
+                    .map(|value| &**value)
+                    .or_else(|| (key == CONTENT_FINGERPRINT).then_some(&*self.content_fingerprint))
+            })
+        })
+        .unwrap_or(false)
+    }
+}
+
+impl<'s> SarifLog<'s> {
+    /// Derive a partial fingerprint for every result.
+    ///
+    /// The fingerprint is added as [`CONTENT_FINGERPRINT`] to the [`partial_fingerprints`] of
+    /// each result, which does not specify it already. It consists of a hash of the rule, the
+    /// artifact and the snippet of the primary location, or the message if no snippet is
+    /// available. Line and column numbers do not contribute to the fingerprint.
+    ///
+    /// [`partial_fingerprints`]: Result::partial_fingerprints
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     baseline::CONTENT_FINGERPRINT,
+    ///     schema::{Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent},
+    /// };
+    ///
+    /// let result = Result::new(Message::new("unused variable `x`")).with_rule_id("unused_variables");
+    /// let mut log = SarifLog::new(SchemaVersion::V2_1_0).with_run(
+    ///     Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([result.clone(), result]),
+    /// );
+    /// log.compute_partial_fingerprints();
+    ///
+    /// let results = log.results().collect::<Vec<_>>();
+    /// let first = &results[0].partial_fingerprints[CONTENT_FINGERPRINT];
+    /// let second = &results[1].partial_fingerprints[CONTENT_FINGERPRINT];
+    ///
+    /// assert_eq!(first.strip_suffix(":1"), second.strip_suffix(":2"));
+    /// ```
+    pub fn compute_partial_fingerprints(&mut self) {
+        for run in self.runs.iter_mut().flatten() {
+            let fingerprints = content_fingerprints(run);
+            for (result, fingerprint) in run.results.iter_mut().flatten().zip(fingerprints) {
+                result
+                    .partial_fingerprints
+                    .entry(Cow::Borrowed(CONTENT_FINGERPRINT))
+                    .or_insert(Cow::Owned(fingerprint));
+            }
+        }
+    }
+
+    /// Set the [`baseline_state`] of every result by comparing this log to a previous log.
+    ///
+    /// Runs are compared to the runs of the baseline with the same driver name. Two results match
+    /// if they have the same rule and
+    ///
+    /// - all [`fingerprints`] they have in common are equal, or, if they have none in common,
+    /// - all [`partial_fingerprints`] they have in common are equal.
+    ///
+    /// Partial fingerprints are [computed] for this log before comparing, and are derived for the
+    /// baseline, if not present.
+    ///
+    /// A matched result is [`Unchanged`] unless its kind, level or message changed, in which case
+    /// it is [`Updated`]. Results without a match are [`New`]. Results of the baseline without a
+    /// match are added to the first run of the tool as [`Absent`]. Runs without results, e.g.
+    /// because the tool failed, do not participate in the comparison.
+    ///
+    /// [`baseline_state`]: Result::baseline_state
+    /// [`fingerprints`]: Result::fingerprints
+    /// [`partial_fingerprints`]: Result::partial_fingerprints
+    /// [computed]: Self::compute_partial_fingerprints
+    /// [`Unchanged`]: BaselineState::Unchanged
+    /// [`Updated`]: BaselineState::Updated
+    /// [`New`]: BaselineState::New
+    /// [`Absent`]: BaselineState::Absent
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{
+    ///     BaselineState, Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    /// };
+    ///
+    /// let clippy = Run::new(Tool::new(ToolComponent::new("clippy")));
+    /// let unused_x =
+    ///     Result::new(Message::new("unused variable `x`")).with_rule_id("unused_variables");
+    /// let unused_y =
+    ///     Result::new(Message::new("unused variable `y`")).with_rule_id("unused_variables");
+    /// let needless_borrow =
+    ///     Result::new(Message::new("needless borrow")).with_rule_id("needless_borrow");
+    ///
+    /// let baseline = SarifLog::new(SchemaVersion::V2_1_0).with_run(
+    ///     clippy
+    ///         .clone()
+    ///         .with_results([unused_x.clone(), needless_borrow]),
+    /// );
+    /// let mut log =
+    ///     SarifLog::new(SchemaVersion::V2_1_0).with_run(clippy.with_results([unused_x, unused_y]));
+    /// log.apply_baseline(&baseline);
+    ///
+    /// assert!(
+    ///     log.results()
+    ///         .map(|result| (result.message.text.as_deref(), result.baseline_state))
+    ///         .eq([
+    ///             (Some("unused variable `x`"), Some(BaselineState::Unchanged)),
+    ///             (Some("unused variable `y`"), Some(BaselineState::New)),
+    ///             (Some("needless borrow"), Some(BaselineState::Absent)),
+    ///         ])
+    /// );
+    ///
+    /// // Only fail on findings introduced since the baseline
+    /// let new = log
+    ///     .results()
+    ///     .filter(|result| result.baseline_state == Some(BaselineState::New))
+    ///     .count();
+    /// assert_eq!(new, 1);
+    /// ```
+    pub fn apply_baseline(&mut self, baseline: &SarifLog<'s>) {
+        self.compute_partial_fingerprints();
+
+        let Some(runs) = &mut self.runs else {
+            return;
+        };
+        let baseline_runs = baseline.runs.as_deref().unwrap_or_default();
+
+        let tools = runs
+            .iter()
+            .filter(|run| run.results.is_some())
+            .map(|run| run.tool.driver.name.clone())
+            .collect::<BTreeSet<_>>();
+
+        // Absent results by the index of the run they are added to
+        let mut absent = BTreeMap::<usize, Vec<(&Result<'s>, &Run<'s>)>>::new();
+
+        for tool in tools {
+            let mut candidates = BTreeMap::<Option<String>, Vec<Candidate>>::new();
+            for (index, run) in baseline_runs
+                .iter()
+                .enumerate()
+                .filter(|(_, run)| run.tool.driver.name == tool)
+            {
+                for (result, content_fingerprint) in
+                    run.results.iter().flatten().zip(content_fingerprints(run))
+                {
+                    candidates
+                        .entry(rule_id(run, result).map(String::from))
+                        .or_default()
+                        .push(Candidate {
+                            run: index,
+                            result,
+                            content_fingerprint,
+                            matched: false,
+                        });
+                }
+            }
+
+            let mut first_run = None;
+            for (index, run) in runs
+                .iter_mut()
+                .enumerate()
+                .filter(|(_, run)| run.tool.driver.name == tool && run.results.is_some())
+            {
+                first_run.get_or_insert(index);
+
+                let rule_ids = run
+                    .results
+                    .iter()
+                    .flatten()
+                    .map(|result| rule_id(run, result).map(String::from))
+                    .collect::<Vec<_>>();
+                for (result, rule_id) in run.results.iter_mut().flatten().zip(rule_ids) {
+                    let candidate = candidates.get_mut(&rule_id).and_then(|candidates| {
+                        candidates
+                            .iter_mut()
+                            .find(|candidate| !candidate.matched && candidate.is_match(result))
+                    });
+
+                    result.baseline_state = Some(match candidate {
+                        Some(candidate) => {
+                            candidate.matched = true;
+                            let previous = candidate.result;
+                            if result.correlation_guid.is_none() {
+                                result.correlation_guid = previous.correlation_guid;
+                            }
+
+                            if result.kind == previous.kind
+                                && result.level == previous.level
+                                && result.message == previous.message
+                            {
+                                BaselineState::Unchanged
+                            } else {
+                                BaselineState::Updated
+                            }
+                        }
+                        None => BaselineState::New,
+                    });
+                }
+            }
+
+            if let Some(first_run) = first_run {
+                absent.entry(first_run).or_default().extend(
+                    candidates
+                        .into_values()
+                        .flatten()
+                        .filter(|candidate| !candidate.matched)
+                        .map(|candidate| (candidate.result, &baseline_runs[candidate.run])),
+                );
+            }
+        }
+
+        *runs = mem::take(runs)
+            .into_iter()
+            .enumerate()
+            .map(|(index, run)| match absent.remove(&index) {
+                Some(results) if !results.is_empty() => {
+                    let mut builder = RunBuilder::from_run(run);
+                    for (result, source) in results {
+                        builder.import_result(
+                            result.clone().with_baseline_state(BaselineState::Absent),
+                            source,
+                        );
+                    }
+                    builder.build()
+                }
+                _ => run,
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use alloc::vec::Vec;
+
+    use coverage_helper::test;
+
+    use crate::{
+        baseline::CONTENT_FINGERPRINT,
+        schema::{
+            tests::validate_schema, Artifact, ArtifactContent, ArtifactLocation, BaselineState,
+            Level, Location, Message, PhysicalLocation, Region, ReportingDescriptor, Result, Run,
+            SarifLog, SchemaVersion, Tool, ToolComponent,
+        },
+    };
+
+    fn unused_variable(file: &'static str, line: usize, variable: &'static str) -> Result<'static> {
+        Result::new(Message::new("unused variable `{0}`").with_argument(variable))
+            .with_rule_index(0)
+            .with_location(
+                Location::new().with_physical_location(
+                    PhysicalLocation::new()
+                        .with_artifact_location(ArtifactLocation::new(file))
+                        .with_region(
+                            Region::new()
+                                .with_start_line(line)
+                                .with_snippet(ArtifactContent::text("let x = 5;")),
+                        ),
+                ),
+            )
+    }
+
+    fn log(results: impl IntoIterator<Item = Result<'static>>) -> SarifLog<'static> {
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(
+            Run::new(Tool::new(
+                ToolComponent::new("clippy")
+                    .with_rule(ReportingDescriptor::new("unused_variables")),
+            ))
+            .with_results(results),
+        )
+    }
+
+    fn fingerprints<'l>(log: &'l SarifLog) -> Vec<&'l str> {
+        log.results()
+            .map(|result| &*result.partial_fingerprints[CONTENT_FINGERPRINT])
+            .collect()
+    }
+
+    fn hash(fingerprint: &str) -> &str {
+        fingerprint
+            .split_once(':')
+            .map_or(fingerprint, |(hash, _)| hash)
+    }
+
+    #[test]
+    fn content_fingerprint() {
+        let mut log_1 = log([
+            unused_variable("src/lib.rs", 12, "x"),
+            unused_variable("src/lib.rs", 14, "x"),
+            unused_variable("src/main.rs", 12, "x"),
+        ]);
+        let mut log_2 = log([
+            unused_variable("src/lib.rs", 20, "x"),
+            unused_variable("src/lib.rs", 24, "x"),
+            unused_variable("src/main.rs", 2, "x"),
+        ]);
+        log_1.compute_partial_fingerprints();
+        log_2.compute_partial_fingerprints();
+        validate_schema(&log_1);
+
+        let fingerprints_1 = fingerprints(&log_1);
+        assert_eq!(fingerprints_1, fingerprints(&log_2));
+        assert_eq!(hash(fingerprints_1[0]), hash(fingerprints_1[1]));
+        assert_ne!(fingerprints_1[0], fingerprints_1[1]);
+        assert_ne!(hash(fingerprints_1[0]), hash(fingerprints_1[2]));
+    }
+
+    #[test]
+    fn existing_fingerprint() {
+        let mut log = log([unused_variable("src/lib.rs", 12, "x")
+            .with_partial_fingerprint(CONTENT_FINGERPRINT, "0123456789abcdef:1")]);
+        log.compute_partial_fingerprints();
+
+        assert_eq!(fingerprints(&log), ["0123456789abcdef:1"]);
+    }
+
+    #[test]
+    fn baseline_states() {
+        let baseline = log([
+            unused_variable("src/lib.rs", 12, "x"),
+            unused_variable("src/lib.rs", 14, "y"),
+            unused_variable("src/lib.rs", 16, "z"),
+        ]);
+        let mut current = log([
+            unused_variable("src/lib.rs", 13, "x"),
+            unused_variable("src/lib.rs", 15, "y").with_level(Level::Error),
+            unused_variable("src/main.rs", 4, "x"),
+        ]);
+        current.apply_baseline(&baseline);
+        validate_schema(&current);
+
+        assert!(current.results().map(|result| result.baseline_state).eq([
+            Some(BaselineState::Unchanged),
+            Some(BaselineState::Updated),
+            Some(BaselineState::New),
+            Some(BaselineState::Absent),
+        ]));
+    }
+
+    #[test]
+    fn fingerprints_take_precedence() {
+        let baseline = log([unused_variable("src/lib.rs", 12, "x")
+            .with_fingerprint("stableResultHash/v1", "1")
+            .with_partial_fingerprint("primaryLocationLineHash/v1", "a")]);
+        let mut current = log([
+            unused_variable("src/lib.rs", 12, "x")
+                .with_fingerprint("stableResultHash/v1", "2")
+                .with_partial_fingerprint("primaryLocationLineHash/v1", "a"),
+            unused_variable("src/main.rs", 12, "y")
+                .with_fingerprint("stableResultHash/v1", "1")
+                .with_partial_fingerprint("primaryLocationLineHash/v1", "b"),
+        ]);
+        current.apply_baseline(&baseline);
+
+        assert!(
+            current
+                .results()
+                .map(|result| result.baseline_state)
+                .eq([Some(BaselineState::New), Some(BaselineState::Updated)])
+        );
+    }
+
+    #[test]
+    fn absent_results_are_remapped() {
+        let artifact = Artifact::new().with_location(ArtifactLocation::new("src/lib.rs"));
+        let baseline = SarifLog::new(SchemaVersion::V2_1_0).with_run(
+            Run::new(Tool::new(
+                ToolComponent::new("rustc")
+                    .with_rule(ReportingDescriptor::new("E0599"))
+                    .with_rule(ReportingDescriptor::new("E0308")),
+            ))
+            .with_artifact(Artifact::new().with_location(ArtifactLocation::new("src/main.rs")))
+            .with_artifact(artifact.clone())
+            .with_result(
+                Result::new(Message::new("mismatched types"))
+                    .with_rule_index(1)
+                    .with_location(Location::new().with_physical_location(
+                        PhysicalLocation::new().with_artifact_location(
+                            ArtifactLocation::new("src/lib.rs").with_index(1),
+                        ),
+                    )),
+            ),
+        );
+        let mut current = SarifLog::new(SchemaVersion::V2_1_0)
+            .with_run(Run::new(Tool::new(ToolComponent::new("rustc"))).with_results([]))
+            .with_run(Run::new(Tool::new(ToolComponent::new("clippy"))));
+        current.apply_baseline(&baseline);
+        validate_schema(&current);
+
+        let runs = current.runs.expect("no runs found");
+        let rustc = &runs[0];
+        assert_eq!(rustc.tool.driver.rules, [ReportingDescriptor::new("E0308")]);
+        assert_eq!(rustc.artifacts, [artifact]);
+
+        let results = rustc.results.as_deref().unwrap_or_default();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].rule_index, Some(0));
+        assert_eq!(results[0].baseline_state, Some(BaselineState::Absent));
+        assert_eq!(
+            results[0].locations[0]
+                .physical_location
+                .as_ref()
+                .and_then(|location| location.artifact_location.as_ref())
+                .and_then(|location| location.index),
+            Some(0)
+        );
+
+        assert_eq!(runs[1].results, None);
+    }
+}
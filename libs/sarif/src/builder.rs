@@ -0,0 +1,474 @@
+//! Incremental construction of a [`Run`].
+//!
+//! Constructing a [`Run`] with the `with_*` methods of the [`schema`] types requires every rule
+//! to be known upfront and every [`Result`] to reference its rule by index. [`RunBuilder`] keeps
+//! track of the rules and artifacts of a run, so results can be added one at a time, e.g. while
+//! an analysis is running.
+//!
+//! [`schema`]: crate::schema
+
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+use crate::schema::{
+    Artifact, ArtifactLocation, Invocation, Location, ReportingDescriptor, Result, Run, Tool,
+    ToolComponent,
+};
+
+/// Builds a [`Run`] by adding rules, artifacts and results one at a time.
+///
+/// Rules are identified by [`ReportingDescriptor::id`] and artifacts by the [`uri`] and
+/// [`uri_base_id`] of their [`location`], adding the same rule or artifact twice returns the index
+/// of the existing entry.
+///
+/// [`uri`]: ArtifactLocation::uri
+/// [`uri_base_id`]: ArtifactLocation::uri_base_id
+/// [`location`]: Artifact::location
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     builder::RunBuilder,
+///     schema::{Message, ReportingDescriptor, Result, ToolComponent},
+/// };
+///
+/// let mut builder = RunBuilder::new(ToolComponent::new("clippy"));
+/// builder.add_rule(ReportingDescriptor::new("unused_variables"));
+///
+/// for variable in ["x", "y"] {
+///     builder.add_result(
+///         Result::new(Message::new("unused variable `{0}`").with_argument(variable))
+///             .with_rule_id("unused_variables"),
+///     );
+/// }
+/// builder.add_result(Result::new(Message::new("mismatched types")).with_rule_id("E0308"));
+///
+/// let run = builder.build();
+///
+/// assert_eq!(run.tool.driver.rules.len(), 2);
+/// assert!(
+///     run.results
+///         .expect("results should be present")
+///         .iter()
+///         .map(|result| result.rule_index)
+///         .eq([Some(0), Some(0), Some(1)])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RunBuilder<'s> {
+    run: Run<'s>,
+    results: Vec<Result<'s>>,
+    rules: BTreeMap<Cow<'s, str>, usize>,
+    artifacts: BTreeMap<ArtifactKey<'s>, usize>,
+}
+
+impl<'s> RunBuilder<'s> {
+    /// Create a new `RunBuilder` for a run of the given tool component.
+    ///
+    /// Rules already present on the driver are registered.
+    #[must_use]
+    pub fn new(driver: ToolComponent<'s>) -> Self {
+        Self::from_run(Run::new(Tool::new(driver)))
+    }
+
+    /// Create a new `RunBuilder` continuing an existing run.
+    ///
+    /// The rules of the driver, the artifacts, and the results of the run are retained.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{Invocation, Message, Result, Run, Tool, ToolComponent},
+    /// };
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_invocation(Invocation::new(true))
+    ///     .with_result(Result::new(Message::new("unused variable `x`")));
+    ///
+    /// let mut builder = RunBuilder::from_run(run);
+    /// builder.add_result(Result::new(Message::new("unused variable `y`")));
+    /// let run = builder.build();
+    ///
+    /// assert_eq!(run.invocations.len(), 1);
+    /// assert_eq!(run.results.map(|results| results.len()), Some(2));
+    /// ```
+    #[must_use]
+    pub fn from_run(mut run: Run<'s>) -> Self {
+        let rules = run
+            .tool
+            .driver
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| (rule.id.clone(), index))
+            .collect();
+        let artifacts = run
+            .artifacts
+            .iter()
+            .enumerate()
+            .map(|(index, artifact)| (artifact_key(artifact.location.as_ref()), index))
+            .collect();
+        let results = run.results.take().unwrap_or_default();
+
+        Self {
+            run,
+            results,
+            rules,
+            artifacts,
+        }
+    }
+
+    /// Register a rule and return its index in the rules of the driver.
+    ///
+    /// If a rule with the same id has already been registered, the existing index is returned and
+    /// `rule` is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{ReportingDescriptor, ToolComponent},
+    /// };
+    ///
+    /// let mut builder = RunBuilder::new(ToolComponent::new("rustc"));
+    ///
+    /// assert_eq!(builder.add_rule(ReportingDescriptor::new("E0308")), 0);
+    /// assert_eq!(builder.add_rule(ReportingDescriptor::new("E0599")), 1);
+    /// assert_eq!(builder.add_rule(ReportingDescriptor::new("E0308")), 0);
+    /// ```
+    pub fn add_rule(&mut self, rule: ReportingDescriptor<'s>) -> usize {
+        if let Some(&index) = self.rules.get(&rule.id) {
+            return index;
+        }
+
+        let rules = &mut self.run.tool.driver.rules;
+        let index = rules.len();
+        self.rules.insert(rule.id.clone(), index);
+        rules.push(rule);
+        index
+    }
+
+    /// Returns the index of the rule with the given id, if it has been registered.
+    #[must_use]
+    pub fn rule_index(&self, id: &str) -> Option<usize> {
+        self.rules.get(id).copied()
+    }
+
+    /// Register an artifact and return its index in the artifacts of the run.
+    ///
+    /// If an artifact with the same location has already been registered, the existing index is
+    /// returned and `artifact` is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{Artifact, ArtifactLocation, ToolComponent},
+    /// };
+    ///
+    /// let mut builder = RunBuilder::new(ToolComponent::new("clippy"));
+    /// let lib = Artifact::new().with_location(ArtifactLocation::new("src/lib.rs"));
+    /// let main = Artifact::new().with_location(ArtifactLocation::new("src/main.rs"));
+    ///
+    /// assert_eq!(builder.add_artifact(lib.clone()), 0);
+    /// assert_eq!(builder.add_artifact(main), 1);
+    /// assert_eq!(builder.add_artifact(lib), 0);
+    /// ```
+    pub fn add_artifact(&mut self, artifact: Artifact<'s>) -> usize {
+        let key = artifact_key(artifact.location.as_ref());
+        if let Some(&index) = self.artifacts.get(&key) {
+            return index;
+        }
+
+        let index = self.run.artifacts.len();
+        self.artifacts.insert(key, index);
+        self.run.artifacts.push(artifact);
+        index
+    }
+
+    /// Add an invocation of the tool to the run.
+    pub fn add_invocation(&mut self, invocation: Invocation<'s>) -> &mut Self {
+        self.run.invocations.push(invocation);
+        self
+    }
+
+    /// Add a result to the run.
+    ///
+    /// If the result specifies a [`rule_id`] but no [`rule_index`], the index is resolved from the
+    /// registered rules. Rules, which have not been registered yet, are added to the driver with
+    /// only their id set.
+    ///
+    /// [`rule_id`]: Result::rule_id
+    /// [`rule_index`]: Result::rule_index
+    pub fn add_result(&mut self, mut result: Result<'s>) -> &mut Self {
+        if let (Some(rule_id), None) = (&result.rule_id, result.rule_index) {
+            result.rule_index = Some(self.add_rule(ReportingDescriptor::new(rule_id.clone())));
+        }
+
+        self.results.push(result);
+        self
+    }
+
+    /// Merge another run of the same tool into this run.
+    ///
+    /// The rules of the driver, the artifacts, the invocations, and the results of `run` are added
+    /// to this run. Rule and artifact indices referenced by `run` are remapped to the indices of
+    /// this run, rules and artifacts already present are not duplicated. Everything else of `run`,
+    /// e.g. the tool extensions, is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::{
+    ///     builder::RunBuilder,
+    ///     schema::{Message, ReportingDescriptor, Result, Run, Tool, ToolComponent},
+    /// };
+    ///
+    /// let mut builder =
+    ///     RunBuilder::new(ToolComponent::new("rustc").with_rule(ReportingDescriptor::new("E0308")));
+    /// builder.add_result(Result::new(Message::new("mismatched types")).with_rule_index(0));
+    ///
+    /// builder.merge(
+    ///     Run::new(Tool::new(
+    ///         ToolComponent::new("rustc")
+    ///             .with_rule(ReportingDescriptor::new("E0599"))
+    ///             .with_rule(ReportingDescriptor::new("E0308")),
+    ///     ))
+    ///     .with_results([
+    ///         Result::new(Message::new("mismatched types")).with_rule_index(1),
+    ///         Result::new(Message::new("no method named `foo` found")).with_rule_index(0),
+    ///     ]),
+    /// );
+    /// let run = builder.build();
+    ///
+    /// assert_eq!(run.tool.driver.rules.len(), 2);
+    /// assert!(
+    ///     run.results
+    ///         .expect("results should be present")
+    ///         .iter()
+    ///         .map(|result| result.rule_index)
+    ///         .eq([Some(0), Some(0), Some(1)])
+    /// );
+    /// ```
+    pub fn merge(&mut self, run: Run<'s>) -> &mut Self {
+        let rules: Vec<usize> = run
+            .tool
+            .driver
+            .rules
+            .into_iter()
+            .map(|rule| self.add_rule(rule))
+            .collect();
+
+        let offset = self.run.artifacts.len();
+        let artifacts: Vec<usize> = run
+            .artifacts
+            .into_iter()
+            .map(|artifact| self.add_artifact(artifact))
+            .collect();
+        for artifact in &mut self.run.artifacts[offset..] {
+            artifact.parent_index = artifact
+                .parent_index
+                .and_then(|index| artifacts.get(index).copied());
+            if let Some(location) = &mut artifact.location {
+                location.index = location
+                    .index
+                    .and_then(|index| artifacts.get(index).copied());
+            }
+        }
+
+        for mut invocation in run.invocations {
+            for location in invocation
+                .tool_execution_notifications
+                .iter_mut()
+                .chain(&mut invocation.tool_configuration_notifications)
+                .flat_map(|notification| &mut notification.locations)
+                .filter_map(artifact_location_mut)
+            {
+                location.index = location
+                    .index
+                    .and_then(|index| artifacts.get(index).copied());
+            }
+            self.add_invocation(invocation);
+        }
+
+        for mut result in run.results.into_iter().flatten() {
+            result.rule_index = result
+                .rule_index
+                .and_then(|index| rules.get(index).copied());
+            for location in artifact_locations_mut(&mut result) {
+                location.index = location
+                    .index
+                    .and_then(|index| artifacts.get(index).copied());
+            }
+            self.add_result(result);
+        }
+
+        self
+    }
+
+    /// Add a copy of a result of another run.
+    ///
+    /// Only the rule and the artifacts referenced by `result` are copied from `source`. The parent
+    /// of a copied artifact is not retained, as it may not be present in this run.
+    pub(crate) fn import_result(&mut self, mut result: Result<'s>, source: &Run<'s>) -> &mut Self {
+        if let Some(rule) = result
+            .rule_index
+            .and_then(|index| source.tool.driver.rules.get(index))
+        {
+            result.rule_index = Some(self.add_rule(rule.clone()));
+        } else {
+            result.rule_index = None;
+        }
+
+        for location in artifact_locations_mut(&mut result) {
+            location.index = location
+                .index
+                .and_then(|index| source.artifacts.get(index))
+                .map(|artifact| {
+                    let mut artifact = artifact.clone();
+                    artifact.parent_index = None;
+                    if let Some(location) = &mut artifact.location {
+                        location.index = None;
+                    }
+                    self.add_artifact(artifact)
+                });
+        }
+
+        self.add_result(result)
+    }
+
+    /// Returns the run built so far, without its results.
+    ///
+    /// This is used to write the header of a run before its results are streamed, see
+    /// [`SarifWriter::begin_run`].
+    ///
+    /// [`SarifWriter::begin_run`]: crate::writer::SarifWriter::begin_run
+    #[must_use]
+    pub const fn run(&self) -> &Run<'s> {
+        &self.run
+    }
+
+    /// Finish the run.
+    ///
+    /// The results of the returned run are always present, even if no result has been added, to
+    /// indicate that the tool ran successfully.
+    #[must_use]
+    pub fn build(self) -> Run<'s> {
+        let mut run = self.run;
+        run.results = Some(self.results);
+        run
+    }
+}
+
+type ArtifactKey<'s> = (Option<Cow<'s, str>>, Option<Cow<'s, str>>);
+
+fn artifact_key<'s>(location: Option<&ArtifactLocation<'s>>) -> ArtifactKey<'s> {
+    location.map_or((None, None), |location| {
+        (location.uri.clone(), location.uri_base_id.clone())
+    })
+}
+
+fn artifact_location_mut<'r, 's>(
+    location: &'r mut Location<'s>,
+) -> Option<&'r mut ArtifactLocation<'s>> {
+    location
+        .physical_location
+        .as_mut()?
+        .artifact_location
+        .as_mut()
+}
+
+/// Returns every artifact location referenced by a result.
+fn artifact_locations_mut<'r, 's>(
+    result: &'r mut Result<'s>,
+) -> impl Iterator<Item = &'r mut ArtifactLocation<'s>> {
+    let code_flow_locations = result
+        .code_flows
+        .iter_mut()
+        .flat_map(|code_flow| &mut code_flow.thread_flows)
+        .flat_map(|thread_flow| &mut thread_flow.locations)
+        .filter_map(|location| location.location.as_mut());
+
+    result.analysis_target.as_mut().into_iter().chain(
+        result
+            .locations
+            .iter_mut()
+            .chain(&mut result.related_locations)
+            .chain(code_flow_locations)
+            .filter_map(artifact_location_mut),
+    )
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use alloc::vec::Vec;
+
+    use coverage_helper::test;
+
+    use crate::{
+        builder::RunBuilder,
+        schema::{
+            tests::validate_schema, Artifact, ArtifactLocation, Invocation, Location, Message,
+            PhysicalLocation, ReportingDescriptor, Result, SarifLog, SchemaVersion, ToolComponent,
+        },
+    };
+
+    #[test]
+    fn empty() {
+        let run = RunBuilder::new(ToolComponent::new("clippy")).build();
+
+        assert_eq!(run.results, Some(Vec::new()));
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn results() {
+        let mut builder = RunBuilder::new(
+            ToolComponent::new("clippy").with_rule(ReportingDescriptor::new("unused_variables")),
+        );
+        builder.add_invocation(Invocation::new(true));
+
+        for (file, variable) in [
+            ("src/lib.rs", "x"),
+            ("src/main.rs", "y"),
+            ("src/lib.rs", "z"),
+        ] {
+            let location = ArtifactLocation::new(file);
+            let index = builder.add_artifact(Artifact::new().with_location(location.clone()));
+
+            builder.add_result(
+                Result::new(Message::new("unused variable `{0}`").with_argument(variable))
+                    .with_rule_id("unused_variables")
+                    .with_location(Location::new().with_physical_location(
+                        PhysicalLocation::new().with_artifact_location(location.with_index(index)),
+                    )),
+            );
+        }
+        builder.add_result(
+            Result::new(Message::new("needless borrow")).with_rule_id("needless_borrow"),
+        );
+
+        assert_eq!(builder.rule_index("unused_variables"), Some(0));
+        assert_eq!(builder.rule_index("needless_borrow"), Some(1));
+        assert_eq!(builder.rule_index("E0308"), None);
+
+        let run = builder.build();
+        assert_eq!(run.tool.driver.rules.len(), 2);
+        assert_eq!(run.artifacts.len(), 2);
+        assert!(
+            run.results
+                .as_ref()
+                .expect("results should be present")
+                .iter()
+                .map(|result| result.rule_index)
+                .eq([Some(0), Some(0), Some(0), Some(1)])
+        );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -9,10 +9,14 @@
 #![cfg_attr(coverage_nightly, feature(no_coverage))]
 
 extern crate alloc;
-#[cfg(test)]
+#[cfg(any(test, feature = "std"))]
 extern crate std;
 
+pub mod baseline;
+pub mod builder;
 pub mod schema;
+#[cfg(feature = "std")]
+pub mod writer;
 
 #[cfg(feature = "serde")]
 pub(crate) mod serde;
@@ -1,10 +1,19 @@
 //! The JSON schema of the SARIF log file format as a Rust module.
 
+mod artifact;
+mod artifact_location;
+mod code_flow;
+mod invocation;
+mod location;
 mod log;
 mod message;
 mod multiformat_message_string;
+mod notification;
+mod physical_location;
 mod properties;
+mod region;
 mod reporting_descriptor;
+mod result;
 mod run;
 mod tool;
 
@@ -12,11 +21,20 @@ mod tool;
 use serde::{Deserialize, Serialize};
 
 pub use self::{
+    artifact::{Artifact, ArtifactContent},
+    artifact_location::ArtifactLocation,
+    code_flow::{CodeFlow, Importance, ThreadFlow, ThreadFlowLocation},
+    invocation::Invocation,
+    location::Location,
     log::SarifLog,
     message::Message,
     multiformat_message_string::MultiformatMessageString,
+    notification::Notification,
+    physical_location::PhysicalLocation,
     properties::PropertyBag,
+    region::Region,
     reporting_descriptor::ReportingDescriptor,
+    result::{BaselineState, Level, Result, ResultKind},
     run::Run,
     tool::{Tool, ToolComponent},
 };
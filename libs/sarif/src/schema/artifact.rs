@@ -0,0 +1,536 @@
+use alloc::{borrow::Cow, collections::BTreeMap};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactLocation, Message, MultiformatMessageString, PropertyBag};
+
+/// A single artifact.
+///
+/// In some cases, this artifact might be nested within another artifact, e.g. a file inside of an
+/// archive.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Artifact<'s> {
+    /// A short description of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<ArtifactLocation<'s>>,
+
+    /// Identifies the index of the immediate parent of the artifact, if this artifact is nested.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<usize>,
+
+    /// The offset in bytes of the artifact within its containing artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub offset: Option<u64>,
+
+    /// The length of the artifact in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub length: Option<u64>,
+
+    /// The MIME type (RFC 2045) of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub mime_type: Option<Cow<'s, str>>,
+
+    /// The contents of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub contents: Option<ArtifactContent<'s>>,
+
+    /// Specifies the encoding for an artifact object that refers to a text file.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub encoding: Option<Cow<'s, str>>,
+
+    /// Specifies the source language for any artifact object that refers to a text file that
+    /// contains source code.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// A dictionary, each of whose keys is the name of a hash function and each of whose values
+    /// is the hashed value of the artifact produced by the specified hash function.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub hashes: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the artifact was most recently
+    /// modified, formatted according to ISO 8601.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub last_modified_time_utc: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Artifact<'s> {
+    /// Create a new, empty `Artifact`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new();
+    ///
+    /// assert_eq!(artifact.location, None);
+    /// assert!(artifact.hashes.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            description: None,
+            location: None,
+            parent_index: None,
+            offset: None,
+            length: None,
+            mime_type: None,
+            contents: None,
+            encoding: None,
+            source_language: None,
+            hashes: BTreeMap::new(),
+            last_modified_time_utc: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the description of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, Message};
+    ///
+    /// let artifact = Artifact::new().with_description(Message::new("crate root"));
+    ///
+    /// assert_eq!(artifact.description, Some(Message::new("crate root")));
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Set the location of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new().with_location(ArtifactLocation::new("src/lib.rs"));
+    ///
+    /// assert_eq!(artifact.location, Some(ArtifactLocation::new("src/lib.rs")));
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: ArtifactLocation<'s>) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Set the index of the artifact containing this artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new()
+    ///     .with_location(ArtifactLocation::new("/docs/intro.md"))
+    ///     .with_parent_index(0)
+    ///     .with_offset(17522);
+    ///
+    /// assert_eq!(artifact.parent_index, Some(0));
+    /// assert_eq!(artifact.offset, Some(17522));
+    /// ```
+    #[must_use]
+    pub const fn with_parent_index(mut self, parent_index: usize) -> Self {
+        self.parent_index = Some(parent_index);
+        self
+    }
+
+    /// Set the offset in bytes of the artifact within its parent.
+    ///
+    /// See [`with_parent_index`] for an example.
+    ///
+    /// [`with_parent_index`]: Self::with_parent_index
+    #[must_use]
+    pub const fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the length of the artifact in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_length(1742);
+    ///
+    /// assert_eq!(artifact.length, Some(1742));
+    /// ```
+    #[must_use]
+    pub const fn with_length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Set the MIME type of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_mime_type("text/x-rust");
+    ///
+    /// assert_eq!(artifact.mime_type.as_deref(), Some("text/x-rust"));
+    /// ```
+    #[must_use]
+    pub fn with_mime_type(mut self, mime_type: impl Into<Cow<'s, str>>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Set the contents of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactContent};
+    ///
+    /// let artifact = Artifact::new().with_contents(ArtifactContent::text("fn main() {}"));
+    ///
+    /// assert_eq!(
+    ///     artifact.contents,
+    ///     Some(ArtifactContent::text("fn main() {}"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_contents(mut self, contents: ArtifactContent<'s>) -> Self {
+        self.contents = Some(contents);
+        self
+    }
+
+    /// Set the encoding of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_encoding("utf-8");
+    ///
+    /// assert_eq!(artifact.encoding.as_deref(), Some("utf-8"));
+    /// ```
+    #[must_use]
+    pub fn with_encoding(mut self, encoding: impl Into<Cow<'s, str>>) -> Self {
+        self.encoding = Some(encoding.into());
+        self
+    }
+
+    /// Set the source language of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_source_language("rust");
+    ///
+    /// assert_eq!(artifact.source_language.as_deref(), Some("rust"));
+    /// ```
+    #[must_use]
+    pub fn with_source_language(mut self, source_language: impl Into<Cow<'s, str>>) -> Self {
+        self.source_language = Some(source_language.into());
+        self
+    }
+
+    /// Add the hash of the artifact produced by the given hash function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_hash("sha-256", "b13ce2678a8807ba0765ab94a0ecd394");
+    ///
+    /// assert_eq!(
+    ///     artifact.hashes["sha-256"],
+    ///     "b13ce2678a8807ba0765ab94a0ecd394"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_hash(
+        mut self,
+        function: impl Into<Cow<'s, str>>,
+        hash: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.hashes.insert(function.into(), hash.into());
+        self
+    }
+
+    /// Set the time the artifact was last modified, formatted according to ISO 8601.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_last_modified_time_utc("2016-07-16T14:18:25Z");
+    ///
+    /// assert_eq!(
+    ///     artifact.last_modified_time_utc.as_deref(),
+    ///     Some("2016-07-16T14:18:25Z")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_last_modified_time_utc(mut self, time: impl Into<Cow<'s, str>>) -> Self {
+        self.last_modified_time_utc = Some(time.into());
+        self
+    }
+
+    /// Add a property to the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_properties(|properties| properties.with_tag("generated"));
+    ///
+    /// assert!(artifact.properties.tags.iter().eq(["generated"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+/// Represents the contents of an artifact.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+#[non_exhaustive]
+pub struct ArtifactContent<'s> {
+    /// UTF-8-encoded content from a text artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub text: Option<Cow<'s, str>>,
+
+    /// MIME Base64-encoded content from a binary artifact, or from a text artifact in its original
+    /// encoding.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub binary: Option<Cow<'s, str>>,
+
+    /// An alternate rendered representation of the artifact, e.g. a decompiled representation of a
+    /// binary region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rendered: Option<MultiformatMessageString<'s>>,
+
+    /// Key/value pairs that provide additional information about the artifact content.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactContent<'s> {
+    /// Create a new `ArtifactContent` from the contents of a text artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::text("fn main() {}");
+    ///
+    /// assert_eq!(content.text.as_deref(), Some("fn main() {}"));
+    /// assert_eq!(content.binary, None);
+    /// ```
+    #[must_use]
+    pub fn text(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: Some(text.into()),
+            binary: None,
+            rendered: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Create a new `ArtifactContent` from the Base64-encoded contents of a binary artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::binary("f0VMRgIBAQ==");
+    ///
+    /// assert_eq!(content.binary.as_deref(), Some("f0VMRgIBAQ=="));
+    /// assert_eq!(content.text, None);
+    /// ```
+    #[must_use]
+    pub fn binary(binary: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: None,
+            binary: Some(binary.into()),
+            rendered: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set an alternative rendered representation of the content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use sarif::schema::{ArtifactContent, MultiformatMessageString, PropertyBag};
+    ///
+    /// let rendered = MultiformatMessageString {
+    ///     text: Cow::Borrowed("ELF 64-bit LSB executable"),
+    ///     markdown: None,
+    ///     properties: PropertyBag::new(),
+    /// };
+    /// let content = ArtifactContent::binary("f0VMRgIBAQ==").with_rendered(rendered.clone());
+    ///
+    /// assert_eq!(content.rendered, Some(rendered));
+    /// ```
+    #[must_use]
+    pub fn with_rendered(mut self, rendered: MultiformatMessageString<'s>) -> Self {
+        self.rendered = Some(rendered);
+        self
+    }
+
+    /// Add a property to the artifact content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::text("fn main() {}")
+    ///     .with_properties(|properties| properties.with_tag("truncated"));
+    ///
+    /// assert!(content.properties.tags.iter().eq(["truncated"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, Artifact, ArtifactContent, ArtifactLocation, Message, Run,
+        SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn text() {
+        let artifact = Artifact::new()
+            .with_description(Message::new("crate root"))
+            .with_location(ArtifactLocation::new("src/lib.rs").with_uri_base_id("SRCROOT"))
+            .with_length(12)
+            .with_mime_type("text/x-rust")
+            .with_contents(ArtifactContent::text("fn main() {}"))
+            .with_encoding("utf-8")
+            .with_source_language("rust")
+            .with_hash("sha-256", "b13ce2678a8807ba0765ab94a0ecd394")
+            .with_last_modified_time_utc("2016-07-16T14:18:25Z");
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_artifact(artifact);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn nested() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_artifact(
+                Artifact::new()
+                    .with_location(ArtifactLocation::new("app.zip"))
+                    .with_mime_type("application/zip"),
+            )
+            .with_artifact(
+                Artifact::new()
+                    .with_location(ArtifactLocation::new("/docs/intro.md"))
+                    .with_parent_index(0)
+                    .with_offset(17522)
+                    .with_length(4050)
+                    .with_contents(ArtifactContent::binary("IyBJbnRybw==")),
+            );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
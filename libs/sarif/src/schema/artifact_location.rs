@@ -0,0 +1,186 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Message, PropertyBag};
+
+/// Specifies the location of an artifact.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactLocation<'s> {
+    /// A string containing a valid relative or absolute URI.
+    ///
+    /// If the URI is relative, it is resolved against [`uri_base_id`], if present.
+    ///
+    /// [`uri_base_id`]: Self::uri_base_id
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uri: Option<Cow<'s, str>>,
+
+    /// A string which indirectly specifies the absolute URI with respect to which a relative URI
+    /// in the [`uri`] property is interpreted.
+    ///
+    /// [`uri`]: Self::uri
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uri_base_id: Option<Cow<'s, str>>,
+
+    /// The index within the run artifacts array of the artifact object associated with the
+    /// artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<usize>,
+
+    /// A short description of the artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// Key/value pairs that provide additional information about the artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactLocation<'s> {
+    /// Create a new `ArtifactLocation` with the given URI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs");
+    ///
+    /// assert_eq!(location.uri.as_deref(), Some("src/lib.rs"));
+    /// ```
+    #[must_use]
+    pub fn new(uri: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            uri: Some(uri.into()),
+            uri_base_id: None,
+            index: None,
+            description: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the base identifier the URI is resolved against.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs").with_uri_base_id("SRCROOT");
+    ///
+    /// assert_eq!(location.uri_base_id.as_deref(), Some("SRCROOT"));
+    /// ```
+    #[must_use]
+    pub fn with_uri_base_id(mut self, uri_base_id: impl Into<Cow<'s, str>>) -> Self {
+        self.uri_base_id = Some(uri_base_id.into());
+        self
+    }
+
+    /// Set the index of the associated artifact within the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs").with_index(0);
+    ///
+    /// assert_eq!(location.index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Set the description of the artifact location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Message};
+    ///
+    /// let location = ArtifactLocation::new("Cargo.toml").with_description(Message::new("manifest"));
+    ///
+    /// assert_eq!(location.description, Some(Message::new("manifest")));
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Add a property to the artifact location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs")
+    ///     .with_properties(|properties| properties.with_tag("library"));
+    ///
+    /// assert!(location.properties.tags.iter().eq(["library"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, Artifact, ArtifactLocation, Location, Message, PhysicalLocation,
+        Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn indexed() {
+        let artifact_location = ArtifactLocation::new("src/lib.rs")
+            .with_uri_base_id("SRCROOT")
+            .with_index(0)
+            .with_description(Message::new("library root"));
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_artifact(Artifact::new().with_location(artifact_location.clone()))
+            .with_result(
+                Result::new(Message::new("unused variable `x`")).with_location(
+                    Location::new().with_physical_location(
+                        PhysicalLocation::new().with_artifact_location(artifact_location),
+                    ),
+                ),
+            );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -0,0 +1,561 @@
+use alloc::{borrow::Cow, collections::BTreeSet, vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Location, Message, PropertyBag};
+
+/// A set of threads, each of which is represented by a sequence of locations visited by a program
+/// during its execution, that together reveal the problem described by a result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct CodeFlow<'s> {
+    /// A message relevant to the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// An array of one or more unique thread flow objects, each of which describes the progress of
+    /// a program through a thread of execution.
+    ///
+    /// The array **shall not** be empty.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub thread_flows: Vec<ThreadFlow<'s>>,
+
+    /// Key/value pairs that provide additional information about the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> CodeFlow<'s> {
+    /// Create a new `CodeFlow` from a single thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Location, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new(Location::new()));
+    /// let code_flow = CodeFlow::new(thread_flow.clone());
+    ///
+    /// assert_eq!(code_flow.thread_flows, vec![thread_flow]);
+    /// ```
+    #[must_use]
+    pub fn new(thread_flow: ThreadFlow<'s>) -> Self {
+        Self {
+            message: None,
+            thread_flows: vec![thread_flow],
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the message relevant to the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Location, Message, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new(ThreadFlowLocation::new(Location::new())))
+    ///     .with_message(Message::new("Path from declaration to usage"));
+    ///
+    /// assert_eq!(
+    ///     code_flow.message,
+    ///     Some(Message::new("Path from declaration to usage"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Add a thread flow to the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Location, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let code_flow =
+    ///     CodeFlow::new(ThreadFlow::new(ThreadFlowLocation::new(Location::new())).with_id("main"))
+    ///         .with_thread_flow(
+    ///             ThreadFlow::new(ThreadFlowLocation::new(Location::new())).with_id("worker"),
+    ///         );
+    ///
+    /// assert_eq!(code_flow.thread_flows.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_thread_flow(mut self, thread_flow: ThreadFlow<'s>) -> Self {
+        self.thread_flows.push(thread_flow);
+        self
+    }
+
+    /// Add a property to the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Location, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new(ThreadFlowLocation::new(Location::new())))
+    ///     .with_properties(|properties| properties.with_tag("taint"));
+    ///
+    /// assert!(code_flow.properties.tags.iter().eq(["taint"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+/// Describes a sequence of code locations that specify a path through a single thread of execution
+/// such as an operating system or fiber.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ThreadFlow<'s> {
+    /// An string that uniquely identifies the thread flow within the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<Cow<'s, str>>,
+
+    /// A message relevant to the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// A temporally ordered array of thread flow locations, each of which describes a location
+    /// visited by the tool while producing the result.
+    ///
+    /// The array **shall not** be empty.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locations: Vec<ThreadFlowLocation<'s>>,
+
+    /// Key/value pairs that provide additional information about the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ThreadFlow<'s> {
+    /// Create a new `ThreadFlow` starting at the given location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let location = ThreadFlowLocation::new(Location::new());
+    /// let thread_flow = ThreadFlow::new(location.clone());
+    ///
+    /// assert_eq!(thread_flow.locations, vec![location]);
+    /// ```
+    #[must_use]
+    pub fn new(location: ThreadFlowLocation<'s>) -> Self {
+        Self {
+            id: None,
+            message: None,
+            locations: vec![location],
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the identifier of the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow =
+    ///     ThreadFlow::new(ThreadFlowLocation::new(Location::new())).with_id("thread-52");
+    ///
+    /// assert_eq!(thread_flow.id.as_deref(), Some("thread-52"));
+    /// ```
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<Cow<'s, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the message relevant to the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new(Location::new()))
+    ///     .with_message(Message::new("main thread"));
+    ///
+    /// assert_eq!(thread_flow.message, Some(Message::new("main thread")));
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Add a location visited after the previous locations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new(Location::new().with_id(0)))
+    ///     .with_location(ThreadFlowLocation::new(Location::new().with_id(1)));
+    ///
+    /// assert_eq!(thread_flow.locations.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: ThreadFlowLocation<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Add a property to the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new(Location::new()))
+    ///     .with_properties(|properties| properties.with_tag("async"));
+    ///
+    /// assert!(thread_flow.properties.tags.iter().eq(["async"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+/// Specifies how important a [`ThreadFlowLocation`] is within a [`ThreadFlow`].
+///
+/// If absent, the importance is [`Importance::Important`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Importance {
+    /// The location is important to understand the problem, but is not essential.
+    #[default]
+    Important,
+    /// The location is essential to understand the problem.
+    Essential,
+    /// The location is not important to understand the problem.
+    Unimportant,
+}
+
+/// A location visited by an analysis tool while simulating or monitoring the execution of a
+/// program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ThreadFlowLocation<'s> {
+    /// The index within the run thread flow locations array.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<usize>,
+
+    /// The code location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<Location<'s>>,
+
+    /// A set of distinct strings that categorize the thread flow location, e.g. `"acquire"` or
+    /// `"release"`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeSet::is_empty")
+    )]
+    pub kinds: BTreeSet<Cow<'s, str>>,
+
+    /// The name of the module that contains the code that is executing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub module: Option<Cow<'s, str>>,
+
+    /// An integer representing a containment hierarchy within the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub nesting_level: Option<usize>,
+
+    /// An integer representing the temporal order in which execution reached this location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub execution_order: Option<usize>,
+
+    /// Specifies the importance of this location in understanding the code flow in which it
+    /// occurs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub importance: Option<Importance>,
+
+    /// Key/value pairs that provide additional information about the thread flow location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ThreadFlowLocation<'s> {
+    /// Create a new `ThreadFlowLocation` at the given location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlowLocation};
+    ///
+    /// let thread_flow_location = ThreadFlowLocation::new(Location::new().with_id(0));
+    ///
+    /// assert_eq!(
+    ///     thread_flow_location.location,
+    ///     Some(Location::new().with_id(0))
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn new(location: Location<'s>) -> Self {
+        Self {
+            index: None,
+            location: Some(location),
+            kinds: BTreeSet::new(),
+            module: None,
+            nesting_level: None,
+            execution_order: None,
+            importance: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the index within the thread flow locations of the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlowLocation};
+    ///
+    /// let thread_flow_location = ThreadFlowLocation::new(Location::new()).with_index(3);
+    ///
+    /// assert_eq!(thread_flow_location.index, Some(3));
+    /// ```
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Add a kind categorizing the thread flow location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlowLocation};
+    ///
+    /// let thread_flow_location = ThreadFlowLocation::new(Location::new())
+    ///     .with_kind("acquire")
+    ///     .with_kind("lock");
+    ///
+    /// assert!(thread_flow_location.kinds.iter().eq(["acquire", "lock"]));
+    /// ```
+    #[must_use]
+    pub fn with_kind(mut self, kind: impl Into<Cow<'s, str>>) -> Self {
+        self.kinds.insert(kind.into());
+        self
+    }
+
+    /// Set the name of the module containing the executing code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlowLocation};
+    ///
+    /// let thread_flow_location = ThreadFlowLocation::new(Location::new()).with_module("platform");
+    ///
+    /// assert_eq!(thread_flow_location.module.as_deref(), Some("platform"));
+    /// ```
+    #[must_use]
+    pub fn with_module(mut self, module: impl Into<Cow<'s, str>>) -> Self {
+        self.module = Some(module.into());
+        self
+    }
+
+    /// Set the nesting level of the location within the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlowLocation};
+    ///
+    /// let thread_flow_location = ThreadFlowLocation::new(Location::new()).with_nesting_level(1);
+    ///
+    /// assert_eq!(thread_flow_location.nesting_level, Some(1));
+    /// ```
+    #[must_use]
+    pub const fn with_nesting_level(mut self, nesting_level: usize) -> Self {
+        self.nesting_level = Some(nesting_level);
+        self
+    }
+
+    /// Set the order in which execution reached this location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlowLocation};
+    ///
+    /// let thread_flow_location = ThreadFlowLocation::new(Location::new()).with_execution_order(7);
+    ///
+    /// assert_eq!(thread_flow_location.execution_order, Some(7));
+    /// ```
+    #[must_use]
+    pub const fn with_execution_order(mut self, execution_order: usize) -> Self {
+        self.execution_order = Some(execution_order);
+        self
+    }
+
+    /// Set the importance of the location to understand the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Importance, Location, ThreadFlowLocation};
+    ///
+    /// let thread_flow_location =
+    ///     ThreadFlowLocation::new(Location::new()).with_importance(Importance::Essential);
+    ///
+    /// assert_eq!(thread_flow_location.importance, Some(Importance::Essential));
+    /// ```
+    #[must_use]
+    pub const fn with_importance(mut self, importance: Importance) -> Self {
+        self.importance = Some(importance);
+        self
+    }
+
+    /// Add a property to the thread flow location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, ThreadFlowLocation};
+    ///
+    /// let thread_flow_location = ThreadFlowLocation::new(Location::new())
+    ///     .with_properties(|properties| properties.with_tag("entry"));
+    ///
+    /// assert!(thread_flow_location.properties.tags.iter().eq(["entry"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, CodeFlow, Importance, Location, Message,
+        PhysicalLocation, Region, Result, Run, SarifLog, SchemaVersion, ThreadFlow,
+        ThreadFlowLocation, Tool, ToolComponent,
+    };
+
+    fn location(line: usize, message: &str) -> Location {
+        Location::new()
+            .with_message(Message::new(message))
+            .with_physical_location(
+                PhysicalLocation::new()
+                    .with_artifact_location(ArtifactLocation::new("src/lib.rs"))
+                    .with_region(Region::new().with_start_line(line)),
+            )
+    }
+
+    #[test]
+    fn declaration_to_usage() {
+        let thread_flow = ThreadFlow::new(
+            ThreadFlowLocation::new(location(15, "variable `ptr` declared"))
+                .with_importance(Importance::Essential)
+                .with_execution_order(0),
+        )
+        .with_id("thread-52")
+        .with_location(
+            ThreadFlowLocation::new(location(20, "`offset` computed"))
+                .with_importance(Importance::Unimportant)
+                .with_module("platform")
+                .with_nesting_level(1)
+                .with_kind("call")
+                .with_execution_order(1),
+        )
+        .with_location(
+            ThreadFlowLocation::new(location(25, "uninitialized variable `ptr` used"))
+                .with_importance(Importance::Essential)
+                .with_execution_order(2),
+        );
+
+        let result = Result::new(Message::new("use of uninitialized variable `ptr`"))
+            .with_code_flow(
+                CodeFlow::new(thread_flow)
+                    .with_message(Message::new("Path from declaration to usage")),
+            );
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -0,0 +1,496 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactLocation, Notification, PropertyBag};
+
+/// The runtime environment of the analysis tool run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Invocation<'s> {
+    /// The command line used to invoke the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub command_line: Option<Cow<'s, str>>,
+
+    /// An array of strings, containing in order the command line arguments passed to the tool
+    /// from the operating system.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub arguments: Option<Vec<Cow<'s, str>>>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the invocation started,
+    /// formatted according to ISO 8601.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_time_utc: Option<Cow<'s, str>>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the invocation ended,
+    /// formatted according to ISO 8601.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_time_utc: Option<Cow<'s, str>>,
+
+    /// The process exit code.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_code: Option<i32>,
+
+    /// A list of runtime conditions detected by the tool during the analysis.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tool_execution_notifications: Vec<Notification<'s>>,
+
+    /// A list of conditions detected by the tool that are relevant to the tool's configuration.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tool_configuration_notifications: Vec<Notification<'s>>,
+
+    /// The reason for the process exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_code_description: Option<Cow<'s, str>>,
+
+    /// The name of the signal that caused the process to exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_signal_name: Option<Cow<'s, str>>,
+
+    /// The numeric value of the signal that caused the process to exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_signal_number: Option<i32>,
+
+    /// The reason given by the operating system that the process failed to start.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub process_start_failure_message: Option<Cow<'s, str>>,
+
+    /// Specifies whether the tool's execution completed successfully.
+    ///
+    /// This is `true` even if the tool detected results, as long as the analysis itself was not
+    /// interrupted.
+    pub execution_successful: bool,
+
+    /// The machine that hosted the analysis tool run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub machine: Option<Cow<'s, str>>,
+
+    /// The account that ran the analysis tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub account: Option<Cow<'s, str>>,
+
+    /// The process id for the analysis tool run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub process_id: Option<u32>,
+
+    /// The working directory for the analysis tool run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub working_directory: Option<ArtifactLocation<'s>>,
+
+    /// Key/value pairs that provide additional information about the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Invocation<'s> {
+    /// Create a new `Invocation`, specifying whether the tool's execution completed successfully.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true);
+    ///
+    /// assert!(invocation.execution_successful);
+    /// assert_eq!(invocation.exit_code, None);
+    /// ```
+    #[must_use]
+    pub const fn new(execution_successful: bool) -> Self {
+        Self {
+            command_line: None,
+            arguments: None,
+            start_time_utc: None,
+            end_time_utc: None,
+            exit_code: None,
+            tool_execution_notifications: Vec::new(),
+            tool_configuration_notifications: Vec::new(),
+            exit_code_description: None,
+            exit_signal_name: None,
+            exit_signal_number: None,
+            process_start_failure_message: None,
+            execution_successful,
+            machine: None,
+            account: None,
+            process_id: None,
+            working_directory: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the command line used to invoke the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_command_line("cargo clippy --workspace");
+    ///
+    /// assert_eq!(
+    ///     invocation.command_line.as_deref(),
+    ///     Some("cargo clippy --workspace")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_command_line(mut self, command_line: impl Into<Cow<'s, str>>) -> Self {
+        self.command_line = Some(command_line.into());
+        self
+    }
+
+    /// Set the command line arguments passed to the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_arguments(["clippy", "--workspace"]);
+    ///
+    /// assert_eq!(
+    ///     invocation.arguments,
+    ///     Some(vec!["clippy".into(), "--workspace".into()])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_arguments(
+        mut self,
+        arguments: impl IntoIterator<Item = impl Into<Cow<'s, str>>>,
+    ) -> Self {
+        self.arguments = Some(arguments.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the time the invocation started, formatted according to ISO 8601.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_start_time_utc("2016-07-16T14:18:25Z")
+    ///     .with_end_time_utc("2016-07-16T14:19:01Z");
+    ///
+    /// assert_eq!(
+    ///     invocation.start_time_utc.as_deref(),
+    ///     Some("2016-07-16T14:18:25Z")
+    /// );
+    /// assert_eq!(
+    ///     invocation.end_time_utc.as_deref(),
+    ///     Some("2016-07-16T14:19:01Z")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_start_time_utc(mut self, start_time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.start_time_utc = Some(start_time_utc.into());
+        self
+    }
+
+    /// Set the time the invocation ended, formatted according to ISO 8601.
+    ///
+    /// See [`with_start_time_utc`] for an example.
+    ///
+    /// [`with_start_time_utc`]: Self::with_start_time_utc
+    #[must_use]
+    pub fn with_end_time_utc(mut self, end_time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.end_time_utc = Some(end_time_utc.into());
+        self
+    }
+
+    /// Set the exit code of the process.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_exit_code(1)
+    ///     .with_exit_code_description("lints were emitted");
+    ///
+    /// assert_eq!(invocation.exit_code, Some(1));
+    /// assert_eq!(
+    ///     invocation.exit_code_description.as_deref(),
+    ///     Some("lints were emitted")
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_exit_code(mut self, exit_code: i32) -> Self {
+        self.exit_code = Some(exit_code);
+        self
+    }
+
+    /// Set the reason for the process exit.
+    ///
+    /// See [`with_exit_code`] for an example.
+    ///
+    /// [`with_exit_code`]: Self::with_exit_code
+    #[must_use]
+    pub fn with_exit_code_description(
+        mut self,
+        exit_code_description: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.exit_code_description = Some(exit_code_description.into());
+        self
+    }
+
+    /// Set the signal that caused the process to exit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(false).with_exit_signal("SIGKILL", 9);
+    ///
+    /// assert_eq!(invocation.exit_signal_name.as_deref(), Some("SIGKILL"));
+    /// assert_eq!(invocation.exit_signal_number, Some(9));
+    /// ```
+    #[must_use]
+    pub fn with_exit_signal(mut self, name: impl Into<Cow<'s, str>>, number: i32) -> Self {
+        self.exit_signal_name = Some(name.into());
+        self.exit_signal_number = Some(number);
+        self
+    }
+
+    /// Set the reason the process failed to start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(false).with_process_start_failure_message("permission denied");
+    ///
+    /// assert_eq!(
+    ///     invocation.process_start_failure_message.as_deref(),
+    ///     Some("permission denied")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_process_start_failure_message(mut self, message: impl Into<Cow<'s, str>>) -> Self {
+        self.process_start_failure_message = Some(message.into());
+        self
+    }
+
+    /// Add a runtime condition detected by the tool during the analysis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("Run started."));
+    /// let invocation = Invocation::new(true).with_tool_execution_notification(notification.clone());
+    ///
+    /// assert_eq!(invocation.tool_execution_notifications, vec![notification]);
+    /// ```
+    #[must_use]
+    pub fn with_tool_execution_notification(mut self, notification: Notification<'s>) -> Self {
+        self.tool_execution_notifications.push(notification);
+        self
+    }
+
+    /// Add a condition detected by the tool, which is relevant to the tool's configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("unknown configuration option"));
+    /// let invocation =
+    ///     Invocation::new(true).with_tool_configuration_notification(notification.clone());
+    ///
+    /// assert_eq!(invocation.tool_configuration_notifications, vec![
+    ///     notification
+    /// ]);
+    /// ```
+    #[must_use]
+    pub fn with_tool_configuration_notification(mut self, notification: Notification<'s>) -> Self {
+        self.tool_configuration_notifications.push(notification);
+        self
+    }
+
+    /// Set the machine that hosted the analysis tool run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_machine("BLD01")
+    ///     .with_account("buildAgent")
+    ///     .with_process_id(1218);
+    ///
+    /// assert_eq!(invocation.machine.as_deref(), Some("BLD01"));
+    /// assert_eq!(invocation.account.as_deref(), Some("buildAgent"));
+    /// assert_eq!(invocation.process_id, Some(1218));
+    /// ```
+    #[must_use]
+    pub fn with_machine(mut self, machine: impl Into<Cow<'s, str>>) -> Self {
+        self.machine = Some(machine.into());
+        self
+    }
+
+    /// Set the account that ran the analysis tool.
+    ///
+    /// See [`with_machine`] for an example.
+    ///
+    /// [`with_machine`]: Self::with_machine
+    #[must_use]
+    pub fn with_account(mut self, account: impl Into<Cow<'s, str>>) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// Set the process id of the analysis tool run.
+    ///
+    /// See [`with_machine`] for an example.
+    ///
+    /// [`with_machine`]: Self::with_machine
+    #[must_use]
+    pub const fn with_process_id(mut self, process_id: u32) -> Self {
+        self.process_id = Some(process_id);
+        self
+    }
+
+    /// Set the working directory of the analysis tool run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_working_directory(ArtifactLocation::new("file:///home/buildAgent/src"));
+    ///
+    /// assert_eq!(
+    ///     invocation.working_directory,
+    ///     Some(ArtifactLocation::new("file:///home/buildAgent/src"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_working_directory(mut self, working_directory: ArtifactLocation<'s>) -> Self {
+        self.working_directory = Some(working_directory);
+        self
+    }
+
+    /// Add a property to the invocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_properties(|properties| properties.with_tag("ci"));
+    ///
+    /// assert!(invocation.properties.tags.iter().eq(["ci"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, Invocation, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent,
+    };
+
+    #[test]
+    fn successful() {
+        let invocation = Invocation::new(true)
+            .with_command_line("cargo clippy --workspace")
+            .with_arguments(["clippy", "--workspace"])
+            .with_start_time_utc("2016-07-16T14:18:25Z")
+            .with_end_time_utc("2016-07-16T14:19:01Z")
+            .with_exit_code(0)
+            .with_machine("BLD01")
+            .with_account("buildAgent")
+            .with_process_id(1218)
+            .with_working_directory(ArtifactLocation::new("file:///home/buildAgent/src"));
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(invocation);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn failed() {
+        let invocation = Invocation::new(false)
+            .with_exit_code(137)
+            .with_exit_code_description("killed")
+            .with_exit_signal("SIGKILL", 9)
+            .with_process_start_failure_message("out of memory");
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(invocation);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
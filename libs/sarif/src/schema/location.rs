@@ -0,0 +1,178 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Message, PhysicalLocation, PropertyBag};
+
+/// A location within a programming artifact ([§3.28]).
+///
+/// [§3.28]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317670
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Location<'s> {
+    /// Value that distinguishes this location from all other locations within a single result
+    /// object ([§3.28.2]).
+    ///
+    /// Embedded links in a [`Message`] refer to a location by this identifier.
+    ///
+    /// [§3.28.2]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317672
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<usize>,
+
+    /// Identifies the artifact and region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub physical_location: Option<PhysicalLocation<'s>>,
+
+    /// A message relevant to the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// Key/value pairs that provide additional information about the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Location<'s> {
+    /// Create a new, empty `Location`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location = Location::new();
+    ///
+    /// assert_eq!(location.id, None);
+    /// assert_eq!(location.physical_location, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            id: None,
+            physical_location: None,
+            message: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the identifier of the location within a result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location = Location::new().with_id(2);
+    ///
+    /// assert_eq!(location.id, Some(2));
+    /// ```
+    #[must_use]
+    pub const fn with_id(mut self, id: usize) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Set the physical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, PhysicalLocation};
+    ///
+    /// let physical_location =
+    ///     PhysicalLocation::new().with_artifact_location(ArtifactLocation::new("src/main.rs"));
+    /// let location = Location::new().with_physical_location(physical_location.clone());
+    ///
+    /// assert_eq!(location.physical_location, Some(physical_location));
+    /// ```
+    #[must_use]
+    pub fn with_physical_location(mut self, physical_location: PhysicalLocation<'s>) -> Self {
+        self.physical_location = Some(physical_location);
+        self
+    }
+
+    /// Set the message relevant to the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message};
+    ///
+    /// let location = Location::new().with_message(Message::new("variable declared here"));
+    ///
+    /// assert_eq!(
+    ///     location.message.and_then(|message| message.text).as_deref(),
+    ///     Some("variable declared here")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Add a property to the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location = Location::new().with_properties(|properties| properties.with_tag("generated"));
+    ///
+    /// assert!(location.properties.tags.iter().eq(["generated"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, Location, Message, PhysicalLocation, Result, Run,
+        SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn related() {
+        let declaration = Location::new()
+            .with_id(0)
+            .with_message(Message::new("variable `x` declared here"))
+            .with_physical_location(
+                PhysicalLocation::new().with_artifact_location(ArtifactLocation::new("src/lib.rs")),
+            );
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(
+            Result::new(Message::new("unused variable [`x`](0)"))
+                .with_location(Location::new())
+                .with_related_location(declaration),
+        );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
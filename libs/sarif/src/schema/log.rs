@@ -1,9 +1,13 @@
 use alloc::{borrow::Cow, vec, vec::Vec};
+use core::mem;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::schema::{Run, SchemaVersion};
+use crate::{
+    builder::RunBuilder,
+    schema::{Result, Run, SchemaVersion, ToolComponent},
+};
 
 /// Specifies the version of the file format and contains the output from one or more runs.
 ///
@@ -95,7 +99,9 @@ pub struct SarifLog<'s> {
 
 #[cfg(feature = "serde")]
 impl<'s, 'de: 's> Deserialize<'de> for SarifLog<'s> {
-    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
         #[derive(Deserialize)]
         #[serde(rename_all = "camelCase")]
         struct OptionalSarifLog<'s> {
@@ -204,6 +210,123 @@ impl<'s> SarifLog<'s> {
         }
         self
     }
+
+    /// Returns an iterator over the results of all runs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent};
+    ///
+    /// let log = SarifLog::new(SchemaVersion::V2_1_0).with_runs([
+    ///     Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///         .with_result(Result::new(Message::new("unused variable `x`"))),
+    ///     Run::new(Tool::new(ToolComponent::new("rustfmt")))
+    ///         .with_result(Result::new(Message::new("incorrect formatting"))),
+    /// ]);
+    ///
+    /// assert_eq!(log.results().count(), 2);
+    /// ```
+    pub fn results(&self) -> impl Iterator<Item = &Result<'s>> {
+        self.runs
+            .iter()
+            .flatten()
+            .flat_map(|run| run.results.iter().flatten())
+    }
+
+    /// Append the runs of another log to this log.
+    ///
+    /// The runs are kept as they are, use [`merge_runs`] to combine runs of the same tool.
+    ///
+    /// [`merge_runs`]: Self::merge_runs
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Run, SarifLog, SchemaVersion, Tool, ToolComponent};
+    ///
+    /// let mut log = SarifLog::new(SchemaVersion::V2_1_0)
+    ///     .with_run(Run::new(Tool::new(ToolComponent::new("clippy"))));
+    /// log.merge(
+    ///     SarifLog::new(SchemaVersion::V2_1_0)
+    ///         .with_run(Run::new(Tool::new(ToolComponent::new("rustfmt")))),
+    /// );
+    ///
+    /// assert_eq!(log.runs.unwrap().len(), 2);
+    /// ```
+    pub fn merge(&mut self, other: Self) {
+        if let Some(runs) = other.runs {
+            self.extend(runs);
+        }
+    }
+
+    /// Combine all runs of the same tool into a single run.
+    ///
+    /// Runs are considered to be of the same tool if the [`name`], the [`version`], and the
+    /// [`semantic_version`] of their driver are equal. The combined run is placed at the position
+    /// of the first run of the tool, see [`RunBuilder::merge`] for how the runs are combined.
+    ///
+    /// [`name`]: ToolComponent::name
+    /// [`version`]: ToolComponent::version
+    /// [`semantic_version`]: ToolComponent::semantic_version
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent};
+    ///
+    /// let clippy = Run::new(Tool::new(ToolComponent::new("clippy")));
+    /// let rustfmt = Run::new(Tool::new(ToolComponent::new("rustfmt")));
+    ///
+    /// let mut log = SarifLog::new(SchemaVersion::V2_1_0).with_runs([
+    ///     clippy
+    ///         .clone()
+    ///         .with_result(Result::new(Message::new("unused variable `x`"))),
+    ///     rustfmt.clone(),
+    ///     clippy.with_result(Result::new(Message::new("unused variable `y`"))),
+    /// ]);
+    /// log.merge_runs();
+    ///
+    /// let runs = log.runs.unwrap();
+    /// assert_eq!(runs.len(), 2);
+    /// assert_eq!(runs[0].results.as_ref().map(Vec::len), Some(2));
+    /// assert_eq!(runs[1], rustfmt);
+    /// ```
+    pub fn merge_runs(&mut self) {
+        let Some(runs) = &mut self.runs else {
+            return;
+        };
+
+        // Runs without results, e.g. runs only exporting rule metadata, must not gain results
+        let mut merged: Vec<(RunBuilder<'s>, bool)> = Vec::new();
+        for run in mem::take(runs) {
+            let has_results = run.results.is_some();
+            match merged
+                .iter_mut()
+                .find(|(builder, _)| is_same_tool(&builder.run().tool.driver, &run.tool.driver))
+            {
+                Some((builder, merged_has_results)) => {
+                    builder.merge(run);
+                    *merged_has_results |= has_results;
+                }
+                None => merged.push((RunBuilder::from_run(run), has_results)),
+            }
+        }
+
+        runs.extend(merged.into_iter().map(|(builder, has_results)| {
+            let mut run = builder.build();
+            if !has_results {
+                run.results = None;
+            }
+            run
+        }));
+    }
+}
+
+fn is_same_tool(lhs: &ToolComponent, rhs: &ToolComponent) -> bool {
+    lhs.name == rhs.name
+        && lhs.version == rhs.version
+        && lhs.semantic_version == rhs.semantic_version
 }
 
 impl<'s> Extend<Run<'s>> for SarifLog<'s> {
@@ -218,7 +341,7 @@ impl<'s> Extend<Run<'s>> for SarifLog<'s> {
 #[cfg(test)]
 #[cfg(feature = "serde")]
 pub(crate) mod tests {
-    use alloc::{borrow::Cow, vec};
+    use alloc::{borrow::Cow, vec, vec::Vec};
 
     use coverage_helper::test;
     use semver::Version;
@@ -226,8 +349,8 @@ pub(crate) mod tests {
     use serde_json::json;
 
     use crate::schema::{
-        tests::validate_schema, ReportingDescriptor, Run, SarifLog, SchemaVersion, Tool,
-        ToolComponent,
+        tests::validate_schema, Artifact, ArtifactLocation, Location, Message, PhysicalLocation,
+        ReportingDescriptor, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
     };
 
     #[test]
@@ -315,6 +438,80 @@ pub(crate) mod tests {
         assert_eq!(log, log_2);
     }
 
+    #[test]
+    fn merge() {
+        let mut log = SarifLog::new(SchemaVersion::V2_1_0)
+            .with_run(Run::new(Tool::new(ToolComponent::new("clippy"))));
+        log.merge(SarifLog {
+            schema: SchemaVersion::V2_1_0.schema_id().into(),
+            version: SchemaVersion::V2_1_0,
+            runs: None,
+        });
+        log.merge(
+            SarifLog::new(SchemaVersion::V2_1_0)
+                .with_run(Run::new(Tool::new(ToolComponent::new("clippy")))),
+        );
+        validate_schema(&log);
+
+        assert_eq!(log.runs.expect("no runs found").len(), 2);
+    }
+
+    #[test]
+    fn merge_runs() {
+        fn result(file: &'static str, index: usize) -> Result<'static> {
+            Result::new(Message::new("mismatched types"))
+                .with_rule_id("E0308")
+                .with_location(
+                    Location::new().with_physical_location(
+                        PhysicalLocation::new()
+                            .with_artifact_location(ArtifactLocation::new(file).with_index(index)),
+                    ),
+                )
+        }
+
+        let rustc = |version| {
+            Tool::new(
+                ToolComponent::new("rustc")
+                    .with_semantic_version(Version::new(1, version, 0))
+                    .with_rule(ReportingDescriptor::new("E0308")),
+            )
+        };
+
+        let mut log = SarifLog::new(SchemaVersion::V2_1_0).with_runs([
+            Run::new(rustc(70))
+                .with_artifact(Artifact::new().with_location(ArtifactLocation::new("src/lib.rs")))
+                .with_result(result("src/lib.rs", 0)),
+            Run::new(Tool::new(ToolComponent::new("rustfmt"))),
+            Run::new(rustc(70))
+                .with_artifact(Artifact::new().with_location(ArtifactLocation::new("src/main.rs")))
+                .with_artifact(Artifact::new().with_location(ArtifactLocation::new("src/lib.rs")))
+                .with_results([result("src/main.rs", 0), result("src/lib.rs", 1)]),
+            Run::new(rustc(71)).with_results([]),
+            Run::new(Tool::new(ToolComponent::new("rustfmt"))),
+        ]);
+        log.merge_runs();
+        validate_schema(&log);
+
+        let runs = log.runs.expect("no runs found");
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].tool.driver.rules.len(), 1);
+        assert_eq!(runs[0].artifacts.len(), 2);
+        assert!(
+            runs[0]
+                .results
+                .iter()
+                .flatten()
+                .map(|result| result.locations[0]
+                    .physical_location
+                    .as_ref()
+                    .and_then(|location| location.artifact_location.as_ref())
+                    .and_then(|location| location.index))
+                .eq([Some(0), Some(1), Some(0)])
+        );
+        assert_eq!(runs[1].results, None);
+        assert_eq!(runs[2].results.as_ref().map(Vec::len), Some(0));
+    }
+
     #[test]
     fn full() {
         validate_schema(
@@ -480,3 +480,126 @@ pub struct Message<'s> {
     )]
     pub properties: PropertyBag<'s>,
 }
+
+impl<'s> Message<'s> {
+    /// Create a new `Message` with the given plain text message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("unused variable `x`");
+    ///
+    /// assert_eq!(message.text.as_deref(), Some("unused variable `x`"));
+    /// assert_eq!(message.id, None);
+    /// ```
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: Some(text.into()),
+            markdown: None,
+            id: None,
+            arguments: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Create a new `Message`, which refers to a message string by its identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::from_id("variableDeclared").with_argument("ptr");
+    ///
+    /// assert_eq!(message.id.as_deref(), Some("variableDeclared"));
+    /// assert_eq!(message.text, None);
+    /// ```
+    #[must_use]
+    pub fn from_id(id: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: None,
+            markdown: None,
+            id: Some(id.into()),
+            arguments: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the Markdown formatted message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("unused variable \"x\"").with_markdown("unused variable `x`");
+    ///
+    /// assert_eq!(message.markdown.as_deref(), Some("unused variable `x`"));
+    /// ```
+    #[must_use]
+    pub fn with_markdown(mut self, markdown: impl Into<Cow<'s, str>>) -> Self {
+        self.markdown = Some(markdown.into());
+        self
+    }
+
+    /// Add an argument to substitute into the message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("unused variable `{0}`").with_argument("x");
+    ///
+    /// assert_eq!(message.arguments, ["x"]);
+    /// ```
+    #[must_use]
+    pub fn with_argument(mut self, argument: impl Into<Cow<'s, str>>) -> Self {
+        self.arguments.push(argument.into());
+        self
+    }
+
+    /// Add arguments to substitute into the message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("`{0}` is not `{1}`").with_arguments(["x", "Send"]);
+    ///
+    /// assert_eq!(message.arguments, ["x", "Send"]);
+    /// ```
+    #[must_use]
+    pub fn with_arguments(
+        mut self,
+        arguments: impl IntoIterator<Item = impl Into<Cow<'s, str>>>,
+    ) -> Self {
+        self.arguments.extend(arguments.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add a property to the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("unused variable `x`")
+    ///     .with_properties(|properties| properties.with_tag("lint"));
+    ///
+    /// assert!(message.properties.tags.iter().eq(["lint"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
@@ -0,0 +1,217 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Level, Location, Message, PropertyBag};
+
+/// Describes a condition relevant to the tool itself, as opposed to being relevant to a target
+/// being analyzed by the tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Notification<'s> {
+    /// The locations relevant to this notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub locations: Vec<Location<'s>>,
+
+    /// A message that describes the condition that was encountered.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: Message<'s>,
+
+    /// A value specifying the severity level of the notification.
+    ///
+    /// If absent, the level is [`Level::Warning`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<Level>,
+
+    /// The thread identifier of the code that generated the notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub thread_id: Option<u64>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the analysis tool generated the
+    /// notification, formatted according to ISO 8601.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub time_utc: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Notification<'s> {
+    /// Create a new `Notification` with the given message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("Run started."));
+    ///
+    /// assert_eq!(notification.message, Message::new("Run started."));
+    /// assert_eq!(notification.level, None);
+    /// ```
+    #[must_use]
+    pub const fn new(message: Message<'s>) -> Self {
+        Self {
+            locations: Vec::new(),
+            message,
+            level: None,
+            thread_id: None,
+            time_utc: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Add a location relevant to the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, Message, Notification, PhysicalLocation};
+    ///
+    /// let location = Location::new().with_physical_location(
+    ///     PhysicalLocation::new().with_artifact_location(ArtifactLocation::new("clippy.toml")),
+    /// );
+    /// let notification = Notification::new(Message::new("unknown configuration option"))
+    ///     .with_location(location.clone());
+    ///
+    /// assert_eq!(notification.locations, vec![location]);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Set the severity level of the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Level, Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("Run started.")).with_level(Level::Note);
+    ///
+    /// assert_eq!(notification.level, Some(Level::Note));
+    /// ```
+    #[must_use]
+    pub const fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Set the identifier of the thread, which generated the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("Run started.")).with_thread_id(52);
+    ///
+    /// assert_eq!(notification.thread_id, Some(52));
+    /// ```
+    #[must_use]
+    pub const fn with_thread_id(mut self, thread_id: u64) -> Self {
+        self.thread_id = Some(thread_id);
+        self
+    }
+
+    /// Set the time the notification was generated, formatted according to ISO 8601.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification =
+    ///     Notification::new(Message::new("Run started.")).with_time_utc("2016-07-16T14:18:43Z");
+    ///
+    /// assert_eq!(
+    ///     notification.time_utc.as_deref(),
+    ///     Some("2016-07-16T14:18:43Z")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_time_utc(mut self, time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.time_utc = Some(time_utc.into());
+        self
+    }
+
+    /// Add a property to the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("Run started."))
+    ///     .with_properties(|properties| properties.with_tag("lifecycle"));
+    ///
+    /// assert!(notification.properties.tags.iter().eq(["lifecycle"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, Invocation, Level, Location, Message,
+        Notification, PhysicalLocation, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn notifications() {
+        let configuration = Notification::new(Message::new("unknown configuration option"))
+            .with_level(Level::Warning)
+            .with_location(
+                Location::new().with_physical_location(
+                    PhysicalLocation::new()
+                        .with_artifact_location(ArtifactLocation::new("clippy.toml")),
+                ),
+            );
+        let execution = Notification::new(Message::new("Run started."))
+            .with_level(Level::Note)
+            .with_thread_id(52)
+            .with_time_utc("2016-07-16T14:18:43Z");
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(
+            Invocation::new(true)
+                .with_tool_configuration_notification(configuration)
+                .with_tool_execution_notification(execution),
+        );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
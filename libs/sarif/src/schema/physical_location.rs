@@ -0,0 +1,190 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactLocation, PropertyBag, Region};
+
+/// A physical location relevant to a result.
+///
+/// Specifies a reference to a programming artifact together with a range of bytes or characters
+/// within that artifact.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct PhysicalLocation<'s> {
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub artifact_location: Option<ArtifactLocation<'s>>,
+
+    /// Specifies a portion of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub region: Option<Region<'s>>,
+
+    /// Specifies a portion of the artifact that encloses the region.
+    ///
+    /// Allows a viewer to display additional context around the region. If present, [`region`]
+    /// **shall** also be present.
+    ///
+    /// [`region`]: Self::region
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub context_region: Option<Region<'s>>,
+
+    /// Key/value pairs that provide additional information about the physical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> PhysicalLocation<'s> {
+    /// Create a new, empty `PhysicalLocation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::PhysicalLocation;
+    ///
+    /// let physical_location = PhysicalLocation::new();
+    ///
+    /// assert_eq!(physical_location.artifact_location, None);
+    /// assert_eq!(physical_location.region, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            artifact_location: None,
+            region: None,
+            context_region: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the location of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation};
+    ///
+    /// let physical_location =
+    ///     PhysicalLocation::new().with_artifact_location(ArtifactLocation::new("src/main.rs"));
+    ///
+    /// assert_eq!(
+    ///     physical_location.artifact_location,
+    ///     Some(ArtifactLocation::new("src/main.rs"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_artifact_location(mut self, artifact_location: ArtifactLocation<'s>) -> Self {
+        self.artifact_location = Some(artifact_location);
+        self
+    }
+
+    /// Set the region within the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{PhysicalLocation, Region};
+    ///
+    /// let physical_location = PhysicalLocation::new().with_region(Region::new().with_start_line(4));
+    ///
+    /// assert_eq!(
+    ///     physical_location
+    ///         .region
+    ///         .and_then(|region| region.start_line),
+    ///     Some(4)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_region(mut self, region: Region<'s>) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Set the region enclosing the [`region`] to provide additional context.
+    ///
+    /// [`region`]: Self::region
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{PhysicalLocation, Region};
+    ///
+    /// let physical_location = PhysicalLocation::new()
+    ///     .with_region(Region::new().with_start_line(4))
+    ///     .with_context_region(Region::new().with_start_line(3).with_end_line(5));
+    ///
+    /// assert_eq!(
+    ///     physical_location
+    ///         .context_region
+    ///         .and_then(|region| region.end_line),
+    ///     Some(5)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_context_region(mut self, context_region: Region<'s>) -> Self {
+        self.context_region = Some(context_region);
+        self
+    }
+
+    /// Add a property to the physical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::PhysicalLocation;
+    ///
+    /// let physical_location =
+    ///     PhysicalLocation::new().with_properties(|properties| properties.with_tag("macro"));
+    ///
+    /// assert!(physical_location.properties.tags.iter().eq(["macro"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, Location, Message, PhysicalLocation, Region,
+        Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn context_region() {
+        let physical_location = PhysicalLocation::new()
+            .with_artifact_location(ArtifactLocation::new("src/lib.rs"))
+            .with_region(Region::new().with_start_line(4))
+            .with_context_region(Region::new().with_start_line(3).with_end_line(5));
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(
+            Result::new(Message::new("unused variable `x`"))
+                .with_location(Location::new().with_physical_location(physical_location)),
+        );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -0,0 +1,371 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactContent, Message, PropertyBag};
+
+/// A region within an artifact where a result was detected.
+///
+/// A region is either specified in terms of lines and columns ("text region"), character offsets
+/// or byte offsets. Lines and columns are `1`-based, offsets are `0`-based.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Region<'s> {
+    /// The line number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_line: Option<usize>,
+
+    /// The column number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_column: Option<usize>,
+
+    /// The line number of the last character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_line: Option<usize>,
+
+    /// The column number of the character following the end of the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_column: Option<usize>,
+
+    /// The zero-based offset from the beginning of the artifact of the first character in the
+    /// region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_offset: Option<usize>,
+
+    /// The length of the region in characters.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_length: Option<usize>,
+
+    /// The zero-based offset from the beginning of the artifact of the first byte in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_offset: Option<usize>,
+
+    /// The length of the region in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_length: Option<usize>,
+
+    /// The portion of the artifact contents within the specified region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub snippet: Option<ArtifactContent<'s>>,
+
+    /// A message relevant to the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// Specifies the source language, if any, of the portion of the artifact specified by the
+    /// region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Region<'s> {
+    /// Create a new, empty `Region`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new();
+    ///
+    /// assert_eq!(region.start_line, None);
+    /// assert_eq!(region.snippet, None);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            start_line: None,
+            start_column: None,
+            end_line: None,
+            end_column: None,
+            char_offset: None,
+            char_length: None,
+            byte_offset: None,
+            byte_length: None,
+            snippet: None,
+            message: None,
+            source_language: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the line of the first character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start_line(12);
+    ///
+    /// assert_eq!(region.start_line, Some(12));
+    /// ```
+    #[must_use]
+    pub const fn with_start_line(mut self, start_line: usize) -> Self {
+        self.start_line = Some(start_line);
+        self
+    }
+
+    /// Set the column of the first character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start_line(12).with_start_column(5);
+    ///
+    /// assert_eq!(region.start_column, Some(5));
+    /// ```
+    #[must_use]
+    pub const fn with_start_column(mut self, start_column: usize) -> Self {
+        self.start_column = Some(start_column);
+        self
+    }
+
+    /// Set the line of the last character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start_line(12).with_end_line(14);
+    ///
+    /// assert_eq!(region.end_line, Some(14));
+    /// ```
+    #[must_use]
+    pub const fn with_end_line(mut self, end_line: usize) -> Self {
+        self.end_line = Some(end_line);
+        self
+    }
+
+    /// Set the column of the character following the end of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_start_line(12).with_end_column(20);
+    ///
+    /// assert_eq!(region.end_column, Some(20));
+    /// ```
+    #[must_use]
+    pub const fn with_end_column(mut self, end_column: usize) -> Self {
+        self.end_column = Some(end_column);
+        self
+    }
+
+    /// Set the region in terms of characters from the beginning of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_char_range(254, 1);
+    ///
+    /// assert_eq!(region.char_offset, Some(254));
+    /// assert_eq!(region.char_length, Some(1));
+    /// ```
+    #[must_use]
+    pub const fn with_char_range(mut self, offset: usize, length: usize) -> Self {
+        self.char_offset = Some(offset);
+        self.char_length = Some(length);
+        self
+    }
+
+    /// Set the region in terms of bytes from the beginning of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_byte_range(1024, 16);
+    ///
+    /// assert_eq!(region.byte_offset, Some(1024));
+    /// assert_eq!(region.byte_length, Some(16));
+    /// ```
+    #[must_use]
+    pub const fn with_byte_range(mut self, offset: usize, length: usize) -> Self {
+        self.byte_offset = Some(offset);
+        self.byte_length = Some(length);
+        self
+    }
+
+    /// Set the contents of the artifact within the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactContent, Region};
+    ///
+    /// let region = Region::new()
+    ///     .with_start_line(12)
+    ///     .with_snippet(ArtifactContent::text("let x = 5;"));
+    ///
+    /// assert_eq!(
+    ///     region.snippet.and_then(|snippet| snippet.text).as_deref(),
+    ///     Some("let x = 5;")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_snippet(mut self, snippet: ArtifactContent<'s>) -> Self {
+        self.snippet = Some(snippet);
+        self
+    }
+
+    /// Set the message relevant to the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Region};
+    ///
+    /// let region = Region::new()
+    ///     .with_start_line(12)
+    ///     .with_message(Message::new("`x` is never read"));
+    ///
+    /// assert_eq!(region.message, Some(Message::new("`x` is never read")));
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Set the source language of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new()
+    ///     .with_start_line(12)
+    ///     .with_source_language("rust");
+    ///
+    /// assert_eq!(region.source_language.as_deref(), Some("rust"));
+    /// ```
+    #[must_use]
+    pub fn with_source_language(mut self, source_language: impl Into<Cow<'s, str>>) -> Self {
+        self.source_language = Some(source_language.into());
+        self
+    }
+
+    /// Add a property to the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new().with_properties(|properties| properties.with_tag("expanded"));
+    ///
+    /// assert!(region.properties.tags.iter().eq(["expanded"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactContent, ArtifactLocation, Location, Message,
+        PhysicalLocation, Region, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    fn log_with_region(region: Region) -> SarifLog {
+        let location = Location::new().with_physical_location(
+            PhysicalLocation::new()
+                .with_artifact_location(ArtifactLocation::new("src/lib.rs"))
+                .with_region(region),
+        );
+
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(
+            Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(
+                Result::new(Message::new("unused variable `x`")).with_location(location),
+            ),
+        )
+    }
+
+    #[test]
+    fn text_region() {
+        validate_schema(&log_with_region(
+            Region::new()
+                .with_start_line(12)
+                .with_start_column(9)
+                .with_end_line(12)
+                .with_end_column(10)
+                .with_snippet(ArtifactContent::text("let x = 5;"))
+                .with_message(Message::new("`x` is never read"))
+                .with_source_language("rust"),
+        ));
+    }
+
+    #[test]
+    fn offset_region() {
+        validate_schema(&log_with_region(
+            Region::new()
+                .with_char_range(254, 1)
+                .with_byte_range(254, 1),
+        ));
+    }
+}
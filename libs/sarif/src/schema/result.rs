@@ -0,0 +1,622 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::{ArtifactLocation, CodeFlow, Location, Message, PropertyBag};
+
+/// The nature of a [`Result`].
+///
+/// If [`Result::kind`] is absent, it **shall** default to [`ResultKind::Fail`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ResultKind {
+    /// The rule was not evaluated, because it does not apply to the analysis target.
+    NotApplicable,
+    /// The rule was evaluated, and the tool concluded that there was no violation.
+    Pass,
+    /// The rule was evaluated, and the tool concluded that there was a violation.
+    #[default]
+    Fail,
+    /// The rule was evaluated, and the tool concluded that a human is required to decide whether
+    /// there was a violation.
+    Review,
+    /// The tool was unable to conclude whether the rule was violated.
+    Open,
+    /// The result does not represent a rule violation, but is provided for informational purposes.
+    Informational,
+}
+
+/// The severity of a [`Result`] or a [`Notification`].
+///
+/// If the `level` is absent, it **shall** default to [`Level::Warning`].
+///
+/// [`Notification`]: crate::schema::Notification
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Level {
+    /// The concept of “severity” does not apply to this result.
+    None,
+    /// A minor problem or an opportunity to improve the code was found.
+    Note,
+    /// A problem was found, which is not considered serious.
+    #[default]
+    Warning,
+    /// A serious problem was found.
+    Error,
+}
+
+/// The state of a [`Result`] relative to a baseline of a previous run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum BaselineState {
+    /// The result was detected in the current run but was not detected in the baseline run.
+    New,
+    /// The result was detected in both the current run and the baseline run, and did not change
+    /// between them.
+    Unchanged,
+    /// The result was detected in both the current run and the baseline run, but changed between
+    /// them in a way that the tool considers significant.
+    Updated,
+    /// The result was detected in the baseline run but was not detected in the current run.
+    Absent,
+}
+
+/// A result produced by an analysis tool ([§3.27]).
+///
+/// A `Result` object describes a single result detected by an analysis tool.
+///
+/// [§3.27]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317638
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Result<'s> {
+    /// The stable, unique identifier of the rule, if any, to which this result is relevant.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule_id: Option<Cow<'s, str>>,
+
+    /// The index within the tool component rules array of the rule object associated with this
+    /// result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule_index: Option<usize>,
+
+    /// The nature of the result.
+    ///
+    /// If absent, the kind is [`ResultKind::Fail`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub kind: Option<ResultKind>,
+
+    /// The severity of the result.
+    ///
+    /// If absent, the level is [`Level::Warning`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<Level>,
+
+    /// A message that describes the result ([§3.27.11]).
+    ///
+    /// The first sentence of the message only will be displayed when visible space is limited.
+    ///
+    /// [§3.27.11]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317649
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: Message<'s>,
+
+    /// Identifies the artifact that the analysis tool was instructed to scan.
+    ///
+    /// This need not be the same as the artifact where the result actually occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub analysis_target: Option<ArtifactLocation<'s>>,
+
+    /// The set of locations where the result was detected.
+    ///
+    /// Specify only one location unless the problem indicated by the result can only be corrected
+    /// by making a change at every specified location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub locations: Vec<Location<'s>>,
+
+    /// A stable, unique identifier for the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// A stable, unique identifier for the equivalence class of logically identical results to
+    /// which this result belongs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub correlation_guid: Option<Uuid>,
+
+    /// The number of times this logically unique result was observed in this run.
+    ///
+    /// If present, the value **shall** be at least `1`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub occurrence_count: Option<u64>,
+
+    /// A set of strings that contribute to the stable, unique identity of the result.
+    ///
+    /// The keys are the names of the fingerprint algorithms, usually suffixed by a version, e.g.
+    /// `"primaryLocationLineHash/v1"`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub partial_fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// A set of strings each of which individually defines a stable, unique identity for the
+    /// result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// An array of code flows which describe the progress of a program through a thread of
+    /// execution.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub code_flows: Vec<CodeFlow<'s>>,
+
+    /// A set of locations relevant to this result.
+    ///
+    /// Messages in the result may refer to these locations by their [`Location::id`] using
+    /// embedded links.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub related_locations: Vec<Location<'s>>,
+
+    /// The state of the result with respect to a previous baseline run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub baseline_state: Option<BaselineState>,
+
+    /// Key/value pairs that provide additional information about the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Result<'s> {
+    /// Create a new `Result` with the given message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable `x`"));
+    ///
+    /// assert_eq!(result.message.text.as_deref(), Some("unused variable `x`"));
+    /// assert!(result.locations.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new(message: Message<'s>) -> Self {
+        Self {
+            rule_id: None,
+            rule_index: None,
+            kind: None,
+            level: None,
+            message,
+            analysis_target: None,
+            locations: Vec::new(),
+            guid: None,
+            correlation_guid: None,
+            occurrence_count: None,
+            partial_fingerprints: BTreeMap::new(),
+            fingerprints: BTreeMap::new(),
+            code_flows: Vec::new(),
+            related_locations: Vec::new(),
+            baseline_state: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the identifier of the rule this result is relevant to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable `x`")).with_rule_id("unused_variables");
+    ///
+    /// assert_eq!(result.rule_id.as_deref(), Some("unused_variables"));
+    /// ```
+    #[must_use]
+    pub fn with_rule_id(mut self, rule_id: impl Into<Cow<'s, str>>) -> Self {
+        self.rule_id = Some(rule_id.into());
+        self
+    }
+
+    /// Set the index of the rule this result is relevant to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable `x`")).with_rule_index(3);
+    ///
+    /// assert_eq!(result.rule_index, Some(3));
+    /// ```
+    #[must_use]
+    pub const fn with_rule_index(mut self, rule_index: usize) -> Self {
+        self.rule_index = Some(rule_index);
+        self
+    }
+
+    /// Set the kind of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, ResultKind};
+    ///
+    /// let result = Result::new(Message::new("no unsafe code found")).with_kind(ResultKind::Pass);
+    ///
+    /// assert_eq!(result.kind, Some(ResultKind::Pass));
+    /// ```
+    #[must_use]
+    pub const fn with_kind(mut self, kind: ResultKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Set the level of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Level, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_level(Level::Error);
+    ///
+    /// assert_eq!(result.level, Some(Level::Error));
+    /// ```
+    #[must_use]
+    pub const fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Set the artifact that the analysis tool was instructed to scan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable `x`"))
+    ///     .with_analysis_target(ArtifactLocation::new("src/lib.rs"));
+    ///
+    /// assert_eq!(
+    ///     result.analysis_target,
+    ///     Some(ArtifactLocation::new("src/lib.rs"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_analysis_target(mut self, analysis_target: ArtifactLocation<'s>) -> Self {
+        self.analysis_target = Some(analysis_target);
+        self
+    }
+
+    /// Add a location where the result was detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, Message, PhysicalLocation, Result};
+    ///
+    /// let location = Location::new().with_physical_location(
+    ///     PhysicalLocation::new().with_artifact_location(ArtifactLocation::new("src/lib.rs")),
+    /// );
+    /// let result = Result::new(Message::new("unused variable `x`")).with_location(location.clone());
+    ///
+    /// assert_eq!(result.locations, vec![location]);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Add locations where the result was detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("duplicated code"))
+    ///     .with_locations([Location::new().with_id(0), Location::new().with_id(1)]);
+    ///
+    /// assert_eq!(result.locations.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_locations(mut self, locations: impl IntoIterator<Item = Location<'s>>) -> Self {
+        self.locations.extend(locations);
+        self
+    }
+
+    /// Set the stable, unique identifier of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    /// use uuid::Uuid;
+    ///
+    /// let guid = Uuid::from_u128(0x8F62_D8A0_C14F_4516_9959_1A66_3BA6_FB99);
+    /// let result = Result::new(Message::new("unused variable `x`")).with_guid(guid);
+    ///
+    /// assert_eq!(result.guid, Some(guid));
+    /// ```
+    #[must_use]
+    pub const fn with_guid(mut self, guid: Uuid) -> Self {
+        self.guid = Some(guid);
+        self
+    }
+
+    /// Set the identifier of the equivalence class of logically identical results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    /// use uuid::Uuid;
+    ///
+    /// let guid = Uuid::from_u128(0xBC65_0830_A9FE_44CB_8818_AD6C_3872_79A0);
+    /// let result = Result::new(Message::new("unused variable `x`")).with_correlation_guid(guid);
+    ///
+    /// assert_eq!(result.correlation_guid, Some(guid));
+    /// ```
+    #[must_use]
+    pub const fn with_correlation_guid(mut self, correlation_guid: Uuid) -> Self {
+        self.correlation_guid = Some(correlation_guid);
+        self
+    }
+
+    /// Set the number of times this result was observed in this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable `x`")).with_occurrence_count(2);
+    ///
+    /// assert_eq!(result.occurrence_count, Some(2));
+    /// ```
+    #[must_use]
+    pub const fn with_occurrence_count(mut self, occurrence_count: u64) -> Self {
+        self.occurrence_count = Some(occurrence_count);
+        self
+    }
+
+    /// Add a partial fingerprint, which contributes to the identity of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable `x`"))
+    ///     .with_partial_fingerprint("primaryLocationLineHash/v1", "39fa2ee980eb94b0:1");
+    ///
+    /// assert_eq!(
+    ///     result.partial_fingerprints["primaryLocationLineHash/v1"],
+    ///     "39fa2ee980eb94b0:1"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_partial_fingerprint(
+        mut self,
+        key: impl Into<Cow<'s, str>>,
+        fingerprint: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.partial_fingerprints
+            .insert(key.into(), fingerprint.into());
+        self
+    }
+
+    /// Add a fingerprint, which on its own defines the identity of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable `x`"))
+    ///     .with_fingerprint("stableResultHash/v1", "8d1ac8ad9c9b6e0e");
+    ///
+    /// assert_eq!(
+    ///     result.fingerprints["stableResultHash/v1"],
+    ///     "8d1ac8ad9c9b6e0e"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_fingerprint(
+        mut self,
+        key: impl Into<Cow<'s, str>>,
+        fingerprint: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.fingerprints.insert(key.into(), fingerprint.into());
+        self
+    }
+
+    /// Add a code flow to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Location, Message, Result, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new(ThreadFlowLocation::new(
+    ///     Location::new().with_message(Message::new("variable `x` declared")),
+    /// )));
+    /// let result = Result::new(Message::new("unused variable `x`")).with_code_flow(code_flow.clone());
+    ///
+    /// assert_eq!(result.code_flows, vec![code_flow]);
+    /// ```
+    #[must_use]
+    pub fn with_code_flow(mut self, code_flow: CodeFlow<'s>) -> Self {
+        self.code_flows.push(code_flow);
+        self
+    }
+
+    /// Add a location relevant to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, Result};
+    ///
+    /// let related = Location::new()
+    ///     .with_id(0)
+    ///     .with_message(Message::new("variable `x` declared here"));
+    /// let result = Result::new(Message::new("unused variable `[x](0)`"))
+    ///     .with_related_location(related.clone());
+    ///
+    /// assert_eq!(result.related_locations, vec![related]);
+    /// ```
+    #[must_use]
+    pub fn with_related_location(mut self, location: Location<'s>) -> Self {
+        self.related_locations.push(location);
+        self
+    }
+
+    /// Set the state of the result relative to a baseline run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{BaselineState, Message, Result};
+    ///
+    /// let result =
+    ///     Result::new(Message::new("unused variable `x`")).with_baseline_state(BaselineState::New);
+    ///
+    /// assert_eq!(result.baseline_state, Some(BaselineState::New));
+    /// ```
+    #[must_use]
+    pub const fn with_baseline_state(mut self, baseline_state: BaselineState) -> Self {
+        self.baseline_state = Some(baseline_state);
+        self
+    }
+
+    /// Add a property to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("unused variable `x`"))
+    ///     .with_properties(|properties| properties.with_tag("maintainability"));
+    ///
+    /// assert!(result.properties.tags.iter().eq(["maintainability"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+    use uuid::Uuid;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, BaselineState, Level, Location, Message,
+        PhysicalLocation, Region, Result, ResultKind, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent,
+    };
+
+    #[test]
+    fn minimal() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_result(Result::new(Message::new("unused variable `x`")));
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn full() {
+        let location = Location::new().with_physical_location(
+            PhysicalLocation::new()
+                .with_artifact_location(ArtifactLocation::new("src/lib.rs"))
+                .with_region(Region::new().with_start_line(3).with_start_column(9)),
+        );
+
+        let result = Result::new(Message::new("unused variable `x`"))
+            .with_rule_id("unused_variables")
+            .with_rule_index(0)
+            .with_kind(ResultKind::Fail)
+            .with_level(Level::Warning)
+            .with_analysis_target(ArtifactLocation::new("src/lib.rs"))
+            .with_location(location)
+            .with_guid(Uuid::from_u128(0x8F62_D8A0_C14F_4516_9959_1A66_3BA6_FB99))
+            .with_correlation_guid(Uuid::from_u128(0xBC65_0830_A9FE_44CB_8818_AD6C_3872_79A0))
+            .with_occurrence_count(1)
+            .with_partial_fingerprint("primaryLocationLineHash/v1", "39fa2ee980eb94b0:1")
+            .with_fingerprint("stableResultHash/v1", "8d1ac8ad9c9b6e0e")
+            .with_baseline_state(BaselineState::New)
+            .with_properties(|properties| properties.with_tag("maintainability"));
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -1,7 +1,9 @@
+use alloc::vec::Vec;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::schema::Tool;
+use crate::schema::{Artifact, Invocation, Result, Tool};
 
 /// Describes a single run of an analysis tool, and contains the reported output of that run.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,6 +21,33 @@ pub struct Run<'s> {
     /// command-line arguments and the like) is identical for all aggregated files.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub tool: Tool<'s>,
+
+    /// Describes the invocation of the analysis tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub invocations: Vec<Invocation<'s>>,
+
+    /// An array of artifact objects relevant to the run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub artifacts: Vec<Artifact<'s>>,
+
+    /// The set of results contained in a SARIF log.
+    ///
+    /// The results array can be omitted when a run is solely exporting rules metadata. It must be
+    /// present (but may be empty) if a log file represents an actual scan. This is why [`None`]
+    /// is distinct from an empty array: the former indicates, that the tool did not produce any
+    /// results (e.g. because it failed), while the latter indicates, that the tool ran
+    /// successfully and did not find any problems.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub results: Option<Vec<Result<'s>>>,
 }
 
 impl<'s> Run<'s> {
@@ -35,7 +64,97 @@ impl<'s> Run<'s> {
     /// ```
     #[must_use]
     pub const fn new(tool: Tool<'s>) -> Self {
-        Self { tool }
+        Self {
+            tool,
+            invocations: Vec::new(),
+            artifacts: Vec::new(),
+            results: None,
+        }
+    }
+
+    /// Add an invocation of the analysis tool to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_invocation(Invocation::new(true).with_exit_code(0));
+    ///
+    /// assert_eq!(run.invocations, vec![
+    ///     Invocation::new(true).with_exit_code(0)
+    /// ]);
+    /// ```
+    #[must_use]
+    pub fn with_invocation(mut self, invocation: Invocation<'s>) -> Self {
+        self.invocations.push(invocation);
+        self
+    }
+
+    /// Add an artifact to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation, Run, Tool, ToolComponent};
+    ///
+    /// let artifact = Artifact::new().with_location(ArtifactLocation::new("src/lib.rs"));
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_artifact(artifact.clone());
+    ///
+    /// assert_eq!(run.artifacts, vec![artifact]);
+    /// ```
+    #[must_use]
+    pub fn with_artifact(mut self, artifact: Artifact<'s>) -> Self {
+        self.artifacts.push(artifact);
+        self
+    }
+
+    /// Add a result to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_result(Result::new(Message::new("unused variable `x`")));
+    ///
+    /// assert_eq!(
+    ///     run.results,
+    ///     Some(vec![Result::new(Message::new("unused variable `x`"))])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_result(mut self, result: Result<'s>) -> Self {
+        self.results.get_or_insert_with(Vec::new).push(result);
+        self
+    }
+
+    /// Add results to the run.
+    ///
+    /// Calling this with an empty iterator marks the run as successful without any results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([
+    ///     Result::new(Message::new("unused variable `x`")),
+    ///     Result::new(Message::new("unused variable `y`")),
+    /// ]);
+    ///
+    /// assert_eq!(run.results.map(|results| results.len()), Some(2));
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([]);
+    ///
+    /// assert_eq!(run.results, Some(Vec::new()));
+    /// ```
+    #[must_use]
+    pub fn with_results(mut self, results: impl IntoIterator<Item = Result<'s>>) -> Self {
+        self.results.get_or_insert_with(Vec::new).extend(results);
+        self
     }
 }
 
@@ -54,4 +173,11 @@ pub(crate) mod tests {
 
         validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
     }
+
+    #[test]
+    fn no_results() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([]);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
 }
@@ -0,0 +1,311 @@
+//! Streaming serialization of SARIF logs.
+//!
+//! Analysis tools may report thousands of results, which makes collecting all of them into a
+//! [`SarifLog`] before serializing it expensive. [`SarifWriter`] writes a log incrementally to an
+//! [`io::Write`]r instead: the properties of a [`Run`] are written first, followed by every
+//! [`Result`] as soon as it is produced. The written document is equivalent to serializing the
+//! [`SarifLog`] containing all runs and results.
+//!
+//! [`SarifLog`]: crate::schema::SarifLog
+
+use alloc::string::String;
+use std::io;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::schema::{Result, Run, SchemaVersion};
+
+/// Writes a SARIF log incrementally to an [`io::Write`]r.
+///
+/// Runs are started with [`begin_run`], which returns a [`RunWriter`] to stream the results of the
+/// run. A run is closed when the next run is started or when the log is [`finish`]ed.
+///
+/// If the `SarifWriter` is dropped without calling [`finish`], the written document is
+/// incomplete.
+///
+/// [`begin_run`]: Self::begin_run
+/// [`finish`]: Self::finish
+///
+/// # Example
+///
+/// ```
+/// use sarif::{
+///     schema::{Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent},
+///     writer::SarifWriter,
+/// };
+///
+/// let mut writer = SarifWriter::new(Vec::new(), SchemaVersion::V2_1_0)?;
+///
+/// let run = Run::new(Tool::new(ToolComponent::new("clippy")));
+/// let mut results = writer.begin_run(&run)?;
+/// for variable in ["x", "y"] {
+///     results.write_result(&Result::new(
+///         Message::new("unused variable `{0}`").with_argument(variable),
+///     ))?;
+/// }
+///
+/// let output = writer.finish()?;
+/// let log: SarifLog = serde_json::from_slice(&output)?;
+///
+/// assert_eq!(
+///     log,
+///     SarifLog::new(SchemaVersion::V2_1_0).with_run(run.with_results([
+///         Result::new(Message::new("unused variable `{0}`").with_argument("x")),
+///         Result::new(Message::new("unused variable `{0}`").with_argument("y")),
+///     ]))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct SarifWriter<W> {
+    writer: W,
+    runs: usize,
+    run_open: bool,
+}
+
+impl<W: io::Write> SarifWriter<W> {
+    /// Create a new `SarifWriter` and write the header of the log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn new(writer: W, version: SchemaVersion) -> io::Result<Self> {
+        let mut this = Self {
+            writer,
+            runs: 0,
+            run_open: false,
+        };
+
+        this.writer.write_all(b"{\"version\":")?;
+        serde_json::to_writer(&mut this.writer, &version)?;
+        this.writer.write_all(b",\"$schema\":")?;
+        serde_json::to_writer(&mut this.writer, version.schema_id())?;
+        this.writer.write_all(b",\"runs\":[")?;
+
+        Ok(this)
+    }
+
+    fn end_run(&mut self) -> io::Result<()> {
+        if self.run_open {
+            self.writer.write_all(b"]}")?;
+            self.run_open = false;
+        }
+
+        Ok(())
+    }
+
+    /// Start a new run, closing the previous one.
+    ///
+    /// All properties of `run` are written immediately, including any [`results`] it already
+    /// contains. Additional results are written by the returned [`RunWriter`].
+    ///
+    /// [`results`]: Run::results
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `run` cannot be serialized or writing to the underlying writer fails.
+    pub fn begin_run(&mut self, run: &Run) -> io::Result<RunWriter<'_, W>> {
+        self.end_run()?;
+
+        let mut properties = match serde_json::to_value(run)? {
+            Value::Object(properties) => properties,
+            _ => Map::new(),
+        };
+        let results = properties.remove("results");
+
+        if self.runs > 0 {
+            self.writer.write_all(b",")?;
+        }
+        self.writer.write_all(b"{")?;
+        write_properties(&mut self.writer, &properties)?;
+        if !properties.is_empty() {
+            self.writer.write_all(b",")?;
+        }
+        self.writer.write_all(b"\"results\":[")?;
+
+        self.runs += 1;
+        self.run_open = true;
+
+        let mut run_writer = RunWriter {
+            writer: self,
+            results: 0,
+        };
+        if let Some(Value::Array(results)) = results {
+            for result in &results {
+                run_writer.write(result)?;
+            }
+        }
+
+        Ok(run_writer)
+    }
+
+    /// Close the current run and the log and return the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to, or flushing, the underlying writer fails.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.end_run()?;
+        self.writer.write_all(b"]}")?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+fn write_properties(
+    writer: &mut impl io::Write,
+    properties: &Map<String, Value>,
+) -> io::Result<()> {
+    for (index, (key, value)) in properties.iter().enumerate() {
+        if index > 0 {
+            writer.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut *writer, key)?;
+        writer.write_all(b":")?;
+        serde_json::to_writer(&mut *writer, value)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the results of a run started by [`SarifWriter::begin_run`].
+///
+/// Results are written as they are, a [`Result::rule_index`] is not resolved. To reference the
+/// rules of the run by index, they have to be added to the driver of the [`Run`] before the run is
+/// started, e.g. using [`RunBuilder`].
+///
+/// [`RunBuilder`]: crate::builder::RunBuilder
+#[derive(Debug)]
+pub struct RunWriter<'w, W> {
+    writer: &'w mut SarifWriter<W>,
+    results: usize,
+}
+
+impl<W: io::Write> RunWriter<'_, W> {
+    fn write(&mut self, result: &impl Serialize) -> io::Result<()> {
+        if self.results > 0 {
+            self.writer.writer.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut self.writer.writer, result)?;
+        self.results += 1;
+
+        Ok(())
+    }
+
+    /// Write a single result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `result` cannot be serialized or writing to the underlying writer
+    /// fails.
+    pub fn write_result(&mut self, result: &Result) -> io::Result<()> {
+        self.write(result)
+    }
+
+    /// Write all results of the iterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a result cannot be serialized or writing to the underlying writer
+    /// fails.
+    pub fn write_results<'r, 's: 'r>(
+        &mut self,
+        results: impl IntoIterator<Item = &'r Result<'s>>,
+    ) -> io::Result<()> {
+        results
+            .into_iter()
+            .try_for_each(|result| self.write_result(result))
+    }
+
+    /// The number of results written to this run.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.results
+    }
+
+    /// Returns `true` if no result has been written to this run.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.results == 0
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloc::vec::Vec;
+
+    use coverage_helper::test;
+
+    use crate::{
+        builder::RunBuilder,
+        schema::{
+            tests::validate_schema, Invocation, Message, ReportingDescriptor, Result, Run,
+            SarifLog, SchemaVersion, Tool, ToolComponent,
+        },
+        writer::SarifWriter,
+    };
+
+    fn read(output: &[u8]) -> SarifLog {
+        let log = serde_json::from_slice(output).expect("could not parse SARIF log");
+        validate_schema(&log);
+        log
+    }
+
+    #[test]
+    fn empty() {
+        let writer = SarifWriter::new(Vec::new(), SchemaVersion::V2_1_0).expect("could not write");
+        let output = writer.finish().expect("could not write");
+
+        assert_eq!(read(&output), SarifLog::new(SchemaVersion::V2_1_0));
+        assert_eq!(
+            output,
+            serde_json::to_vec(&SarifLog::new(SchemaVersion::V2_1_0)).expect("could not serialize")
+        );
+    }
+
+    #[test]
+    fn runs() {
+        let mut builder = RunBuilder::new(
+            ToolComponent::new("clippy").with_rule(ReportingDescriptor::new("unused_variables")),
+        );
+        builder.add_invocation(Invocation::new(true));
+        let results = ["x", "y", "z"].map(|variable| {
+            Result::new(Message::new("unused variable `{0}`").with_argument(variable))
+                .with_rule_id("unused_variables")
+                .with_rule_index(
+                    builder
+                        .rule_index("unused_variables")
+                        .expect("rule missing"),
+                )
+        });
+        let clippy = builder.run().clone();
+        let rustfmt = Run::new(Tool::new(ToolComponent::new("rustfmt")))
+            .with_result(Result::new(Message::new("incorrect formatting")));
+
+        let mut writer =
+            SarifWriter::new(Vec::new(), SchemaVersion::V2_1_0).expect("could not write");
+
+        let mut run = writer.begin_run(&clippy).expect("could not write");
+        run.write_results(&results).expect("could not write");
+        assert_eq!(run.len(), 3);
+
+        let run = writer.begin_run(&rustfmt).expect("could not write");
+        assert_eq!(run.len(), 1);
+
+        let run = writer
+            .begin_run(&Run::new(Tool::new(ToolComponent::new("rustc"))))
+            .expect("could not write");
+        assert!(run.is_empty());
+
+        let output = writer.finish().expect("could not write");
+
+        let expected = SarifLog::new(SchemaVersion::V2_1_0).with_runs([
+            clippy.with_results(results),
+            rustfmt,
+            Run::new(Tool::new(ToolComponent::new("rustc"))).with_results([]),
+        ]);
+        assert_eq!(read(&output), expected);
+    }
+}